@@ -19,6 +19,7 @@ use qail_core::fmt::Formatter;
 use qail_core::prelude::*;
 use qail_core::transpiler::{Dialect, ToSql};
 
+use qail::explain::explain_live;
 use qail::introspection;
 use qail::lint::lint_schema;
 #[cfg(feature = "watch")]
@@ -28,11 +29,16 @@ use qail::migrations::{
     migrate_apply, migrate_down, migrate_plan, migrate_reset, migrate_rollback, migrate_status,
     migrate_up,
 };
+use qail::query_fmt::format_query_file;
+#[cfg(feature = "watch")]
+use qail::query_watch::watch_queries;
 #[cfg(feature = "repl")]
 use qail::repl::run_repl;
 use qail::resolve::resolve_db_url;
 use qail::schema::{OutputFormat as SchemaOutputFormat, check_schema, diff_schemas_cmd};
-use qail::schema_tools::{doctor_schema, format_schema_source, merge_schema, split_schema};
+use qail::schema_tools::{
+    SchemaFormat, convert_schema, doctor_schema, format_schema_source, merge_schema, split_schema,
+};
 
 #[derive(Parser)]
 #[command(name = "qail")]
@@ -120,6 +126,21 @@ impl From<CliMigrateDirection> for MigrateDirection {
     }
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum CliSchemaFormat {
+    Qail,
+    Json,
+}
+
+impl From<CliSchemaFormat> for SchemaFormat {
+    fn from(value: CliSchemaFormat) -> Self {
+        match value {
+            CliSchemaFormat::Qail => SchemaFormat::Qail,
+            CliSchemaFormat::Json => SchemaFormat::Json,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize a new QAIL project
@@ -144,11 +165,20 @@ enum Commands {
         #[arg(long)]
         deployment: Option<String>,
     },
-    /// Parse and explain a QAIL query
-    Explain { query: String },
+    /// Parse and explain a QAIL query (with --url, print the live query plan)
+    Explain {
+        query: String,
+        /// Database URL; plans the query with EXPLAIN (FORMAT JSON) without running it
+        #[arg(long)]
+        url: Option<String>,
+    },
     /// Interactive QAIL REPL — type queries, see SQL in real-time
     #[cfg(feature = "repl")]
-    Repl,
+    Repl {
+        /// Database URL; also runs each query and prints its rows
+        #[arg(long)]
+        url: Option<String>,
+    },
 
     /// Generate a migration file
     Mig {
@@ -189,8 +219,14 @@ EXAMPLES:
         #[arg(long)]
         ssh: Option<String>,
     },
-    /// Format a QAIL query or schema source path (file/dir)
-    Fmt { input: String },
+    /// Format a QAIL query, statement file, or schema source path (file/dir)
+    #[command(visible_alias = "format")]
+    Fmt {
+        input: String,
+        /// Rewrite statement files in place instead of printing a diff
+        #[arg(long)]
+        write: bool,
+    },
     /// Validate a QAIL schema file (and optionally audit source for RLS coverage)
     Check {
         /// Schema file path (or old:new for migration validation)
@@ -229,10 +265,10 @@ EXAMPLES:
         #[arg(long)]
         strict: bool,
     },
-    /// Watch schema file for changes and auto-generate migrations [requires --features watch]
+    /// Watch a schema file (auto-generate migrations) or a statement file (live SQL) [requires --features watch]
     #[cfg(feature = "watch")]
     Watch {
-        /// Schema file to watch
+        /// Schema source or `.qail` statement file to watch
         schema: String,
         /// Database URL to apply changes to (optional)
         #[arg(short, long)]
@@ -590,6 +626,17 @@ enum SchemaAction {
         #[arg(short, long, default_value = "schema.qail")]
         output: String,
     },
+    /// Convert a schema between QAIL and JSON
+    Convert {
+        /// Input schema (`.json`, `.qail` or schema/ directory)
+        input: String,
+        /// Target format
+        #[arg(long, value_enum)]
+        to: CliSchemaFormat,
+        /// Output file (default: stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -671,13 +718,19 @@ enum MigrateAction {
     qail migrate plan v1.qail:v2.qail
     
     # Save generated SQL to a file
-    qail migrate plan v1.qail:v2.qail -o migration.sql"#)]
+    qail migrate plan v1.qail:v2.qail -o migration.sql
+
+    # Machine-readable change list (added/dropped tables, columns, types)
+    qail migrate plan v1.qail:v2.qail --json"#)]
     Plan {
         /// Schema diff (old.qail:new.qail)
         schema_diff: String,
         /// Save SQL to file
         #[arg(short, long)]
         output: Option<String>,
+        /// Output classified changes and SQL as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Apply migrations (forward)
     #[command(after_help = r#"SCHEMA DIFF FORMAT:
@@ -711,7 +764,7 @@ EXAMPLES:
         /// Codebase path to scan for breaking changes (blocks if found)
         #[arg(short, long)]
         codebase: Option<String>,
-        /// Force migration even if breaking changes detected
+        /// Force migration even if breaking changes or edited applied migrations are detected
         #[arg(long)]
         force: bool,
         /// Explicitly allow destructive migration operations
@@ -1035,9 +1088,15 @@ async fn main() -> Result<()> {
         }) => {
             qail::init::run_init(name.clone(), mode.clone(), url.clone(), deployment.clone())?;
         }
-        Some(Commands::Explain { query }) => explain_query(query),
+        Some(Commands::Explain { query, url: None }) => explain_query(query),
+        Some(Commands::Explain {
+            query,
+            url: Some(url),
+        }) => {
+            explain_live(query, Some(url), cli.dialect.clone().into()).await?;
+        }
         #[cfg(feature = "repl")]
-        Some(Commands::Repl) => run_repl(),
+        Some(Commands::Repl { url }) => run_repl(url.as_deref(), cli.dialect.clone().into()).await,
 
         Some(Commands::Mig { query, name }) => {
             generate_migration(query, name.clone())?;
@@ -1046,8 +1105,8 @@ async fn main() -> Result<()> {
             let db_url = resolve_db_url(url.as_deref())?;
             introspection::pull_schema(&db_url, introspection::SchemaOutputFormat::Qail).await?;
         }
-        Some(Commands::Fmt { input }) => {
-            format_input(input)?;
+        Some(Commands::Fmt { input, write }) => {
+            format_input(input, *write)?;
         }
         Some(Commands::Check {
             schema,
@@ -1087,7 +1146,12 @@ async fn main() -> Result<()> {
             url,
             auto_apply,
         }) => {
-            watch_schema(schema, url.as_deref(), *auto_apply).await?;
+            let path = std::path::Path::new(schema.as_str());
+            if path.is_file() && !is_schema_file(path)? {
+                watch_queries(schema, cli.dialect.clone().into())?;
+            } else {
+                watch_schema(schema, url.as_deref(), *auto_apply).await?;
+            }
         }
         Some(Commands::Migrate { action }) => match action {
             MigrateAction::Status { url } => {
@@ -1103,7 +1167,8 @@ async fn main() -> Result<()> {
             MigrateAction::Plan {
                 schema_diff,
                 output,
-            } => migrate_plan(schema_diff, output.as_deref())?,
+                json,
+            } => migrate_plan(schema_diff, output.as_deref(), *json)?,
             MigrateAction::Up {
                 schema_diff,
                 url,
@@ -1388,6 +1453,9 @@ async fn main() -> Result<()> {
             SchemaAction::Merge { input, output } => {
                 merge_schema(input, output)?;
             }
+            SchemaAction::Convert { input, to, output } => {
+                convert_schema(input, (*to).into(), output.as_deref())?;
+            }
         },
         None => {
             if let Some(query) = &cli.query {
@@ -1437,8 +1505,12 @@ fn transpile_query(query: &str, cli: &Cli) -> Result<()> {
     Ok(())
 }
 
-fn format_input(input: &str) -> Result<()> {
+fn format_input(input: &str, write: bool) -> Result<()> {
     let path = std::path::Path::new(input);
+    if path.is_file() && !is_schema_file(path)? {
+        format_query_file(path, write)?;
+        return Ok(());
+    }
     if path.exists() {
         return format_schema_source(input);
     }
@@ -1452,6 +1524,13 @@ fn format_input(input: &str) -> Result<()> {
     Ok(())
 }
 
+/// Schema files parse as `table ... { }` definitions; statement files don't.
+fn is_schema_file(path: &std::path::Path) -> Result<bool> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+    Ok(qail_core::migrate::parse_qail(&content).is_ok())
+}
+
 fn generate_migration(query: &str, name_override: Option<String>) -> Result<()> {
     let cmd = qail_core::parse(query).map_err(|e| anyhow::anyhow!("Parse error: {}", e))?;
 
@@ -1499,6 +1578,21 @@ fn explain_query(query: &str) {
                 println!("  {} {}", "Columns:".dimmed(), cmd.columns.len());
             }
 
+            for hint in cmd.index_usable_filters() {
+                if let Some(reason) = hint.non_sargable {
+                    let why = match reason {
+                        qail_core::ast::NonSargableReason::LeadingWildcard => "leading wildcard",
+                        qail_core::ast::NonSargableReason::FuzzyMatch => "fuzzy match",
+                    };
+                    println!(
+                        "  {} {} ({}, cannot use an index)",
+                        "⚠ Filter:".yellow(),
+                        hint.condition,
+                        why
+                    );
+                }
+            }
+
             println!();
             println!("  {} {}", "SQL:".cyan(), cmd.to_sql().white().bold());
         }
@@ -44,10 +44,11 @@
 //! ```
 
 use crate::colors::*;
+use crate::query_fmt::{QailSegment, split_qail_segments};
 use anyhow::Result;
 use qail_core::prelude::*;
 use qail_core::transpiler::ToSql;
-use qail_pg::PgDriver;
+use qail_pg::{PgDriver, QueryResult};
 
 /// Configuration for exec command
 pub struct ExecConfig {
@@ -159,60 +160,13 @@ fn build_ssh_tunnel_args(
 }
 
 fn split_qail_statements(content: &str) -> Vec<String> {
-    let mut statements = Vec::new();
-    let mut current = String::new();
-    let mut in_triple_single = false;
-    let mut in_triple_double = false;
-    let mut chars = content.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        // Check for triple quotes
-        if c == '\'' && !in_triple_double {
-            if chars.peek() == Some(&'\'') {
-                chars.next();
-                if chars.peek() == Some(&'\'') {
-                    chars.next();
-                    current.push_str("'''");
-                    in_triple_single = !in_triple_single;
-                    continue;
-                } else {
-                    current.push_str("''");
-                    continue;
-                }
-            }
-        } else if c == '"' && !in_triple_single && chars.peek() == Some(&'"') {
-            chars.next();
-            if chars.peek() == Some(&'"') {
-                chars.next();
-                current.push_str("\"\"\"");
-                in_triple_double = !in_triple_double;
-                continue;
-            } else {
-                current.push_str("\"\"");
-                continue;
-            }
-        }
-
-        // Handle newlines - statement boundary if not in multi-line string
-        if c == '\n' && !in_triple_single && !in_triple_double {
-            let trimmed = current.trim();
-            if !trimmed.is_empty() && !trimmed.starts_with('#') && !trimmed.starts_with("--") {
-                statements.push(current.trim().to_string());
-            }
-            current.clear();
-            continue;
-        }
-
-        current.push(c);
-    }
-
-    // Don't forget the last statement
-    let trimmed = current.trim();
-    if !trimmed.is_empty() && !trimmed.starts_with('#') && !trimmed.starts_with("--") {
-        statements.push(current.trim().to_string());
-    }
-
-    statements
+    split_qail_segments(content)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            QailSegment::Statement(statement) => Some(statement),
+            QailSegment::Blank | QailSegment::Comment(_) => None,
+        })
+        .collect()
 }
 
 /// Run the exec command (type-safe QAIL AST only)
@@ -359,78 +313,7 @@ pub async fn run_exec(config: ExecConfig) -> Result<()> {
                     } else {
                         println!("{}", "✓".green());
                         success_count += 1;
-
-                        if result.columns.is_empty() {
-                            println!("  {}", "(no columns)".dimmed());
-                        } else {
-                            // Calculate column widths
-                            let mut widths: Vec<usize> =
-                                result.columns.iter().map(|c| c.len()).collect();
-                            for row in &result.rows {
-                                for (j, col) in row.iter().enumerate() {
-                                    if j < widths.len() {
-                                        let len = col.as_ref().map(|s| s.len()).unwrap_or(1); // "∅"
-                                        if len > widths[j] {
-                                            widths[j] = len;
-                                        }
-                                    }
-                                }
-                            }
-                            // Cap column widths at 40 chars for readability
-                            for w in widths.iter_mut() {
-                                if *w > 40 {
-                                    *w = 40;
-                                }
-                            }
-
-                            // Print header
-                            println!();
-                            let header: Vec<String> = result
-                                .columns
-                                .iter()
-                                .enumerate()
-                                .map(|(j, c)| format!("{:<width$}", c, width = widths[j]))
-                                .collect();
-                            println!("  {}", header.join(" │ ").cyan().bold());
-
-                            // Print separator
-                            let sep: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
-                            println!("  {}", sep.join("─┼─").dimmed());
-
-                            // Print rows
-                            for row in &result.rows {
-                                let cells: Vec<String> = row
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(j, col)| {
-                                        let val = col
-                                            .as_ref()
-                                            .map(|s| {
-                                                if s.len() > 40 {
-                                                    format!("{}…", &s[..39])
-                                                } else {
-                                                    s.clone()
-                                                }
-                                            })
-                                            .unwrap_or_else(|| "∅".to_string());
-                                        let w = if j < widths.len() {
-                                            widths[j]
-                                        } else {
-                                            val.len()
-                                        };
-                                        format!("{:<width$}", val, width = w)
-                                    })
-                                    .collect();
-                                println!("  {}", cells.join(" │ "));
-                            }
-
-                            // Row count
-                            println!(
-                                "\n  {} {} row(s)",
-                                "→".dimmed(),
-                                result.rows.len().to_string().green()
-                            );
-                        }
+                        print_result_table(&result);
                     }
                 }
                 Err(e) => {
@@ -494,6 +377,81 @@ pub async fn run_exec(config: ExecConfig) -> Result<()> {
     Ok(())
 }
 
+/// Print a row result as an aligned table, capping columns at 40 chars.
+pub(crate) fn print_result_table(result: &QueryResult) {
+    if result.columns.is_empty() {
+        println!("  {}", "(no columns)".dimmed());
+        return;
+    }
+
+    // Calculate column widths
+    let mut widths: Vec<usize> = result.columns.iter().map(|c| c.len()).collect();
+    for row in &result.rows {
+        for (j, col) in row.iter().enumerate() {
+            if j < widths.len() {
+                let len = col.as_ref().map(|s| s.len()).unwrap_or(1); // "∅"
+                if len > widths[j] {
+                    widths[j] = len;
+                }
+            }
+        }
+    }
+    // Cap column widths at 40 chars for readability
+    for w in widths.iter_mut() {
+        if *w > 40 {
+            *w = 40;
+        }
+    }
+
+    // Print header
+    println!();
+    let header: Vec<String> = result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(j, c)| format!("{:<width$}", c, width = widths[j]))
+        .collect();
+    println!("  {}", header.join(" │ ").cyan().bold());
+
+    // Print separator
+    let sep: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+    println!("  {}", sep.join("─┼─").dimmed());
+
+    // Print rows
+    for row in &result.rows {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(j, col)| {
+                let val = col
+                    .as_ref()
+                    .map(|s| {
+                        if s.len() > 40 {
+                            format!("{}…", &s[..39])
+                        } else {
+                            s.clone()
+                        }
+                    })
+                    .unwrap_or_else(|| "∅".to_string());
+                let w = if j < widths.len() {
+                    widths[j]
+                } else {
+                    val.len()
+                };
+                format!("{:<width$}", val, width = w)
+            })
+            .collect();
+        println!("  {}", cells.join(" │ "));
+    }
+
+    // Row count
+    println!(
+        "\n  {} {} row(s)",
+        "→".dimmed(),
+        result.rows.len().to_string().green()
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,191 @@
+//! `qail explain --url`: run a query through the planner and print the plan tree.
+
+use anyhow::{Result, anyhow, bail};
+use qail_core::transpiler::{Dialect, ToSql};
+use qail_pg::PgDriver;
+use serde_json::Value;
+
+use crate::colors::*;
+
+/// One rendered line of a plan tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanLine {
+    /// Tree-drawing prefix (`├─ `, `│  └─ `, ...).
+    pub prefix: String,
+    /// Node type plus target, e.g. `Seq Scan on users`.
+    pub label: String,
+    /// Estimated rows.
+    pub rows: u64,
+    /// Estimated startup cost.
+    pub startup_cost: f64,
+    /// Estimated total cost.
+    pub total_cost: f64,
+}
+
+impl PlanLine {
+    /// Plain-text rendering (no ANSI styling).
+    pub fn plain(&self) -> String {
+        format!(
+            "{}{}  (cost={:.2}..{:.2} rows={})",
+            self.prefix, self.label, self.startup_cost, self.total_cost, self.rows
+        )
+    }
+}
+
+/// Flatten PostgreSQL `EXPLAIN (FORMAT JSON)` output into tree lines.
+pub fn plan_tree_lines(explain_json: &str) -> Result<Vec<PlanLine>> {
+    let parsed: Value =
+        serde_json::from_str(explain_json).map_err(|e| anyhow!("Invalid EXPLAIN JSON: {}", e))?;
+    let root = parsed
+        .get(0)
+        .and_then(|entry| entry.get("Plan"))
+        .ok_or_else(|| anyhow!("EXPLAIN JSON has no top-level Plan"))?;
+
+    let mut lines = Vec::new();
+    push_plan_node(root, "", None, &mut lines)?;
+    Ok(lines)
+}
+
+fn push_plan_node(
+    node: &Value,
+    indent: &str,
+    is_last: Option<bool>,
+    lines: &mut Vec<PlanLine>,
+) -> Result<()> {
+    let node_type = node
+        .get("Node Type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("plan node is missing \"Node Type\""))?;
+
+    let mut label = node_type.to_string();
+    if let Some(index) = node.get("Index Name").and_then(Value::as_str) {
+        label.push_str(&format!(" using {}", index));
+    }
+    if let Some(relation) = node.get("Relation Name").and_then(Value::as_str) {
+        label.push_str(&format!(" on {}", relation));
+        if let Some(alias) = node.get("Alias").and_then(Value::as_str)
+            && alias != relation
+        {
+            label.push_str(&format!(" {}", alias));
+        }
+    }
+
+    let number = |key: &str| node.get(key).and_then(Value::as_f64).unwrap_or(0.0);
+    let (prefix, child_indent) = match is_last {
+        None => (String::new(), String::new()),
+        Some(true) => (format!("{indent}└─ "), format!("{indent}   ")),
+        Some(false) => (format!("{indent}├─ "), format!("{indent}│  ")),
+    };
+
+    lines.push(PlanLine {
+        prefix,
+        label,
+        rows: number("Plan Rows").max(0.0) as u64,
+        startup_cost: number("Startup Cost"),
+        total_cost: number("Total Cost"),
+    });
+
+    if let Some(children) = node.get("Plans").and_then(Value::as_array) {
+        for (idx, child) in children.iter().enumerate() {
+            let last = idx + 1 == children.len();
+            push_plan_node(child, &child_indent, Some(last), lines)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn print_plan_lines(lines: &[PlanLine]) {
+    for line in lines {
+        println!(
+            "  {}{}  {}",
+            line.prefix.dimmed(),
+            line.label.cyan().bold(),
+            format!(
+                "(cost={:.2}..{:.2} rows={})",
+                line.startup_cost, line.total_cost, line.rows
+            )
+            .dimmed()
+        );
+    }
+}
+
+/// Plan `query` against a live database and print the plan tree.
+///
+/// PostgreSQL runs `EXPLAIN (FORMAT JSON)`; the query itself is not executed.
+/// SQLite has no runtime driver in the CLI, so the `EXPLAIN QUERY PLAN`
+/// statement is printed for the caller to run.
+pub async fn explain_live(query: &str, url: Option<&str>, dialect: Dialect) -> Result<()> {
+    let cmd = qail_core::parse(query).map_err(|e| anyhow!("Parse error: {}", e))?;
+
+    if dialect == Dialect::SQLite {
+        println!(
+            "EXPLAIN QUERY PLAN {}",
+            cmd.to_sql_with_dialect(Dialect::SQLite)
+        );
+        return Ok(());
+    }
+
+    let db_url = crate::resolve::resolve_db_url(url)?;
+    let mut driver = PgDriver::connect_url(&db_url)
+        .await
+        .map_err(|e| anyhow!("Connection failed: {}", e))?;
+    let json = driver
+        .explain_json(&cmd)
+        .await
+        .map_err(|e| anyhow!("EXPLAIN failed: {}", e))?;
+    if json.is_empty() {
+        bail!("EXPLAIN returned no plan");
+    }
+
+    println!("{}", "🔍 Query Plan".cyan().bold());
+    println!();
+    println!("  {} {}", "SQL:".dimmed(), cmd.to_sql().white());
+    println!();
+    print_plan_lines(&plan_tree_lines(&json)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PLAN: &str = r#"[{"Plan": {
+        "Node Type": "Hash Join", "Startup Cost": 12.5, "Total Cost": 48.25, "Plan Rows": 120,
+        "Plans": [
+            {"Node Type": "Seq Scan", "Relation Name": "orders", "Alias": "o",
+             "Startup Cost": 0.0, "Total Cost": 22.0, "Plan Rows": 1200},
+            {"Node Type": "Hash", "Startup Cost": 10.0, "Total Cost": 10.0, "Plan Rows": 40,
+             "Plans": [
+                {"Node Type": "Index Scan", "Relation Name": "users", "Alias": "users",
+                 "Index Name": "users_pkey",
+                 "Startup Cost": 0.28, "Total Cost": 10.0, "Plan Rows": 40}
+             ]}
+        ]
+    }}]"#;
+
+    #[test]
+    fn plan_tree_renders_nested_nodes_with_costs() {
+        let rendered: Vec<String> = plan_tree_lines(SAMPLE_PLAN)
+            .unwrap()
+            .iter()
+            .map(PlanLine::plain)
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                "Hash Join  (cost=12.50..48.25 rows=120)",
+                "├─ Seq Scan on orders o  (cost=0.00..22.00 rows=1200)",
+                "└─ Hash  (cost=10.00..10.00 rows=40)",
+                "   └─ Index Scan using users_pkey on users  (cost=0.28..10.00 rows=40)",
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_tree_rejects_unexpected_shape() {
+        assert!(plan_tree_lines("[]").is_err());
+        assert!(plan_tree_lines(r#"[{"Plan": {"Total Cost": 1.0}}]"#).is_err());
+        assert!(plan_tree_lines("not json").is_err());
+    }
+}
@@ -33,10 +33,13 @@ pub mod backup;
 pub mod branch;
 pub mod colors;
 pub mod exec;
+pub mod explain;
 pub mod init;
 pub mod introspection;
 pub mod lint;
 pub mod migrations;
+pub mod query_fmt;
+pub mod query_watch;
 #[cfg(feature = "repl")]
 pub mod repl;
 pub mod resolve;
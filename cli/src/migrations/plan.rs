@@ -2,29 +2,36 @@
 
 use crate::colors::*;
 use anyhow::Result;
-use qail_core::migrate::{diff_schemas_checked, parse_qail_file};
+use qail_core::migrate::{diff_schemas_detailed, parse_qail_file};
 
 use crate::sql_gen::{cmd_to_sql, generate_rollback_sql};
 
 /// Preview migration SQL without executing (dry-run).
-pub fn migrate_plan(schema_diff_path: &str, output: Option<&str>) -> Result<()> {
-    println!("{}", "📋 Migration Plan (dry-run)".cyan().bold());
-    println!();
+///
+/// With `json`, prints the classified changes and the UP/DOWN SQL as a single
+/// JSON document instead of the boxed report.
+pub fn migrate_plan(schema_diff_path: &str, output: Option<&str>, json: bool) -> Result<()> {
+    if !json {
+        println!("{}", "📋 Migration Plan (dry-run)".cyan().bold());
+        println!();
+    }
 
-    let cmds = if schema_diff_path.contains(':') && !schema_diff_path.starts_with("postgres") {
+    let diff = if schema_diff_path.contains(':') && !schema_diff_path.starts_with("postgres") {
         let parts: Vec<&str> = schema_diff_path.splitn(2, ':').collect();
         let old_path = parts[0];
         let new_path = parts[1];
 
-        println!("  {} → {}", old_path.yellow(), new_path.yellow());
-        println!();
+        if !json {
+            println!("  {} → {}", old_path.yellow(), new_path.yellow());
+            println!();
+        }
 
         let old_schema = parse_qail_file(old_path)
             .map_err(|e| anyhow::anyhow!("Failed to parse old schema: {}", e))?;
         let new_schema = parse_qail_file(new_path)
             .map_err(|e| anyhow::anyhow!("Failed to parse new schema: {}", e))?;
 
-        diff_schemas_checked(&old_schema, &new_schema).map_err(|e| {
+        diff_schemas_detailed(&old_schema, &new_schema).map_err(|e| {
             anyhow::anyhow!("State-based diff unsupported for this schema pair: {}", e)
         })?
     } else {
@@ -33,6 +40,18 @@ pub fn migrate_plan(schema_diff_path: &str, output: Option<&str>) -> Result<()>
         ));
     };
 
+    let cmds = &diff.commands;
+
+    if json {
+        let report = serde_json::json!({
+            "changes": diff.changes,
+            "up": cmds.iter().map(cmd_to_sql).collect::<Vec<_>>(),
+            "down": cmds.iter().map(generate_rollback_sql).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
     if cmds.is_empty() {
         println!(
             "{}",
@@ -73,7 +92,7 @@ pub fn migrate_plan(schema_diff_path: &str, output: Option<&str>) -> Result<()>
     if let Some(path) = output {
         let mut content = String::new();
         content.push_str("-- Migration UP\n");
-        for cmd in &cmds {
+        for cmd in cmds {
             content.push_str(&format!("{};\n", cmd_to_sql(cmd)));
         }
         content.push_str("\n-- Migration DOWN (rollback)\n");
@@ -2,6 +2,7 @@
 
 use crate::colors::*;
 use anyhow::Result;
+use qail_core::ast::Qail;
 use qail_core::migrate::{diff_schemas_checked, parse_qail_file};
 use qail_core::transpiler::ToSql;
 use qail_pg::driver::PgDriver;
@@ -213,6 +214,49 @@ pub async fn migrate_up(
     )
     .await?;
 
+    // === PHASE 0.25: Applied Receipt Checksums ===
+    ensure_migration_table(&mut driver)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create migration table: {}", e))?;
+    let receipts_cmd =
+        Qail::get("_qail_migrations").columns(vec!["version", "name", "checksum", "sql_up"]);
+    let rows = driver
+        .fetch_all(&receipts_cmd)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to query applied migrations: {}", e))?;
+    let receipts: Vec<AppliedReceipt> = rows
+        .iter()
+        .map(|row| AppliedReceipt {
+            version: row.get_string(0).unwrap_or_default(),
+            name: row.get_string(1),
+            checksum: row.get_string(2),
+            sql_up: row.get_string(3),
+        })
+        .collect();
+    let tampered = tampered_receipts(&receipts);
+    if !tampered.is_empty() {
+        println!(
+            "{}",
+            "⚠️  Applied migrations were edited after being recorded:"
+                .yellow()
+                .bold()
+        );
+        for version in &tampered {
+            println!("   {} {}", "CHECKSUM MISMATCH".red(), version.yellow());
+        }
+        if !force {
+            return Err(anyhow::anyhow!(
+                "Migration blocked: stored SQL no longer matches the recorded checksum for {}.\n\
+                 Reconcile _qail_migrations or re-run with --force.",
+                tampered.join(", ")
+            ));
+        }
+        println!(
+            "{}",
+            "⚠️  Proceeding anyway due to --force flag...".yellow()
+        );
+    }
+
     // === PHASE 0.5: Shadow Receipt Verification ===
     if !policy.require_shadow_receipt {
         println!(
@@ -422,6 +466,34 @@ pub async fn migrate_up(
     Ok(())
 }
 
+/// A `_qail_migrations` row as read back for checksum verification.
+struct AppliedReceipt {
+    version: String,
+    name: Option<String>,
+    checksum: Option<String>,
+    sql_up: Option<String>,
+}
+
+/// Versions of receipts written by `migrate up`/`migrate down` whose stored SQL
+/// no longer hashes to the stored checksum.
+///
+/// Those receipts record `md5(sql_up)`; file-based `migrate apply` receipts use
+/// a command checksum instead and are verified by `migrate apply` itself.
+fn tampered_receipts(receipts: &[AppliedReceipt]) -> Vec<String> {
+    receipts
+        .iter()
+        .filter(|r| {
+            r.name
+                .as_deref()
+                .is_some_and(|name| name.starts_with("auto_") || name.starts_with("rollback_"))
+        })
+        .filter_map(|r| {
+            let (checksum, sql) = (r.checksum.as_deref()?, r.sql_up.as_deref()?);
+            (crate::time::md5_hex(sql) != checksum).then(|| r.version.clone())
+        })
+        .collect()
+}
+
 fn print_impact_warnings(impact: &qail_core::analyzer::MigrationImpact) {
     if impact.warnings.is_empty() {
         return;
@@ -468,7 +540,7 @@ fn print_impact_warnings(impact: &qail_core::analyzer::MigrationImpact) {
 
 #[cfg(test)]
 mod tests {
-    use super::{MigrateUpOptions, migrate_up};
+    use super::{AppliedReceipt, MigrateUpOptions, migrate_up, tampered_receipts};
     use std::fs;
     use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -538,4 +610,32 @@ table users {
             "blocked migration should return error (non-zero exit path)"
         );
     }
+
+    fn receipt(version: &str, name: &str, checksum: &str, sql: &str) -> AppliedReceipt {
+        AppliedReceipt {
+            version: version.to_string(),
+            name: Some(name.to_string()),
+            checksum: Some(checksum.to_string()),
+            sql_up: Some(sql.to_string()),
+        }
+    }
+
+    #[test]
+    fn tampered_receipt_checksum_is_reported() {
+        let sql = "ALTER TABLE users ADD COLUMN email TEXT;\n";
+        let intact = receipt("20240101", "auto_20240101", &crate::time::md5_hex(sql), sql);
+        let tampered = receipt(
+            "20240102",
+            "auto_20240102",
+            &crate::time::md5_hex(sql),
+            "ALTER TABLE users ADD COLUMN email VARCHAR(64);\n",
+        );
+        // File-based receipts use a command checksum, not md5(sql_up).
+        let applied = receipt("001_init", "001_init.up.qail", "cmds:abc", sql);
+
+        assert_eq!(
+            tampered_receipts(&[intact, tampered, applied]),
+            vec!["20240102".to_string()]
+        );
+    }
 }
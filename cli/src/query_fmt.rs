@@ -0,0 +1,327 @@
+//! Canonical formatting for `.qail` statement files.
+//!
+//! Statement files hold one QAIL command per line (triple-quoted strings may
+//! span lines), with `#` / `--` comment lines in between. Formatting keeps
+//! comments and paragraph breaks, and rewrites each statement through the
+//! AST `Formatter` onto a single line.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+use qail_core::fmt::Formatter;
+
+use crate::colors::*;
+
+/// One line-level unit of a `.qail` statement file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QailSegment {
+    /// Empty or whitespace-only line.
+    Blank,
+    /// Full-line `#` or `--` comment (trimmed).
+    Comment(String),
+    /// A statement, possibly spanning lines inside triple quotes (trimmed).
+    Statement(String),
+}
+
+/// Split statement-file content into blank lines, comments and statements.
+///
+/// Newlines inside `'''` / `"""` strings do not end a statement.
+pub fn split_qail_segments(content: &str) -> Vec<QailSegment> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut in_triple_single = false;
+    let mut in_triple_double = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        // Check for triple quotes
+        if c == '\'' && !in_triple_double {
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    current.push_str("'''");
+                    in_triple_single = !in_triple_single;
+                    continue;
+                } else {
+                    current.push_str("''");
+                    continue;
+                }
+            }
+        } else if c == '"' && !in_triple_single && chars.peek() == Some(&'"') {
+            chars.next();
+            if chars.peek() == Some(&'"') {
+                chars.next();
+                current.push_str("\"\"\"");
+                in_triple_double = !in_triple_double;
+                continue;
+            } else {
+                current.push_str("\"\"");
+                continue;
+            }
+        }
+
+        // Handle newlines - segment boundary if not in multi-line string
+        if c == '\n' && !in_triple_single && !in_triple_double {
+            segments.push(classify_segment(&current));
+            current.clear();
+            continue;
+        }
+
+        current.push(c);
+    }
+
+    // A trailing newline leaves nothing behind; anything else is a last line.
+    if !current.is_empty() {
+        segments.push(classify_segment(&current));
+    }
+
+    segments
+}
+
+fn classify_segment(raw: &str) -> QailSegment {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        QailSegment::Blank
+    } else if trimmed.starts_with('#') || trimmed.starts_with("--") {
+        QailSegment::Comment(trimmed.to_string())
+    } else {
+        QailSegment::Statement(trimmed.to_string())
+    }
+}
+
+/// Render one statement in canonical single-line form.
+///
+/// Statements carrying inline comments, or whose formatted form would not
+/// parse back to the same AST, are returned unchanged.
+pub fn format_statement(statement: &str) -> Result<String> {
+    let cmd = qail_core::parse(statement).map_err(|e| anyhow!("Parse error: {}", e))?;
+
+    if has_comment(statement) {
+        return Ok(statement.to_string());
+    }
+
+    let formatted = Formatter::new()
+        .format(&cmd)
+        .map_err(|e| anyhow!("Format error: {}", e))?;
+    let canonical = formatted
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match qail_core::parse(&canonical) {
+        Ok(reparsed) if reparsed == cmd => Ok(canonical),
+        _ => Ok(statement.to_string()),
+    }
+}
+
+/// Whether `statement` holds a `--` or `/* */` comment outside string
+/// literals, quoted identifiers and `$$` bodies.
+fn has_comment(statement: &str) -> bool {
+    let bytes = statement.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        match bytes[i] {
+            b'\'' | b'"' => {
+                let quote = bytes[i];
+                i += 1;
+                while i < bytes.len() {
+                    if bytes[i] == quote {
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+            }
+            b'$' if rest.starts_with(b"$$") => {
+                i = statement[i + 2..]
+                    .find("$$")
+                    .map_or(bytes.len(), |end| i + 2 + end + 1);
+            }
+            _ if rest.starts_with(b"--") || rest.starts_with(b"/*") => return true,
+            _ => {}
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Format a whole statement file.
+///
+/// Comments are kept verbatim (trimmed), runs of blank lines collapse to one,
+/// and the result ends with a single newline.
+pub fn format_qail_source(content: &str) -> Result<String> {
+    let mut out: Vec<String> = Vec::new();
+    let mut pending_blank = false;
+    let mut statement_no = 0usize;
+
+    for segment in split_qail_segments(content) {
+        let line = match segment {
+            QailSegment::Blank => {
+                pending_blank = !out.is_empty();
+                continue;
+            }
+            QailSegment::Comment(comment) => comment,
+            QailSegment::Statement(statement) => {
+                statement_no += 1;
+                format_statement(&statement)
+                    .with_context(|| format!("statement {}", statement_no))?
+            }
+        };
+        if pending_blank {
+            out.push(String::new());
+            pending_blank = false;
+        }
+        out.push(line);
+    }
+
+    if out.is_empty() {
+        return Ok(String::new());
+    }
+    let mut formatted = out.join("\n");
+    formatted.push('\n');
+    Ok(formatted)
+}
+
+/// Line-oriented diff between two texts: changed lines as `-`/`+` pairs.
+///
+/// Formatting never adds or removes statements, so a positional comparison
+/// after dropping blank lines is enough to show what moved.
+pub fn line_diff(before: &str, after: &str) -> String {
+    let old: Vec<&str> = before.lines().filter(|l| !l.trim().is_empty()).collect();
+    let new: Vec<&str> = after.lines().filter(|l| !l.trim().is_empty()).collect();
+    let mut diff = String::new();
+
+    for idx in 0..old.len().max(new.len()) {
+        let (o, n) = (old.get(idx), new.get(idx));
+        if o.map(|l| l.trim()) == n.copied() {
+            continue;
+        }
+        if let Some(o) = o {
+            diff.push_str(&format!("-{}\n", o));
+        }
+        if let Some(n) = n {
+            diff.push_str(&format!("+{}\n", n));
+        }
+    }
+    diff
+}
+
+/// Format a `.qail` statement file, printing a diff or rewriting it in place.
+///
+/// Returns `true` when the file is not already canonical.
+pub fn format_query_file(path: &Path, write: bool) -> Result<bool> {
+    let before =
+        fs::read_to_string(path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+    let after = format_qail_source(&before)
+        .with_context(|| format!("Failed to format '{}'", path.display()))?;
+
+    if before == after {
+        println!("{} {} already formatted", "✓".green(), path.display());
+        return Ok(false);
+    }
+
+    if write {
+        fs::write(path, &after).with_context(|| format!("Failed to write '{}'", path.display()))?;
+        println!("{} formatted {}", "✓".green(), path.display());
+    } else {
+        println!("{} {}", "---".dimmed(), path.display());
+        for line in line_diff(&before, &after).lines() {
+            if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line.green());
+            }
+        }
+        println!(
+            "{} re-run with {} to apply",
+            "→".dimmed(),
+            "--write".yellow()
+        );
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSY: &str = "\
+# seed users
+
+
+GET   users FIELDS id,email   WHERE active = true   LIMIT 10
+-- cleanup
+del sessions where user_id = 7
+
+";
+
+    const CANONICAL: &str = "\
+# seed users
+
+get users fields id, email where active = true limit 10
+-- cleanup
+del sessions where user_id = 7
+";
+
+    #[test]
+    fn messy_file_formats_to_canonical_golden() {
+        assert_eq!(format_qail_source(MESSY).unwrap(), CANONICAL);
+    }
+
+    #[test]
+    fn canonical_file_is_a_fixed_point() {
+        assert_eq!(format_qail_source(CANONICAL).unwrap(), CANONICAL);
+    }
+
+    #[test]
+    fn statements_with_inline_comments_are_left_alone() {
+        let src = "get users fields id -- only ids\n";
+        assert_eq!(format_qail_source(src).unwrap(), src);
+    }
+
+    #[test]
+    fn comment_markers_inside_strings_do_not_block_formatting() {
+        assert_eq!(
+            format_statement("GET  notes  WHERE body = 'a -- b /* c */'").unwrap(),
+            "get notes where body = 'a -- b /* c */'"
+        );
+        let src = "get notes where body = 'x' -- trailing";
+        assert_eq!(format_statement(src).unwrap(), src);
+    }
+
+    #[test]
+    fn triple_quoted_statement_stays_one_segment() {
+        let segments = split_qail_segments("add notes fields body values '''a\nb'''\nget notes\n");
+        assert_eq!(
+            segments,
+            vec![
+                QailSegment::Statement("add notes fields body values '''a\nb'''".to_string()),
+                QailSegment::Statement("get notes".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_name_the_statement() {
+        let err = format_qail_source("get users\nget users fields\n").unwrap_err();
+        assert!(format!("{err:#}").contains("statement 2"), "{err:#}");
+    }
+
+    #[test]
+    fn line_diff_reports_changed_lines_only() {
+        let diff = line_diff(MESSY, CANONICAL);
+        assert_eq!(
+            diff,
+            "-GET   users FIELDS id,email   WHERE active = true   LIMIT 10\n\
+             +get users fields id, email where active = true limit 10\n"
+        );
+    }
+}
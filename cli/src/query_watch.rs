@@ -0,0 +1,264 @@
+//! Query-file watch mode: re-transpile a `.qail` statement file on every save.
+
+use qail_core::error::QailError;
+use qail_core::transpiler::{Dialect, ToSql};
+
+use crate::colors::*;
+use crate::query_fmt::{QailSegment, split_qail_segments};
+
+/// Why a statement failed to transpile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementError {
+    /// Parser message.
+    pub message: String,
+    /// Byte column within the statement to point the caret at, if known.
+    pub column: Option<usize>,
+}
+
+/// Result of transpiling one statement of a query file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementOutcome {
+    /// 1-based statement number.
+    pub index: usize,
+    /// 1-based line the statement starts on.
+    pub line: usize,
+    /// Statement source (trimmed).
+    pub source: String,
+    /// Generated SQL, or the parse failure.
+    pub result: std::result::Result<String, StatementError>,
+}
+
+/// Parse and transpile every statement in `content`.
+///
+/// Each statement is handled independently so one typo does not hide the
+/// SQL for the rest of the file.
+pub fn transpile_statements(content: &str, dialect: Dialect) -> Vec<StatementOutcome> {
+    let mut outcomes = Vec::new();
+    let mut line = 1usize;
+
+    for segment in split_qail_segments(content) {
+        let start_line = line;
+        match segment {
+            QailSegment::Blank | QailSegment::Comment(_) => line += 1,
+            QailSegment::Statement(source) => {
+                line += 1 + source.matches('\n').count();
+                let result = match qail_core::parse(&source) {
+                    Ok(cmd) => Ok(cmd.to_sql_with_dialect(dialect)),
+                    Err(err) => Err(statement_error(&source, &err)),
+                };
+                outcomes.push(StatementOutcome {
+                    index: outcomes.len() + 1,
+                    line: start_line,
+                    source,
+                    result,
+                });
+            }
+        }
+    }
+
+    outcomes
+}
+
+pub(crate) fn statement_error(source: &str, err: &QailError) -> StatementError {
+    let (position, message) = match err {
+        QailError::Parse { position, message } => (*position, message.clone()),
+        other => (0, other.to_string()),
+    };
+
+    let column = if position > 0 && position < source.len() {
+        Some(position)
+    } else {
+        message
+            .split_once("Unexpected trailing content: '")
+            .and_then(|(_, rest)| rest.strip_suffix('\''))
+            .and_then(|trailing| source.rfind(trailing))
+    };
+
+    StatementError { message, column }
+}
+
+/// Render the caret line for `column` under a single-line statement.
+///
+/// A byte offset past the end or inside a multi-byte character is clamped
+/// back to the previous character boundary.
+pub fn caret_line(source: &str, column: usize) -> String {
+    let prefix = source.get(..column).unwrap_or_else(|| {
+        let mut end = column.min(source.len());
+        while !source.is_char_boundary(end) {
+            end -= 1;
+        }
+        &source[..end]
+    });
+    let line_start = prefix.rfind('\n').map_or(0, |idx| idx + 1);
+    let width = prefix[line_start..].chars().count();
+    format!("{}^", " ".repeat(width))
+}
+
+/// Print transpile outcomes, SQL for good statements and a caret for bad ones.
+pub fn print_outcomes(outcomes: &[StatementOutcome]) {
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(sql) => {
+                println!(
+                    "  {} {} {}",
+                    "✓".green(),
+                    format!("[{}]", outcome.index).dimmed(),
+                    sql.cyan()
+                );
+            }
+            Err(err) => {
+                println!(
+                    "  {} statement {} (line {}): {}",
+                    "✗".red(),
+                    outcome.index,
+                    outcome.line,
+                    err.message.red()
+                );
+                println!("      {}", outcome.source.yellow());
+                if let Some(column) = err.column {
+                    println!("      {}", caret_line(&outcome.source, column).red().bold());
+                }
+            }
+        }
+    }
+}
+
+/// Watch a `.qail` statement file and print the transpiled SQL on every change.
+#[cfg(feature = "watch")]
+pub fn watch_queries(path: &str, dialect: Dialect) -> anyhow::Result<()> {
+    use notify_debouncer_full::{DebounceEventResult, new_debouncer, notify::RecursiveMode};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    println!("{}", "👀 QAIL Query Watch Mode".cyan().bold());
+    println!("   Watching: {}", path.yellow());
+    println!("   Press {} to stop\n", "Ctrl+C".red());
+
+    let run = || -> anyhow::Result<()> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path, e))?;
+        let outcomes = transpile_statements(&content, dialect);
+        let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+        let now = crate::time::timestamp_short();
+        if failed == 0 {
+            println!(
+                "[{}] {} {} statement(s)",
+                now.dimmed(),
+                "✓".green(),
+                outcomes.len()
+            );
+        } else {
+            println!(
+                "[{}] {} {} of {} statement(s) failed",
+                now.dimmed(),
+                "✗".red(),
+                failed,
+                outcomes.len()
+            );
+        }
+        print_outcomes(&outcomes);
+        println!();
+        Ok(())
+    };
+
+    run()?;
+
+    let (tx, rx) = channel::<DebounceEventResult>();
+    let mut debouncer = new_debouncer(Duration::from_millis(300), None, tx)?;
+    debouncer.watch(std::path::Path::new(path), RecursiveMode::NonRecursive)?;
+
+    loop {
+        match rx.recv() {
+            Ok(Ok(events)) => {
+                if !events.is_empty()
+                    && let Err(e) = run()
+                {
+                    println!("{} {}", "✗".red(), e);
+                }
+            }
+            Ok(Err(errors)) => {
+                for e in errors {
+                    println!("{} Watch error: {}", "✗".red(), e);
+                }
+            }
+            Err(e) => {
+                println!("{} Channel error: {}", "✗".red(), e);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retranspile_reports_sql_and_failing_statement() {
+        let content = "\
+# users
+get users fields id
+
+get users fields id whre active = true
+del sessions where id = 1
+";
+        let outcomes = transpile_statements(content, Dialect::Postgres);
+        assert_eq!(outcomes.len(), 3);
+
+        assert_eq!(outcomes[0].line, 2);
+        assert_eq!(outcomes[0].result, Ok("SELECT id FROM users".to_string()));
+
+        assert_eq!(outcomes[1].index, 2);
+        assert_eq!(outcomes[1].line, 4);
+        let err = outcomes[1].result.clone().unwrap_err();
+        assert!(err.message.contains("whre"), "{}", err.message);
+        assert_eq!(err.column, Some("get users fields id ".len()));
+        assert_eq!(
+            caret_line(&outcomes[1].source, err.column.unwrap()),
+            format!("{}^", " ".repeat(20))
+        );
+
+        assert_eq!(outcomes[2].line, 5);
+        assert_eq!(
+            outcomes[2].result,
+            Ok("DELETE FROM sessions WHERE id = 1".to_string())
+        );
+    }
+
+    #[test]
+    fn caret_line_clamps_offsets_in_non_ascii_source() {
+        let source = "get users where name = 'é' whre";
+        let column = source.find("whre").unwrap();
+        assert_eq!(
+            caret_line(source, column),
+            format!("{}^", " ".repeat(column - 1))
+        );
+
+        // Inside `é` and past the end both clamp instead of panicking.
+        let inside = source.find('é').unwrap() + 1;
+        assert_eq!(caret_line(source, inside), format!("{}^", " ".repeat(24)));
+        assert_eq!(
+            caret_line(source, source.len() + 5),
+            format!("{}^", " ".repeat(source.chars().count()))
+        );
+    }
+
+    #[test]
+    fn edit_fixing_a_statement_clears_its_error() {
+        let broken = transpile_statements("get users fields\n", Dialect::Postgres);
+        assert!(broken[0].result.is_err());
+
+        let fixed = transpile_statements("get users fields id\n", Dialect::Postgres);
+        assert_eq!(fixed[0].result, Ok("SELECT id FROM users".to_string()));
+    }
+
+    #[test]
+    fn multiline_statement_advances_line_numbers() {
+        let content = "add notes fields body values '''a\nb'''\nget users\n";
+        let outcomes = transpile_statements(content, Dialect::Postgres);
+        assert_eq!(outcomes[0].line, 1);
+        assert_eq!(outcomes[1].line, 3);
+    }
+}
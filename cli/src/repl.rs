@@ -1,10 +1,119 @@
 //! REPL mode for interactive QAIL queries
 
 use crate::colors::*;
-use qail_core::transpiler::ToSql;
+use crate::query_watch::{StatementError, caret_line, statement_error};
+use qail_core::ast::{Action, Qail};
+use qail_core::transpiler::{Dialect, ToSql};
+use qail_pg::PgDriver;
+
+/// Per-session settings changed by meta-commands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplState {
+    /// Dialect used to render SQL.
+    pub dialect: Dialect,
+    /// Show the query plan instead of running the query (`.explain`).
+    pub explain: bool,
+}
+
+impl ReplState {
+    /// Start a session rendering SQL for `dialect`.
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            explain: false,
+        }
+    }
+}
+
+/// What the REPL should do with one input line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineOutcome {
+    /// Blank input.
+    Empty,
+    /// Leave the REPL.
+    Exit,
+    /// Print the help text.
+    Help,
+    /// Clear the screen.
+    Clear,
+    /// A meta-command changed the session; print this note.
+    Info(String),
+    /// A query parsed; `sql` is what will be shown (or explained).
+    Query {
+        /// Parsed command, for running against `--url`.
+        cmd: Box<Qail>,
+        /// Rendered SQL, prefixed with `EXPLAIN` in explain mode.
+        sql: String,
+    },
+    /// The line did not parse, or a meta-command was malformed.
+    Error(StatementError),
+}
+
+/// Turn one input line into an outcome, applying meta-commands to `state`.
+///
+/// This is the terminal-independent core of the REPL.
+pub fn process_line(state: &mut ReplState, line: &str) -> LineOutcome {
+    let line = line.trim();
+    match line {
+        "" => return LineOutcome::Empty,
+        ".exit" | ".quit" | "exit" | "quit" => return LineOutcome::Exit,
+        ".help" | "help" => return LineOutcome::Help,
+        ".clear" | "clear" => return LineOutcome::Clear,
+        ".explain" => {
+            state.explain = !state.explain;
+            let mode = if state.explain { "on" } else { "off" };
+            return LineOutcome::Info(format!("explain {}", mode));
+        }
+        _ => {}
+    }
+
+    if let Some(rest) = line.strip_prefix(".dialect") {
+        return match rest.trim().to_ascii_lowercase().as_str() {
+            "" => LineOutcome::Info(format!("dialect {}", dialect_name(state.dialect))),
+            "postgres" | "postgresql" | "pg" => {
+                state.dialect = Dialect::Postgres;
+                LineOutcome::Info("dialect postgres".to_string())
+            }
+            "sqlite" => {
+                state.dialect = Dialect::SQLite;
+                LineOutcome::Info("dialect sqlite".to_string())
+            }
+            other => LineOutcome::Error(StatementError {
+                message: format!("Unknown dialect '{}'. Expected: postgres, sqlite", other),
+                column: None,
+            }),
+        };
+    }
+
+    match qail_core::parse(line) {
+        Ok(cmd) => {
+            let sql = cmd.to_sql_with_dialect(state.dialect);
+            let sql = match (state.explain, state.dialect) {
+                (false, _) => sql,
+                (true, Dialect::SQLite) => format!("EXPLAIN QUERY PLAN {}", sql),
+                (true, _) => format!("EXPLAIN {}", sql),
+            };
+            LineOutcome::Query {
+                cmd: Box::new(cmd),
+                sql,
+            }
+        }
+        Err(e) => LineOutcome::Error(statement_error(line, &e)),
+    }
+}
+
+fn dialect_name(dialect: Dialect) -> &'static str {
+    match dialect {
+        Dialect::SQLite => "sqlite",
+        _ => "postgres",
+    }
+}
 
 /// Run the interactive REPL mode.
-pub fn run_repl() {
+///
+/// With `url`, queries also run against PostgreSQL (or are planned with
+/// `EXPLAIN (FORMAT JSON)` in explain mode) and their rows are printed.
+pub async fn run_repl(url: Option<&str>, dialect: Dialect) {
     use rustyline::DefaultEditor;
     use rustyline::error::ReadlineError;
 
@@ -14,9 +123,20 @@ pub fn run_repl() {
         "Type queries to see generated SQL. Commands:".dimmed()
     );
     println!("  {}  - Exit the REPL", ".exit".yellow());
-    println!("  {} - Clear screen", ".clear".yellow());
+    println!("  {}  - Show all commands", ".help".yellow());
     println!();
 
+    let mut driver = match url {
+        Some(url) => match connect(url).await {
+            Ok(driver) => Some(driver),
+            Err(e) => {
+                eprintln!("{} {}", "Failed to connect:".red(), e);
+                return;
+            }
+        },
+        None => None,
+    };
+
     let mut rl = match DefaultEditor::new() {
         Ok(editor) => editor,
         Err(e) => {
@@ -31,42 +151,37 @@ pub fn run_repl() {
         .unwrap_or_default();
     let _ = rl.load_history(&history_path);
 
+    let mut state = ReplState::new(dialect);
     loop {
         let prompt = "qail> ".cyan().bold().to_string();
         match rl.readline(&prompt) {
             Ok(line) => {
-                let line = line.trim();
-                if line.is_empty() {
-                    continue;
+                if !line.trim().is_empty() {
+                    let _ = rl.add_history_entry(line.trim());
                 }
 
-                let _ = rl.add_history_entry(line);
-
-                match line {
-                    ".exit" | ".quit" | "exit" | "quit" => {
+                match process_line(&mut state, &line) {
+                    LineOutcome::Empty => {}
+                    LineOutcome::Exit => {
                         println!("{}", "Goodbye! 👋".green());
                         break;
                     }
-                    ".help" | "help" => {
-                        show_repl_help();
-                        continue;
-                    }
-                    ".clear" | "clear" => {
-                        print!("\x1B[2J\x1B[1;1H");
-                        continue;
-                    }
-
-                    _ => {}
-                }
-
-                match qail_core::parse(line) {
-                    Ok(cmd) => {
-                        let sql = cmd.to_sql();
+                    LineOutcome::Help => show_repl_help(),
+                    LineOutcome::Clear => print!("\x1B[2J\x1B[1;1H"),
+                    LineOutcome::Info(note) => println!("{} {}", "•".cyan(), note.dimmed()),
+                    LineOutcome::Query { cmd, sql } => {
                         println!("{} {}", "→".green(), sql.white().bold());
+                        if let Some(driver) = driver.as_mut() {
+                            run_query(driver, &state, &cmd).await;
+                        }
                         println!();
                     }
-                    Err(e) => {
-                        eprintln!("{} {}", "✗".red(), e.to_string().red());
+                    LineOutcome::Error(err) => {
+                        eprintln!("{} {}", "✗".red(), err.message.red());
+                        if let Some(column) = err.column {
+                            eprintln!("  {}", line.trim().yellow());
+                            eprintln!("  {}", caret_line(line.trim(), column).red().bold());
+                        }
                     }
                 }
             }
@@ -88,13 +203,62 @@ pub fn run_repl() {
     let _ = rl.save_history(&history_path);
 }
 
+async fn connect(url: &str) -> anyhow::Result<PgDriver> {
+    let db_url = crate::resolve::resolve_db_url(Some(url))?;
+    PgDriver::connect_url(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Run (or plan) a parsed query against the connected database.
+async fn run_query(driver: &mut PgDriver, state: &ReplState, cmd: &Qail) {
+    if state.dialect == Dialect::SQLite {
+        println!(
+            "  {}",
+            "--url runs PostgreSQL only; switch with .dialect postgres".dimmed()
+        );
+        return;
+    }
+
+    if state.explain {
+        let plan = match driver.explain_json(cmd).await {
+            Ok(json) => crate::explain::plan_tree_lines(&json),
+            Err(e) => Err(anyhow::anyhow!("EXPLAIN failed: {}", e)),
+        };
+        match plan {
+            Ok(lines) => crate::explain::print_plan_lines(&lines),
+            Err(e) => eprintln!("{} {}", "✗".red(), e.to_string().red()),
+        }
+        return;
+    }
+
+    if matches!(cmd.action, Action::Get) {
+        match driver.query_ast(cmd).await {
+            Ok(result) => crate::exec::print_result_table(&result),
+            Err(e) => eprintln!("{} {}", "✗".red(), e.to_string().red()),
+        }
+    } else {
+        match driver.execute(cmd).await {
+            Ok(count) => println!("  {} {} row(s) affected", "✓".green(), count),
+            Err(e) => eprintln!("{} {}", "✗".red(), e.to_string().red()),
+        }
+    }
+}
+
 /// Show REPL help information.
 pub fn show_repl_help() {
     println!("{}", "QAIL REPL Commands:".cyan().bold());
-    println!("  {}     - Exit the REPL", ".exit".yellow());
-    println!("  {}     - Show this help", ".help".yellow());
-    println!("  {}    - Clear screen", ".clear".yellow());
-    println!("  {}    - Clear screen", ".clear".yellow());
+    println!("  {}              - Exit the REPL", ".exit".yellow());
+    println!("  {}              - Show this help", ".help".yellow());
+    println!("  {}             - Clear screen", ".clear".yellow());
+    println!(
+        "  {} - Switch the SQL dialect",
+        ".dialect postgres|sqlite".yellow()
+    );
+    println!(
+        "  {}           - Toggle EXPLAIN output (plan tree with --url)",
+        ".explain".yellow()
+    );
     println!();
     println!("{}", "Query Examples (v2 keyword syntax):".cyan().bold());
     println!("  get users fields *");
@@ -104,3 +268,53 @@ pub fn show_repl_help() {
     println!("  del users where id = $1");
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_line_renders_valid_query_and_meta_commands() {
+        let mut state = ReplState::new(Dialect::Postgres);
+
+        let LineOutcome::Query { sql, .. } = process_line(&mut state, "get users fields id") else {
+            panic!("expected a query");
+        };
+        assert_eq!(sql, "SELECT id FROM users");
+
+        assert_eq!(
+            process_line(&mut state, ".dialect sqlite"),
+            LineOutcome::Info("dialect sqlite".to_string())
+        );
+        assert_eq!(
+            process_line(&mut state, ".explain"),
+            LineOutcome::Info("explain on".to_string())
+        );
+        let LineOutcome::Query { sql, .. } = process_line(&mut state, "get users fields id") else {
+            panic!("expected a query");
+        };
+        assert_eq!(sql, "EXPLAIN QUERY PLAN SELECT \"id\" FROM \"users\"");
+
+        assert_eq!(process_line(&mut state, "   "), LineOutcome::Empty);
+        assert_eq!(process_line(&mut state, ".exit"), LineOutcome::Exit);
+    }
+
+    #[test]
+    fn process_line_reports_parse_error_with_caret() {
+        let mut state = ReplState::new(Dialect::Postgres);
+        let line = "get users fields id where";
+
+        let LineOutcome::Error(err) = process_line(&mut state, line) else {
+            panic!("expected an error");
+        };
+        assert!(!err.message.is_empty());
+        let column = err.column.expect("error should point at the input");
+        assert!(column < line.len());
+        assert_eq!(caret_line(line, column).len(), column + 1);
+
+        let LineOutcome::Error(err) = process_line(&mut state, ".dialect oracle") else {
+            panic!("expected an error");
+        };
+        assert!(err.message.contains("Unknown dialect 'oracle'"));
+    }
+}
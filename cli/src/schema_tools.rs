@@ -277,6 +277,51 @@ pub fn merge_schema(input: &str, output: &str) -> Result<()> {
     Ok(())
 }
 
+/// Target format for `qail schema convert`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaFormat {
+    /// Canonical `.qail` schema text.
+    Qail,
+    /// JSON produced by `Schema::to_json`.
+    Json,
+}
+
+/// Convert a schema between QAIL and JSON.
+///
+/// `.json` inputs are read with `Schema::from_json`; anything else is parsed
+/// as a QAIL schema source (file or modular directory). Prints to stdout
+/// unless `output` is given.
+pub fn convert_schema(input: &str, to: SchemaFormat, output: Option<&str>) -> Result<()> {
+    let schema = if Path::new(input)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+    {
+        let text =
+            fs::read_to_string(input).with_context(|| format!("Failed to read '{}'", input))?;
+        Schema::from_json(&text).map_err(|e| anyhow!("Failed to parse '{}': {}", input, e))?
+    } else {
+        parse_qail_file(input).map_err(|e| anyhow!("Failed to parse schema: {}", e))?
+    };
+
+    let text = match to {
+        SchemaFormat::Qail => canonical_schema_text(&schema),
+        SchemaFormat::Json => {
+            let mut json = schema.to_json().map_err(|e| anyhow!(e))?;
+            json.push('\n');
+            json
+        }
+    };
+
+    match output {
+        Some(output) => {
+            write_text(Path::new(output), &text)?;
+            eprintln!("{} converted '{}' -> '{}'", "✓".green(), input, output);
+        }
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
 /// Format schema file or schema directory modules in-place.
 pub fn format_schema_source(path: &str) -> Result<()> {
     let resolved = qail_core::schema_source::resolve_schema_source(path)
@@ -136,6 +136,7 @@ pub fn cmd_to_sql(cmd: &Qail) -> String {
             }
             format!("ALTER TABLE {} DROP COLUMN ...", cmd.table)
         }
+        Action::AlterAddConstraint if !cmd.table_constraints.is_empty() => cmd.to_sql(),
         Action::AlterAddConstraint => {
             let name = cmd.channel.as_deref().unwrap_or("...");
             let expr = cmd.payload.as_deref().unwrap_or("...");
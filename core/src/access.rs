@@ -60,6 +60,11 @@ impl AccessPolicy {
         if let Some(source_query) = &cmd.source_query {
             self.check_command_inner(ctx, source_query)?;
         }
+        for join in &cmd.joins {
+            if let Some(subquery) = &join.subquery {
+                self.check_command_inner(ctx, subquery)?;
+            }
+        }
         if let Some(merge) = &cmd.merge {
             match &merge.source {
                 MergeSource::Query { query, .. } => self.check_command_inner(ctx, query)?,
@@ -214,6 +219,10 @@ impl AccessPolicy {
         cte_names: &BTreeSet<String>,
     ) -> Result<(), AccessError> {
         for join in &cmd.joins {
+            // Derived-table joins are checked as commands; `table` is only their alias.
+            if join.subquery.is_some() {
+                continue;
+            }
             let table = normalize_table_ref(&join.table);
             if table.is_empty() || cte_names.contains(&table) {
                 continue;
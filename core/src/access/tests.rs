@@ -584,6 +584,27 @@ fn subqueries_are_checked_recursively() {
         .expect("outer and subquery table policies should pass");
 }
 
+#[test]
+fn derived_table_joins_check_the_subquery_not_the_alias() {
+    let policy = read_policy("users");
+    let secrets = Qail::get("secrets").columns(["token"]);
+    let cmd = Qail::get("users").left_join_lateral(secrets, "users");
+
+    let err = policy
+        .check_command(&AccessContext::anonymous(), &cmd)
+        .expect_err("subquery table needs its own policy");
+    assert_eq!(err.kind, AccessErrorKind::NoPolicy);
+    assert_eq!(err.table, "secrets");
+
+    let recent = Qail::get("users").columns(["id"]).limit(3);
+    policy
+        .check_command(
+            &AccessContext::anonymous(),
+            &Qail::get("users").left_join_lateral(recent, "recent"),
+        )
+        .expect("alias is not a table and needs no policy");
+}
+
 #[test]
 fn correlated_subqueries_enforce_outer_read_column_policy() {
     let policy = AccessPolicy::new()
@@ -216,11 +216,18 @@ fn collect_subquery_references(
             collect_expr_subquery_references(path, line, expr, cte_aliases, refs);
         }
     }
-    if let Some(on_conflict) = &cmd.on_conflict
-        && let ConflictAction::DoUpdate { assignments } = &on_conflict.action
-    {
-        for (_, expr) in assignments {
-            collect_expr_subquery_references(path, line, expr, cte_aliases, refs);
+    if let Some(on_conflict) = &cmd.on_conflict {
+        collect_conditions_subquery_references(
+            path,
+            line,
+            &on_conflict.predicate,
+            cte_aliases,
+            refs,
+        );
+        if let ConflictAction::DoUpdate { assignments } = &on_conflict.action {
+            for (_, expr) in assignments {
+                collect_expr_subquery_references(path, line, expr, cte_aliases, refs);
+            }
         }
     }
     if let Some(merge) = &cmd.merge {
@@ -463,6 +470,7 @@ fn collect_reference_columns_for_scope(cmd: &crate::Qail, scope: ColumnScope<'_>
         for column in &on_conflict.columns {
             push_column_ref(column, scope, &mut cols, &mut seen);
         }
+        collect_conditions_columns(&on_conflict.predicate, scope, &mut cols, &mut seen);
         if let ConflictAction::DoUpdate { assignments } = &on_conflict.action {
             for (column, expr) in assignments {
                 push_column_ref(column, scope, &mut cols, &mut seen);
@@ -965,6 +973,8 @@ mod tests {
                         kind: JoinKind::Left,
                         on: None,
                         on_true: true,
+                        using: Vec::new(),
+                        subquery: None,
                     }],
                     ..Default::default()
                 })),
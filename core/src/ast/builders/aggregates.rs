@@ -1,5 +1,6 @@
 //! Aggregate function builders (COUNT, SUM, AVG, etc.)
 
+use super::functions::{FunctionBuilder, coalesce};
 use crate::ast::{AggregateFunc, Condition, Expr};
 
 /// COUNT(*) aggregate
@@ -46,6 +47,11 @@ pub fn sum(column: &str) -> AggregateBuilder {
     }
 }
 
+/// COALESCE(SUM(column), default) aggregate, e.g. `0` instead of NULL for no rows.
+pub fn sum_coalesced(column: &str, default: impl Into<Expr>) -> FunctionBuilder {
+    sum(column).or_default(default)
+}
+
 /// AVG(column) aggregate
 pub fn avg(column: &str) -> AggregateBuilder {
     AggregateBuilder {
@@ -163,6 +169,16 @@ impl AggregateBuilder {
         self.build()
     }
 
+    /// Wrap as `COALESCE(agg, default)` so empty or all-NULL input yields `default`.
+    ///
+    /// Any alias moves to the COALESCE expression.
+    pub fn or_default(mut self, default: impl Into<Expr>) -> FunctionBuilder {
+        let alias = self.alias.take();
+        let mut wrapped = coalesce([self.build(), default.into()]);
+        wrapped.alias = alias;
+        wrapped
+    }
+
     /// Build the final Expr
     pub fn build(self) -> Expr {
         Expr::Aggregate {
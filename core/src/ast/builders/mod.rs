@@ -11,7 +11,7 @@
 //! - `functions` - Function calls (COALESCE, REPLACE, etc.)
 //! - `literals` - Literal values (text, int, float, boolean)
 //! - `conditions` - WHERE clause conditions (eq, gt, like, etc.)
-//! - `time` - Time functions (NOW, INTERVAL)
+//! - `time` - Time functions (NOW, INTERVAL, DATE_TRUNC, AT TIME ZONE)
 //! - `case_when` - CASE WHEN expressions
 //! - `cast` - Type casting
 //! - `binary` - Binary operations (+, -, ||)
@@ -51,7 +51,7 @@ pub use columns::{col, param, star};
 // Aggregates
 pub use aggregates::{
     AggregateBuilder, array_agg, avg, bool_and, bool_or, count, count_distinct, count_filter,
-    json_agg, jsonb_agg, max, min, sum,
+    json_agg, jsonb_agg, max, min, sum, sum_coalesced,
 };
 
 // JSON
@@ -73,7 +73,7 @@ pub use conditions::{
 };
 
 // Time
-pub use time::{interval, now, now_minus, now_plus};
+pub use time::{at_time_zone, date_trunc, interval, now, now_minus, now_plus};
 
 // CASE WHEN
 pub use case_when::{CaseBuilder, case_when};
@@ -119,6 +119,28 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_date_trunc_and_at_time_zone() {
+        use crate::ast::Qail;
+        use crate::transpiler::{Dialect, ToSql};
+
+        let cmd = Qail::get("events")
+            .column_expr(date_trunc("hour", col("ts")).alias("bucket"))
+            .column_expr(at_time_zone(col("ts"), "UTC").alias("ts_utc"));
+        let sql = cmd.to_sql();
+        assert!(sql.contains("DATE_TRUNC('hour', ts) AS bucket"), "{sql}");
+        assert!(sql.contains("(ts AT TIME ZONE 'UTC') AS ts_utc"), "{sql}");
+
+        let sqlite = cmd.to_sql_with_dialect(Dialect::SQLite);
+        assert!(
+            sqlite.contains("NULL /* ERROR: SQLite does not support AT TIME ZONE */"),
+            "{sqlite}"
+        );
+
+        let reparsed = crate::parser::parse(&cmd.to_string()).unwrap();
+        assert_eq!(reparsed.columns, cmd.columns);
+    }
+
     #[test]
     fn test_case_when() {
         let expr = case_when(gt("x", 0), int(1))
@@ -1,5 +1,8 @@
 //! Time function builders (NOW, INTERVAL, etc.)
 
+use super::binary::{BinaryBuilder, binary};
+use super::functions::{FunctionBuilder, func};
+use super::literals::text;
 use crate::ast::{BinaryOp, Expr};
 
 /// NOW() function
@@ -44,3 +47,21 @@ pub fn now_plus(duration: &str) -> Expr {
         alias: None,
     }
 }
+
+/// DATE_TRUNC('unit', expr) - truncate a timestamp to `unit` precision
+/// # Example
+/// ```ignore
+/// date_trunc("hour", col("ts")).alias("bucket")  // DATE_TRUNC('hour', ts) AS bucket
+/// ```
+pub fn date_trunc(unit: &str, expr: impl Into<Expr>) -> FunctionBuilder {
+    func("DATE_TRUNC", vec![text(unit), expr.into()])
+}
+
+/// expr AT TIME ZONE 'tz' - convert a timestamp to the given zone
+/// # Example
+/// ```ignore
+/// at_time_zone(col("created_at"), "UTC")  // (created_at AT TIME ZONE 'UTC')
+/// ```
+pub fn at_time_zone(expr: impl Into<Expr>, tz: &str) -> BinaryBuilder {
+    binary(expr, BinaryOp::AtTimeZone, text(tz))
+}
@@ -3,11 +3,85 @@
 //! DISTINCT ON, HAVING, row locks, table sampling, JOIN aliases, etc.
 
 use crate::ast::{
-    CTEDef, Cage, CageKind, Condition, Expr, Join, JoinKind, LockMode, LogicalOp, Operator,
-    OverridingKind, Qail, SampleMethod, SortOrder, Value,
+    Action, CTEDef, Cage, CageKind, Condition, CopyOptions, Expr, Join, JoinKind, LockMode,
+    LogicalOp, Operator, OverridingKind, Qail, SampleMethod, SortOrder, Value,
 };
+use crate::error::QailError;
 
 impl Qail {
+    /// Wrap this query as `SELECT COUNT(*) FROM (<query>) _sub`.
+    ///
+    /// Filters, joins, grouping and DISTINCT are kept so the count matches
+    /// the rows the query would return; ORDER BY, LIMIT/OFFSET, FETCH and
+    /// row locks are dropped since they cannot change the count of an
+    /// unpaginated result.
+    pub fn count_only(mut self) -> Self {
+        if self.action == crate::ast::Action::Cnt {
+            self.action = crate::ast::Action::Get;
+        }
+        self.cages.retain(|cage| {
+            !matches!(
+                cage.kind,
+                CageKind::Sort(_) | CageKind::Limit(_) | CageKind::Offset(_)
+            )
+        });
+        self.fetch = None;
+        self.lock_mode = None;
+        self.skip_locked = false;
+        self.nowait = false;
+
+        Qail {
+            action: crate::ast::Action::Get,
+            table: "_sub".to_string(),
+            columns: vec![Expr::Aggregate {
+                col: "*".to_string(),
+                func: crate::ast::AggregateFunc::Count,
+                distinct: false,
+                filter: None,
+                alias: None,
+            }],
+            source_query: Some(Box::new(self)),
+            ..Default::default()
+        }
+    }
+
+    /// Append `LIMIT max_rows` to a row-returning `get` that has no limit.
+    ///
+    /// Queries that already carry LIMIT or FETCH FIRST are left alone, as
+    /// are queries returning at most one row: `cnt`, and `get` whose
+    /// columns are all aggregates with no GROUP BY.
+    ///
+    /// ```
+    /// use qail_core::prelude::*;
+    ///
+    /// let sql = Qail::get("users").columns(["id"]).with_auto_limit(1000).to_sql();
+    /// assert_eq!(sql, "SELECT id FROM users LIMIT 1000");
+    /// ```
+    pub fn with_auto_limit(mut self, max_rows: usize) -> Self {
+        let has_limit = self.fetch.is_some()
+            || self
+                .cages
+                .iter()
+                .any(|cage| matches!(cage.kind, CageKind::Limit(_)));
+        let single_row = !self.columns.is_empty()
+            && self
+                .columns
+                .iter()
+                .all(|col| matches!(col, Expr::Aggregate { .. }))
+            && !self
+                .cages
+                .iter()
+                .any(|cage| cage.kind == CageKind::Partition);
+        if self.action == crate::ast::Action::Get && !has_limit && !single_row {
+            self.cages.push(Cage {
+                kind: CageKind::Limit(max_rows),
+                conditions: vec![],
+                logical_op: LogicalOp::And,
+            });
+        }
+        self
+    }
+
     /// Add a column expression.
     pub fn column_expr(mut self, expr: Expr) -> Self {
         self.columns.push(expr);
@@ -104,6 +178,61 @@ impl Qail {
         self
     }
 
+    /// Fold the joins of a `del`/`set` into `DELETE … USING` /
+    /// `UPDATE … FROM` tables, with their ON conditions ANDed into WHERE.
+    ///
+    /// PostgreSQL has no JOIN syntax on DELETE/UPDATE, so only inner and
+    /// cross joins can be expressed this way. Commands without joins, and
+    /// non-mutations, are returned unchanged.
+    pub fn fold_mutation_joins(&self) -> Result<Qail, QailError> {
+        let action = self.action;
+        if self.joins.is_empty() || !matches!(action, Action::Del | Action::Set) {
+            return Ok(self.clone());
+        }
+        let mut folded = self.clone();
+        let mut conditions = Vec::new();
+        for join in std::mem::take(&mut folded.joins) {
+            if !matches!(join.kind, JoinKind::Inner | JoinKind::Cross) {
+                let statement = if action == Action::Del {
+                    "DELETE"
+                } else {
+                    "UPDATE"
+                };
+                return Err(QailError::Validation(format!(
+                    "{} supports only inner joins (write `inner join {}`), got {:?} join",
+                    statement, join.table, join.kind
+                )));
+            }
+            if !join.using.is_empty() {
+                return Err(QailError::Validation(format!(
+                    "join on '{}' uses USING (...); write an ON condition for DELETE/UPDATE",
+                    join.table
+                )));
+            }
+            let on = join.on.unwrap_or_default();
+            if join.kind == JoinKind::Inner && on.is_empty() && !join.on_true {
+                return Err(QailError::MissingJoinCondition(join.table));
+            }
+            conditions.extend(on);
+            if action == Action::Del {
+                folded.using_tables.push(join.table);
+            } else {
+                folded.from_tables.push(join.table);
+            }
+        }
+        if !conditions.is_empty() {
+            folded.cages.insert(
+                0,
+                Cage {
+                    kind: CageKind::Filter,
+                    conditions,
+                    logical_op: LogicalOp::And,
+                },
+            );
+        }
+        Ok(folded)
+    }
+
     /// FOR UPDATE row lock.
     pub fn for_update(mut self) -> Self {
         self.lock_mode = Some(LockMode::Update);
@@ -118,6 +247,18 @@ impl Qail {
         self
     }
 
+    /// Add SKIP LOCKED to the current row lock.
+    pub fn skip_locked(mut self) -> Self {
+        self.skip_locked = true;
+        self
+    }
+
+    /// Add NOWAIT to the current row lock.
+    pub fn nowait(mut self) -> Self {
+        self.nowait = true;
+        self
+    }
+
     /// FOR NO KEY UPDATE row lock.
     pub fn for_no_key_update(mut self) -> Self {
         self.lock_mode = Some(LockMode::NoKeyUpdate);
@@ -186,6 +327,12 @@ impl Qail {
         self
     }
 
+    /// `COPY … TO STDOUT WITH (...)` options for an export.
+    pub fn copy_options(mut self, options: CopyOptions) -> Self {
+        self.copy_options = Some(options);
+        self
+    }
+
     /// SELECT FROM ONLY (exclude child tables).
     pub fn only(mut self) -> Self {
         self.only_table = true;
@@ -210,6 +357,8 @@ impl Qail {
                 is_array_unnest: false,
             }]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         });
         self
     }
@@ -232,6 +381,8 @@ impl Qail {
                 is_array_unnest: false,
             }]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         });
         self
     }
@@ -265,6 +416,8 @@ impl Qail {
             table: table.as_ref().to_string(),
             on: Some(conditions),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         });
         self
     }
@@ -279,6 +432,68 @@ impl Qail {
         self.join_conds(JoinKind::Inner, table, conditions)
     }
 
+    /// CROSS JOIN (explicit cartesian product, no ON condition).
+    pub fn cross_join(mut self, table: impl AsRef<str>) -> Self {
+        self.joins.push(Join {
+            kind: JoinKind::Cross,
+            table: table.as_ref().to_string(),
+            on: None,
+            on_true: false,
+            using: Vec::new(),
+            subquery: None,
+        });
+        self
+    }
+
+    /// JOIN ... USING (col, ...) on identically-named columns.
+    pub fn join_using<I, S>(mut self, kind: JoinKind, table: impl AsRef<str>, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.joins.push(Join {
+            kind,
+            table: table.as_ref().to_string(),
+            on: None,
+            on_true: false,
+            using: columns
+                .into_iter()
+                .map(|c| c.as_ref().to_string())
+                .collect(),
+            subquery: None,
+        });
+        self
+    }
+
+    /// `LEFT JOIN LATERAL (<query>) alias ON TRUE`.
+    ///
+    /// The subquery may reference columns of tables joined before it.
+    pub fn left_join_lateral(self, query: Qail, alias: impl AsRef<str>) -> Self {
+        self.join_subquery(JoinKind::Lateral, query, alias)
+    }
+
+    /// `INNER JOIN LATERAL (<query>) alias ON TRUE`.
+    pub fn inner_join_lateral(self, query: Qail, alias: impl AsRef<str>) -> Self {
+        self.join_subquery(JoinKind::LateralInner, query, alias)
+    }
+
+    /// JOIN a derived table: `<kind> JOIN (<query>) alias ON TRUE`.
+    ///
+    /// Narrow the join afterwards by filtering on the alias, or use a
+    /// lateral kind to correlate the subquery with the outer row.
+    pub fn join_subquery(mut self, kind: JoinKind, query: Qail, alias: impl AsRef<str>) -> Self {
+        let on_true = kind != JoinKind::Cross;
+        self.joins.push(Join {
+            kind,
+            table: alias.as_ref().to_string(),
+            on: None,
+            on_true,
+            using: Vec::new(),
+            subquery: Some(Box::new(query)),
+        });
+        self
+    }
+
     /// Set an alias for the FROM table.
     pub fn table_alias(mut self, alias: impl AsRef<str>) -> Self {
         self.table = format!("{} {}", self.table, alias.as_ref());
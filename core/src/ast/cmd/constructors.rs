@@ -2,7 +2,7 @@
 //!
 //! Methods like get(), set(), add(), del(), make(), etc.
 
-use crate::ast::{Action, Qail};
+use crate::ast::{Action, CopyOptions, Qail};
 
 impl Qail {
     /// SELECT — query rows.
@@ -68,6 +68,16 @@ impl Qail {
         }
     }
 
+    /// `COPY (SELECT …) TO STDOUT WITH (FORMAT csv, HEADER true)` export.
+    pub fn export_csv(table: impl Into<String>) -> Self {
+        Self {
+            action: Action::Export,
+            table: table.into(),
+            copy_options: Some(CopyOptions::csv_with_header()),
+            ..Default::default()
+        }
+    }
+
     /// CREATE TABLE.
     pub fn make(table: impl Into<String>) -> Self {
         Self {
@@ -189,6 +199,29 @@ impl Qail {
         }
     }
 
+    /// Create a `TABLE name` command (shorthand for `SELECT * FROM name`).
+    pub fn table(name: impl Into<String>) -> Self {
+        Self {
+            action: Action::Table,
+            table: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a standalone `VALUES` command; add rows with [`Qail::values`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let cmd = Qail::values_list().values([1, 2]).values([3, 4]);
+    /// // Generates: VALUES (1, 2), (3, 4)
+    /// ```
+    pub fn values_list() -> Self {
+        Self {
+            action: Action::Values,
+            ..Default::default()
+        }
+    }
+
     // PostgreSQL Procedural Commands
 
     /// Create a CALL command to invoke a stored procedure.
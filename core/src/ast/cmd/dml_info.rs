@@ -0,0 +1,115 @@
+//! Write classification for audit logging.
+
+use crate::ast::{Action, CageKind, Qail};
+
+/// The kind of row change a DML command makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DmlKind {
+    /// `INSERT` (including upserts).
+    Insert,
+    /// `UPDATE`.
+    Update,
+    /// `DELETE`.
+    Delete,
+}
+
+/// The table a DML command writes and how.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DmlInfo {
+    /// Table being written, without any alias.
+    pub table: String,
+    /// Kind of write.
+    pub kind: DmlKind,
+    /// Whether the command carries a `WHERE` filter.
+    pub has_where: bool,
+}
+
+impl Qail {
+    /// Primary table and write kind of this command, or `None` when it does
+    /// not change rows through INSERT/UPDATE/DELETE.
+    ///
+    /// `add` and `put` are inserts, `set` an update and `del` a delete.
+    /// Reads, DDL, `TRUNCATE` and `MERGE` (whose clauses can do all three)
+    /// return `None`.
+    ///
+    /// ```
+    /// use qail_core::ast::DmlKind;
+    ///
+    /// let cmd = qail_core::parser::parse("del sessions where expired = true").unwrap();
+    /// let info = cmd.dml_info().unwrap();
+    /// assert_eq!(
+    ///     (info.table.as_str(), info.kind, info.has_where),
+    ///     ("sessions", DmlKind::Delete, true)
+    /// );
+    /// ```
+    pub fn dml_info(&self) -> Option<DmlInfo> {
+        let kind = match self.action {
+            Action::Add | Action::Put => DmlKind::Insert,
+            Action::Set => DmlKind::Update,
+            Action::Del => DmlKind::Delete,
+            _ => return None,
+        };
+        let has_where = self
+            .cages
+            .iter()
+            .any(|cage| cage.kind == CageKind::Filter && !cage.conditions.is_empty());
+        // `users u` / `users AS u` name the table `users`.
+        let table = self.table.split_whitespace().next().unwrap_or(&self.table);
+        Some(DmlInfo {
+            table: table.to_string(),
+            kind,
+            has_where,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn dml_info_classifies_writes() {
+        let info = |query: &str| parse(query).unwrap().dml_info();
+
+        assert_eq!(
+            info("add users fields name values 'Ana'"),
+            Some(DmlInfo {
+                table: "users".to_string(),
+                kind: DmlKind::Insert,
+                has_where: false,
+            })
+        );
+        assert_eq!(
+            info("set users values verified = true where id = $1"),
+            Some(DmlInfo {
+                table: "users".to_string(),
+                kind: DmlKind::Update,
+                has_where: true,
+            })
+        );
+        assert_eq!(
+            info("del sessions"),
+            Some(DmlInfo {
+                table: "sessions".to_string(),
+                kind: DmlKind::Delete,
+                has_where: false,
+            })
+        );
+    }
+
+    #[test]
+    fn dml_info_strips_table_alias() {
+        let info = Qail::del("sessions s").dml_info().unwrap();
+        assert_eq!(info.table, "sessions");
+
+        let info = Qail::set("users AS u").dml_info().unwrap();
+        assert_eq!(info.table, "users");
+    }
+
+    #[test]
+    fn dml_info_is_none_for_reads_and_ddl() {
+        assert_eq!(parse("get users fields id").unwrap().dml_info(), None);
+        assert_eq!(parse("make users id:uuid:pk").unwrap().dml_info(), None);
+    }
+}
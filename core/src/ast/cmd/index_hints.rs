@@ -0,0 +1,118 @@
+//! Static index-usability hints for filter conditions.
+
+use crate::ast::{CageKind, Condition, Operator, Qail, Value};
+
+/// Why a filter cannot use a plain B-tree index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonSargableReason {
+    /// LIKE/ILIKE pattern starts with `%` or `_`.
+    LeadingWildcard,
+    /// Fuzzy `~` match, which always wraps the value in `%...%`.
+    FuzzyMatch,
+}
+
+/// A filter condition classified by whether an index can serve it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterIndexHint {
+    /// The classified filter condition.
+    pub condition: Condition,
+    /// Why the filter is non-sargable, or `None` if an index is usable.
+    pub non_sargable: Option<NonSargableReason>,
+}
+
+impl FilterIndexHint {
+    /// Whether an index on the filtered column can serve this condition.
+    pub fn is_sargable(&self) -> bool {
+        self.non_sargable.is_none()
+    }
+}
+
+impl Qail {
+    /// Classify every `WHERE` filter condition for index usability.
+    ///
+    /// This is static analysis over the conditions only: a prefix search
+    /// (`name like 'foo%'`) is sargable, while a leading wildcard
+    /// (`'%foo'`, `'%foo%'`) or fuzzy match forces a scan. Bound parameters
+    /// are assumed sargable since their value is unknown.
+    ///
+    /// ```
+    /// use qail_core::prelude::*;
+    ///
+    /// let cmd = Qail::get("users").filter("name", Operator::Like, "%foo");
+    /// assert!(!cmd.index_usable_filters()[0].is_sargable());
+    /// ```
+    pub fn index_usable_filters(&self) -> Vec<FilterIndexHint> {
+        self.cages
+            .iter()
+            .filter(|cage| cage.kind == CageKind::Filter)
+            .flat_map(|cage| &cage.conditions)
+            .map(|condition| FilterIndexHint {
+                condition: condition.clone(),
+                non_sargable: non_sargable_reason(condition),
+            })
+            .collect()
+    }
+}
+
+fn non_sargable_reason(condition: &Condition) -> Option<NonSargableReason> {
+    match condition.op {
+        Operator::Fuzzy => Some(NonSargableReason::FuzzyMatch),
+        Operator::Like | Operator::NotLike | Operator::ILike | Operator::NotILike => {
+            match &condition.value {
+                Value::String(pattern) if pattern.starts_with(['%', '_']) => {
+                    Some(NonSargableReason::LeadingWildcard)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn like_hint(pattern: &str) -> FilterIndexHint {
+        let cmd = Qail::get("users").filter("name", Operator::Like, pattern);
+        cmd.index_usable_filters().remove(0)
+    }
+
+    #[test]
+    fn test_prefix_like_is_sargable() {
+        assert!(like_hint("foo%").is_sargable());
+    }
+
+    #[test]
+    fn test_leading_wildcard_like_is_not_sargable() {
+        for pattern in ["%foo", "%foo%", "_oo"] {
+            assert_eq!(
+                like_hint(pattern).non_sargable,
+                Some(NonSargableReason::LeadingWildcard),
+                "{pattern}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_classifies_parsed_filters() {
+        let cmd = crate::parser::parse(
+            "get users fields id where email like 'a%' and name ilike '%b' and bio ~ 'c' and id = $1",
+        )
+        .unwrap();
+        let hints: Vec<_> = cmd
+            .index_usable_filters()
+            .into_iter()
+            .map(|hint| hint.non_sargable)
+            .collect();
+        assert_eq!(
+            hints,
+            [
+                None,
+                Some(NonSargableReason::LeadingWildcard),
+                Some(NonSargableReason::FuzzyMatch),
+                None,
+            ]
+        );
+    }
+}
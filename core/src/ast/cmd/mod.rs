@@ -39,8 +39,19 @@ pub struct Qail {
     /// PostgreSQL MERGE specification.
     #[serde(default)]
     pub merge: Option<Merge>,
+    /// `COPY … TO STDOUT` format options for exports.
+    #[serde(default)]
+    pub copy_options: Option<CopyOptions>,
     /// INSERT … SELECT source query.
     pub source_query: Option<Box<Qail>>,
+    /// Set-returning function used as the FROM source
+    /// (`Expr::FunctionCall`, e.g. `generate_series(1, 10)`).
+    #[serde(default)]
+    pub table_function: Option<Expr>,
+    /// Set-returning functions listed in FROM after the table, e.g.
+    /// `unnest(tags) AS tag`; `true` adds `WITH ORDINALITY`.
+    #[serde(default)]
+    pub from_functions: Vec<(Expr, bool)>,
     /// LISTEN/NOTIFY channel.
     pub channel: Option<String>,
     /// NOTIFY payload.
@@ -55,6 +66,9 @@ pub struct Qail {
     pub lock_mode: Option<LockMode>,
     /// SKIP LOCKED modifier for row locking (FOR UPDATE SKIP LOCKED).
     pub skip_locked: bool,
+    /// NOWAIT modifier for row locking (FOR SHARE NOWAIT).
+    #[serde(default)]
+    pub nowait: bool,
     /// FETCH FIRST n ROWS [ONLY|WITH TIES].
     pub fetch: Option<(u64, bool)>,
     /// INSERT with DEFAULT VALUES.
@@ -87,6 +101,9 @@ pub struct Qail {
     pub trigger_def: Option<crate::ast::TriggerDef>,
     /// RLS policy definition.
     pub policy_def: Option<crate::migrate::policy::RlsPolicy>,
+    /// Leading comment captured by [`crate::parser::parse_with_comments`].
+    #[serde(default)]
+    pub doc: Option<String>,
 }
 
 /// Common Table Expression (WITH clause) definition.
@@ -111,6 +128,9 @@ pub struct CTEDef {
 pub struct OnConflict {
     /// Conflict target columns.
     pub columns: Vec<String>,
+    /// Partial-index predicate (`ON CONFLICT (cols) WHERE ...`), ANDed.
+    #[serde(default)]
+    pub predicate: Vec<Condition>,
     /// What to do on conflict.
     pub action: ConflictAction,
 }
@@ -127,6 +147,50 @@ pub enum ConflictAction {
     },
 }
 
+/// Output options for `COPY (…) TO STDOUT` exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct CopyOptions {
+    /// Output format.
+    pub format: CopyFormat,
+    /// Emit a header row with the column names.
+    pub header: bool,
+}
+
+impl CopyOptions {
+    /// CSV with a header row.
+    pub fn csv_with_header() -> Self {
+        Self {
+            format: CopyFormat::Csv,
+            header: true,
+        }
+    }
+}
+
+impl std::fmt::Display for CopyOptions {
+    /// Renders the `WITH (...)` clause, e.g. `WITH (FORMAT csv, HEADER true)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self.format {
+            CopyFormat::Text => "text",
+            CopyFormat::Csv => "csv",
+        };
+        write!(f, "WITH (FORMAT {}", format)?;
+        if self.header {
+            write!(f, ", HEADER true")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// `COPY` data format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum CopyFormat {
+    /// Tab-separated text (PostgreSQL default).
+    #[default]
+    Text,
+    /// Comma-separated values.
+    Csv,
+}
+
 /// PostgreSQL `MERGE` specification.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Merge {
@@ -206,6 +270,7 @@ impl Default for OnConflict {
     fn default() -> Self {
         Self {
             columns: vec![],
+            predicate: vec![],
             action: ConflictAction::DoNothing,
         }
     }
@@ -239,7 +304,10 @@ impl Default for Qail {
             returning: None,
             on_conflict: None,
             merge: None,
+            copy_options: None,
             source_query: None,
+            table_function: None,
+            from_functions: vec![],
             channel: None,
             payload: None,
             savepoint_name: None,
@@ -247,6 +315,7 @@ impl Default for Qail {
             using_tables: vec![],
             lock_mode: None,
             skip_locked: false,
+            nowait: false,
             fetch: None,
             default_values: false,
             overriding: None,
@@ -264,6 +333,7 @@ impl Default for Qail {
             function_def: None,
             trigger_def: None,
             policy_def: None,
+            doc: None,
         }
     }
 }
@@ -272,11 +342,22 @@ impl Default for Qail {
 mod advanced;
 mod constructors;
 mod cte;
+mod dml_info;
+mod index_hints;
 mod merge;
+mod normalize;
+mod params;
 mod query;
 mod rls;
+mod schema_prefix;
+mod set_ops;
+mod table_rewrite;
 mod vector;
 
+pub use dml_info::{DmlInfo, DmlKind};
+pub use index_hints::{FilterIndexHint, NonSargableReason};
+pub use normalize::NormalizedCmd;
+
 impl std::fmt::Display for Qail {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Use the Formatter from the fmt module for canonical output
@@ -0,0 +1,164 @@
+//! Literal-free query shapes for semantic deduplication.
+
+use std::hash::{Hash, Hasher};
+
+use crate::ast::visit::{VisitMut, walk_qail_mut};
+use crate::ast::{Condition, Expr, Qail, Value};
+
+/// A query with its literal values replaced by positional placeholders.
+///
+/// Two commands that differ only in constants normalize to equal
+/// `NormalizedCmd`s, so this can key a cache of prepared statements or plans.
+#[derive(Debug, Clone)]
+pub struct NormalizedCmd {
+    shape: Qail,
+    key: String,
+}
+
+impl NormalizedCmd {
+    /// The command with literals replaced by `Value::Param(n)`.
+    pub fn shape(&self) -> &Qail {
+        &self.shape
+    }
+
+    /// Canonical serialized form used for equality and hashing.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl PartialEq for NormalizedCmd {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for NormalizedCmd {}
+
+impl Hash for NormalizedCmd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl Qail {
+    /// Split this command into a literal-free shape and its constants.
+    ///
+    /// Literal condition values (filters, HAVING, join `ON`, `MERGE`
+    /// conditions, SET/INSERT payloads), including those in CTEs, set
+    /// operations and subqueries, become `Value::Param(n)`. Numbering
+    /// continues after the highest placeholder already present, and the
+    /// returned values are in placeholder order. Structural parts such as `LIMIT`, `NULL` and
+    /// column references are kept in the shape. The `doc` comment is
+    /// ignored.
+    ///
+    /// ```ignore
+    /// let (a, va) = qail_core::parse("get users where id = 1")?.normalize();
+    /// let (b, vb) = qail_core::parse("get users where id = 2")?.normalize();
+    /// assert_eq!(a, b);
+    /// assert_ne!(va, vb);
+    /// ```
+    pub fn normalize(&self) -> (NormalizedCmd, Vec<Value>) {
+        let mut shape = self.clone();
+        shape.doc = None;
+
+        let mut max_param = 0usize;
+        shape.visit_condition_values(&mut |value| {
+            if let Value::Param(n) = value {
+                max_param = max_param.max(*n);
+            }
+        });
+
+        let mut literals = Vec::new();
+        shape.visit_condition_values(&mut |value| {
+            if is_literal(value) {
+                literals.push(std::mem::replace(
+                    value,
+                    Value::Param(max_param + literals.len() + 1),
+                ));
+            }
+        });
+
+        let key = serde_json::to_string(&shape).unwrap_or_else(|_| format!("{shape:?}"));
+        (NormalizedCmd { shape, key }, literals)
+    }
+
+    fn visit_condition_values(&mut self, f: &mut impl FnMut(&mut Value)) {
+        ConditionValues(f).visit_qail(self);
+    }
+}
+
+/// Hands every condition value to `F`, descending into nested commands.
+/// Expressions keep their literals: they can be structural (`ORDER BY 1`).
+struct ConditionValues<F>(F);
+
+impl<F: FnMut(&mut Value)> VisitMut for ConditionValues<F> {
+    fn visit_qail(&mut self, cmd: &mut Qail) {
+        // An ON CONFLICT predicate must match a partial index verbatim.
+        let on_conflict = cmd.on_conflict.take();
+        walk_qail_mut(self, cmd);
+        cmd.on_conflict = on_conflict;
+    }
+
+    fn visit_condition(&mut self, condition: &mut Condition) {
+        match &mut condition.value {
+            Value::Subquery(sub) => self.visit_qail(sub),
+            value => (self.0)(value),
+        }
+    }
+
+    fn visit_expr(&mut self, _expr: &mut Expr) {}
+}
+
+fn is_literal(value: &Value) -> bool {
+    match value {
+        Value::Bool(_)
+        | Value::Int(_)
+        | Value::BigInt(_)
+        | Value::Float(_)
+        | Value::String(_)
+        | Value::Uuid(_)
+        | Value::Interval { .. }
+        | Value::IntervalParts { .. }
+        | Value::Timestamp(_)
+        | Value::Bytes(_)
+        | Value::Vector(_)
+        | Value::Json(_) => true,
+        Value::Array(values) => !values.is_empty() && values.iter().all(is_literal),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Value;
+    use crate::parser::parse;
+
+    #[test]
+    fn literal_only_differences_share_a_shape() {
+        let (a, a_values) = parse("get users[id = 1] fields id").unwrap().normalize();
+        let (b, b_values) = parse("get users[id = 2] fields id").unwrap().normalize();
+
+        assert_eq!(a, b);
+        assert_eq!(a_values, vec![Value::Int(1)]);
+        assert_eq!(b_values, vec![Value::Int(2)]);
+        assert_eq!(a.shape().cages[0].conditions[0].value, Value::Param(1));
+
+        let (c, _) = parse("get users fields id where email = 'x'")
+            .unwrap()
+            .normalize();
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn normalize_numbers_after_existing_params() {
+        let (shape, values) = parse("get users where org_id = $1 and active = true")
+            .unwrap()
+            .normalize();
+
+        let conditions = &shape.shape().cages[0].conditions;
+        assert_eq!(conditions[0].value, Value::Param(1));
+        assert_eq!(conditions[1].value, Value::Param(2));
+        assert_eq!(values, vec![Value::Bool(true)]);
+    }
+}
@@ -0,0 +1,111 @@
+//! Named parameter discovery and binding without transpiling.
+
+use crate::ast::visit::{Visit, VisitMut};
+use crate::ast::{Qail, Value};
+
+impl Qail {
+    /// Names of the `:named` parameters this command uses, deduplicated in
+    /// first-appearance order.
+    ///
+    /// Walks CTEs, selected expressions, joins, cages (filters, payloads,
+    /// sorts), `HAVING`, `ON CONFLICT`/`MERGE` assignments, `RETURNING`,
+    /// subqueries and set operations. `LIMIT`/`OFFSET` are plain integers
+    /// and never hold parameters.
+    ///
+    /// ```
+    /// let cmd = qail_core::parser::parse(
+    ///     "get users fields id where org_id = :org and role = :role and owner = :org",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cmd.param_names(), ["org", "role"]);
+    /// ```
+    pub fn param_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.visit_values(&mut |value| {
+            if let Value::NamedParam(name) = value
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        });
+        names
+    }
+
+    /// Replace every `:named` parameter with a positional `$n`.
+    ///
+    /// Names are numbered in [`param_names`](Self::param_names) order,
+    /// starting after the highest positional parameter already present, so
+    /// explicit `$n` keep their meaning and a repeated name reuses its
+    /// number. Returns the names in placeholder order.
+    pub(crate) fn bind_named_params(&mut self) -> Vec<String> {
+        let names = self.param_names();
+        if names.is_empty() {
+            return names;
+        }
+        let mut max_positional = 0usize;
+        self.visit_values(&mut |value| {
+            if let Value::Param(n) = value {
+                max_positional = max_positional.max(*n);
+            }
+        });
+        self.visit_values_mut(&mut |value| {
+            if let Value::NamedParam(name) = value
+                && let Some(pos) = names.iter().position(|known| known == name)
+            {
+                *value = Value::Param(max_positional + pos + 1);
+            }
+        });
+        names
+    }
+
+    /// Visit every value reachable from this command, in clause order.
+    pub(crate) fn visit_values_mut(&mut self, f: &mut impl FnMut(&mut Value)) {
+        ScalarsMut(f).visit_qail(self);
+    }
+
+    /// Visit every value reachable from this command, in clause order,
+    /// without mutating it.
+    pub(crate) fn visit_values(&self, f: &mut impl FnMut(&Value)) {
+        Scalars(f).visit_qail(self);
+    }
+}
+
+/// Hands every scalar value to `F`.
+struct Scalars<F>(F);
+
+impl<F: FnMut(&Value)> Visit for Scalars<F> {
+    fn visit_scalar(&mut self, value: &Value) {
+        (self.0)(value);
+    }
+}
+
+/// Hands every scalar value to `F` for rewriting.
+struct ScalarsMut<F>(F);
+
+impl<F: FnMut(&mut Value)> VisitMut for ScalarsMut<F> {
+    fn visit_scalar(&mut self, value: &mut Value) {
+        (self.0)(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse;
+
+    #[test]
+    fn param_names_in_first_appearance_order() {
+        let cmd = parse(
+            "get users fields id, coalesce(nickname, :fallback) as display \
+             where org_id = :org and id in (get members fields user_id where team = :team) \
+             and owner_org = :org",
+        )
+        .unwrap();
+        assert_eq!(cmd.param_names(), ["fallback", "org", "team"]);
+    }
+
+    #[test]
+    fn param_names_includes_payload() {
+        let cmd = parse("set users values name = :name where id = :id").unwrap();
+        assert_eq!(cmd.param_names(), ["name", "id"]);
+    }
+}
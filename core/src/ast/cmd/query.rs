@@ -318,6 +318,8 @@ impl Qail {
                 is_array_unnest: false,
             }]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         });
         self
     }
@@ -457,6 +459,16 @@ impl Qail {
         self
     }
 
+    /// Add several payload rows (multi-row INSERT), one Payload cage per row.
+    pub fn values_rows<I, R, V>(self, rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        rows.into_iter().fold(self, |cmd, row| cmd.values(row))
+    }
+
     /// Set a column = value pair for UPDATE or INSERT.
     pub fn set_value(mut self, column: impl AsRef<str>, value: impl Into<Value>) -> Self {
         let payload_cage = self
@@ -571,6 +583,7 @@ impl Qail {
                 .iter()
                 .map(|c| c.as_ref().to_string())
                 .collect(),
+            predicate: vec![],
             action: ConflictAction::DoUpdate {
                 assignments: updates
                     .iter()
@@ -600,6 +613,7 @@ impl Qail {
                 .iter()
                 .map(|c| c.as_ref().to_string())
                 .collect(),
+            predicate: vec![],
             action: ConflictAction::DoNothing,
         });
         self
@@ -0,0 +1,170 @@
+//! Schema qualification for unqualified table references.
+
+use crate::ast::visit::{VisitMut, walk_qail_mut};
+use crate::ast::{Action, Qail};
+
+impl Qail {
+    /// Qualify every unqualified table reference with `schema`.
+    ///
+    /// Applies to the main table, joins, `UPDATE … FROM` / `DELETE … USING`
+    /// tables, and recursively to CTE bodies, set operations, source queries
+    /// and subqueries anywhere in the command. Already-qualified names
+    /// (`other.users`) and references to CTE names in scope are left
+    /// untouched.
+    ///
+    /// ```ignore
+    /// let cmd = Qail::get("users").with_schema_prefix("tenant_42");
+    /// assert_eq!(cmd.to_sql(), "SELECT * FROM tenant_42.users");
+    /// ```
+    pub fn with_schema_prefix(mut self, schema: impl AsRef<str>) -> Self {
+        let schema = schema.as_ref();
+        if !schema.is_empty() {
+            SchemaPrefix {
+                schema,
+                ctes: Vec::new(),
+            }
+            .visit_qail(&mut self);
+        }
+        self
+    }
+}
+
+/// Qualifies the table references of every command it visits, tracking the
+/// CTE names in scope.
+struct SchemaPrefix<'a> {
+    schema: &'a str,
+    ctes: Vec<String>,
+}
+
+impl VisitMut for SchemaPrefix<'_> {
+    fn visit_qail(&mut self, cmd: &mut Qail) {
+        let outer_ctes = self.ctes.len();
+        self.ctes
+            .extend(cmd.ctes.iter().map(|cte| cte.name.clone()));
+
+        for cte in &mut cmd.ctes {
+            if let Some(source_table) = &mut cte.source_table {
+                *source_table = qualify_table_reference(source_table, self.schema, &self.ctes);
+            }
+        }
+        let is_write_target = matches!(
+            cmd.action,
+            Action::Add | Action::Set | Action::Del | Action::Put | Action::Over
+        );
+        // A source query on a SELECT is a derived table; its "table" is the alias.
+        if is_write_target || cmd.source_query.is_none() {
+            cmd.table = qualify_table_reference(&cmd.table, self.schema, &self.ctes);
+        }
+        // A derived-table join's "table" is its alias.
+        for join in cmd.joins.iter_mut().filter(|join| join.subquery.is_none()) {
+            join.table = qualify_table_reference(&join.table, self.schema, &self.ctes);
+        }
+        for table in cmd.from_tables.iter_mut().chain(&mut cmd.using_tables) {
+            *table = qualify_table_reference(table, self.schema, &self.ctes);
+        }
+
+        walk_qail_mut(self, cmd);
+        self.ctes.truncate(outer_ctes);
+    }
+}
+
+/// Prefix `reference` (`table` or `table alias`) with `schema` when it names
+/// a plain, unqualified table that is not a CTE in scope.
+fn qualify_table_reference(reference: &str, schema: &str, ctes: &[String]) -> String {
+    let base = reference.split_whitespace().next().unwrap_or("");
+    let is_plain_identifier = !base.is_empty()
+        && base
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if !is_plain_identifier || ctes.iter().any(|cte| cte == base) {
+        return reference.to_string();
+    }
+    format!("{schema}.{}", reference.trim_start())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{Expr, Operator, Qail, Value};
+    use crate::transpiler::ToSql;
+
+    #[test]
+    fn joined_query_is_qualified_but_cte_names_are_not() {
+        let recent =
+            Qail::get("orders")
+                .columns(["id", "user_id"])
+                .filter("status", Operator::Eq, "paid");
+        let cmd = Qail::get("recent")
+            .with("recent", recent)
+            .columns(["recent.id", "users.email"])
+            .left_join("users", "recent.user_id", "users.id")
+            .left_join("audit.events", "events.order_id", "recent.id")
+            .with_schema_prefix("tenant_42");
+
+        assert_eq!(
+            cmd.to_sql(),
+            "WITH recent(id, user_id) AS (SELECT id, user_id FROM tenant_42.orders \
+             WHERE status = 'paid') \
+             SELECT recent.id, users.email FROM recent \
+             LEFT JOIN tenant_42.users ON recent.user_id = users.id \
+             LEFT JOIN audit.events ON events.order_id = recent.id"
+        );
+    }
+
+    #[test]
+    fn aliased_tables_and_awkward_schema_names_are_quoted_per_part() {
+        let cmd = Qail::get("users")
+            .table_alias("u")
+            .column("u.id")
+            .with_schema_prefix("tenant-42");
+        assert_eq!(cmd.to_sql(), "SELECT u.id FROM \"tenant-42\".users u");
+    }
+
+    #[test]
+    fn condition_and_expression_subqueries_are_qualified() {
+        let admins = Qail::get("admins").column("user_id");
+        let order_count = Qail::get("orders").column("total").limit(1);
+        let cmd = Qail::get("users")
+            .column("id")
+            .columns_expr([Expr::Subquery {
+                query: Box::new(order_count),
+                alias: Some("order_count".to_string()),
+            }])
+            .filter("id", Operator::In, Value::Subquery(Box::new(admins)))
+            .with_schema_prefix("tenant_42");
+
+        let sql = cmd.to_sql();
+        assert!(
+            sql.contains("(SELECT total FROM tenant_42.orders LIMIT 1) AS order_count"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("id IN (SELECT user_id FROM tenant_42.admins)"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn insert_select_qualifies_target_and_source() {
+        let mut cmd = Qail::add("orders").columns(["user_id"]);
+        cmd.source_query = Some(Box::new(Qail::get("users").column("id")));
+        let cmd = cmd.with_schema_prefix("tenant_42");
+
+        assert_eq!(cmd.table, "tenant_42.orders");
+        assert_eq!(cmd.source_query.as_ref().unwrap().table, "tenant_42.users");
+    }
+
+    #[test]
+    fn join_subquery_is_qualified_but_its_alias_is_not() {
+        let latest = Qail::get("orders").column("total").limit(1);
+        let cmd = Qail::get("users")
+            .column("recent.total")
+            .left_join_lateral(latest, "recent")
+            .with_schema_prefix("tenant_42");
+
+        assert_eq!(
+            cmd.to_sql(),
+            "SELECT recent.total FROM tenant_42.users LEFT JOIN LATERAL \
+             (SELECT total FROM tenant_42.orders LIMIT 1) recent ON TRUE"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Set-operation builders (UNION, INTERSECT, EXCEPT).
+
+use crate::ast::{Expr, Qail, SetOp};
+use crate::error::QailError;
+
+impl Qail {
+    /// Append `UNION <other>`.
+    ///
+    /// Errors when both branches have a known projection and their column
+    /// counts differ.
+    ///
+    /// ```ignore
+    /// let cmd = Qail::get("a").column("id").union(Qail::get("b").column("id"))?;
+    /// assert_eq!(cmd.to_sql(), "SELECT id FROM a UNION SELECT id FROM b");
+    /// ```
+    pub fn union(self, other: Qail) -> Result<Self, QailError> {
+        self.set_op(SetOp::Union, other)
+    }
+
+    /// Append `UNION ALL <other>`.
+    pub fn union_all(self, other: Qail) -> Result<Self, QailError> {
+        self.set_op(SetOp::UnionAll, other)
+    }
+
+    /// Append `INTERSECT <other>`.
+    pub fn intersect(self, other: Qail) -> Result<Self, QailError> {
+        self.set_op(SetOp::Intersect, other)
+    }
+
+    /// Append `EXCEPT <other>`.
+    pub fn except(self, other: Qail) -> Result<Self, QailError> {
+        self.set_op(SetOp::Except, other)
+    }
+
+    /// Append `op <other>` to `set_ops`, checking projection arity.
+    pub fn set_op(mut self, op: SetOp, other: Qail) -> Result<Self, QailError> {
+        if let (Some(left), Some(right)) = (self.projection_arity(), other.projection_arity())
+            && left != right
+        {
+            return Err(QailError::Validation(format!(
+                "{op:?} branches select a different number of columns ({left} vs {right})"
+            )));
+        }
+        self.set_ops.push((op, Box::new(other)));
+        Ok(self)
+    }
+
+    /// Column count of the projection, or `None` when it contains `*`.
+    fn projection_arity(&self) -> Option<usize> {
+        let is_star = |expr: &Expr| match expr {
+            Expr::Star => true,
+            Expr::Named(name) => name == "*" || name.ends_with(".*"),
+            _ => false,
+        };
+        if self.columns.is_empty() || self.columns.iter().any(is_star) {
+            None
+        } else {
+            Some(self.columns.len())
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Runtime retargeting of table references (sharding, blue/green swaps).
+
+use std::collections::HashMap;
+
+use crate::ast::visit::{VisitMut, walk_qail_mut};
+use crate::ast::{Action, Qail};
+
+impl Qail {
+    /// Replace table references according to `mapping` (logical → physical).
+    ///
+    /// Applies to the main table, joins, `UPDATE … FROM` / `DELETE … USING`
+    /// tables, and recursively to CTE bodies, set operations, source queries
+    /// and subqueries anywhere in the command (projections, conditions,
+    /// `RETURNING`, `ON CONFLICT`). CTE names in scope are never rewritten.
+    /// An unaliased read reference keeps its logical name as an alias, so
+    /// qualified columns such as `users.id` still resolve. Write targets
+    /// (INSERT, UPDATE, DELETE, bulk insert) are renamed in place, since
+    /// INSERT takes no alias and SQLite needs `AS` for one: qualify their
+    /// columns with the physical name, or leave them unqualified.
+    ///
+    /// ```ignore
+    /// let mut cmd = Qail::get("users").column("users.id");
+    /// cmd.rewrite_table(&HashMap::from([("users".into(), "users_shard_3".into())]));
+    /// assert_eq!(cmd.to_sql(), "SELECT users.id FROM users_shard_3 users");
+    /// ```
+    pub fn rewrite_table(&mut self, mapping: &HashMap<String, String>) {
+        if !mapping.is_empty() {
+            TableRewrite {
+                mapping,
+                ctes: Vec::new(),
+            }
+            .visit_qail(self);
+        }
+    }
+}
+
+/// Retargets the table references of every command it visits, tracking the
+/// CTE names in scope.
+struct TableRewrite<'a> {
+    mapping: &'a HashMap<String, String>,
+    ctes: Vec<String>,
+}
+
+impl TableRewrite<'_> {
+    fn rewrite(&self, reference: &str, keep_logical_alias: bool) -> String {
+        rewrite_table_reference(reference, self.mapping, &self.ctes, keep_logical_alias)
+    }
+}
+
+impl VisitMut for TableRewrite<'_> {
+    fn visit_qail(&mut self, cmd: &mut Qail) {
+        let outer_ctes = self.ctes.len();
+        self.ctes
+            .extend(cmd.ctes.iter().map(|cte| cte.name.clone()));
+
+        for cte in &mut cmd.ctes {
+            if let Some(source_table) = &mut cte.source_table {
+                *source_table = self.rewrite(source_table, true);
+            }
+        }
+        let is_write_target = matches!(
+            cmd.action,
+            Action::Add | Action::Set | Action::Del | Action::Put | Action::Over
+        );
+        // A source query on a SELECT is a derived table; its "table" is the alias.
+        if is_write_target || cmd.source_query.is_none() {
+            cmd.table = self.rewrite(&cmd.table, !is_write_target);
+        }
+        // A derived-table join's "table" is its alias.
+        for join in cmd.joins.iter_mut().filter(|join| join.subquery.is_none()) {
+            join.table = self.rewrite(&join.table, true);
+        }
+        for table in cmd.from_tables.iter_mut().chain(&mut cmd.using_tables) {
+            *table = self.rewrite(table, true);
+        }
+
+        walk_qail_mut(self, cmd);
+        self.ctes.truncate(outer_ctes);
+    }
+}
+
+/// Swap the base of `reference` (`table` or `table alias`) for its mapped
+/// name. With `keep_logical_alias`, the logical name becomes the alias when
+/// none was given.
+fn rewrite_table_reference(
+    reference: &str,
+    mapping: &HashMap<String, String>,
+    ctes: &[String],
+    keep_logical_alias: bool,
+) -> String {
+    let mut parts = reference.split_whitespace();
+    let base = parts.next().unwrap_or("");
+    let Some(target) = mapping.get(base) else {
+        return reference.to_string();
+    };
+    if ctes.iter().any(|cte| cte == base) {
+        return reference.to_string();
+    }
+    let alias: Vec<&str> = parts.collect();
+    if alias.is_empty() && !keep_logical_alias {
+        target.clone()
+    } else if alias.is_empty() {
+        let logical = base.rsplit('.').next().unwrap_or(base);
+        format!("{target} {logical}")
+    } else {
+        format!("{target} {}", alias.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::ast::{ConflictAction, Expr, OnConflict, Operator, Qail, Value};
+    use crate::transpiler::{Dialect, ToSql};
+
+    fn shard_map() -> HashMap<String, String> {
+        HashMap::from([
+            ("users".to_string(), "users_shard_3".to_string()),
+            ("orders".to_string(), "orders_shard_3".to_string()),
+            ("recent".to_string(), "must_not_apply".to_string()),
+        ])
+    }
+
+    #[test]
+    fn joined_query_is_retargeted_but_cte_names_are_not() {
+        let recent =
+            Qail::get("orders")
+                .columns(["id", "user_id"])
+                .filter("status", Operator::Eq, "paid");
+        let mut cmd = Qail::get("recent")
+            .with("recent", recent)
+            .columns(["recent.id", "users.email"])
+            .left_join("users", "recent.user_id", "users.id");
+        cmd.rewrite_table(&shard_map());
+
+        assert_eq!(
+            cmd.to_sql(),
+            "WITH recent(id, user_id) AS (SELECT id, user_id FROM orders_shard_3 orders \
+             WHERE status = 'paid') \
+             SELECT recent.id, users.email FROM recent \
+             LEFT JOIN users_shard_3 users ON recent.user_id = users.id"
+        );
+    }
+
+    #[test]
+    fn aliases_are_kept_and_subqueries_are_rewritten() {
+        let orders = Qail::get("orders").column("user_id");
+        let mut cmd = Qail::get("users").table_alias("u").column("u.id").filter(
+            "u.id",
+            Operator::In,
+            Value::Subquery(Box::new(orders)),
+        );
+        cmd.rewrite_table(&shard_map());
+
+        assert_eq!(
+            cmd.to_sql(),
+            "SELECT u.id FROM users_shard_3 u \
+             WHERE u.id IN (SELECT user_id FROM orders_shard_3 orders)"
+        );
+    }
+
+    #[test]
+    fn write_targets_are_renamed_without_an_alias() {
+        let mut insert = Qail::add("users").set_value("name", "ada");
+        insert.rewrite_table(&shard_map());
+        assert_eq!(
+            insert.to_sql(),
+            "INSERT INTO users_shard_3 VALUES ('ada') RETURNING *"
+        );
+
+        let mut delete = Qail::del("users").eq("id", 7);
+        delete.rewrite_table(&shard_map());
+        assert_eq!(
+            delete.to_sql_with_dialect(Dialect::SQLite),
+            "DELETE FROM \"users_shard_3\" WHERE \"id\" = 7"
+        );
+
+        let mut update = Qail::set("users").set_value("name", "ada").eq("id", 7);
+        update.rewrite_table(&shard_map());
+        assert_eq!(
+            update.to_sql(),
+            "UPDATE users_shard_3 SET name = 'ada' WHERE id = 7"
+        );
+    }
+
+    #[test]
+    fn insert_select_renames_target_and_source() {
+        let mut cmd = Qail::add("orders").columns(["user_id"]);
+        cmd.source_query = Some(Box::new(Qail::get("users").column("id")));
+        cmd.rewrite_table(&shard_map());
+
+        assert_eq!(cmd.table, "orders_shard_3");
+        assert_eq!(
+            cmd.source_query.as_ref().unwrap().table,
+            "users_shard_3 users"
+        );
+    }
+
+    #[test]
+    fn join_subquery_is_rewritten_but_its_alias_is_not() {
+        let latest = Qail::get("orders").column("total").limit(1);
+        let mut cmd = Qail::get("users")
+            .column("recent.total")
+            .left_join_lateral(latest, "recent");
+        cmd.rewrite_table(&shard_map());
+
+        assert_eq!(
+            cmd.to_sql(),
+            "SELECT recent.total FROM users_shard_3 users LEFT JOIN LATERAL \
+             (SELECT total FROM orders_shard_3 orders LIMIT 1) recent ON TRUE"
+        );
+    }
+
+    #[test]
+    fn projection_upsert_and_returning_subqueries_are_rewritten() {
+        let latest_total = || Expr::Subquery {
+            query: Box::new(Qail::get("orders").column("total").limit(1)),
+            alias: Some("latest_total".to_string()),
+        };
+        let subquery_table = |expr: &Expr| match expr {
+            Expr::Subquery { query, .. } => query.table.clone(),
+            other => panic!("expected a subquery, got {other:?}"),
+        };
+
+        let mut select = Qail::get("users")
+            .column("id")
+            .columns_expr([latest_total()]);
+        select.rewrite_table(&shard_map());
+        assert_eq!(subquery_table(&select.columns[1]), "orders_shard_3 orders");
+
+        let mut upsert = Qail::add("users")
+            .set_value("id", 7)
+            .on_conflict_update(&["id"], &[("last_total", latest_total())]);
+        upsert.returning = Some(vec![Expr::Named("id".to_string()), latest_total()]);
+        upsert.rewrite_table(&shard_map());
+
+        assert_eq!(upsert.table, "users_shard_3");
+        let Some(OnConflict {
+            action: ConflictAction::DoUpdate { assignments },
+            ..
+        }) = &upsert.on_conflict
+        else {
+            panic!("expected ON CONFLICT DO UPDATE");
+        };
+        assert_eq!(subquery_table(&assignments[0].1), "orders_shard_3 orders");
+        let returning = upsert.returning.as_ref().unwrap();
+        assert_eq!(subquery_table(&returning[1]), "orders_shard_3 orders");
+    }
+}
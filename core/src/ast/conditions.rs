@@ -13,6 +13,23 @@ pub struct Condition {
     pub is_array_unnest: bool,
 }
 
+impl Condition {
+    /// `IS NULL` / `IS NOT NULL` for an `=` / `!=` comparison against a NULL literal.
+    ///
+    /// `col = NULL` is never true in SQL, so renderers emit a NULL test instead.
+    /// Raw `{...}` conditions also use `= NULL` as their marker and are left alone.
+    pub fn null_test(&self) -> Option<Operator> {
+        if matches!(&self.left, Expr::Named(name) if name.starts_with('{') && name.ends_with('}')) {
+            return None;
+        }
+        match (self.op, &self.value) {
+            (Operator::Eq, Value::Null) => Some(Operator::IsNull),
+            (Operator::Ne, Value::Null) => Some(Operator::IsNotNull),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for Condition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {} {}", self.left, self.op.sql_symbol(), self.value)
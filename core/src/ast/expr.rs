@@ -39,6 +39,8 @@ pub enum BinaryOp {
     IsNull,
     /// IS NOT NULL.
     IsNotNull,
+    /// `AT TIME ZONE` (right side is the zone name).
+    AtTimeZone,
 }
 
 impl std::fmt::Display for BinaryOp {
@@ -60,6 +62,7 @@ impl std::fmt::Display for BinaryOp {
             BinaryOp::Lte => write!(f, "<="),
             BinaryOp::IsNull => write!(f, "IS NULL"),
             BinaryOp::IsNotNull => write!(f, "IS NOT NULL"),
+            BinaryOp::AtTimeZone => write!(f, "AT TIME ZONE"),
         }
     }
 }
@@ -304,6 +307,8 @@ impl std::fmt::Display for Expr {
             Expr::Mod { kind, col } => match kind {
                 ModKind::Add => write!(f, "+{}", col),
                 ModKind::Drop => write!(f, "-{}", col),
+                ModKind::AddIfNotExists => write!(f, "+?{}", col),
+                ModKind::DropIfExists => write!(f, "-?{}", col),
             },
             Expr::Window {
                 name,
@@ -462,7 +467,8 @@ impl std::fmt::Display for Expr {
                 collation,
                 alias,
             } => {
-                write!(f, "{} COLLATE \"{}\"", expr, collation)?;
+                write!(f, "{} COLLATE ", expr)?;
+                crate::fmt::write_collation(f, collation)?;
                 if let Some(a) = alias {
                     write!(f, " AS {}", a)?;
                 }
@@ -539,6 +545,9 @@ pub enum WindowFrame {
         start: FrameBound,
         /// Frame end bound.
         end: FrameBound,
+        /// Optional EXCLUDE clause.
+        #[serde(default)]
+        exclude: Option<FrameExclusion>,
     },
     /// RANGE BETWEEN start AND end
     Range {
@@ -546,9 +555,36 @@ pub enum WindowFrame {
         start: FrameBound,
         /// Frame end bound.
         end: FrameBound,
+        /// Optional EXCLUDE clause.
+        #[serde(default)]
+        exclude: Option<FrameExclusion>,
     },
 }
 
+/// Window frame EXCLUDE clause
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FrameExclusion {
+    /// EXCLUDE CURRENT ROW.
+    CurrentRow,
+    /// EXCLUDE GROUP (the current row and its peers).
+    Group,
+    /// EXCLUDE TIES (peers of the current row, but not the row itself).
+    Ties,
+    /// EXCLUDE NO OTHERS.
+    NoOthers,
+}
+
+impl std::fmt::Display for FrameExclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameExclusion::CurrentRow => write!(f, "EXCLUDE CURRENT ROW"),
+            FrameExclusion::Group => write!(f, "EXCLUDE GROUP"),
+            FrameExclusion::Ties => write!(f, "EXCLUDE TIES"),
+            FrameExclusion::NoOthers => write!(f, "EXCLUDE NO OTHERS"),
+        }
+    }
+}
+
 /// Window frame boundary
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum FrameBound {
@@ -634,6 +670,54 @@ pub enum TableConstraint {
     },
 }
 
+impl Expr {
+    /// The expression with its output alias removed.
+    ///
+    /// Aliases are only valid in a select list; positions such as
+    /// `DISTINCT ON (...)` and `ORDER BY` must render the bare expression.
+    /// Borrows when there is no alias to strip.
+    pub fn without_alias(&self) -> std::borrow::Cow<'_, Expr> {
+        use std::borrow::Cow;
+
+        let mut stripped = match self {
+            Expr::Aliased { name, .. } => return Cow::Owned(Expr::Named(name.clone())),
+            Expr::Aggregate { alias: Some(_), .. }
+            | Expr::Cast { alias: Some(_), .. }
+            | Expr::Case { alias: Some(_), .. }
+            | Expr::JsonAccess { alias: Some(_), .. }
+            | Expr::FunctionCall { alias: Some(_), .. }
+            | Expr::SpecialFunction { alias: Some(_), .. }
+            | Expr::Binary { alias: Some(_), .. }
+            | Expr::ArrayConstructor { alias: Some(_), .. }
+            | Expr::RowConstructor { alias: Some(_), .. }
+            | Expr::Subscript { alias: Some(_), .. }
+            | Expr::Collate { alias: Some(_), .. }
+            | Expr::FieldAccess { alias: Some(_), .. }
+            | Expr::Subquery { alias: Some(_), .. }
+            | Expr::Exists { alias: Some(_), .. } => self.clone(),
+            _ => return Cow::Borrowed(self),
+        };
+        match &mut stripped {
+            Expr::Aggregate { alias, .. }
+            | Expr::Cast { alias, .. }
+            | Expr::Case { alias, .. }
+            | Expr::JsonAccess { alias, .. }
+            | Expr::FunctionCall { alias, .. }
+            | Expr::SpecialFunction { alias, .. }
+            | Expr::Binary { alias, .. }
+            | Expr::ArrayConstructor { alias, .. }
+            | Expr::RowConstructor { alias, .. }
+            | Expr::Subscript { alias, .. }
+            | Expr::Collate { alias, .. }
+            | Expr::FieldAccess { alias, .. }
+            | Expr::Subquery { alias, .. }
+            | Expr::Exists { alias, .. } => *alias = None,
+            _ => {}
+        }
+        Cow::Owned(stripped)
+    }
+}
+
 // ==================== From Implementations for Ergonomic API ====================
 
 impl From<&str> for Expr {
@@ -1,4 +1,4 @@
-use crate::ast::{Condition, JoinKind};
+use crate::ast::{Condition, JoinKind, Qail};
 
 /// A JOIN clause in the query.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -11,4 +11,11 @@ pub struct Join {
     pub on: Option<Vec<Condition>>,
     /// If true, use ON TRUE (unconditional join). Used for joining CTEs.
     pub on_true: bool,
+    /// Shared column names for `USING (...)` equi-joins.
+    #[serde(default)]
+    pub using: Vec<String>,
+    /// Derived-table source, e.g. `LEFT JOIN LATERAL (SELECT ...) alias`;
+    /// `table` then holds the alias.
+    #[serde(default)]
+    pub subquery: Option<Box<Qail>>,
 }
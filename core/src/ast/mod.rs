@@ -14,17 +14,20 @@ pub mod joins;
 pub mod operators;
 /// Value types for parameters and literals.
 pub mod values;
+/// Shared command-tree traversal.
+pub(crate) mod visit;
 
 pub use self::cages::{Cage, CageKind};
 pub use self::cmd::Qail;
 pub use self::cmd::{
-    CTEDef, ConflictAction, Merge, MergeAction, MergeClause, MergeMatchKind, MergeSource,
+    CTEDef, ConflictAction, CopyFormat, CopyOptions, DmlInfo, DmlKind, FilterIndexHint, Merge,
+    MergeAction, MergeClause, MergeMatchKind, MergeSource, NonSargableReason, NormalizedCmd,
     OnConflict,
 };
 pub use self::conditions::Condition;
 pub use self::expr::{
-    BinaryOp, ColumnGeneration, Constraint, Expr, FrameBound, FunctionDef, IndexDef,
-    TableConstraint, TriggerDef, TriggerEvent, TriggerTiming, WindowFrame,
+    BinaryOp, ColumnGeneration, Constraint, Expr, FrameBound, FrameExclusion, FunctionDef,
+    IndexDef, TableConstraint, TriggerDef, TriggerEvent, TriggerTiming, WindowFrame,
 };
 pub use self::joins::Join;
 pub use self::operators::{
@@ -158,6 +158,10 @@ pub enum Action {
     CreatePolicy,
     /// DROP POLICY.
     DropPolicy,
+    /// TABLE name (shorthand for SELECT * FROM name).
+    Table,
+    /// Standalone VALUES row list.
+    Values,
 }
 
 impl std::fmt::Display for Action {
@@ -241,6 +245,8 @@ impl std::fmt::Display for Action {
             Action::Revoke => write!(f, "REVOKE"),
             Action::CreatePolicy => write!(f, "CREATE_POLICY"),
             Action::DropPolicy => write!(f, "DROP_POLICY"),
+            Action::Table => write!(f, "TABLE"),
+            Action::Values => write!(f, "VALUES"),
         }
     }
 }
@@ -329,6 +335,8 @@ pub enum Operator {
     RegexI,
     /// SIMILAR TO.
     SimilarTo,
+    /// pg_trgm similarity `%` (value is bound as-is, without wildcards).
+    Similar,
     /// JSONB `<@` contained-by.
     ContainedBy,
     /// Array `&&` overlap.
@@ -380,6 +388,7 @@ impl Operator {
             Operator::Regex => "~",
             Operator::RegexI => "~*",
             Operator::SimilarTo => "SIMILAR TO",
+            Operator::Similar => "%",
             Operator::ContainedBy => "<@",
             Operator::Overlaps => "&&",
             Operator::TextSearch => "@@",
@@ -471,8 +480,10 @@ pub enum JoinKind {
     Left,
     /// RIGHT (OUTER) JOIN.
     Right,
-    /// LATERAL join.
+    /// LEFT JOIN LATERAL.
     Lateral,
+    /// INNER JOIN LATERAL.
+    LateralInner,
     /// FULL (OUTER) JOIN.
     Full,
     /// CROSS JOIN.
@@ -499,6 +510,10 @@ pub enum ModKind {
     Add,
     /// DROP.
     Drop,
+    /// ADD ... IF NOT EXISTS (idempotent add).
+    AddIfNotExists,
+    /// DROP ... IF EXISTS (idempotent drop).
+    DropIfExists,
 }
 
 /// GROUP BY mode for advanced aggregations
@@ -6,6 +6,69 @@ pub(crate) fn escape_sql_literal_body(value: &str) -> String {
     value.replace('\0', "").replace('\'', "''")
 }
 
+/// Whether `text` is a plain decimal integer (optional leading `-`).
+///
+/// `Value::BigInt` payloads are emitted verbatim, so every SQL path checks
+/// them with this before writing them unquoted.
+pub fn is_integer_literal(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Render `dt` as a PostgreSQL `timestamptz` text literal body, e.g.
+/// `2024-01-02 03:04:05+00` or `2024-01-02 03:04:05.25+00`.
+///
+/// Precision is truncated to microseconds, which is all PostgreSQL stores,
+/// and trailing fractional zeros are dropped.
+pub fn timestamptz_text(dt: &chrono::DateTime<chrono::Utc>) -> String {
+    let micros = dt.timestamp_subsec_micros();
+    let base = dt.format("%Y-%m-%d %H:%M:%S");
+    if micros == 0 {
+        format!("{base}+00")
+    } else {
+        let fraction = format!("{micros:06}");
+        format!("{base}.{}+00", fraction.trim_end_matches('0'))
+    }
+}
+
+/// Render an interval as PostgreSQL's default `postgres` style text, e.g.
+/// `1 mon 7 days 03:00:00` or `2 years -1 days -00:00:00.5`.
+pub fn interval_text(months: i32, days: i32, micros: i64) -> String {
+    let plural = |n: i64| if n == 1 { "" } else { "s" };
+    let mut parts = Vec::new();
+
+    let years = i64::from(months) / 12;
+    let months = i64::from(months) % 12;
+    if years != 0 {
+        parts.push(format!("{years} year{}", plural(years)));
+    }
+    if months != 0 {
+        parts.push(format!("{months} mon{}", plural(months)));
+    }
+    if days != 0 {
+        let days = i64::from(days);
+        parts.push(format!("{days} day{}", plural(days)));
+    }
+
+    if micros != 0 || parts.is_empty() {
+        let sign = if micros < 0 { "-" } else { "" };
+        let abs = micros.unsigned_abs();
+        let hours = abs / 3_600_000_000;
+        let minutes = abs / 60_000_000 % 60;
+        let seconds = abs / 1_000_000 % 60;
+        let fraction = abs % 1_000_000;
+        let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+        if fraction != 0 {
+            let fraction = format!("{fraction:06}");
+            time.push('.');
+            time.push_str(fraction.trim_end_matches('0'));
+        }
+        parts.push(time);
+    }
+
+    parts.join(" ")
+}
+
 /// Time interval unit for duration expressions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum IntervalUnit {
@@ -48,6 +111,9 @@ pub enum Value {
     Bool(bool),
     /// 64-bit integer.
     Int(i64),
+    /// Integer literal outside the `i64` range, kept as its decimal digits
+    /// (optional leading `-`) and emitted verbatim for `numeric` columns.
+    BigInt(String),
     /// 64-bit float.
     Float(f64),
     /// Text string.
@@ -75,6 +141,16 @@ pub enum Value {
         /// Unit of time.
         unit: IntervalUnit,
     },
+    /// Structured interval with PostgreSQL's own component split, bound
+    /// without going through text arithmetic.
+    IntervalParts {
+        /// Whole months (years are stored as 12 months).
+        months: i32,
+        /// Whole days.
+        days: i32,
+        /// Microseconds of the time part.
+        micros: i64,
+    },
     /// Timestamp literal.
     Timestamp(String),
     /// Binary data (bytea)
@@ -93,6 +169,8 @@ impl std::fmt::Display for Value {
             Value::Null => write!(f, "NULL"),
             Value::Bool(b) => write!(f, "{}", b),
             Value::Int(n) => write!(f, "{}", n),
+            Value::BigInt(n) if is_integer_literal(n) => write!(f, "{}", n),
+            Value::BigInt(n) => write!(f, "'{}'", escape_sql_literal_body(n)),
             Value::Float(n) => write!(f, "{}", n),
             Value::String(s) => write!(f, "'{}'", escape_sql_literal_body(s)),
             Value::Param(n) => write!(f, "${}", n),
@@ -113,6 +191,11 @@ impl std::fmt::Display for Value {
             Value::Uuid(u) => write!(f, "'{}'", u),
             Value::NullUuid => write!(f, "NULL"),
             Value::Interval { amount, unit } => write!(f, "INTERVAL '{} {}'", amount, unit),
+            Value::IntervalParts {
+                months,
+                days,
+                micros,
+            } => write!(f, "INTERVAL '{}'", interval_text(*months, *days, *micros)),
             Value::Timestamp(ts) => write!(f, "'{}'", escape_sql_literal_body(ts)),
             Value::Bytes(bytes) => {
                 write!(f, "'\\x")?;
@@ -137,6 +220,34 @@ impl std::fmt::Display for Value {
     }
 }
 
+impl Value {
+    /// The `Value::Json` form of a string literal holding a non-empty JSON
+    /// object, so `@>` compares it as `jsonb` rather than leaving an untyped
+    /// literal. Arrays stay strings because `'[1,5]'` is also a range
+    /// literal, and `'{}'` / `'{a,b}'` because they are PostgreSQL array
+    /// literals; write a bare `[...]` JSON literal to get `jsonb`.
+    pub fn as_json_document(&self) -> Option<Value> {
+        let Value::String(text) = self else {
+            return None;
+        };
+        match serde_json::from_str::<serde_json::Value>(text).ok()? {
+            serde_json::Value::Object(map) if !map.is_empty() => Some(Value::Json(text.clone())),
+            _ => None,
+        }
+    }
+
+    /// Timestamp literal for a UTC instant, in `timestamptz` text form.
+    pub fn from_datetime(dt: &chrono::DateTime<chrono::Utc>) -> Self {
+        Value::Timestamp(timestamptz_text(dt))
+    }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for Value {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        Value::from_datetime(&dt)
+    }
+}
+
 impl From<bool> for Value {
     fn from(b: bool) -> Self {
         Value::Bool(b)
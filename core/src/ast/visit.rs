@@ -0,0 +1,230 @@
+//! Shared traversal of command trees.
+//!
+//! [`Visit`] and [`VisitMut`] walk every nested command, condition,
+//! expression and value of a [`Qail`] in clause order, which is also the
+//! order placeholders are numbered in. Implementors override the hooks they
+//! care about and call the matching `walk_*` function to keep descending.
+//! Both traits are generated from one definition so they cannot drift apart.
+
+use crate::ast::{
+    Condition, ConflictAction, Expr, MergeAction, MergeSource, Operator, Qail, Value,
+};
+
+macro_rules! visitor {
+    (
+        $visit:ident,
+        $walk_qail:ident,
+        $walk_condition:ident,
+        $walk_expr:ident,
+        $walk_value:ident
+        $(, $m:tt)?
+    ) => {
+        pub(crate) trait $visit {
+            /// A command: the root, CTE bodies, join, source and set-operation
+            /// queries, and subqueries in expressions and conditions.
+            fn visit_qail(&mut self, cmd: &$($m)? Qail) {
+                $walk_qail(self, cmd);
+            }
+
+            /// A condition from a filter, join `ON`, `HAVING`, `ON CONFLICT`,
+            /// `MERGE`, sort or aggregate `FILTER` clause.
+            fn visit_condition(&mut self, condition: &$($m)? Condition) {
+                $walk_condition(self, condition);
+            }
+
+            /// An expression, including nested ones.
+            fn visit_expr(&mut self, expr: &$($m)? Expr) {
+                $walk_expr(self, expr);
+            }
+
+            /// A value that is not an array, subquery or expression.
+            fn visit_scalar(&mut self, _value: &$($m)? Value) {}
+        }
+
+        pub(crate) fn $walk_qail<V: $visit + ?Sized>(v: &mut V, cmd: &$($m)? Qail) {
+            for cte in &$($m)? cmd.ctes {
+                v.visit_qail(&$($m)? cte.base_query);
+                if let Some(recursive) = &$($m)? cte.recursive_query {
+                    v.visit_qail(recursive);
+                }
+            }
+            for expr in &$($m)? cmd.distinct_on {
+                v.visit_expr(expr);
+            }
+            for expr in &$($m)? cmd.columns {
+                v.visit_expr(expr);
+            }
+            if let Some(function) = &$($m)? cmd.table_function {
+                v.visit_expr(function);
+            }
+            for (function, _) in &$($m)? cmd.from_functions {
+                v.visit_expr(function);
+            }
+            for join in &$($m)? cmd.joins {
+                if let Some(subquery) = &$($m)? join.subquery {
+                    v.visit_qail(subquery);
+                }
+                for condition in (&$($m)? join.on).into_iter().flatten() {
+                    v.visit_condition(condition);
+                }
+            }
+            for cage in &$($m)? cmd.cages {
+                for condition in &$($m)? cage.conditions {
+                    v.visit_condition(condition);
+                }
+            }
+            for condition in &$($m)? cmd.having {
+                v.visit_condition(condition);
+            }
+            if let Some(on_conflict) = &$($m)? cmd.on_conflict {
+                for condition in &$($m)? on_conflict.predicate {
+                    v.visit_condition(condition);
+                }
+                if let ConflictAction::DoUpdate { assignments } = &$($m)? on_conflict.action {
+                    for (_, expr) in assignments {
+                        v.visit_expr(expr);
+                    }
+                }
+            }
+            if let Some(merge) = &$($m)? cmd.merge {
+                if let MergeSource::Query { query, .. } = &$($m)? merge.source {
+                    v.visit_qail(query);
+                }
+                for condition in &$($m)? merge.on {
+                    v.visit_condition(condition);
+                }
+                for clause in &$($m)? merge.clauses {
+                    for condition in &$($m)? clause.condition {
+                        v.visit_condition(condition);
+                    }
+                    match &$($m)? clause.action {
+                        MergeAction::Update { assignments } => {
+                            for (_, expr) in assignments {
+                                v.visit_expr(expr);
+                            }
+                        }
+                        MergeAction::Insert { values, .. } => {
+                            for expr in values {
+                                v.visit_expr(expr);
+                            }
+                        }
+                        MergeAction::Delete | MergeAction::DoNothing => {}
+                    }
+                }
+            }
+            for expr in (&$($m)? cmd.returning).into_iter().flatten() {
+                v.visit_expr(expr);
+            }
+            if let Some(source) = &$($m)? cmd.source_query {
+                v.visit_qail(source);
+            }
+            for (_, set_query) in &$($m)? cmd.set_ops {
+                v.visit_qail(set_query);
+            }
+        }
+
+        pub(crate) fn $walk_condition<V: $visit + ?Sized>(
+            v: &mut V,
+            condition: &$($m)? Condition,
+        ) {
+            v.visit_expr(&$($m)? condition.left);
+            // Value-less operators never render their value, except EXISTS which
+            // renders a subquery; anything else there must not be visited.
+            let renders_value = condition.op.needs_value()
+                || matches!(
+                    (condition.op, &condition.value),
+                    (Operator::Exists | Operator::NotExists, Value::Subquery(_))
+                );
+            if renders_value {
+                $walk_value(v, &$($m)? condition.value);
+            }
+        }
+
+        pub(crate) fn $walk_expr<V: $visit + ?Sized>(v: &mut V, expr: &$($m)? Expr) {
+            match expr {
+                Expr::Star
+                | Expr::Named(_)
+                | Expr::Aliased { .. }
+                | Expr::Def { .. }
+                | Expr::JsonAccess { .. } => {}
+                Expr::Aggregate { filter, .. } => {
+                    for condition in filter.into_iter().flatten() {
+                        v.visit_condition(condition);
+                    }
+                }
+                Expr::Cast { expr, .. }
+                | Expr::Mod { col: expr, .. }
+                | Expr::Collate { expr, .. }
+                | Expr::FieldAccess { expr, .. } => v.visit_expr(expr),
+                Expr::Window { params, order, .. } => {
+                    for param in params {
+                        v.visit_expr(param);
+                    }
+                    for cage in order {
+                        for condition in &$($m)? cage.conditions {
+                            v.visit_condition(condition);
+                        }
+                    }
+                }
+                Expr::Case {
+                    when_clauses,
+                    else_value,
+                    ..
+                } => {
+                    for (condition, value) in when_clauses {
+                        v.visit_condition(condition);
+                        v.visit_expr(value);
+                    }
+                    if let Some(value) = else_value {
+                        v.visit_expr(value);
+                    }
+                }
+                Expr::FunctionCall { args, .. }
+                | Expr::ArrayConstructor { elements: args, .. }
+                | Expr::RowConstructor { elements: args, .. } => {
+                    for arg in args {
+                        v.visit_expr(arg);
+                    }
+                }
+                Expr::SpecialFunction { args, .. } => {
+                    for (_, arg) in args {
+                        v.visit_expr(arg);
+                    }
+                }
+                Expr::Binary { left, right, .. } => {
+                    v.visit_expr(left);
+                    v.visit_expr(right);
+                }
+                Expr::Subscript { expr, index, .. } => {
+                    v.visit_expr(expr);
+                    v.visit_expr(index);
+                }
+                Expr::Literal(value) => $walk_value(v, value),
+                Expr::Subquery { query, .. } | Expr::Exists { query, .. } => v.visit_qail(query),
+            }
+        }
+
+        pub(crate) fn $walk_value<V: $visit + ?Sized>(v: &mut V, value: &$($m)? Value) {
+            match value {
+                Value::Array(values) => {
+                    for value in values {
+                        $walk_value(v, value);
+                    }
+                }
+                Value::Subquery(query) => v.visit_qail(query),
+                Value::Expr(expr) => v.visit_expr(expr),
+                value => v.visit_scalar(value),
+            }
+        }
+    };
+}
+
+visitor!(Visit, walk_qail, walk_condition, walk_expr, walk_value);
+visitor!(
+    VisitMut,
+    walk_qail_mut,
+    walk_condition_mut,
+    walk_expr_mut,
+    walk_value_mut,
+    mut
+);
@@ -9,7 +9,7 @@ use crate::migrate::types::ColumnType;
 
 use super::schema::Schema;
 
-fn qail_type_to_rust(col_type: &ColumnType) -> &'static str {
+pub(crate) fn qail_type_to_rust(col_type: &ColumnType) -> &'static str {
     match col_type {
         ColumnType::Uuid => "uuid::Uuid",
         ColumnType::Text | ColumnType::Varchar(_) => "String",
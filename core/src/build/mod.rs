@@ -18,6 +18,7 @@
 //! - `QAIL=live` - Validate against live database
 //! - `QAIL=false` - Skip validation
 //! - `QAIL_SCAN_DIRS=src,app` - Comma-separated Rust source roots to scan
+//! - `QAIL_QUERY_FILE=queries.qail` - Type-check this `.qail` query file's parameters
 
 /// Typed schema code generation.
 mod codegen;
@@ -37,13 +38,15 @@ mod sql_guard;
 mod validate;
 
 // ── Re-exports for public API ────────────────────────────────────────
+pub(crate) use codegen::qail_type_to_rust;
 pub use codegen::{generate_schema_code, generate_typed_schema};
 pub use scanner::{
     QailUsage, scan_source_files, scan_source_text, source_uses_super_admin_without_allow,
 };
 pub use schema::{ForeignKey, ResourceSchema, Schema, TableSchema};
 pub use validate::{
-    ValidationDiagnostic, ValidationDiagnosticKind, validate, validate_against_schema_diagnostics,
+    ValidationDiagnostic, ValidationDiagnosticKind, load_query_file, validate,
+    validate_against_schema_diagnostics,
 };
 
 #[cfg(test)]
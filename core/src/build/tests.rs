@@ -4395,3 +4395,31 @@ fn demo() {
         "only SuperAdminToken::for_system_process(...) should set the file flag"
     );
 }
+
+#[test]
+fn test_load_query_file_infers_and_rejects_untyped_params() {
+    let schema = Schema::parse(
+        r#"
+table users {
+  id UUID primary_key
+  email TEXT
+}
+"#,
+    )
+    .unwrap();
+
+    let query_file = load_query_file(
+        "query find_user(email) -> User:\n  get users where email = :email\n",
+        &schema,
+    )
+    .unwrap();
+    assert_eq!(query_file.queries[0].params[0].typ, "String");
+
+    let err = load_query_file(
+        "query find_user(email, nick) -> User:\n  get users where email = :email and nickname = :nick\n",
+        &schema,
+    )
+    .unwrap_err();
+    assert!(err.contains("find_user(:nick)"), "{err}");
+    assert!(!err.contains("find_user(:email)"), "{err}");
+}
@@ -5,6 +5,7 @@ use std::path::Path;
 
 use super::scanner::{QailUsage, scan_source_files};
 use super::schema::Schema;
+use crate::parser::query_file::QueryFile;
 
 fn has_explicit_tenant_scope(cmd: &crate::ast::Qail) -> bool {
     cmd.cages.iter().any(|cage| {
@@ -402,6 +403,38 @@ fn run_sql_policy_checks(scan_roots: &[String]) {
     }
 }
 
+/// Parse a `.qail` query file and fill in its parameter types from `schema`.
+///
+/// Fails when the file does not parse or when a parameter has neither a
+/// declared nor an inferable type.
+pub fn load_query_file(source: &str, schema: &Schema) -> Result<QueryFile, String> {
+    let mut query_file = QueryFile::parse(source)?;
+    query_file.infer_param_types(schema)?;
+    Ok(query_file)
+}
+
+/// Type-check the `.qail` query file named by `QAIL_QUERY_FILE` against the
+/// schema. Off unless the variable is set; a configured file that cannot be
+/// read or checked fails the build.
+fn run_query_file_checks(schema: &Schema) {
+    println!("cargo:rerun-if-env-changed=QAIL_QUERY_FILE");
+
+    let path = std::env::var("QAIL_QUERY_FILE").unwrap_or_default();
+    let path = path.trim();
+    if path.is_empty() {
+        return;
+    }
+    println!("cargo:rerun-if-changed={}", path);
+
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => fail_build(format!("QAIL: Failed to read {}: {}", path, e)),
+    };
+    if let Err(e) = load_query_file(&source, schema) {
+        fail_build(format!("QAIL: {}: {}", path, e));
+    }
+}
+
 /// Build validation entrypoint for build.rs.
 /// Failures are reported via `cargo:warning` and process exit code 1.
 pub fn validate() {
@@ -454,6 +487,7 @@ pub fn validate() {
                     // ── N+1 detection ──────────────────────────────────────
                     run_nplus1_checks(&scan_roots);
                     run_sql_policy_checks(&scan_roots);
+                    run_query_file_checks(&schema);
                 }
                 Err(e) => {
                     fail_build(format!("QAIL: Failed to parse schema source: {}", e));
@@ -539,6 +573,7 @@ pub fn validate() {
                     // ── N+1 detection ──────────────────────────────────────
                     run_nplus1_checks(&scan_roots);
                     run_sql_policy_checks(&scan_roots);
+                    run_query_file_checks(&schema);
                 }
                 Err(e) => {
                     fail_build(format!("QAIL: Failed to parse schema after pull: {}", e));
@@ -43,6 +43,17 @@ pub enum QailError {
     /// Configuration error.
     Config(String),
 
+    /// Non-cross join without an ON condition (would be a cartesian product).
+    MissingJoinCondition(String),
+
+    /// Unqualified column that exists in more than one joined table.
+    AmbiguousColumn {
+        /// Unqualified column name.
+        column: String,
+        /// Joined tables that define the column.
+        candidates: Vec<String>,
+    },
+
     /// I/O error.
     Io(std::io::Error),
 }
@@ -56,6 +67,14 @@ impl QailError {
         }
     }
 
+    /// Byte offset into the query for parse errors, `None` otherwise.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::Parse { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+
     /// Create a missing symbol error.
     pub fn missing(symbol: &'static str, description: &'static str) -> Self {
         Self::MissingSymbol {
@@ -90,6 +109,15 @@ impl std::fmt::Display for QailError {
             Self::Execution(msg) => write!(f, "Execution error: {msg}"),
             Self::Validation(msg) => write!(f, "Validation error: {msg}"),
             Self::Config(msg) => write!(f, "Configuration error: {msg}"),
+            Self::MissingJoinCondition(table) => write!(
+                f,
+                "Join to '{table}' has no ON condition; use a cross join for a cartesian product"
+            ),
+            Self::AmbiguousColumn { column, candidates } => write!(
+                f,
+                "Column '{column}' is ambiguous; qualify it with one of: {}",
+                candidates.join(", ")
+            ),
             Self::Io(err) => write!(f, "IO error: {err}"),
         }
     }
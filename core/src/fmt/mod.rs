@@ -1,12 +1,30 @@
 use crate::ast::{
-    Action, Cage, CageKind, Condition, Expr, Join, LogicalOp, MergeAction, MergeMatchKind,
-    MergeSource, Operator, Qail, SortOrder, Value,
+    Action, Cage, CageKind, Condition, Expr, GroupByMode, Join, LockMode, LogicalOp, MergeAction,
+    MergeMatchKind, MergeSource, Operator, Qail, SetOp, SortOrder, Value,
 };
+use crate::transpiler::split_collation_name;
 use std::fmt::{Result, Write};
 
 #[cfg(test)]
 mod tests;
 
+/// Write a collation name the parser reads back. Schema-qualified names stay
+/// bare (`pg_catalog.en_US`) so they parse back as separate parts; a single
+/// name is written quoted.
+pub(crate) fn write_collation(out: &mut impl Write, collation: &str) -> Result {
+    let is_bare = |part: &String| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    match split_collation_name(collation) {
+        Some(parts) if parts.len() > 1 && parts.iter().all(is_bare) => {
+            write!(out, "{}", parts.join("."))
+        }
+        Some(parts) if parts.len() == 1 => write!(out, "\"{}\"", parts[0]),
+        _ => write!(out, "\"{}\"", collation),
+    }
+}
+
 /// Pretty-printer for QAIL AST nodes.
 ///
 /// Renders a `Qail` command back into human-readable QAIL syntax
@@ -35,6 +53,15 @@ impl Formatter {
 
     /// Format a query into a readable QAIL string.
     pub fn format(mut self, cmd: &Qail) -> std::result::Result<String, std::fmt::Error> {
+        if let Some(doc) = &cmd.doc {
+            for line in doc.lines() {
+                if line.is_empty() {
+                    writeln!(self.buffer, "--")?;
+                } else {
+                    writeln!(self.buffer, "-- {}", line)?;
+                }
+            }
+        }
         self.visit_cmd(cmd)?;
         Ok(self.buffer)
     }
@@ -70,7 +97,34 @@ impl Formatter {
 
         // Action and Table
         match cmd.action {
-            Action::Get => write!(self.buffer, "get {}", cmd.table)?,
+            Action::Get => match &cmd.table_function {
+                Some(function) => {
+                    write!(self.buffer, "get ")?;
+                    self.format_column(function)?;
+                }
+                None => {
+                    write!(self.buffer, "get {}", cmd.table)?;
+                    for (function, with_ordinality) in &cmd.from_functions {
+                        write!(self.buffer, ", ")?;
+                        match function {
+                            Expr::FunctionCall { name, args, alias } if *with_ordinality => {
+                                let args_str: Vec<String> =
+                                    args.iter().map(|a| a.to_string()).collect();
+                                write!(
+                                    self.buffer,
+                                    "{}({}) with ordinality",
+                                    name,
+                                    args_str.join(", ")
+                                )?;
+                                if let Some(a) = alias {
+                                    write!(self.buffer, " as {}", a)?;
+                                }
+                            }
+                            _ => self.format_column(function)?,
+                        }
+                    }
+                }
+            },
             Action::Set => write!(self.buffer, "set {}", cmd.table)?,
             Action::Del => write!(self.buffer, "del {}", cmd.table)?,
             Action::Add => write!(self.buffer, "add {}", cmd.table)?,
@@ -78,23 +132,35 @@ impl Formatter {
                 self.format_merge(cmd)?;
                 return Ok(());
             }
+            Action::Table => write!(self.buffer, "table {}", cmd.table)?,
+            Action::Values => {
+                write!(self.buffer, "values ")?;
+                let rows = cmd
+                    .cages
+                    .iter()
+                    .filter(|c| matches!(c.kind, CageKind::Payload));
+                for (i, row) in rows.enumerate() {
+                    if i > 0 {
+                        write!(self.buffer, ", ")?;
+                    }
+                    write!(self.buffer, "(")?;
+                    for (j, cond) in row.conditions.iter().enumerate() {
+                        if j > 0 {
+                            write!(self.buffer, ", ")?;
+                        }
+                        self.format_value(&cond.value)?;
+                    }
+                    write!(self.buffer, ")")?;
+                }
+                writeln!(self.buffer)?;
+                return Ok(());
+            }
             _ => write!(self.buffer, "{} {}", cmd.action, cmd.table)?, // Fallback for others
         }
         writeln!(self.buffer)?;
 
         // self.indent_level += 1; // Removed: Clauses should act at same level as command
 
-        // Cages: Group By (if any "by" equivalent exists? No, "by" is usually implicit in AST or explicit in group_by_mode?)
-        // The proposal example shows "by phone_number".
-        // In AST `cmd.rs`, there isn't a direct "Group By" list, usually inferred or group_by_mode.
-        // Wait, where is `by phone_number` stored in AST?
-        // Checking `ast/cmd.rs`: `group_by_mode: GroupByMode`.
-        // Usually group by is inferred from aggregates or explicit.
-        // If the AST doesn't have explicit group by columns, we might need to derive it or it's in `cages`?
-        // Let's check `cages.rs` again. `CageKind` has `Filter`, `Sort`, `Limit`... no `GroupBy`.
-        // Maybe it's implied by non-aggregated columns in a `Get` with aggregates?
-        // For now, I will skip "by" unless I find it in AST.
-
         if !cmd.columns.is_empty() {
             // But proposal says "Canonical".
             // "get table" implies "get table fields *" usually?
@@ -150,6 +216,45 @@ impl Formatter {
             writeln!(self.buffer)?;
         }
 
+        // Group By (Partition Cage)
+        if let Some(cage) = cmd
+            .cages
+            .iter()
+            .find(|c| matches!(c.kind, CageKind::Partition))
+        {
+            self.indent()?;
+            write!(self.buffer, "group by ")?;
+            if let GroupByMode::GroupingSets(sets) = &cmd.group_by_mode {
+                write!(self.buffer, "grouping sets(")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(self.buffer, ", ")?;
+                    }
+                    write!(self.buffer, "({})", set.join(", "))?;
+                }
+                write!(self.buffer, ")")?;
+            } else {
+                let wrapper = match cmd.group_by_mode {
+                    GroupByMode::Rollup => Some("rollup"),
+                    GroupByMode::Cube => Some("cube"),
+                    _ => None,
+                };
+                if let Some(wrapper) = wrapper {
+                    write!(self.buffer, "{}(", wrapper)?;
+                }
+                for (i, cond) in cage.conditions.iter().enumerate() {
+                    if i > 0 {
+                        write!(self.buffer, ", ")?;
+                    }
+                    self.format_column(&cond.left)?;
+                }
+                if wrapper.is_some() {
+                    write!(self.buffer, ")")?;
+                }
+            }
+            writeln!(self.buffer)?;
+        }
+
         // Order By (Sort Cages)
         let sorts: Vec<&Cage> = cmd
             .cages
@@ -191,6 +296,36 @@ impl Formatter {
             }
         }
 
+        if let Some(lock) = cmd.lock_mode {
+            self.indent()?;
+            let mode = match lock {
+                LockMode::Update => "update",
+                LockMode::NoKeyUpdate => "no key update",
+                LockMode::Share => "share",
+                LockMode::KeyShare => "key share",
+            };
+            write!(self.buffer, "for {}", mode)?;
+            if cmd.skip_locked {
+                write!(self.buffer, " skip locked")?;
+            } else if cmd.nowait {
+                write!(self.buffer, " nowait")?;
+            }
+            writeln!(self.buffer)?;
+        }
+
+        for (op, operand) in &cmd.set_ops {
+            self.indent()?;
+            let op = match op {
+                SetOp::Union => "union",
+                SetOp::UnionAll => "union all",
+                SetOp::Intersect => "intersect",
+                SetOp::Except => "except",
+            };
+            writeln!(self.buffer, "{}", op)?;
+            self.indent()?;
+            self.visit_cmd(operand)?;
+        }
+
         // self.indent_level -= 1; // Removed matching decrement
         Ok(())
     }
@@ -429,6 +564,8 @@ impl Formatter {
                 let prefix = match kind {
                     crate::ast::ModKind::Add => "+",
                     crate::ast::ModKind::Drop => "-",
+                    crate::ast::ModKind::AddIfNotExists => "+?",
+                    crate::ast::ModKind::DropIfExists => "-?",
                 };
                 write!(self.buffer, "{}{}", prefix, col)?;
             }
@@ -473,7 +610,8 @@ impl Formatter {
                 alias,
             } => {
                 self.format_column(expr)?;
-                write!(self.buffer, " COLLATE \"{}\"", collation)?;
+                write!(self.buffer, " COLLATE ")?;
+                write_collation(&mut self.buffer, collation)?;
                 if let Some(a) = alias {
                     write!(self.buffer, " as {}", a)?;
                 }
@@ -514,13 +652,30 @@ impl Formatter {
     }
 
     fn format_join(&mut self, join: &Join) -> Result {
-        match join.kind {
-            crate::ast::JoinKind::Inner => write!(self.buffer, "join {}", join.table)?,
-            crate::ast::JoinKind::Left => write!(self.buffer, "left join {}", join.table)?,
-            crate::ast::JoinKind::Right => write!(self.buffer, "right join {}", join.table)?,
-            crate::ast::JoinKind::Full => write!(self.buffer, "full join {}", join.table)?,
-            crate::ast::JoinKind::Cross => write!(self.buffer, "cross join {}", join.table)?,
-            crate::ast::JoinKind::Lateral => write!(self.buffer, "lateral join {}", join.table)?,
+        let keyword = match join.kind {
+            crate::ast::JoinKind::Inner => "join",
+            crate::ast::JoinKind::Left => "left join",
+            crate::ast::JoinKind::Right => "right join",
+            crate::ast::JoinKind::Full => "full join",
+            crate::ast::JoinKind::Cross => "cross join",
+            crate::ast::JoinKind::Lateral => "left join lateral",
+            crate::ast::JoinKind::LateralInner => "inner join lateral",
+        };
+        match &join.subquery {
+            Some(query) => {
+                write!(self.buffer, "{} (", keyword)?;
+                self.visit_cmd(query)?;
+                write!(self.buffer, ") as {}", join.table)?;
+            }
+            None => write!(self.buffer, "{} {}", keyword, join.table)?,
+        }
+
+        if join.on_true {
+            write!(self.buffer, " on true")?;
+        }
+
+        if !join.using.is_empty() {
+            write!(self.buffer, " using ({})", join.using.join(", "))?;
         }
 
         if let Some(conditions) = &join.on
@@ -555,6 +710,7 @@ impl Formatter {
                 Operator::Lt => write!(self.buffer, " < ")?,
                 Operator::Lte => write!(self.buffer, " <= ")?,
                 Operator::Fuzzy => write!(self.buffer, " ~ ")?, // ILIKE
+                Operator::Similar => write!(self.buffer, " ~% ")?,
                 Operator::In => write!(self.buffer, " in ")?,
                 Operator::NotIn => write!(self.buffer, " not in ")?,
                 Operator::IsNull => write!(self.buffer, " is null")?,
@@ -577,6 +733,7 @@ impl Formatter {
             Value::Null => write!(self.buffer, "null")?,
             Value::Bool(b) => write!(self.buffer, "{}", b)?,
             Value::Int(n) => write!(self.buffer, "{}", n)?,
+            Value::BigInt(n) => write!(self.buffer, "{}", n)?,
             Value::Float(n) => write!(self.buffer, "{}", n)?,
             Value::Param(n) => write!(self.buffer, "${}", n)?,
             Value::Function(f) => write!(self.buffer, "{}", f)?,
@@ -605,6 +762,23 @@ impl Formatter {
             Value::Interval { amount, unit } => {
                 write!(self.buffer, "interval '{} {}'", amount, unit)?
             }
+            Value::IntervalParts {
+                months,
+                days,
+                micros,
+            } => {
+                let mut parts = Vec::new();
+                if *months != 0 {
+                    parts.push(format!("{months} months"));
+                }
+                if *days != 0 {
+                    parts.push(format!("{days} days"));
+                }
+                if *micros != 0 || parts.is_empty() {
+                    parts.push(format!("{micros} microseconds"));
+                }
+                write!(self.buffer, "interval({})", parts.join(" "))?
+            }
             Value::Timestamp(ts) => write!(
                 self.buffer,
                 "'{}'",
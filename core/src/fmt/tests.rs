@@ -12,6 +12,76 @@ fn test_fmt_simple_get() {
     assert_eq!(output.trim(), "get users");
 }
 
+#[test]
+fn test_fmt_row_lock_round_trips() {
+    let cmd = crate::parse("get jobs fields id limit 1 for update skip locked").unwrap();
+    let output = Formatter::new().format(&cmd).unwrap();
+    assert!(
+        output.trim_end().ends_with("for update skip locked"),
+        "{output}"
+    );
+    assert_eq!(crate::parse(&output.replace('\n', " ")).unwrap(), cmd);
+}
+
+#[test]
+fn test_fmt_group_by_rollup_round_trips() {
+    let cmd =
+        crate::parse("get sales fields region, month, sum(amount) group by rollup(region, month)")
+            .unwrap();
+    let output = Formatter::new().format(&cmd).unwrap();
+    assert!(
+        output.contains("group by rollup(region, month)"),
+        "{output}"
+    );
+    assert_eq!(crate::parse(&output.replace('\n', " ")).unwrap(), cmd);
+}
+
+#[test]
+fn test_fmt_group_by_grouping_sets_round_trips() {
+    let cmd = crate::parse(
+        "get sales fields region, month, sum(amount) \
+         group by grouping sets((region, month), (region), ())",
+    )
+    .unwrap();
+    assert_eq!(
+        cmd.group_by_mode,
+        crate::ast::GroupByMode::GroupingSets(vec![
+            vec!["region".to_string(), "month".to_string()],
+            vec!["region".to_string()],
+            vec![],
+        ])
+    );
+
+    let output = Formatter::new().format(&cmd).unwrap();
+    assert!(
+        output.contains("group by grouping sets((region, month), (region), ())"),
+        "{output}"
+    );
+    assert_eq!(crate::parse(&output.replace('\n', " ")).unwrap(), cmd);
+}
+
+#[test]
+fn test_fmt_set_operations_round_trip() {
+    let cmd = crate::parse(
+        "get customers fields email where active = true union all get leads fields email \
+         except get bounced fields email",
+    )
+    .unwrap();
+    let output = Formatter::new().format(&cmd).unwrap();
+    assert!(output.contains("union all\n"), "{output}");
+    assert_eq!(crate::parse(&output.replace('\n', " ")).unwrap(), cmd);
+}
+
+#[test]
+fn test_fmt_table_and_values_round_trip() {
+    for query in ["table users", "values (1, 'a'), (2, 'b')"] {
+        let cmd = crate::parse(query).unwrap();
+        let output = Formatter::new().format(&cmd).unwrap();
+        assert_eq!(output.trim(), query);
+        assert_eq!(crate::parse(&output).unwrap(), cmd);
+    }
+}
+
 #[test]
 fn test_fmt_get_fields() {
     let mut cmd = Qail::get("users");
@@ -57,6 +127,8 @@ fn test_fmt_complex_query() {
         kind: JoinKind::Inner,
         on: None, // Implicit join for now to match proposal simplification or explicit? Proposal had explicit ON in example 4.
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     }];
 
     // Proposal example 4: join message_stats on ...
@@ -68,7 +68,7 @@ pub mod wire;
 #[cfg(test)]
 mod proptest;
 
-pub use parser::parse;
+pub use parser::{parse, parse_many, parse_with_comments};
 
 /// Ergonomic alias for Qail - the primary query builder type.
 pub type Qail = ast::Qail;
@@ -0,0 +1,270 @@
+//! Structured, human-reviewable view of a schema diff.
+//!
+//! [`diff_schemas_detailed`] runs the same checked diff as
+//! [`diff_schemas_checked`] and classifies each resulting command, so a
+//! dry-run can report *what* changes (a dropped table, a retyped column)
+//! instead of only the SQL that would run.
+
+use super::diff::diff_schemas_checked;
+use super::schema::Schema;
+use crate::ast::{Action, Expr, Qail};
+
+/// One classified schema change.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SchemaChange {
+    /// `CREATE TABLE`.
+    AddedTable {
+        /// Table name.
+        table: String,
+    },
+    /// `DROP TABLE`.
+    DroppedTable {
+        /// Table name.
+        table: String,
+    },
+    /// `ALTER TABLE ... ADD COLUMN`.
+    AddedColumn {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+        /// Declared SQL type.
+        data_type: String,
+    },
+    /// `ALTER TABLE ... DROP COLUMN`.
+    DroppedColumn {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+    },
+    /// `ALTER TABLE ... ALTER COLUMN ... TYPE`.
+    TypeChanged {
+        /// Table name.
+        table: String,
+        /// Column name.
+        column: String,
+        /// Type in the old schema, when it could be resolved.
+        old_type: Option<String>,
+        /// Type in the new schema.
+        new_type: String,
+    },
+    /// Nullability, default, index or row-level security change.
+    ConstraintChanged {
+        /// Table name.
+        table: String,
+        /// Affected column, if the change is column-scoped.
+        column: Option<String>,
+        /// Short description, e.g. `SET NOT NULL` or `CREATE INDEX idx_users_email`.
+        change: String,
+    },
+    /// Hinted column rename.
+    RenamedColumn {
+        /// Table name.
+        table: String,
+        /// Old column name.
+        from: String,
+        /// New column name.
+        to: String,
+    },
+    /// Anything else the diff emits (e.g. data transforms).
+    Other {
+        /// Table name.
+        table: String,
+        /// Action name.
+        action: String,
+    },
+}
+
+/// Classified result of a schema diff, alongside the commands it came from.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDiff {
+    /// One entry per diff command, in execution order.
+    pub changes: Vec<SchemaChange>,
+    /// Commands as returned by [`diff_schemas_checked`].
+    pub commands: Vec<Qail>,
+}
+
+impl SchemaDiff {
+    /// True when the schemas are identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Checked schema diff with every command classified into a [`SchemaChange`].
+pub fn diff_schemas_detailed(old: &Schema, new: &Schema) -> Result<SchemaDiff, String> {
+    let commands = diff_schemas_checked(old, new)?;
+    let changes = commands.iter().map(|cmd| classify(old, cmd)).collect();
+    Ok(SchemaDiff { changes, commands })
+}
+
+fn classify(old: &Schema, cmd: &Qail) -> SchemaChange {
+    let table = cmd.table.clone();
+    let column = cmd.columns.first().map(column_name);
+    let constraint = |change: &str| SchemaChange::ConstraintChanged {
+        table: table.clone(),
+        column: column.clone(),
+        change: change.to_string(),
+    };
+
+    match cmd.action {
+        Action::Make => SchemaChange::AddedTable { table },
+        Action::Drop => SchemaChange::DroppedTable { table },
+        Action::Alter => match cmd.columns.first() {
+            Some(Expr::Def {
+                name, data_type, ..
+            }) => SchemaChange::AddedColumn {
+                table,
+                column: name.clone(),
+                data_type: data_type.clone(),
+            },
+            _ => SchemaChange::Other {
+                table,
+                action: format!("{:?}", cmd.action),
+            },
+        },
+        Action::AlterDrop => SchemaChange::DroppedColumn {
+            column: column.unwrap_or_default(),
+            table,
+        },
+        Action::AlterType => {
+            let (column, new_type) = match cmd.columns.first() {
+                Some(Expr::Def {
+                    name, data_type, ..
+                }) => (name.clone(), data_type.clone()),
+                other => (other.map(column_name).unwrap_or_default(), String::new()),
+            };
+            let old_type = old
+                .tables
+                .get(&table)
+                .and_then(|t| t.columns.iter().find(|c| c.name == column))
+                .map(|c| c.data_type.to_pg_type());
+            SchemaChange::TypeChanged {
+                table,
+                column,
+                old_type,
+                new_type,
+            }
+        }
+        Action::AlterSetNotNull => constraint("SET NOT NULL"),
+        Action::AlterDropNotNull => constraint("DROP NOT NULL"),
+        Action::AlterSetDefault => constraint(&format!(
+            "SET DEFAULT {}",
+            cmd.payload.as_deref().unwrap_or("")
+        )),
+        Action::AlterDropDefault => constraint("DROP DEFAULT"),
+        Action::AlterEnableRls => constraint("ENABLE ROW LEVEL SECURITY"),
+        Action::AlterDisableRls => constraint("DISABLE ROW LEVEL SECURITY"),
+        Action::AlterForceRls => constraint("FORCE ROW LEVEL SECURITY"),
+        Action::AlterNoForceRls => constraint("NO FORCE ROW LEVEL SECURITY"),
+        Action::Index => match &cmd.index_def {
+            Some(idx) => SchemaChange::ConstraintChanged {
+                table: idx.table.clone(),
+                column: None,
+                change: format!("CREATE INDEX {}", idx.name),
+            },
+            None => constraint("CREATE INDEX"),
+        },
+        Action::DropIndex => {
+            // The diff stores the index name in `table`; recover the owner.
+            let owner = old
+                .indexes
+                .iter()
+                .find(|idx| idx.name == table)
+                .map(|idx| idx.table.clone())
+                .unwrap_or_default();
+            SchemaChange::ConstraintChanged {
+                table: owner,
+                column: None,
+                change: format!("DROP INDEX {}", table),
+            }
+        }
+        Action::Mod => match column.as_deref().and_then(|c| c.split_once(" -> ")) {
+            Some((from, to)) => SchemaChange::RenamedColumn {
+                from: from.to_string(),
+                to: to.to_string(),
+                table,
+            },
+            None => SchemaChange::Other {
+                table,
+                action: format!("{:?}", cmd.action),
+            },
+        },
+        _ => SchemaChange::Other {
+            table,
+            action: format!("{:?}", cmd.action),
+        },
+    }
+}
+
+fn column_name(expr: &Expr) -> String {
+    match expr {
+        Expr::Named(name) => name.clone(),
+        Expr::Def { name, .. } => name.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::schema::{Column, Table};
+    use super::super::types::ColumnType;
+    use super::*;
+
+    #[test]
+    fn detailed_diff_reports_added_column_and_dropped_table() {
+        let mut old = Schema::default();
+        old.add_table(Table::new("users").column(Column::new("id", ColumnType::Serial)));
+        old.add_table(Table::new("sessions").column(Column::new("id", ColumnType::Serial)));
+
+        let mut new = Schema::default();
+        new.add_table(
+            Table::new("users")
+                .column(Column::new("id", ColumnType::Serial))
+                .column(Column::new("email", ColumnType::Text)),
+        );
+
+        let diff = diff_schemas_detailed(&old, &new).expect("table-only diff is supported");
+        assert_eq!(diff.changes.len(), diff.commands.len());
+        assert!(diff.changes.contains(&SchemaChange::AddedColumn {
+            table: "users".to_string(),
+            column: "email".to_string(),
+            data_type: "TEXT".to_string(),
+        }));
+        assert!(diff.changes.contains(&SchemaChange::DroppedTable {
+            table: "sessions".to_string(),
+        }));
+    }
+
+    #[test]
+    fn detailed_diff_resolves_old_type_and_serializes_kind() {
+        let mut old = Schema::default();
+        old.add_table(Table::new("users").column(Column::new("age", ColumnType::Int)));
+        let mut new = Schema::default();
+        new.add_table(Table::new("users").column(Column::new("age", ColumnType::BigInt)));
+
+        let diff = diff_schemas_detailed(&old, &new).unwrap();
+        assert_eq!(
+            diff.changes,
+            vec![SchemaChange::TypeChanged {
+                table: "users".to_string(),
+                column: "age".to_string(),
+                old_type: Some("INT".to_string()),
+                new_type: "BIGINT".to_string(),
+            }]
+        );
+
+        let json = serde_json::to_value(&diff.changes[0]).unwrap();
+        assert_eq!(json["kind"], "type_changed");
+        assert_eq!(json["old_type"], "INT");
+    }
+
+    #[test]
+    fn detailed_diff_of_identical_schemas_is_empty() {
+        let mut schema = Schema::default();
+        schema.add_table(Table::new("users").column(Column::new("id", ColumnType::Serial)));
+        assert!(diff_schemas_detailed(&schema, &schema).unwrap().is_empty());
+    }
+}
@@ -19,6 +19,7 @@
 
 pub mod alter;
 pub mod diff;
+pub mod explain;
 pub mod named_migration;
 pub mod parser;
 pub mod policy;
@@ -28,6 +29,7 @@ pub mod types;
 
 pub use alter::{AlterOp, AlterTable, TableConstraint};
 pub use diff::{diff_schemas, diff_schemas_checked, validate_state_diff_support};
+pub use explain::{SchemaChange, SchemaDiff, diff_schemas_detailed};
 pub use named_migration::{MigrationMeta, parse_migration_meta, validate_dependencies};
 pub use parser::{parse_check_expr_fragment, parse_qail, parse_qail_file};
 pub use policy::{PolicyPermissiveness, PolicyTarget, RlsPolicy, session_bool_check, tenant_check};
@@ -2124,7 +2124,7 @@ fn parse_privilege(raw: &str) -> Result<Privilege, String> {
 
 /// Parse QAIL FK action string to FkAction enum.
 /// Accepts: cascade, set_null, set_default, restrict, no_action
-fn parse_fk_action_str(s: &str) -> Result<FkAction, String> {
+pub(crate) fn parse_fk_action_str(s: &str) -> Result<FkAction, String> {
     match s {
         "cascade" => Ok(FkAction::Cascade),
         "set_null" => Ok(FkAction::SetNull),
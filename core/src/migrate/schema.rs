@@ -19,9 +19,14 @@ use super::types::ColumnType;
 use std::collections::HashMap;
 
 /// A complete database schema.
-#[derive(Debug, Clone, Default)]
+///
+/// Serializes to a stable JSON shape (see [`Schema::to_json`]): tables are
+/// keyed by name in sorted order and every other section is a list.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct Schema {
     /// Declared tables.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub tables: HashMap<String, Table>,
     /// Declared indexes.
     pub indexes: Vec<Index>,
@@ -49,12 +54,21 @@ pub struct Schema {
     pub resources: Vec<ResourceDef>,
 }
 
+/// Serialize a `HashMap` with its keys sorted so JSON output is deterministic.
+fn serialize_sorted_map<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    V: serde::Serialize,
+{
+    serializer.collect_map(map.iter().collect::<std::collections::BTreeMap<_, _>>())
+}
+
 // ============================================================================
 // Infrastructure Resources
 // ============================================================================
 
 /// Kind of infrastructure resource declared in schema.qail.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ResourceKind {
     /// Object storage bucket.
     Bucket,
@@ -82,7 +96,7 @@ impl std::fmt::Display for ResourceKind {
 ///     region "ap-southeast-1"
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ResourceDef {
     /// Resource name (e.g. `"avatars"`).
     pub name: String,
@@ -91,11 +105,12 @@ pub struct ResourceDef {
     /// Cloud provider (e.g. `"s3"`, `"gcs"`).
     pub provider: Option<String>,
     /// Arbitrary key-value properties.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub properties: HashMap<String, String>,
 }
 
 /// A table definition in the schema.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Table {
     /// Table name.
     pub name: String,
@@ -110,7 +125,7 @@ pub struct Table {
 }
 
 /// A column definition with compile-time type safety.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Column {
     /// Column name.
     pub name: String,
@@ -139,7 +154,7 @@ pub struct Column {
 }
 
 /// Foreign key reference definition.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ForeignKey {
     /// Referenced table name.
     pub table: String,
@@ -154,7 +169,7 @@ pub struct ForeignKey {
 }
 
 /// Foreign key action on DELETE/UPDATE.
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum FkAction {
     #[default]
     /// No action on referenced row change.
@@ -170,7 +185,7 @@ pub enum FkAction {
 }
 
 /// An index definition.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Index {
     /// Index name.
     pub name: String,
@@ -193,7 +208,7 @@ pub struct Index {
 }
 
 /// Hints for the migration diff engine to improve migration quality.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum MigrationHint {
     /// Rename a column (not delete + add)
     Rename {
@@ -223,7 +238,7 @@ pub enum MigrationHint {
 // ============================================================================
 
 /// Binary comparison operator used by AST-native CHECK constraints.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum CheckComparisonOp {
     /// Equality (`=`)
     Equal,
@@ -254,7 +269,7 @@ impl CheckComparisonOp {
 }
 
 /// CHECK constraint expression (AST-native where possible, raw SQL fallback when needed)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum CheckExpr {
     /// column > value
     GreaterThan {
@@ -380,7 +395,7 @@ pub enum CheckExpr {
 }
 
 /// CHECK constraint with optional name
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CheckConstraint {
     /// The constraint expression.
     pub expr: CheckExpr,
@@ -393,7 +408,7 @@ pub struct CheckConstraint {
 // ============================================================================
 
 /// Constraint deferral mode
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Deferrable {
     #[default]
     /// Not deferrable (default).
@@ -411,7 +426,7 @@ pub enum Deferrable {
 // ============================================================================
 
 /// GENERATED column type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Generated {
     /// GENERATED ALWAYS AS (expr) STORED
     AlwaysStored(String),
@@ -426,7 +441,7 @@ pub enum Generated {
 // ============================================================================
 
 /// Index method (USING clause)
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum IndexMethod {
     #[default]
     /// B-tree (default for most columns).
@@ -465,7 +480,7 @@ pub(crate) fn index_method_str(method: &IndexMethod) -> &'static str {
 // ============================================================================
 
 /// PostgreSQL extension (e.g. `CREATE EXTENSION IF NOT EXISTS "uuid-ossp"`)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Extension {
     /// Extension name (e.g. `"uuid-ossp"`).
     pub name: String,
@@ -499,7 +514,7 @@ impl Extension {
 }
 
 /// COMMENT ON TABLE/COLUMN
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Comment {
     /// What the comment is attached to.
     pub target: CommentTarget,
@@ -508,7 +523,7 @@ pub struct Comment {
 }
 
 /// Target of a COMMENT ON statement.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum CommentTarget {
     /// COMMENT ON TABLE.
     Table(String),
@@ -557,7 +572,7 @@ impl Comment {
 }
 
 /// Standalone sequence (CREATE SEQUENCE)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Sequence {
     /// Sequence name.
     pub name: String,
@@ -643,7 +658,7 @@ impl Sequence {
 // ============================================================================
 
 /// Standalone ENUM type (CREATE TYPE ... AS ENUM)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct EnumType {
     /// Type name.
     pub name: String,
@@ -668,7 +683,7 @@ impl EnumType {
 }
 
 /// Table-level multi-column foreign key
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct MultiColumnForeignKey {
     /// Source columns.
     pub columns: Vec<String>,
@@ -746,7 +761,7 @@ impl MultiColumnForeignKey {
 // ============================================================================
 
 /// A SQL view definition.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ViewDef {
     /// View name.
     pub name: String,
@@ -774,7 +789,7 @@ impl ViewDef {
 }
 
 /// A PL/pgSQL function definition for the schema model.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SchemaFunctionDef {
     /// Function name.
     pub name: String,
@@ -827,7 +842,7 @@ impl SchemaFunctionDef {
 }
 
 /// A trigger definition for the schema model.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SchemaTriggerDef {
     /// Trigger name.
     pub name: String,
@@ -892,7 +907,7 @@ impl SchemaTriggerDef {
 }
 
 /// GRANT or REVOKE permission.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Grant {
     /// GRANT or REVOKE.
     pub action: GrantAction,
@@ -905,7 +920,7 @@ pub struct Grant {
 }
 
 /// Whether a permission statement is a GRANT or REVOKE.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub enum GrantAction {
     #[default]
     /// Grant privileges.
@@ -915,7 +930,7 @@ pub enum GrantAction {
 }
 
 /// SQL privilege type.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Privilege {
     /// ALL PRIVILEGES.
     All,
@@ -1048,6 +1063,31 @@ impl Schema {
         self.policies.push(policy);
     }
 
+    /// Serialize the schema as pretty-printed JSON.
+    ///
+    /// The shape mirrors these structs field for field; enums use serde's
+    /// default externally tagged form (`"Cascade"`, `{"Varchar": 255}`).
+    ///
+    /// ```
+    /// use qail_core::migrate::{Schema, parse_qail};
+    ///
+    /// let schema = parse_qail("table users {\n  id uuid primary_key\n}").unwrap();
+    /// let json = schema.to_json().unwrap();
+    /// let back = Schema::from_json(&json).unwrap();
+    /// assert!(back.tables.contains_key("users"));
+    /// ```
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Schema JSON error: {}", e))
+    }
+
+    /// Parse a schema from the JSON produced by [`Schema::to_json`].
+    ///
+    /// Missing sections default to empty, so hand-written files only need
+    /// the parts they use.
+    pub fn from_json(input: &str) -> Result<Self, String> {
+        serde_json::from_str(input).map_err(|e| format!("Schema JSON error: {}", e))
+    }
+
     /// Validate all foreign key references in the schema.
     pub fn validate(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
@@ -2236,6 +2276,20 @@ pub fn to_qail_string(schema: &Schema) -> String {
         output.push('\n');
     }
 
+    // Infrastructure resources
+    for resource in &schema.resources {
+        output.push_str(&format!("{} {} {{\n", resource.kind, resource.name));
+        if let Some(provider) = &resource.provider {
+            output.push_str(&format!("  provider {}\n", quote_resource_value(provider)));
+        }
+        let mut properties: Vec<_> = resource.properties.iter().collect();
+        properties.sort();
+        for (key, value) in properties {
+            output.push_str(&format!("  {} {}\n", key, quote_resource_value(value)));
+        }
+        output.push_str("}\n\n");
+    }
+
     // Comments last (tables must exist first)
     for comment in &schema.comments {
         let text = quote_qail_string(&comment.text);
@@ -2259,6 +2313,11 @@ fn quote_qail_string(value: &str) -> String {
     format!("\"{}\"", value.replace('"', "\"\""))
 }
 
+/// Resource blocks use backslash escapes rather than doubled quotes.
+fn quote_resource_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 /// Convert a Schema to a list of Qail commands (CREATE TABLE, CREATE INDEX).
 /// Used by shadow migration to apply the base schema before applying diffs.
 pub fn schema_to_commands(schema: &Schema) -> Vec<crate::ast::Qail> {
@@ -2484,7 +2543,7 @@ pub(super) fn multi_column_fk_to_alter_command(
     }
 }
 
-fn fk_action_to_sql(action: &FkAction) -> &'static str {
+pub(crate) fn fk_action_to_sql(action: &FkAction) -> &'static str {
     match action {
         FkAction::NoAction => "NO ACTION",
         FkAction::Cascade => "CASCADE",
@@ -2604,6 +2663,69 @@ fn looks_like_raw_check_expr(s: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_schema_json_round_trip() {
+        let input = r#"
+extension pgcrypto
+
+enum order_status { pending, paid }
+
+table users {
+  id uuid primary_key default gen_random_uuid()
+  email varchar(255) not_null unique
+  age int check(age >= 18)
+}
+
+table orders {
+  id bigserial primary_key
+  user_id uuid not_null references users(id) on_delete cascade
+  status order_status not_null
+  total decimal(10,2)
+  tags text[]
+}
+
+unique index idx_orders_user on orders (user_id, status)
+
+bucket receipts {
+  provider s3
+  region "eu-west-1"
+}
+
+policy orders_owner on orders for select
+  using $$ user_id = current_setting('app.user_id')::uuid $$
+"#;
+        let schema = crate::migrate::parse_qail(input).unwrap();
+        let json = schema.to_json().unwrap();
+        let from_json = Schema::from_json(&json).unwrap();
+        let qail = to_qail_string(&from_json);
+        let reparsed = crate::migrate::parse_qail(&qail).unwrap();
+
+        assert_eq!(reparsed.to_json().unwrap(), json);
+        assert_eq!(to_qail_string(&reparsed), to_qail_string(&schema));
+        assert_eq!(
+            from_json.tables["orders"].columns[1]
+                .foreign_key
+                .as_ref()
+                .unwrap()
+                .on_delete,
+            FkAction::Cascade
+        );
+    }
+
+    #[test]
+    fn test_schema_json_shape_is_stable() {
+        let mut schema = Schema::new();
+        schema.add_table(Table::new("b").column(Column::new("id", ColumnType::Int)));
+        schema.add_table(Table::new("a").column(Column::new("id", ColumnType::Int)));
+
+        let json = schema.to_json().unwrap();
+        assert!(json.find("\"a\"").unwrap() < json.find("\"b\"").unwrap());
+        assert_eq!(json, Schema::from_json(&json).unwrap().to_json().unwrap());
+
+        let sparse = Schema::from_json(r#"{"tables": {}}"#).unwrap();
+        assert!(sparse.tables.is_empty() && sparse.indexes.is_empty());
+    }
+
     #[test]
     fn test_schema_builder() {
         let mut schema = Schema::new();
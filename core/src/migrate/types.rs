@@ -8,7 +8,7 @@ use std::fmt;
 /// - Type safety (no typos like "uuud" instead of "uuid")
 /// - Compile-time validation (e.g., can this be a primary key?)
 /// - Zero runtime parsing overhead
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ColumnType {
     /// UUID.
     Uuid,
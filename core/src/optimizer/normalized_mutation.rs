@@ -355,7 +355,7 @@ fn reject_unsupported_mutation_features(qail: &Qail) -> Result<(), NormalizeMuta
             "savepoint metadata",
         ));
     }
-    if qail.lock_mode.is_some() || qail.skip_locked {
+    if qail.lock_mode.is_some() || qail.skip_locked || qail.nowait {
         return Err(NormalizeMutationError::UnsupportedFeature("row locks"));
     }
     if qail.fetch.is_some() {
@@ -450,6 +450,7 @@ mod tests {
             }],
             on_conflict: Some(OnConflict {
                 columns: vec!["id".to_string()],
+                predicate: vec![],
                 action: ConflictAction::DoNothing,
             }),
             returning: Some(vec![Expr::Star]),
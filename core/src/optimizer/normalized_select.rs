@@ -237,6 +237,8 @@ impl NormalizedSelect {
                     kind: join.kind.clone(),
                     on: if join.on_true { None } else { join.on.clone() },
                     on_true: join.on_true,
+                    using: Vec::new(),
+                    subquery: None,
                 })
                 .collect(),
             ..Default::default()
@@ -305,6 +307,9 @@ fn reject_unsupported_select_features(qail: &Qail) -> Result<(), NormalizeError>
     if !qail.ctes.is_empty() {
         return Err(NormalizeError::UnsupportedFeature("CTEs"));
     }
+    if qail.joins.iter().any(|join| !join.using.is_empty()) {
+        return Err(NormalizeError::UnsupportedFeature("JOIN USING"));
+    }
     if qail.returning.is_some() {
         return Err(NormalizeError::UnsupportedFeature("RETURNING"));
     }
@@ -325,7 +330,7 @@ fn reject_unsupported_select_features(qail: &Qail) -> Result<(), NormalizeError>
             "auxiliary FROM/USING tables",
         ));
     }
-    if qail.lock_mode.is_some() || qail.skip_locked {
+    if qail.lock_mode.is_some() || qail.skip_locked || qail.nowait {
         return Err(NormalizeError::UnsupportedFeature("row locks"));
     }
     if qail.fetch.is_some() {
@@ -528,6 +533,8 @@ mod tests {
                 kind: JoinKind::Left,
                 on: None,
                 on_true: false,
+                using: Vec::new(),
+                subquery: None,
             }],
             ..Default::default()
         };
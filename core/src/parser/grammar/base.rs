@@ -6,6 +6,7 @@ use nom::{
     bytes::complete::{tag, tag_no_case, take_while1},
     character::complete::{char, digit1, multispace0, multispace1},
     combinator::{map, map_res, opt, recognize, value},
+    multi::{separated_list0, separated_list1},
     sequence::{delimited, preceded},
 };
 
@@ -61,6 +62,55 @@ pub fn parse_interval(input: &str) -> IResult<&str, Value> {
     Ok((input, Value::Interval { amount, unit }))
 }
 
+/// Parse structured interval: interval(1 month 7 days 3 hours)
+pub fn parse_interval_parts(input: &str) -> IResult<&str, Value> {
+    let (input, _) = tag_no_case("interval").parse(input)?;
+    let (input, _) = (multispace0, char('('), multispace0).parse(input)?;
+    let (input, components) = map_res(
+        separated_list1(
+            multispace1,
+            (
+                map_res(recognize((opt(char('-')), digit1)), str::parse::<i64>),
+                preceded(multispace1, take_while1(|c: char| c.is_ascii_alphabetic())),
+            ),
+        ),
+        |components: Vec<(i64, &str)>| interval_parts_from_components(&components),
+    )
+    .parse(input)?;
+    let (input, _) = (multispace0, char(')')).parse(input)?;
+    Ok((input, components))
+}
+
+fn interval_parts_from_components(components: &[(i64, &str)]) -> Result<Value, &'static str> {
+    const OVERFLOW: &str = "interval component out of range";
+    let (mut months, mut days, mut micros) = (0i64, 0i64, 0i64);
+
+    for &(amount, unit) in components {
+        let (field, scale) = match unit.to_ascii_lowercase().as_str() {
+            "year" | "years" => (&mut months, 12),
+            "mon" | "mons" | "month" | "months" => (&mut months, 1),
+            "week" | "weeks" => (&mut days, 7),
+            "day" | "days" => (&mut days, 1),
+            "hour" | "hours" => (&mut micros, 3_600_000_000),
+            "min" | "mins" | "minute" | "minutes" => (&mut micros, 60_000_000),
+            "sec" | "secs" | "second" | "seconds" => (&mut micros, 1_000_000),
+            "ms" | "millisecond" | "milliseconds" => (&mut micros, 1_000),
+            "us" | "microsecond" | "microseconds" => (&mut micros, 1),
+            _ => return Err("unknown interval unit"),
+        };
+        *field = amount
+            .checked_mul(scale)
+            .and_then(|n| field.checked_add(n))
+            .ok_or(OVERFLOW)?;
+    }
+
+    Ok(Value::IntervalParts {
+        months: i32::try_from(months).map_err(|_| OVERFLOW)?,
+        days: i32::try_from(days).map_err(|_| OVERFLOW)?,
+        micros,
+    })
+}
+
 /// Parse value: string, number, bool, null, $param, :named_param, interval, JSON
 pub fn parse_value(input: &str) -> IResult<&str, Value> {
     alt((
@@ -96,16 +146,64 @@ pub fn parse_value(input: &str) -> IResult<&str, Value> {
                     .ok_or_else(|| "float literal must be finite".to_string())
             },
         ),
+        // Structured interval: interval(1 month 7 days)
+        parse_interval_parts,
         // Interval shorthand before plain integers: 24h, 7d, 1w
         parse_interval,
-        // Integer (last, after interval)
-        map_res(recognize((opt(char('-')), digit1)), |s: &str| {
-            s.parse::<i64>().map(Value::Int)
+        // Integer (last, after interval); out-of-range literals keep their digits
+        map(recognize((opt(char('-')), digit1)), |s: &str| {
+            s.parse::<i64>()
+                .map(Value::Int)
+                .unwrap_or_else(|_| Value::BigInt(s.to_string()))
         }),
     ))
     .parse(input)
 }
 
+/// Parse a parenthesized `IN` list into [`Value::Array`]: `(1, 2, 3)` or
+/// `('active', 'pending')`.
+///
+/// Empty lists are rejected, as are lists mixing numbers, strings and
+/// booleans. Parameters and `null` may sit next to any literal type.
+pub fn parse_in_list(input: &str) -> IResult<&str, Value> {
+    let (rest, _) = char('(').parse(input)?;
+    let (rest, _) = multispace0(rest)?;
+    let (rest, values) =
+        separated_list0((multispace0, char(','), multispace0), parse_value).parse(rest)?;
+    let (rest, _) = multispace0(rest)?;
+    let (rest, _) = char(')').parse(rest)?;
+    if values.is_empty() {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            rest,
+            nom::error::ErrorKind::SeparatedList,
+        )));
+    }
+
+    let mut kinds = values.iter().filter_map(in_list_kind);
+    if let Some(first) = kinds.next()
+        && kinds.any(|kind| kind != first)
+    {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    Ok((rest, Value::Array(values)))
+}
+
+/// Literal type class used to reject mixed `IN` lists; `None` for values
+/// that fit any column type (parameters, `null`).
+fn in_list_kind(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Int(_) | Value::BigInt(_) | Value::Float(_) => Some("number"),
+        Value::String(_) => Some("string"),
+        Value::Bool(_) => Some("bool"),
+        Value::Interval { .. } | Value::IntervalParts { .. } => Some("interval"),
+        Value::Json(_) => Some("json"),
+        _ => None,
+    }
+}
+
 fn parse_single_quoted_string(input: &str) -> IResult<&str, Value> {
     parse_quoted_string(input, '\'')
 }
@@ -283,6 +381,7 @@ pub fn parse_operator(input: &str) -> IResult<&str, Operator> {
         // Multi-char symbol operators (before shorter prefixes)
         alt((
             value(Operator::RegexI, tag("~*")),
+            value(Operator::Similar, tag("~%")),
             value(Operator::JsonPathText, tag("#>>")),
             value(Operator::JsonPath, tag("#>")),
             value(Operator::TextSearch, tag("@@")),
@@ -291,6 +390,8 @@ pub fn parse_operator(input: &str) -> IResult<&str, Operator> {
             value(Operator::Contains, tag("@>")),
             value(Operator::ContainedBy, tag("<@")),
             value(Operator::Overlaps, tag("&&")),
+            // `<&>` spells overlap where `&&` would read as a logical AND.
+            value(Operator::Overlaps, tag("<&>")),
             value(Operator::Gte, tag(">=")),
             value(Operator::Lte, tag("<=")),
             value(Operator::Ne, tag("!=")),
@@ -376,7 +477,10 @@ pub fn parse_txn_command(input: &str) -> IResult<&str, Qail> {
             returning: None,
             on_conflict: None,
             merge: None,
+            copy_options: None,
             source_query: None,
+            table_function: None,
+            from_functions: vec![],
             channel: None,
             payload: None,
             savepoint_name: None,
@@ -384,6 +488,7 @@ pub fn parse_txn_command(input: &str) -> IResult<&str, Qail> {
             using_tables: vec![],
             lock_mode: None,
             skip_locked: false,
+            nowait: false,
             fetch: None,
             default_values: false,
             overriding: None,
@@ -399,6 +504,7 @@ pub fn parse_txn_command(input: &str) -> IResult<&str, Qail> {
             function_def: None,
             trigger_def: None,
             policy_def: None,
+            doc: None,
         },
     ))
 }
@@ -4,10 +4,15 @@
 //! - Low: || (concat)
 //! - Medium: + -
 //! - High: * / %
+//! - Highest: AT TIME ZONE
 
 use super::expressions::parse_json_or_ident;
 use crate::ast::*;
-use nom::{IResult, Parser, bytes::complete::tag, character::complete::multispace0};
+use nom::{
+    IResult, Parser,
+    bytes::complete::{tag, tag_no_case},
+    character::complete::{multispace0, multispace1},
+};
 
 /// Parse concatenation (lowest precedence): expr || expr
 pub fn parse_concat_expr(input: &str) -> IResult<&str, Expr> {
@@ -33,11 +38,11 @@ pub fn parse_additive_expr(input: &str) -> IResult<&str, Expr> {
 
 /// Parse multiplicative: expr * expr, expr / expr, expr % expr
 pub fn parse_multiplicative_expr(input: &str) -> IResult<&str, Expr> {
-    let (input, left) = parse_json_or_ident(input)?;
+    let (input, left) = parse_at_time_zone_expr(input)?;
     parse_binary_chain(
         input,
         left,
-        parse_json_or_ident,
+        parse_at_time_zone_expr,
         &[
             ("*", BinaryOp::Mul),
             ("/", BinaryOp::Div),
@@ -46,6 +51,36 @@ pub fn parse_multiplicative_expr(input: &str) -> IResult<&str, Expr> {
     )
 }
 
+/// Parse time zone conversion: expr AT TIME ZONE 'zone'
+pub fn parse_at_time_zone_expr(input: &str) -> IResult<&str, Expr> {
+    let (mut input, mut left) = parse_json_or_ident(input)?;
+    while let Ok((after_keyword, _)) = at_time_zone_keyword(input) {
+        let (after_zone, zone) = parse_json_or_ident(after_keyword)?;
+        left = Expr::Binary {
+            left: Box::new(left),
+            op: BinaryOp::AtTimeZone,
+            right: Box::new(zone),
+            alias: None,
+        };
+        input = after_zone;
+    }
+    Ok((input, left))
+}
+
+fn at_time_zone_keyword(input: &str) -> IResult<&str, ()> {
+    let (input, _) = (
+        multispace1,
+        tag_no_case("at"),
+        multispace1,
+        tag_no_case("time"),
+        multispace1,
+        tag_no_case("zone"),
+        multispace0,
+    )
+        .parse(input)?;
+    Ok((input, ()))
+}
+
 /// Generic left-associative binary chain parser
 pub fn parse_binary_chain<'a, F>(
     mut input: &'a str,
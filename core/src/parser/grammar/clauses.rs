@@ -1,4 +1,4 @@
-use super::base::{parse_identifier, parse_operator, parse_value};
+use super::base::{parse_identifier, parse_in_list, parse_operator, parse_value};
 use super::expressions::parse_expression;
 use crate::ast::*;
 use nom::{
@@ -133,6 +133,14 @@ pub fn parse_single_column(input: &str) -> IResult<&str, Expr> {
                 target_type,
                 alias: Some(a.to_string()),
             },
+            Expr::Binary {
+                left, op, right, ..
+            } => Expr::Binary {
+                left,
+                op,
+                right,
+                alias: Some(a.to_string()),
+            },
             _ => expr,
         };
     }
@@ -184,6 +192,97 @@ pub fn parse_where_clause(input: &str) -> IResult<&str, Vec<Cage>> {
     ))
 }
 
+/// Parse: group by col1, col2 | group by rollup(col1, col2) | group by cube(col1, col2)
+/// | group by grouping sets((col1, col2), (col1), ())
+pub fn parse_group_by_clause(input: &str) -> IResult<&str, (GroupByMode, Cage)> {
+    let (input, _) = tag_no_case("group").parse(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = tag_no_case("by").parse(input)?;
+    let (input, _) = multispace1(input)?;
+
+    let group_list = |input| {
+        separated_list1((multispace0, char(','), multispace0), parse_expression).parse(input)
+    };
+    let wrapped = |keyword: &'static str, mode: GroupByMode| {
+        map(
+            preceded(
+                (tag_no_case(keyword), multispace0),
+                delimited(
+                    (char('('), multispace0),
+                    group_list,
+                    (multispace0, char(')')),
+                ),
+            ),
+            move |exprs| (mode.clone(), exprs),
+        )
+    };
+
+    let grouping_set = delimited(
+        (char('('), multispace0),
+        separated_list0(
+            (multispace0, char(','), multispace0),
+            map(parse_identifier, str::to_string),
+        ),
+        (multispace0, char(')')),
+    );
+    let grouping_sets = map(
+        preceded(
+            (
+                tag_no_case("grouping"),
+                multispace1,
+                tag_no_case("sets"),
+                multispace0,
+            ),
+            delimited(
+                (char('('), multispace0),
+                separated_list1((multispace0, char(','), multispace0), grouping_set),
+                (multispace0, char(')')),
+            ),
+        ),
+        |sets: Vec<Vec<String>>| {
+            // The Partition cage lists every grouped column once.
+            let mut columns: Vec<String> = Vec::new();
+            for column in sets.iter().flatten() {
+                if !columns.contains(column) {
+                    columns.push(column.clone());
+                }
+            }
+            let exprs = columns.into_iter().map(Expr::Named).collect();
+            (GroupByMode::GroupingSets(sets), exprs)
+        },
+    );
+
+    let (input, (mode, exprs)) = alt((
+        grouping_sets,
+        wrapped("rollup", GroupByMode::Rollup),
+        wrapped("cube", GroupByMode::Cube),
+        map(group_list, |exprs| (GroupByMode::Simple, exprs)),
+    ))
+    .parse(input)?;
+
+    let conditions = exprs
+        .into_iter()
+        .map(|left| Condition {
+            left,
+            op: Operator::Eq,
+            value: Value::Null,
+            is_array_unnest: false,
+        })
+        .collect();
+
+    Ok((
+        input,
+        (
+            mode,
+            Cage {
+                kind: CageKind::Partition,
+                conditions,
+                logical_op: LogicalOp::And,
+            },
+        ),
+    ))
+}
+
 /// Parse: having condition and condition2
 /// HAVING is for filtering on aggregates after GROUP BY
 pub fn parse_having_clause(input: &str) -> IResult<&str, Vec<Condition>> {
@@ -303,19 +402,10 @@ pub fn parse_condition(input: &str) -> IResult<&str, Condition> {
         (input, Value::Array(vec![min_val, max_val]))
     } else if matches!(op, Operator::In | Operator::NotIn) {
         let (input, _) = multispace0(input)?;
-        if let Ok((input, _)) = char::<_, nom::error::Error<&str>>('(').parse(input) {
-            let (input, _) = multispace0(input)?;
-            let (input, values) =
-                separated_list0((multispace0, char(','), multispace0), parse_value).parse(input)?;
-            let (input, _) = multispace0(input)?;
-            let (input, _) = char(')').parse(input)?;
-            if values.is_empty() {
-                return Err(nom::Err::Error(nom::error::Error::new(
-                    input,
-                    nom::error::ErrorKind::SeparatedList,
-                )));
-            }
-            (input, Value::Array(values))
+        if let Ok((input, subquery)) = super::dml::parse_subquery_value(input) {
+            (input, subquery)
+        } else if input.starts_with('(') {
+            parse_in_list(input)?
         } else {
             parse_value(input)?
         }
@@ -357,21 +447,27 @@ pub fn parse_order_by_clause(input: &str) -> IResult<&str, Vec<Cage>> {
     Ok((input, sorts))
 }
 
-/// Parse single sort column: col [asc|desc]
+/// Parse single sort column: col [asc|desc] [nulls first|last]
 pub fn parse_sort_column(input: &str) -> IResult<&str, Cage> {
     let (input, expr) = parse_expression(input)?;
     let (input, _) = multispace0(input)?;
 
-    let (input, order) = opt(alt((
-        value(SortOrder::Desc, tag_no_case("desc")),
-        value(SortOrder::Asc, tag_no_case("asc")),
-    )))
-    .parse(input)?;
+    let (input, collation) = opt(parse_collate_suffix).parse(input)?;
+    let expr = match collation {
+        Some(collation) => Expr::Collate {
+            expr: Box::new(expr),
+            collation,
+            alias: None,
+        },
+        None => expr,
+    };
+
+    let (input, order) = parse_sort_order(input)?;
 
     Ok((
         input,
         Cage {
-            kind: CageKind::Sort(order.unwrap_or(SortOrder::Asc)),
+            kind: CageKind::Sort(order),
             conditions: vec![Condition {
                 left: expr,
                 op: Operator::Eq,
@@ -383,6 +479,73 @@ pub fn parse_sort_column(input: &str) -> IResult<&str, Cage> {
     ))
 }
 
+/// Parse an optional sort direction and NULLS placement:
+/// `[asc|desc] [nulls first|last]`, defaulting to ASC.
+pub fn parse_sort_order(input: &str) -> IResult<&str, SortOrder> {
+    let (input, desc) = opt(alt((
+        value(true, tag_no_case("desc")),
+        value(false, tag_no_case("asc")),
+    )))
+    .parse(input)?;
+
+    let nulls = |input| -> IResult<&str, bool> {
+        let (input, _) = tag_no_case("nulls").parse(input)?;
+        let (input, _) = multispace1(input)?;
+        alt((
+            value(true, tag_no_case("first")),
+            value(false, tag_no_case("last")),
+        ))
+        .parse(input)
+    };
+    let (input, nulls_first) = if desc.is_some() {
+        opt(preceded(multispace1, nulls)).parse(input)?
+    } else {
+        opt(nulls).parse(input)?
+    };
+
+    let order = match (desc.unwrap_or(false), nulls_first) {
+        (false, None) => SortOrder::Asc,
+        (true, None) => SortOrder::Desc,
+        (false, Some(true)) => SortOrder::AscNullsFirst,
+        (false, Some(false)) => SortOrder::AscNullsLast,
+        (true, Some(true)) => SortOrder::DescNullsFirst,
+        (true, Some(false)) => SortOrder::DescNullsLast,
+    };
+    Ok((input, order))
+}
+
+/// Parse: collate 'name' | collate "name" | collate name
+///
+/// A quoted name is a single identifier (`'en_US.utf8'`); only the bare
+/// form is split into schema-qualified parts.
+fn parse_collate_suffix(input: &str) -> IResult<&str, String> {
+    let (input, _) = tag_no_case("collate").parse(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, collation) = alt((
+        map_res(parse_value, |v| match v {
+            Value::String(s) if is_safe_collation_name(&s) => {
+                if s.contains('.') || s.starts_with('"') {
+                    Ok(format!("\"{}\"", s.replace('"', "\"\"")))
+                } else {
+                    Ok(s)
+                }
+            }
+            Value::String(_) => Err("invalid collation name"),
+            _ => Err("collation must be a string or identifier"),
+        }),
+        map(parse_identifier, str::to_string),
+    ))
+    .parse(input)?;
+    let (input, _) = multispace0(input)?;
+    Ok((input, collation))
+}
+
+fn is_safe_collation_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(char::is_control)
+        && name.split('.').all(|part| !part.trim().is_empty())
+}
+
 /// Parse: limit N
 pub fn parse_limit_clause(input: &str) -> IResult<&str, Cage> {
     let (input, _) = tag_no_case("limit").parse(input)?;
@@ -415,6 +578,44 @@ pub fn parse_offset_clause(input: &str) -> IResult<&str, Cage> {
     ))
 }
 
+/// Parse a row lock: `for update|no key update|share|key share [skip locked|nowait]`.
+///
+/// The wait policy is `Some(true)` for SKIP LOCKED and `Some(false)` for NOWAIT.
+pub fn parse_lock_clause(input: &str) -> IResult<&str, (LockMode, Option<bool>)> {
+    let (input, _) = (tag_no_case("for"), multispace1).parse(input)?;
+    let (input, mode) = alt((
+        value(LockMode::Update, tag_no_case("update")),
+        value(
+            LockMode::NoKeyUpdate,
+            (
+                tag_no_case("no"),
+                multispace1,
+                tag_no_case("key"),
+                multispace1,
+                tag_no_case("update"),
+            ),
+        ),
+        value(LockMode::Share, tag_no_case("share")),
+        value(
+            LockMode::KeyShare,
+            (tag_no_case("key"), multispace1, tag_no_case("share")),
+        ),
+    ))
+    .parse(input)?;
+    let (input, wait) = opt(preceded(
+        multispace1,
+        alt((
+            value(
+                true,
+                (tag_no_case("skip"), multispace1, tag_no_case("locked")),
+            ),
+            value(false, tag_no_case("nowait")),
+        )),
+    ))
+    .parse(input)?;
+    Ok((input, (mode, wait)))
+}
+
 /// Parse: DISTINCT ON (col1, col2, ...)
 /// Returns list of column names for DISTINCT ON
 pub fn parse_distinct_on(input: &str) -> IResult<&str, Vec<String>> {
@@ -1,5 +1,7 @@
 use super::base::{parse_bare_identifier, parse_identifier};
 use crate::ast::*;
+use crate::migrate::parser::parse_fk_action_str;
+use crate::migrate::schema::fk_action_to_sql;
 use nom::{
     IResult, Parser,
     branch::alt,
@@ -40,7 +42,10 @@ pub fn parse_create_table<'a>(input: &'a str, table: &str) -> IResult<&'a str, Q
             returning: None,
             on_conflict: None,
             merge: None,
+            copy_options: None,
             source_query: None,
+            table_function: None,
+            from_functions: vec![],
             channel: None,
             payload: None,
             savepoint_name: None,
@@ -48,6 +53,7 @@ pub fn parse_create_table<'a>(input: &'a str, table: &str) -> IResult<&'a str, Q
             using_tables: vec![],
             lock_mode: None,
             skip_locked: false,
+            nowait: false,
             fetch: None,
             default_values: false,
             overriding: None,
@@ -63,15 +69,18 @@ pub fn parse_create_table<'a>(input: &'a str, table: &str) -> IResult<&'a str, Q
             function_def: None,
             trigger_def: None,
             policy_def: None,
+            doc: None,
         },
     ))
 }
 
-/// Parse table constraint: primary key (col1, col2) or unique (col1, col2)
+/// Parse table constraint: primary key (col1, col2), unique (col1, col2) or
+/// foreign key (col1, col2) references table (col1, col2) [on delete ...] [on update ...]
 pub fn parse_table_constraint(input: &str) -> IResult<&str, TableConstraint> {
     let (input, _) = multispace0(input)?;
 
     alt((
+        parse_foreign_key_constraint,
         // primary key (col1, col2)
         map(
             (
@@ -108,6 +117,167 @@ pub fn parse_table_constraint(input: &str) -> IResult<&str, TableConstraint> {
     .parse(input)
 }
 
+fn parse_ident_list(input: &str) -> IResult<&str, Vec<String>> {
+    map(
+        delimited(
+            (char('('), multispace0),
+            separated_list1((multispace0, char(','), multispace0), parse_identifier),
+            (multispace0, char(')')),
+        ),
+        |cols: Vec<&str>| cols.iter().map(|s| s.to_string()).collect(),
+    )
+    .parse(input)
+}
+
+/// Parse a table-level foreign key:
+/// `foreign key (org_id) references orgs (id) on delete cascade`
+fn parse_foreign_key_constraint(input: &str) -> IResult<&str, TableConstraint> {
+    let (input, _) = (
+        tag_no_case("foreign"),
+        multispace1,
+        tag_no_case("key"),
+        multispace0,
+    )
+        .parse(input)?;
+    let (input, columns) = parse_ident_list(input)?;
+    let (input, _) = (multispace1, tag_no_case("references"), multispace1).parse(input)?;
+    let (input, ref_table) = parse_identifier(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, ref_columns) = parse_ident_list(input)?;
+    let (input, on_delete) = opt(preceded(
+        (
+            multispace1,
+            tag_no_case("on"),
+            multispace1,
+            tag_no_case("delete"),
+            multispace1,
+        ),
+        parse_fk_action,
+    ))
+    .parse(input)?;
+    let (input, on_update) = opt(preceded(
+        (
+            multispace1,
+            tag_no_case("on"),
+            multispace1,
+            tag_no_case("update"),
+            multispace1,
+        ),
+        parse_fk_action,
+    ))
+    .parse(input)?;
+
+    Ok((
+        input,
+        TableConstraint::ForeignKey {
+            name: None,
+            columns,
+            ref_table: ref_table.to_string(),
+            ref_columns,
+            on_delete: on_delete.map(str::to_string),
+            on_update: on_update.map(str::to_string),
+            deferrable: None,
+        },
+    ))
+}
+
+fn parse_fk_action(input: &str) -> IResult<&str, &'static str> {
+    alt((
+        value("CASCADE", tag_no_case("cascade")),
+        value("RESTRICT", tag_no_case("restrict")),
+        value(
+            "SET NULL",
+            (tag_no_case("set"), multispace1, tag_no_case("null")),
+        ),
+        value(
+            "SET DEFAULT",
+            (tag_no_case("set"), multispace1, tag_no_case("default")),
+        ),
+        value(
+            "NO ACTION",
+            (tag_no_case("no"), multispace1, tag_no_case("action")),
+        ),
+    ))
+    .parse(input)
+}
+
+/// Parse ALTER TABLE constraint changes:
+///
+/// ```text
+/// alter users add constraint users_org_email_key unique (org_id, email)
+/// alter users add constraint users_org_fk foreign key (org_id) references orgs (id)
+/// alter users add constraint users_age_check check (age >= 0)
+/// alter users drop constraint users_org_email_key
+/// ```
+///
+/// The constraint name is stored in `channel`; a CHECK expression goes in
+/// `payload`, any other constraint in `table_constraints`.
+pub fn parse_alter_constraint(input: &str) -> IResult<&str, Qail> {
+    let (input, _) = (tag_no_case("alter"), multispace1).parse(input)?;
+    let (input, table) = parse_identifier(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, add) = alt((
+        value(true, tag_no_case("add")),
+        value(false, tag_no_case("drop")),
+    ))
+    .parse(input)?;
+    let (input, _) = (multispace1, tag_no_case("constraint"), multispace1).parse(input)?;
+    let (input, name) = parse_identifier(input)?;
+
+    let mut cmd = Qail {
+        action: Action::AlterDropConstraint,
+        table: table.to_string(),
+        channel: Some(name.to_string()),
+        ..Default::default()
+    };
+    if !add {
+        return Ok((input, cmd));
+    }
+
+    let (input, _) = multispace1(input)?;
+    cmd.action = Action::AlterAddConstraint;
+    if let Ok((rest, _)) = (
+        tag_no_case::<_, _, nom::error::Error<&str>>("check"),
+        multispace0,
+    )
+        .parse(input)
+    {
+        let (rest, expr) = parse_parenthesized_sql(rest)?;
+        cmd.payload = Some(expr.trim().to_string());
+        return Ok((rest, cmd));
+    }
+    let (input, constraint) = parse_table_constraint(input)?;
+    cmd.table_constraints.push(constraint);
+    Ok((input, cmd))
+}
+
+/// Take the raw text inside a balanced `( ... )`, skipping parentheses in
+/// quoted strings.
+fn parse_parenthesized_sql(input: &str) -> IResult<&str, &str> {
+    let (body, _) = char('(').parse(input)?;
+    let mut depth = 1usize;
+    let mut quote = None;
+    for (idx, ch) in body.char_indices() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&body[idx + 1..], &body[..idx]));
+                }
+            }
+            (None, _) => {}
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::Char,
+    )))
+}
+
 /// Parse column definition: `name:type[:constraint1[:constraint2]]`
 pub fn parse_column_definition(input: &str) -> IResult<&str, Expr> {
     let (input, name) = parse_bare_identifier(input)?;
@@ -171,6 +341,7 @@ fn validate_column_constraints<'a>(
     let mut nullable = false;
     let mut default = false;
     let mut check = false;
+    let mut references = false;
 
     for constraint in constraints {
         match constraint {
@@ -204,6 +375,12 @@ fn validate_column_constraints<'a>(
                 }
                 check = true;
             }
+            Constraint::References(_) => {
+                if references {
+                    return Err(column_definition_error(error_input));
+                }
+                references = true;
+            }
             _ => {}
         }
     }
@@ -219,7 +396,7 @@ fn column_definition_error(input: &str) -> nom::Err<nom::error::Error<&str>> {
     nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify))
 }
 
-/// Parse column constraint: pk, unique, nullable, default=value, check=expr
+/// Parse column constraint: pk, unique, nullable, default=value, check=expr, ref(table.col)
 pub fn parse_constraint(input: &str) -> IResult<&str, Constraint> {
     alt((
         // Primary key
@@ -246,10 +423,74 @@ pub fn parse_constraint(input: &str) -> IResult<&str, Constraint> {
             ),
             |expr: &str| Constraint::Check(vec![expr.to_string()]),
         ),
+        parse_references_constraint,
     ))
     .parse(input)
 }
 
+/// Parse a foreign key: `ref(users.id, on_delete=cascade, on_update=set_null, deferred)`
+/// (a leading `^` is accepted too).
+///
+/// Options after the target are `on_delete=` / `on_update=` with one of
+/// `cascade`, `restrict`, `set_null`, `set_default`, `no_action`, plus the
+/// flags `deferrable`, `deferred` and `immediate`.
+fn parse_references_constraint(input: &str) -> IResult<&str, Constraint> {
+    let (rest, _) = (opt(char('^')), tag_no_case("ref"), char('(')).parse(input)?;
+    let (rest, (table, _, column)) =
+        (parse_bare_identifier, char('.'), parse_bare_identifier).parse(rest)?;
+    let (rest, options) = many0(preceded(
+        (multispace0, char(','), multispace0),
+        take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '='),
+    ))
+    .parse(rest)?;
+    let (rest, _) = (multispace0, char(')')).parse(rest)?;
+
+    let mut on_delete = None;
+    let mut on_update = None;
+    let mut deferrable = None;
+    for option in options {
+        let option = option.to_ascii_lowercase();
+        let (slot, value) = match option.split_once('=') {
+            Some(("on_delete", action)) => (&mut on_delete, fk_action_sql(action)),
+            Some(("on_update", action)) => (&mut on_update, fk_action_sql(action)),
+            Some(_) => (&mut deferrable, None),
+            None => (
+                &mut deferrable,
+                match option.as_str() {
+                    "deferrable" => Some("DEFERRABLE"),
+                    "deferred" => Some("DEFERRABLE INITIALLY DEFERRED"),
+                    "immediate" => Some("DEFERRABLE INITIALLY IMMEDIATE"),
+                    _ => None,
+                },
+            ),
+        };
+        match value {
+            Some(sql) if slot.is_none() => *slot = Some(sql),
+            _ => return Err(column_definition_error(input)),
+        }
+    }
+
+    let mut target = format!("{table}({column})");
+    for (keyword, clause) in [
+        ("ON DELETE ", on_delete),
+        ("ON UPDATE ", on_update),
+        ("", deferrable),
+    ] {
+        if let Some(clause) = clause {
+            target.push(' ');
+            target.push_str(keyword);
+            target.push_str(clause);
+        }
+    }
+    Ok((rest, Constraint::References(target)))
+}
+
+fn fk_action_sql(action: &str) -> Option<&'static str> {
+    parse_fk_action_str(action)
+        .ok()
+        .map(|action| fk_action_to_sql(&action))
+}
+
 fn parse_default_value(input: &str) -> IResult<&str, &str> {
     let mut end = 0usize;
     let mut chars = input.char_indices().peekable();
@@ -324,7 +565,10 @@ pub fn parse_create_index(input: &str) -> IResult<&str, Qail> {
             returning: None,
             on_conflict: None,
             merge: None,
+            copy_options: None,
             source_query: None,
+            table_function: None,
+            from_functions: vec![],
             channel: None,
             payload: None,
             savepoint_name: None,
@@ -332,6 +576,7 @@ pub fn parse_create_index(input: &str) -> IResult<&str, Qail> {
             using_tables: vec![],
             lock_mode: None,
             skip_locked: false,
+            nowait: false,
             fetch: None,
             default_values: false,
             overriding: None,
@@ -347,6 +592,7 @@ pub fn parse_create_index(input: &str) -> IResult<&str, Qail> {
             function_def: None,
             trigger_def: None,
             policy_def: None,
+            doc: None,
         },
     ))
 }
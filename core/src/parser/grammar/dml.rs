@@ -1,10 +1,12 @@
-use super::base::{parse_bare_identifier, parse_value};
+use super::base::{parse_bare_identifier, parse_identifier, parse_value};
 use crate::ast::*;
 use nom::{
     IResult, Parser,
     bytes::complete::tag_no_case,
     character::complete::{char, multispace0, multispace1},
+    combinator::opt,
     multi::separated_list1,
+    sequence::{delimited, preceded},
 };
 use std::collections::HashSet;
 
@@ -25,14 +27,40 @@ pub fn parse_values_clause(input: &str) -> IResult<&str, Cage> {
     ))
 }
 
-/// Parse: values :val1, :val2 (for INSERT/ADD) - just list of values without column names
-pub fn parse_insert_values(input: &str) -> IResult<&str, Cage> {
+/// Parse INSERT/ADD values - just values without column names:
+/// `values :val1, :val2` for a single row, or `values (1, 'a'), (2, 'b')` for several.
+/// Returns one Payload cage per row.
+pub fn parse_insert_values(input: &str) -> IResult<&str, Vec<Cage>> {
     let (input, _) = tag_no_case("values").parse(input)?;
-    let (input, _) = multispace1(input)?;
 
+    if let Ok((remaining, rows)) = preceded(multispace0, parse_value_rows).parse(input) {
+        return Ok((
+            remaining,
+            rows.into_iter().map(insert_payload_cage).collect(),
+        ));
+    }
+
+    let (input, _) = multispace1(input)?;
     let (input, values) =
         separated_list1((multispace0, char(','), multispace0), parse_value).parse(input)?;
 
+    Ok((input, vec![insert_payload_cage(values)]))
+}
+
+/// Parse comma-separated parenthesized rows: `(1, 'a'), (2, 'b')`
+fn parse_value_rows(input: &str) -> IResult<&str, Vec<Vec<Value>>> {
+    separated_list1(
+        (multispace0, char(','), multispace0),
+        delimited(
+            (char('('), multispace0),
+            separated_list1((multispace0, char(','), multispace0), parse_value),
+            (multispace0, char(')')),
+        ),
+    )
+    .parse(input)
+}
+
+fn insert_payload_cage(values: Vec<Value>) -> Cage {
     let conditions: Vec<Condition> = values
         .into_iter()
         .enumerate()
@@ -46,14 +74,30 @@ pub fn parse_insert_values(input: &str) -> IResult<&str, Cage> {
         })
         .collect();
 
-    Ok((
-        input,
-        Cage {
-            kind: CageKind::Payload,
-            conditions,
-            logical_op: LogicalOp::And,
-        },
-    ))
+    Cage {
+        kind: CageKind::Payload,
+        conditions,
+        logical_op: LogicalOp::And,
+    }
+}
+
+/// Parse: table users
+pub fn parse_table_command(input: &str) -> IResult<&str, Qail> {
+    let (input, _) = tag_no_case("table").parse(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, table) = parse_identifier(input)?;
+    Ok((input, Qail::table(table)))
+}
+
+/// Parse: values (1, 'a'), (2, 'b')
+pub fn parse_values_command(input: &str) -> IResult<&str, Qail> {
+    let (input, _) = tag_no_case("values").parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, rows) = parse_value_rows(input)?;
+    let cmd = rows
+        .into_iter()
+        .fold(Qail::values_list(), |cmd, row| cmd.values(row));
+    Ok((input, cmd))
 }
 
 /// Parse comma-separated assignments: col = val, col2 = val2
@@ -99,7 +143,7 @@ pub fn parse_assignment(input: &str) -> IResult<&str, Condition> {
 }
 
 /// Parse a subquery value: (get ...) -> Value::Subquery
-fn parse_subquery_value(input: &str) -> IResult<&str, Value> {
+pub(crate) fn parse_subquery_value(input: &str) -> IResult<&str, Value> {
     let (input, _) = char('(').parse(input)?;
     let (input, _) = multispace0(input)?;
     let (input, subquery) = super::parse_root(input)?;
@@ -112,6 +156,7 @@ fn parse_subquery_value(input: &str) -> IResult<&str, Value> {
 /// Syntax:
 /// - `conflict (col1, col2) nothing` -> ON CONFLICT (col1, col2) DO NOTHING
 /// - `conflict (col1) update col2 = val` -> ON CONFLICT (col1) DO UPDATE SET col2 = val
+/// - `conflict (col1) where active = true nothing` -> ON CONFLICT (col1) WHERE active = true DO NOTHING
 pub fn parse_on_conflict(input: &str) -> IResult<&str, OnConflict> {
     use nom::branch::alt;
 
@@ -130,6 +175,7 @@ pub fn parse_on_conflict(input: &str) -> IResult<&str, OnConflict> {
     let (input, _) = multispace0(input)?;
     let (input, _) = char(')').parse(input)?;
     let (input, _) = multispace0(input)?;
+    let (input, predicate) = opt(parse_conflict_predicate).parse(input)?;
 
     let (input, action) = alt((parse_conflict_nothing, parse_conflict_update)).parse(input)?;
 
@@ -137,11 +183,25 @@ pub fn parse_on_conflict(input: &str) -> IResult<&str, OnConflict> {
         input,
         OnConflict {
             columns: columns.iter().map(|s| s.to_string()).collect(),
+            predicate: predicate.unwrap_or_default(),
             action,
         },
     ))
 }
 
+/// Parse the partial-index predicate: where cond and cond2
+fn parse_conflict_predicate(input: &str) -> IResult<&str, Vec<Condition>> {
+    let (input, _) = tag_no_case("where").parse(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, predicate) = separated_list1(
+        (multispace0, tag_no_case("and"), multispace1),
+        super::clauses::parse_condition,
+    )
+    .parse(input)?;
+    let (input, _) = multispace0(input)?;
+    Ok((input, predicate))
+}
+
 /// Parse: nothing
 fn parse_conflict_nothing(input: &str) -> IResult<&str, ConflictAction> {
     use nom::combinator::value;
@@ -6,7 +6,8 @@
 //! - FILTER (WHERE ...) clause for aggregates
 //! - COUNT(DISTINCT col) syntax
 
-use super::base::{parse_identifier, parse_operator, parse_value};
+use super::base::{parse_identifier, parse_in_list, parse_operator, parse_value};
+use super::clauses::parse_sort_order;
 use super::expressions::parse_expression;
 use crate::ast::*;
 use nom::{
@@ -131,6 +132,76 @@ pub fn parse_function_or_aggregate(input: &str) -> IResult<&str, Expr> {
     }
 }
 
+/// Parse a set-returning function in FROM position: `name(args) [as alias]`.
+///
+/// `series(...)` is shorthand for `generate_series(...)`.
+pub fn parse_table_function(input: &str) -> IResult<&str, Expr> {
+    let (input, name) = parse_identifier(input)?;
+    let (input, _) = char('(').parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, args) =
+        separated_list0((multispace0, char(','), multispace0), parse_function_arg).parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = char(')').parse(input)?;
+    let (input, alias) = opt(preceded(
+        (multispace1, tag_no_case("as"), multispace1),
+        parse_identifier,
+    ))
+    .parse(input)?;
+
+    let name = if name.eq_ignore_ascii_case("series") {
+        "generate_series".to_string()
+    } else {
+        name.to_string()
+    };
+    Ok((
+        input,
+        Expr::FunctionCall {
+            name,
+            args,
+            alias: alias.map(str::to_string),
+        },
+    ))
+}
+
+/// Parse a set-returning function listed after the table in FROM:
+/// `name(args) [with ordinality] [as alias]`.
+///
+/// Returns the function call and whether `WITH ORDINALITY` was given.
+pub fn parse_from_function(input: &str) -> IResult<&str, (Expr, bool)> {
+    let (input, name) = parse_identifier(input)?;
+    let (input, _) = char('(').parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, args) =
+        separated_list0((multispace0, char(','), multispace0), parse_function_arg).parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = char(')').parse(input)?;
+    let (input, with_ordinality) = opt((
+        multispace1,
+        tag_no_case("with"),
+        multispace1,
+        tag_no_case("ordinality"),
+    ))
+    .parse(input)?;
+    let (input, alias) = opt(preceded(
+        (multispace1, tag_no_case("as"), multispace1),
+        parse_identifier,
+    ))
+    .parse(input)?;
+
+    Ok((
+        input,
+        (
+            Expr::FunctionCall {
+                name: name.to_string(),
+                args,
+                alias: alias.map(str::to_string),
+            },
+            with_ordinality.is_some(),
+        ),
+    ))
+}
+
 /// Parse a single function argument (supports expressions or star)
 pub fn parse_function_arg(input: &str) -> IResult<&str, Expr> {
     alt((map(tag("*"), |_| Expr::Star), parse_expression)).parse(input)
@@ -170,20 +241,8 @@ fn parse_filter_conditions(input: &str) -> IResult<&str, Vec<Condition>> {
             (input, Value::Null)
         } else if matches!(op, Operator::In | Operator::NotIn) {
             let (input, _) = multispace0(input)?;
-            if let Ok((input, _)) = char::<_, nom::error::Error<&str>>('(').parse(input) {
-                let (input, _) = multispace0(input)?;
-                let (input, values) =
-                    separated_list0((multispace0, char(','), multispace0), parse_value)
-                        .parse(input)?;
-                let (input, _) = multispace0(input)?;
-                let (input, _) = char(')').parse(input)?;
-                if values.is_empty() {
-                    return Err(nom::Err::Error(nom::error::Error::new(
-                        input,
-                        nom::error::ErrorKind::SeparatedList,
-                    )));
-                }
-                (input, Value::Array(values))
+            if input.starts_with('(') {
+                parse_in_list(input)?
             } else {
                 parse_value(input)?
             }
@@ -332,23 +391,17 @@ fn parse_window_order_by(input: &str) -> IResult<&str, Vec<Cage>> {
     Ok((input, order_parts))
 }
 
-/// Parse a single order by item: col [asc|desc]
+/// Parse a single order by item: col [asc|desc] [nulls first|last]
 fn parse_window_sort_item(input: &str) -> IResult<&str, Cage> {
-    use nom::combinator::value;
-
     let (input, col) = parse_identifier(input)?;
     let (input, _) = multispace0(input)?;
 
-    let (input, order) = opt(alt((
-        value(SortOrder::Desc, tag_no_case("desc")),
-        value(SortOrder::Asc, tag_no_case("asc")),
-    )))
-    .parse(input)?;
+    let (input, order) = parse_sort_order(input)?;
 
     Ok((
         input,
         Cage {
-            kind: CageKind::Sort(order.unwrap_or(SortOrder::Asc)),
+            kind: CageKind::Sort(order),
             conditions: vec![Condition {
                 left: Expr::Named(col.to_string()),
                 op: Operator::Eq,
@@ -360,7 +413,7 @@ fn parse_window_sort_item(input: &str) -> IResult<&str, Cage> {
     ))
 }
 
-/// Parse window frame: ROWS/RANGE BETWEEN start AND end
+/// Parse window frame: ROWS/RANGE BETWEEN start AND end [EXCLUDE ...]
 fn parse_window_frame(input: &str) -> IResult<&str, WindowFrame> {
     use nom::combinator::value;
 
@@ -383,15 +436,46 @@ fn parse_window_frame(input: &str) -> IResult<&str, WindowFrame> {
     // Parse end bound
     let (input, end) = parse_frame_bound(input)?;
 
+    let (input, exclude) = opt(preceded(multispace1, parse_frame_exclusion)).parse(input)?;
+
     let frame = if is_rows {
-        WindowFrame::Rows { start, end }
+        WindowFrame::Rows {
+            start,
+            end,
+            exclude,
+        }
     } else {
-        WindowFrame::Range { start, end }
+        WindowFrame::Range {
+            start,
+            end,
+            exclude,
+        }
     };
 
     Ok((input, frame))
 }
 
+/// Parse frame exclusion: EXCLUDE CURRENT ROW | GROUP | TIES | NO OTHERS
+fn parse_frame_exclusion(input: &str) -> IResult<&str, FrameExclusion> {
+    use nom::combinator::value;
+
+    let (input, _) = tag_no_case("exclude").parse(input)?;
+    let (input, _) = multispace1(input)?;
+    alt((
+        value(
+            FrameExclusion::CurrentRow,
+            (tag_no_case("current"), multispace1, tag_no_case("row")),
+        ),
+        value(FrameExclusion::Group, tag_no_case("group")),
+        value(FrameExclusion::Ties, tag_no_case("ties")),
+        value(
+            FrameExclusion::NoOthers,
+            (tag_no_case("no"), multispace1, tag_no_case("others")),
+        ),
+    ))
+    .parse(input)
+}
+
 /// Parse frame bound: UNBOUNDED PRECEDING, N PRECEDING, CURRENT ROW, N FOLLOWING, UNBOUNDED FOLLOWING
 fn parse_frame_bound(input: &str) -> IResult<&str, FrameBound> {
     use nom::character::complete::i32 as parse_i32;
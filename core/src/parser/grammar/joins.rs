@@ -7,10 +7,13 @@ use nom::{
     bytes::complete::tag_no_case,
     character::complete::{char, multispace0, multispace1},
     combinator::{map, opt},
-    sequence::preceded,
+    multi::separated_list1,
+    sequence::{delimited, preceded},
 };
 
-/// Parse join clause: [left|right|inner] join table [on col = col]
+/// Parse join clause: [left|right|inner|cross] join [lateral] source [on col = col | using (col, ...)]
+///
+/// `source` is a table name or a parenthesized subquery followed by `[as] alias`.
 pub fn parse_join_clause(input: &str) -> IResult<&str, Join> {
     let (input, kind) = alt((
         map(
@@ -25,13 +28,36 @@ pub fn parse_join_clause(input: &str) -> IResult<&str, Join> {
             (tag_no_case("inner"), multispace1, tag_no_case("join")),
             |_| JoinKind::Inner,
         ),
+        map(
+            (tag_no_case("cross"), multispace1, tag_no_case("join")),
+            |_| JoinKind::Cross,
+        ),
         // Default: just "join" = LEFT join
         map(tag_no_case("join"), |_| JoinKind::Left),
     ))
     .parse(input)?;
 
+    let (input, lateral) = opt((multispace1, tag_no_case("lateral"))).parse(input)?;
+    let kind = match (lateral.is_some(), kind) {
+        (false, kind) => kind,
+        (true, JoinKind::Left) => JoinKind::Lateral,
+        (true, JoinKind::Inner) => JoinKind::LateralInner,
+        (true, _) => {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Verify,
+            )));
+        }
+    };
+
     let (input, _) = multispace1(input)?;
-    let (input, table) = parse_identifier(input)?;
+    let (input, (table, subquery)) = alt((
+        map(parse_join_subquery, |(query, alias)| {
+            (alias, Some(Box::new(query)))
+        }),
+        map(parse_identifier, |table| (table, None)),
+    ))
+    .parse(input)?;
     let (input, _) = multispace0(input)?;
 
     // Optional ON clause: either ON TRUE or ON condition
@@ -39,8 +65,18 @@ pub fn parse_join_clause(input: &str) -> IResult<&str, Join> {
     let (input, on_true_result) =
         opt((tag_no_case("on"), multispace1, tag_no_case("true"))).parse(input)?;
 
+    let (input, using) = if on_true_result.is_some() {
+        (input, Vec::new())
+    } else {
+        opt(parse_using_columns)
+            .parse(input)
+            .map(|(input, cols)| (input, cols.unwrap_or_default()))?
+    };
+
     let (input, on_clause, on_true) = if on_true_result.is_some() {
         (input, None, true)
+    } else if !using.is_empty() {
+        (input, None, false)
     } else {
         // Try parsing ON condition
         let (input, cond) = opt(preceded(
@@ -61,12 +97,57 @@ pub fn parse_join_clause(input: &str) -> IResult<&str, Join> {
             kind,
             on: on_clause,
             on_true,
+            using,
+            subquery,
         },
     ))
 }
 
-/// Parse join condition: table.col = table.col
+/// Parse a derived-table join source: `(get ...) [as] alias`.
+fn parse_join_subquery(input: &str) -> IResult<&str, (Qail, &str)> {
+    let (input, _) = (char('('), multispace0).parse(input)?;
+    let (input, query) = super::parse_root(input)?;
+    let (input, _) = (multispace0, char(')'), multispace0).parse(input)?;
+    let (input, _) = opt((tag_no_case("as"), multispace1)).parse(input)?;
+    let (input, alias) = parse_identifier(input)?;
+    Ok((input, (query, alias)))
+}
+
+/// Parse `USING (col, ...)` shared-column list.
+fn parse_using_columns(input: &str) -> IResult<&str, Vec<String>> {
+    let (input, _) = (tag_no_case("using"), multispace0, char('('), multispace0).parse(input)?;
+    let (input, cols) = separated_list1(
+        (multispace0, char(','), multispace0),
+        map(parse_identifier, str::to_string),
+    )
+    .parse(input)?;
+    let (input, _) = (multispace0, char(')')).parse(input)?;
+    Ok((input, cols))
+}
+
+/// Parse join condition: `table.col = table.col [and table.col = table.col ...]`,
+/// optionally wrapped in parentheses for composite keys.
 pub fn parse_join_condition(input: &str) -> IResult<&str, Vec<Condition>> {
+    alt((
+        delimited(
+            (char('('), multispace0),
+            parse_join_equalities,
+            (multispace0, char(')')),
+        ),
+        parse_join_equalities,
+    ))
+    .parse(input)
+}
+
+fn parse_join_equalities(input: &str) -> IResult<&str, Vec<Condition>> {
+    separated_list1(
+        (multispace1, tag_no_case("and"), multispace1),
+        parse_join_equality,
+    )
+    .parse(input)
+}
+
+fn parse_join_equality(input: &str) -> IResult<&str, Condition> {
     let (input, left_expr) = parse_expression(input)?;
     let (input, _) = multispace0(input)?;
     let (input, _) = char('=').parse(input)?;
@@ -75,11 +156,11 @@ pub fn parse_join_condition(input: &str) -> IResult<&str, Vec<Condition>> {
 
     Ok((
         input,
-        vec![Condition {
+        Condition {
             left: left_expr, // Use parsed expression
             op: Operator::Eq,
             value: Value::Column(right_col.to_string()),
             is_array_unnest: false,
-        }],
+        },
     ))
 }
@@ -31,10 +31,12 @@ use self::joins::*;
 use crate::ast::*;
 use nom::{
     IResult, Parser,
+    branch::alt,
     bytes::complete::tag_no_case,
-    character::complete::{multispace0, multispace1},
-    combinator::opt,
+    character::complete::{char, multispace0, multispace1},
+    combinator::{opt, value},
     multi::many0,
+    sequence::{preceded, terminated},
 };
 // use self::expressions::*; // Used in clauses module
 
@@ -42,14 +44,40 @@ use nom::{
 /// This is the recommended entry point - handles SQL comment stripping
 /// and `table[filter]` shorthand desugaring.
 pub fn parse(input: &str) -> Result<Qail, String> {
+    parse_located(input).map_err(|(_, message)| message)
+}
+
+/// Like [`parse`], but also returns the byte offset into `input` where
+/// parsing stopped.
+///
+/// The offset is only exact when preprocessing left the text in place; when
+/// comments were stripped or `table[filter]` was rewritten it is `0`.
+pub(crate) fn parse_located(input: &str) -> Result<Qail, (usize, String)> {
     let cleaned = strip_sql_comments(input);
     // Desugar table[filter] shorthand: "set users[active = true] fields ..."
     // → "set users fields ... where active = true"
     let desugared = desugar_bracket_filter(&cleaned);
+    let offset_of = |rest: &str| {
+        if cleaned == input && desugared == cleaned.trim() {
+            let leading = cleaned.len() - cleaned.trim_start().len();
+            leading + desugared.len() - rest.len()
+        } else {
+            0
+        }
+    };
     match parse_root(&desugared) {
         Ok(("", cmd)) => Ok(cmd),
-        Ok((remaining, _)) => Err(format!("Unexpected trailing content: '{}'", remaining)),
-        Err(e) => Err(format!("Parse error: {:?}", e)),
+        Ok((remaining, _)) => Err((
+            offset_of(remaining),
+            format!("Unexpected trailing content: '{}'", remaining),
+        )),
+        Err(e) => {
+            let rest = match &e {
+                nom::Err::Error(inner) | nom::Err::Failure(inner) => inner.input,
+                nom::Err::Incomplete(_) => "",
+            };
+            Err((offset_of(rest), format!("Parse error: {:?}", e)))
+        }
     }
 }
 
@@ -72,6 +100,7 @@ fn desugar_bracket_filter(input: &str) -> String {
         let before_lower = before_bracket.to_ascii_lowercase();
         if before_lower.contains(" where ")
             || before_lower.contains(" fields ")
+            || before_lower.contains(" group ")
             || before_lower.contains(" having ")
             || before_lower.contains(" order ")
             || before_lower.contains(" limit ")
@@ -125,7 +154,51 @@ fn desugar_bracket_filter(input: &str) -> String {
 
 /// Parse a QAIL query (root entry point).
 /// Note: Does NOT strip comments. Use `parse()` for automatic comment handling.
+///
+/// `get` queries may be chained with `union`, `union all`, `intersect` and
+/// `except`; each operand is a plain `get` and lands in `set_ops` in order.
 pub fn parse_root(input: &str) -> IResult<&str, Qail> {
+    let (mut input, mut cmd) = parse_command(input)?;
+    if cmd.action != Action::Get {
+        return Ok((input, cmd));
+    }
+
+    while let Ok((rest, op)) = preceded(multispace0, parse_set_op).parse(input) {
+        let (rest, operand) = parse_command(rest)?;
+        if operand.action != Action::Get || !operand.ctes.is_empty() {
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                rest,
+                nom::error::ErrorKind::Verify,
+            )));
+        }
+        cmd = cmd.set_op(op, operand).map_err(|_| {
+            nom::Err::Failure(nom::error::Error::new(rest, nom::error::ErrorKind::Verify))
+        })?;
+        input = rest;
+    }
+
+    Ok((input, cmd))
+}
+
+/// Parse a set-operation keyword and the whitespace after it.
+fn parse_set_op(input: &str) -> IResult<&str, SetOp> {
+    terminated(
+        alt((
+            value(
+                SetOp::UnionAll,
+                (tag_no_case("union"), multispace1, tag_no_case("all")),
+            ),
+            value(SetOp::Union, tag_no_case("union")),
+            value(SetOp::Intersect, tag_no_case("intersect")),
+            value(SetOp::Except, tag_no_case("except")),
+        )),
+        multispace1,
+    )
+    .parse(input)
+}
+
+/// Parse a single QAIL command, without trailing set operations.
+fn parse_command(input: &str) -> IResult<&str, Qail> {
     let input = input.trim();
 
     // Try transaction commands first (single keywords)
@@ -138,11 +211,21 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
         return Ok((remaining, cmd));
     }
 
+    // Standalone TABLE / VALUES commands
+    if let Ok((remaining, cmd)) = alt((parse_table_command, parse_values_command)).parse(input) {
+        return Ok((remaining, cmd));
+    }
+
     // Try CREATE INDEX first (special case: "index name on table ...")
     if let Ok((remaining, cmd)) = parse_create_index(input) {
         return Ok((remaining, cmd));
     }
 
+    // ALTER TABLE ADD/DROP CONSTRAINT ("alter users add constraint ...")
+    if let Ok((remaining, cmd)) = parse_alter_constraint(input) {
+        return Ok((remaining, cmd));
+    }
+
     // Try WITH clause (CTE) parsing
     let lower_input = input.to_lowercase();
     let (input, ctes) = if lower_input.starts_with("with")
@@ -191,8 +274,27 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
         (input, vec![])
     };
 
-    //  Parse table name
-    let (input, table) = parse_identifier(input)?;
+    // Table-function source (`get generate_series(1, 10) as n`) or table name
+    let (input, table_function) = if matches!(action, Action::Get) {
+        opt(functions::parse_table_function).parse(input)?
+    } else {
+        (input, None)
+    };
+    let (input, table) = if table_function.is_some() {
+        (input, "")
+    } else {
+        parse_identifier(input)?
+    };
+    // Extra FROM sources after the table (`get events, unnest(tags) as tag`)
+    let (input, from_functions) = if matches!(action, Action::Get) {
+        many0(preceded(
+            (multispace0, char(','), multispace0),
+            functions::parse_from_function,
+        ))
+        .parse(input)?
+    } else {
+        (input, vec![])
+    };
     let (input, _) = multispace0(input)?;
 
     // For MAKE (CREATE TABLE): parse column definitions
@@ -237,6 +339,13 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
     let (input, where_cages) = opt(parse_where_clause).parse(input)?;
     let (input, _) = multispace0(input)?;
 
+    let (input, group_by) = opt(parse_group_by_clause).parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (group_by_mode, group_cage) = match group_by {
+        Some((mode, cage)) => (mode, Some(cage)),
+        None => (GroupByMode::default(), None),
+    };
+
     let (input, having) = opt(parse_having_clause).parse(input)?;
     let (input, _) = multispace0(input)?;
 
@@ -252,6 +361,12 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
     let (input, limit_cage) = opt(parse_limit_clause).parse(input)?;
     let (input, _) = multispace0(input)?;
     let (input, offset_cage) = opt(parse_offset_clause).parse(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, lock) = opt(parse_lock_clause).parse(input)?;
+    let (lock_mode, skip_locked, nowait) = match lock {
+        Some((mode, wait)) => (Some(mode), wait == Some(true), wait == Some(false)),
+        None => (None, false, false),
+    };
 
     let mut cages = Vec::new();
 
@@ -260,14 +375,17 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
         cages.push(sc);
     }
 
-    // For ADD, values come as Payload cage too
+    // For ADD, values come as Payload cages too (one per row)
     if let Some(ac) = add_cages {
-        cages.push(ac);
+        cages.extend(ac);
     }
 
     if let Some(wc) = where_cages {
         cages.extend(wc);
     }
+    if let Some(gc) = group_cage {
+        cages.push(gc);
+    }
     if let Some(oc) = order_cages {
         cages.extend(oc);
     }
@@ -282,7 +400,12 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
         input,
         Qail {
             action,
-            table: table.to_string(),
+            table: match &table_function {
+                Some(Expr::FunctionCall { name, alias, .. }) => {
+                    alias.clone().unwrap_or_else(|| name.clone())
+                }
+                _ => table.to_string(),
+            },
             columns: columns.unwrap_or_else(|| vec![Expr::Star]),
             joins,
             cages,
@@ -292,19 +415,23 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
             table_constraints: vec![],
             set_ops: vec![],
             having: having.unwrap_or_default(),
-            group_by_mode: GroupByMode::default(),
+            group_by_mode,
             returning: None,
             ctes,
             on_conflict,
             merge: None,
+            copy_options: None,
             source_query,
+            table_function,
+            from_functions,
             channel: None,
             payload: None,
             savepoint_name: None,
             from_tables: vec![],
             using_tables: vec![],
-            lock_mode: None,
-            skip_locked: false,
+            lock_mode,
+            skip_locked,
+            nowait,
             fetch: None,
             default_values: false,
             overriding: None,
@@ -320,10 +447,47 @@ pub fn parse_root(input: &str) -> IResult<&str, Qail> {
             function_def: None,
             trigger_def: None,
             policy_def: None,
+            doc: None,
         },
     ))
 }
 
+/// Collect the comments that precede the first token of a query.
+///
+/// `--` lines lose the marker and one following space; `/* */` blocks are
+/// trimmed. Returns `Ok(None)` when the query does not start with a comment
+/// and a parse error at the opening `/*` when a block is never closed.
+pub fn leading_comment(input: &str) -> crate::error::QailResult<Option<String>> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut rest = input.trim_start();
+
+    loop {
+        if let Some(after) = rest.strip_prefix("--") {
+            let (line, tail) = after.split_once('\n').unwrap_or((after, ""));
+            let line = line.strip_prefix(' ').unwrap_or(line);
+            lines.push(line.trim_end().to_string());
+            rest = tail.trim_start();
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            let Some((block, tail)) = after.split_once("*/") else {
+                return Err(crate::error::QailError::parse(
+                    input.len() - rest.len(),
+                    "unterminated /* comment",
+                ));
+            };
+            lines.extend(block.trim().lines().map(|line| line.trim().to_string()));
+            rest = tail.trim_start();
+        } else {
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(lines.join("\n")))
+    }
+}
+
 /// Strip SQL comments from input (both -- line comments and /* */ block comments)
 fn strip_sql_comments(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
@@ -34,6 +34,7 @@ const MAX_INPUT_LENGTH: usize = 64 * 1024;
 /// Uses keyword-based syntax: `get table fields * where col = value`
 /// Also supports shorthand: `get table[filter]` desugars to `get table where filter`
 pub fn parse(input: &str) -> QailResult<Qail> {
+    let leading = input.len() - input.trim_start().len();
     let input = input.trim();
 
     // R8-A: Reject oversized inputs before recursive descent to prevent stack overflow
@@ -48,9 +49,109 @@ pub fn parse(input: &str) -> QailResult<Qail> {
         ));
     }
 
-    // Use grammar::parse which handles comment stripping + [filter] desugaring
-    match grammar::parse(input) {
-        Ok(cmd) => Ok(cmd),
-        Err(e) => Err(QailError::parse(0, e)),
+    // grammar::parse_located handles comment stripping + [filter] desugaring
+    grammar::parse_located(input)
+        .map_err(|(position, message)| QailError::parse(leading + position, message))
+}
+
+/// Parse a QAIL query, keeping its leading comments as [`Qail::doc`].
+///
+/// Comments elsewhere in the query are still stripped. The formatter
+/// re-emits `doc` as `--` lines above the query.
+pub fn parse_with_comments(input: &str) -> QailResult<Qail> {
+    let doc = grammar::leading_comment(input)?;
+    let mut cmd = parse(input)?;
+    cmd.doc = doc;
+    Ok(cmd)
+}
+
+/// Parse several QAIL statements separated by `;`.
+///
+/// Separators inside string literals, quoted identifiers, `$$` bodies,
+/// comments and brackets do not split. Empty statements (such as the one
+/// after a trailing `;`) are skipped. A failure is reported as a parse error
+/// whose message names the 1-based statement and whose position is a byte
+/// offset into `input`.
+pub fn parse_many(input: &str) -> QailResult<Vec<Qail>> {
+    split_statements(input)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (start, statement))| {
+            parse(statement).map_err(|err| match err {
+                QailError::Parse { position, message } => QailError::parse(
+                    start + position,
+                    format!("statement {}: {}", index + 1, message),
+                ),
+                other => other,
+            })
+        })
+        .collect()
+}
+
+/// Split `input` on top-level `;`, returning each non-empty statement with
+/// its byte offset. Statements holding only comments are dropped.
+fn split_statements(input: &str) -> Vec<(usize, &str)> {
+    let bytes = input.as_bytes();
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut has_code = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        match bytes[i] {
+            b'\'' | b'"' => {
+                let quote = bytes[i];
+                i += 1;
+                while i < bytes.len() {
+                    if bytes[i] == quote {
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                has_code = true;
+            }
+            b'$' if rest.starts_with(b"$$") => {
+                i = input[i + 2..]
+                    .find("$$")
+                    .map_or(bytes.len(), |end| i + 2 + end + 1);
+                has_code = true;
+            }
+            b'-' if rest.starts_with(b"--") => {
+                i = input[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'/' if rest.starts_with(b"/*") => {
+                i = input[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 1);
+            }
+            b'(' | b'[' | b'{' => {
+                depth += 1;
+                has_code = true;
+            }
+            b')' | b']' | b'}' => {
+                depth = depth.saturating_sub(1);
+                has_code = true;
+            }
+            b';' if depth == 0 => {
+                if has_code {
+                    statements.push((start, &input[start..i]));
+                }
+                start = i + 1;
+                has_code = false;
+            }
+            c if !c.is_ascii_whitespace() => has_code = true,
+            _ => {}
+        }
+        i += 1;
+    }
+    if has_code {
+        statements.push((start, &input[start..]));
     }
+    statements
 }
@@ -11,16 +11,22 @@
 //! execute create_user(email: String, name: String):
 //!   add users fields email, name values :email, :name
 //! ```
+//!
+//! Parameter types may be omitted (`query find_user(email) -> User:`) and
+//! filled in from a schema with [`QueryFile::infer_param_types`].
 
 use nom::{
     IResult, Parser,
     branch::alt,
     bytes::complete::{tag, tag_no_case, take_while1},
     character::complete::{char, multispace0, multispace1, not_line_ending},
-    combinator::map,
+    combinator::{map, opt},
     multi::{many0, separated_list0},
 };
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{Condition, Expr, Operator, Qail, Value};
+use crate::build::schema::Schema;
 
 /// Collection of named queries from a queries.qail file
 #[derive(Debug, Clone, Default)]
@@ -50,6 +56,9 @@ pub struct QueryParam {
     /// Parameter name.
     pub name: String,
     /// Parameter type (e.g., "Uuid", "String").
+    ///
+    /// Empty when the declaration omits the type and it has not been
+    /// inferred yet (see [`QueryFile::infer_param_types`]).
     pub typ: String,
 }
 
@@ -80,6 +89,136 @@ impl QueryFile {
             .iter()
             .find(|q| q.name.eq_ignore_ascii_case(name))
     }
+
+    /// Fill in undeclared parameter types from the column each parameter is
+    /// compared with or assigned to (`where email = :email`, `in :ids`,
+    /// `values :name`).
+    ///
+    /// Declared types are never overwritten. Returns an error listing every
+    /// untyped parameter whose type could not be deduced — unused, bound to
+    /// an unknown column, or used against columns of different types.
+    pub fn infer_param_types(&mut self, schema: &Schema) -> Result<(), String> {
+        let mut unresolved = Vec::new();
+
+        for query in &mut self.queries {
+            if query.params.iter().all(|p| !p.typ.is_empty()) {
+                continue;
+            }
+            let candidates = match super::parse(&query.body) {
+                Ok(cmd) => param_type_candidates(&cmd, schema),
+                Err(_) => HashMap::new(),
+            };
+
+            for param in query.params.iter_mut().filter(|p| p.typ.is_empty()) {
+                match candidates.get(&param.name).map(Vec::as_slice) {
+                    Some([typ]) => param.typ = typ.clone(),
+                    Some([]) | None => unresolved.push(format!(
+                        "{}(:{}): not bound to a known column",
+                        query.name, param.name
+                    )),
+                    Some(types) => unresolved.push(format!(
+                        "{}(:{}): ambiguous ({})",
+                        query.name,
+                        param.name,
+                        types.join(" vs ")
+                    )),
+                }
+            }
+        }
+
+        if unresolved.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "cannot infer query parameter types, annotate them as `name: Type`:\n  {}",
+                unresolved.join("\n  ")
+            ))
+        }
+    }
+}
+
+/// Collect the distinct Rust types each named parameter is used as.
+fn param_type_candidates(cmd: &Qail, schema: &Schema) -> HashMap<String, Vec<String>> {
+    let mut candidates: HashMap<String, Vec<String>> = HashMap::new();
+    let mut record = |param: &str, typ: String| {
+        let types = candidates.entry(param.to_string()).or_default();
+        if !types.contains(&typ) {
+            types.push(typ);
+        }
+    };
+
+    let join_conditions = cmd.joins.iter().filter_map(|j| j.on.as_deref());
+    let conditions = cmd
+        .cages
+        .iter()
+        .map(|cage| cage.conditions.as_slice())
+        .chain(join_conditions)
+        .flatten();
+
+    for condition in conditions {
+        let Condition {
+            left: Expr::Named(column),
+            op,
+            value: Value::NamedParam(param),
+            ..
+        } = condition
+        else {
+            continue;
+        };
+
+        // `add t fields a, b values :a, :b` binds positionally as `$1`, `$2`.
+        let column = match column
+            .strip_prefix('$')
+            .and_then(|n| n.parse::<usize>().ok())
+        {
+            Some(idx) => match cmd.columns.get(idx.wrapping_sub(1)) {
+                Some(Expr::Named(name)) => name.as_str(),
+                _ => continue,
+            },
+            None => column.as_str(),
+        };
+
+        let Some(col_type) = resolve_column_type(cmd, schema, column) else {
+            continue;
+        };
+        let typ = crate::build::qail_type_to_rust(col_type);
+        match op {
+            Operator::In | Operator::NotIn => record(param, format!("Vec<{typ}>")),
+            Operator::Eq
+            | Operator::Ne
+            | Operator::Gt
+            | Operator::Gte
+            | Operator::Lt
+            | Operator::Lte
+            | Operator::Like
+            | Operator::NotLike
+            | Operator::ILike
+            | Operator::NotILike => record(param, typ.to_string()),
+            _ => {}
+        }
+    }
+
+    candidates
+}
+
+/// Resolve `col` or `table.col` against the command's base and joined tables.
+fn resolve_column_type<'a>(
+    cmd: &Qail,
+    schema: &'a Schema,
+    column: &str,
+) -> Option<&'a crate::migrate::types::ColumnType> {
+    if let Some((table, col)) = column.rsplit_once('.') {
+        return schema.table(table)?.column_type(col);
+    }
+
+    let mut tables =
+        std::iter::once(cmd.table.as_str()).chain(cmd.joins.iter().map(|j| j.table.as_str()));
+    let mut found = tables
+        .by_ref()
+        .filter_map(|t| schema.table(t)?.column_type(column));
+    let first = found.next()?;
+    // A bare name present in several joined tables is ambiguous.
+    found.next().is_none().then_some(first)
 }
 
 // =============================================================================
@@ -295,20 +434,19 @@ fn ws_and_comments(input: &str) -> IResult<&str, ()> {
     Ok((input, ()))
 }
 
-/// Parse a single parameter: name: Type
+/// Parse a single parameter: `name: Type`, or bare `name` to infer the type
 fn parse_param(input: &str) -> IResult<&str, QueryParam> {
     let (input, _) = multispace0(input)?;
     let (input, name) = identifier(input)?;
     let (input, _) = multispace0(input)?;
-    let (input, _) = char(':').parse(input)?;
+    let (input, typ) = opt((char(':'), multispace0, rust_type_expr)).parse(input)?;
     let (input, _) = multispace0(input)?;
-    let (input, typ) = rust_type_expr(input)?;
 
     Ok((
         input,
         QueryParam {
             name: name.to_string(),
-            typ: typ.to_string(),
+            typ: typ.map(|(_, _, typ)| typ.to_string()).unwrap_or_default(),
         },
     ))
 }
@@ -625,6 +763,74 @@ mod tests {
         QueryFile::parse(malformed_qail_body).expect_err("malformed QAIL body must fail");
     }
 
+    const INFER_SCHEMA: &str = r#"
+table users {
+  id UUID primary_key
+  email TEXT unique
+  age INT
+}
+
+table orders {
+  id UUID primary_key
+  user_id UUID
+  total BIGINT
+}
+"#;
+
+    #[test]
+    fn test_infer_param_types_from_schema() {
+        let schema = Schema::parse(INFER_SCHEMA).unwrap();
+        let input = r#"
+            query find_user(email, min_age: i64) -> Option<User>:
+              get users where email = :email and age >= :min_age
+
+            query user_orders(ids, big) -> Vec<Order>:
+              get orders join users on users.id = orders.user_id where orders.id in :ids and total > :big
+
+            execute create_user(email, age):
+              add users fields email, age values :email, :age
+        "#;
+
+        let mut qf = QueryFile::parse(input).expect("parse failed");
+        assert_eq!(qf.queries[0].params[0].typ, "");
+        qf.infer_param_types(&schema).expect("all params inferable");
+
+        let find = &qf.queries[0];
+        assert_eq!(find.params[0].typ, "String");
+        // Explicit annotations win over the schema's i32.
+        assert_eq!(find.params[1].typ, "i64");
+
+        let orders = &qf.queries[1];
+        assert_eq!(orders.params[0].typ, "Vec<uuid::Uuid>");
+        assert_eq!(orders.params[1].typ, "i64");
+
+        let create = &qf.queries[2];
+        assert_eq!(create.params[0].typ, "String");
+        assert_eq!(create.params[1].typ, "i32");
+    }
+
+    #[test]
+    fn test_infer_param_types_lists_params_needing_annotation() {
+        let schema = Schema::parse(INFER_SCHEMA).unwrap();
+        let input = r#"
+            query search(email, needle) -> Vec<User>:
+              get users where email = :email and nickname = :needle
+
+            query by_id(id) -> Vec<Order>:
+              get orders join users on users.id = orders.user_id where id = :id
+        "#;
+
+        let mut qf = QueryFile::parse(input).expect("parse failed");
+        let err = qf.infer_param_types(&schema).unwrap_err();
+
+        assert_eq!(qf.queries[0].params[0].typ, "String");
+        assert_eq!(qf.queries[0].params[1].typ, "");
+        assert!(err.contains("search(:needle)"), "{err}");
+        assert!(!err.contains("search(:email)"), "{err}");
+        // `id` exists on both joined tables, so it needs an explicit type.
+        assert!(err.contains("by_id(:id)"), "{err}");
+    }
+
     #[test]
     fn test_parse_execute() {
         let input = r#"
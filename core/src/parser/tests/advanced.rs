@@ -62,6 +62,37 @@ fn test_parse_strips_comments_outside_literals() {
     );
 }
 
+#[test]
+fn test_parse_with_comments_captures_leading_doc() {
+    let src = "-- Active users for the dashboard.\n\
+               /* Keep in sync with the API. */\n\
+               get users fields id -- trailing note\n\
+               where active = true";
+    let cmd = crate::parser::parse_with_comments(src).unwrap();
+
+    assert_eq!(
+        cmd.doc.as_deref(),
+        Some("Active users for the dashboard.\nKeep in sync with the API.")
+    );
+    assert_eq!(parse(src).unwrap().doc, None);
+
+    let rendered = cmd.to_string();
+    assert!(
+        rendered.starts_with(
+            "-- Active users for the dashboard.\n-- Keep in sync with the API.\nget users"
+        ),
+        "{rendered}"
+    );
+    assert_eq!(crate::parser::parse_with_comments(&rendered).unwrap(), cmd);
+}
+
+#[test]
+fn test_parse_with_comments_rejects_unterminated_block() {
+    let err =
+        crate::parser::parse_with_comments("-- note\n/* never closed\nget users").unwrap_err();
+    assert_eq!(err.position(), Some(8));
+}
+
 #[test]
 fn test_parse_do_preserves_comment_markers_inside_dollar_body() {
     let cmd = parse(
@@ -191,6 +222,41 @@ fn test_interval_month_suffix_is_not_parsed_as_minutes() {
     );
 }
 
+#[test]
+fn test_structured_interval_components() {
+    let cmd = parse("get subscriptions fields id where period = interval(1 month 7 days 3 hours)")
+        .unwrap();
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::IntervalParts {
+            months: 1,
+            days: 7,
+            micros: 3 * 3_600_000_000,
+        }
+    );
+    assert_eq!(
+        crate::transpiler::ToSql::to_sql(&cmd),
+        "SELECT id FROM subscriptions WHERE period = INTERVAL '1 mon 7 days 03:00:00'"
+    );
+
+    for query in [
+        "get subscriptions fields id where period = interval(3 fortnights)",
+        "get subscriptions fields id where period = interval(999999999 years)",
+    ] {
+        assert!(parse(query).is_err(), "invalid interval parsed: {query}");
+    }
+}
+
+#[test]
+fn test_interval_text_matches_postgres_output() {
+    use crate::ast::values::interval_text;
+
+    assert_eq!(interval_text(0, 0, 0), "00:00:00");
+    assert_eq!(interval_text(1, 7, 10_800_000_000), "1 mon 7 days 03:00:00");
+    assert_eq!(interval_text(26, 1, 0), "2 years 2 mons 1 day");
+    assert_eq!(interval_text(0, -1, -500_000), "-1 days -00:00:00.5");
+}
+
 #[test]
 fn test_bracket_literal_does_not_trigger_table_filter_desugar() {
     let cmd = parse("get users fields id where tags && '[\"a\",\"b\"]'").unwrap();
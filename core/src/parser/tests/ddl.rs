@@ -178,6 +178,79 @@ fn test_make_rejects_malformed_types_and_duplicate_column_constraints() {
     }
 }
 
+#[test]
+fn test_alter_add_and_drop_constraint() {
+    use crate::transpiler::ToSql;
+
+    let cmd =
+        parse("alter users add constraint users_org_email_key unique (org_id, email)").unwrap();
+    assert_eq!(cmd.action, Action::AlterAddConstraint);
+    assert_eq!(cmd.channel.as_deref(), Some("users_org_email_key"));
+    assert_eq!(
+        cmd.table_constraints,
+        vec![TableConstraint::Unique(vec![
+            "org_id".to_string(),
+            "email".to_string()
+        ])]
+    );
+    assert_eq!(
+        cmd.to_sql(),
+        "ALTER TABLE users ADD CONSTRAINT users_org_email_key UNIQUE (org_id, email)"
+    );
+
+    let cmd = parse(
+        "alter trips add constraint trips_route_fk foreign key (route_id, stop_id) \
+         references stops (route_id, id) on delete set null on update cascade",
+    )
+    .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "ALTER TABLE trips ADD CONSTRAINT trips_route_fk FOREIGN KEY (route_id, stop_id) \
+         REFERENCES stops(route_id, id) ON DELETE SET NULL ON UPDATE CASCADE"
+    );
+
+    let cmd = parse("alter users add constraint users_age_check check (age >= 0 and (age < 150))")
+        .unwrap();
+    assert_eq!(cmd.payload.as_deref(), Some("age >= 0 and (age < 150)"));
+    assert_eq!(
+        cmd.to_sql(),
+        "ALTER TABLE users ADD CONSTRAINT users_age_check CHECK (age >= 0 and (age < 150))"
+    );
+
+    let cmd = parse("alter users drop constraint users_org_email_key").unwrap();
+    assert_eq!(cmd.action, Action::AlterDropConstraint);
+    assert_eq!(
+        cmd.to_sql(),
+        "ALTER TABLE users DROP CONSTRAINT users_org_email_key"
+    );
+
+    assert!(parse("alter users add constraint users_key").is_err());
+    assert!(parse("alter users add constraint users_age_check check (age >= 0").is_err());
+}
+
+#[test]
+fn test_make_with_table_foreign_key() {
+    let cmd = parse(
+        "make members org_id:uuid, user_id:uuid \
+         primary key (org_id, user_id) \
+         foreign key (org_id) references orgs (id) on delete cascade",
+    )
+    .unwrap();
+    assert_eq!(cmd.table_constraints.len(), 2);
+    assert_eq!(
+        cmd.table_constraints[1],
+        TableConstraint::ForeignKey {
+            name: None,
+            columns: vec!["org_id".to_string()],
+            ref_table: "orgs".to_string(),
+            ref_columns: vec!["id".to_string()],
+            on_delete: Some("CASCADE".to_string()),
+            on_update: None,
+            deferrable: None,
+        }
+    );
+}
+
 // Keep manual construction for unimplemented/complex commands
 #[test]
 fn test_ddl_commands_manual() {
@@ -77,6 +77,23 @@ fn test_set_mixed_and_or_rejected() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_insert_accepts_repeated_value_rows() {
+    let cmd = parse("add users fields id, name values (1, 'Ana'), (2, 'Bo')").unwrap();
+    let rows: Vec<&Cage> = cmd
+        .cages
+        .iter()
+        .filter(|cage| cage.kind == CageKind::Payload)
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1].conditions[0].value, Value::Int(2));
+    assert_eq!(rows[1].conditions[1].value, Value::String("Bo".to_string()));
+
+    let single = parse("add users fields id, name values 1, 'Ana'").unwrap();
+    assert_eq!(single.cages.len(), 1);
+    assert_eq!(single.cages[0].conditions.len(), 2);
+}
+
 #[test]
 fn test_conflict_update_escapes_triple_quoted_string_assignment() {
     let cmd =
@@ -95,6 +112,29 @@ fn test_conflict_update_escapes_triple_quoted_string_assignment() {
     }
 }
 
+#[test]
+fn test_conflict_partial_index_predicate() {
+    let cmd = parse(
+        "add users fields email, name values 'a@b.com', 'Ana' \
+         conflict (email) where active = true and deleted_at is null update name = excluded.name",
+    )
+    .unwrap();
+
+    let on_conflict = cmd.on_conflict.unwrap();
+    assert_eq!(on_conflict.columns, ["email"]);
+    assert_eq!(on_conflict.predicate.len(), 2);
+    assert_eq!(
+        on_conflict.predicate[0].left,
+        Expr::Named("active".to_string())
+    );
+    assert_eq!(on_conflict.predicate[0].value, Value::Bool(true));
+    assert_eq!(on_conflict.predicate[1].op, Operator::IsNull);
+    assert!(matches!(
+        on_conflict.action,
+        ConflictAction::DoUpdate { .. }
+    ));
+}
+
 #[test]
 fn test_conflict_rejects_malformed_or_duplicate_targets() {
     for query in [
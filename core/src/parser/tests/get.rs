@@ -62,6 +62,45 @@ fn test_v2_get_expression_literals_stay_structured() {
     }
 }
 
+#[test]
+fn test_v2_case_with_two_branches_else_and_alias() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse(
+        "get orders fields status, case when total > 100 then 'big' \
+         when total > 10 then 'mid' else 'small' end as tier",
+    )
+    .unwrap();
+
+    let Expr::Case {
+        when_clauses,
+        else_value,
+        alias,
+    } = &cmd.columns[1]
+    else {
+        panic!("expected case expression, got {:?}", cmd.columns[1]);
+    };
+    assert_eq!(when_clauses.len(), 2);
+    for ((condition, then), (bound, label)) in when_clauses.iter().zip([(100, "big"), (10, "mid")])
+    {
+        assert_eq!(condition.left, Expr::Named("total".to_string()));
+        assert_eq!(condition.op, Operator::Gt);
+        assert_eq!(condition.value, Value::Int(bound));
+        assert_eq!(**then, Expr::Literal(Value::String(label.to_string())));
+    }
+    assert_eq!(
+        else_value.as_deref(),
+        Some(&Expr::Literal(Value::String("small".to_string())))
+    );
+    assert_eq!(alias.as_deref(), Some("tier"));
+
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT status, CASE WHEN total > 100 THEN 'big' WHEN total > 10 THEN 'mid' \
+         ELSE 'small' END AS tier FROM orders"
+    );
+}
+
 #[test]
 fn test_v2_get_with_filter() {
     let cmd = parse("get users fields * where active = true").unwrap();
@@ -155,6 +194,29 @@ fn test_v2_get_with_sort_default_asc() {
     assert_eq!(sort_cage.unwrap().kind, CageKind::Sort(SortOrder::Asc));
 }
 
+#[test]
+fn test_v2_get_with_sort_collation() {
+    let cmd = parse("get users fields name order by name collate 'en_US' desc").unwrap();
+    let sort_cage = &cmd.cages[0];
+    assert_eq!(sort_cage.kind, CageKind::Sort(SortOrder::Desc));
+    assert_eq!(
+        sort_cage.conditions[0].left,
+        Expr::Collate {
+            expr: Box::new(Expr::Named("name".to_string())),
+            collation: "en_US".to_string(),
+            alias: None,
+        }
+    );
+
+    for query in [
+        "get users fields name order by name collate ''",
+        "get users fields name order by name collate 'en.'",
+        "get users fields name order by name collate 'en\nUS'",
+    ] {
+        assert!(parse(query).is_err(), "invalid collation parsed: {query}");
+    }
+}
+
 #[test]
 fn test_v2_fuzzy_match() {
     let cmd = parse("get users fields id where name ~ \"john\"").unwrap();
@@ -188,6 +250,34 @@ fn test_v2_in_accepts_named_parameter_array() {
     );
 }
 
+#[test]
+fn test_v2_in_accepts_subquery_and_list() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse("get users fields id where id in (get orders fields user_id where total > 10)")
+        .unwrap();
+    let cond = &cmd.cages[0].conditions[0];
+    assert_eq!(cond.op, Operator::In);
+    assert!(matches!(&cond.value, Value::Subquery(sub) if sub.table == "orders"));
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM users WHERE id IN (SELECT user_id FROM orders WHERE total > 10)"
+    );
+
+    let cmd = parse("get users fields id where id not in (get bans fields user_id)").unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM users WHERE id NOT IN (SELECT user_id FROM bans)"
+    );
+
+    let cmd = parse("get users fields id where id in (1, 2)").unwrap();
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::Array(vec![Value::Int(1), Value::Int(2)])
+    );
+    assert_eq!(cmd.to_sql(), "SELECT id FROM users WHERE id IN (1, 2)");
+}
+
 #[test]
 fn test_v2_rejects_empty_in_lists() {
     for query in [
@@ -208,8 +298,16 @@ fn test_v2_numeric_overflow_is_rejected() {
     assert!(parse(&format!("get users fields * limit {huge}")).is_err());
     assert!(parse(&format!("get users fields * offset {huge}")).is_err());
     assert!(parse(&format!("get users fields id where email = ${huge}")).is_err());
-    assert!(parse(&format!("get users fields id where age = {huge}")).is_err());
     assert!(parse(&format!("get users fields id where age = {huge}d")).is_err());
+    // Filter values beyond i64 are kept as BigInt rather than rejected.
+    assert_eq!(
+        parse(&format!("get users fields id where age = {huge}"))
+            .unwrap()
+            .cages[0]
+            .conditions[0]
+            .value,
+        Value::BigInt(huge.to_string())
+    );
 }
 
 #[test]
@@ -300,3 +398,148 @@ fn test_v2_full_query() {
     assert_eq!(cmd.columns.len(), 3);
     assert!(!cmd.cages.is_empty());
 }
+
+#[test]
+fn test_v2_integer_literals_beyond_i64_keep_their_digits() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse("get ledger fields id where amount > 123456789012345678901234567890").unwrap();
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::BigInt("123456789012345678901234567890".to_string())
+    );
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM ledger WHERE amount > 123456789012345678901234567890"
+    );
+
+    let cmd = parse("get ledger fields id where amount <= -9223372036854775809").unwrap();
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::BigInt("-9223372036854775809".to_string())
+    );
+
+    // One past i64::MAX overflows; i64::MAX itself stays a plain Int.
+    let cmd = parse("get ledger fields id where amount = 9223372036854775808").unwrap();
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::BigInt("9223372036854775808".to_string())
+    );
+    let cmd = parse("get ledger fields id where amount = 9223372036854775807").unwrap();
+    assert_eq!(cmd.cages[0].conditions[0].value, Value::Int(i64::MAX));
+}
+
+#[test]
+fn test_v2_union_and_union_all() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse("get customers fields email union get leads fields email").unwrap();
+    assert_eq!(cmd.set_ops.len(), 1);
+    assert_eq!(cmd.set_ops[0].0, SetOp::Union);
+    assert_eq!(cmd.set_ops[0].1.table, "leads");
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT email FROM customers UNION SELECT email FROM leads"
+    );
+
+    let cmd = parse(
+        "get customers fields email where active = true \
+         UNION ALL get leads fields email",
+    )
+    .unwrap();
+    assert_eq!(cmd.set_ops[0].0, SetOp::UnionAll);
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT email FROM customers WHERE active = true UNION ALL SELECT email FROM leads"
+    );
+}
+
+#[test]
+fn test_v2_chained_set_operations_keep_order() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse(
+        "get a fields id union get b fields id union all get c fields id \
+         except get d fields id intersect get e fields id",
+    )
+    .unwrap();
+    let ops: Vec<_> = cmd
+        .set_ops
+        .iter()
+        .map(|(op, operand)| (*op, operand.table.as_str()))
+        .collect();
+    assert_eq!(
+        ops,
+        [
+            (SetOp::Union, "b"),
+            (SetOp::UnionAll, "c"),
+            (SetOp::Except, "d"),
+            (SetOp::Intersect, "e"),
+        ]
+    );
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM a UNION SELECT id FROM b UNION ALL SELECT id FROM c \
+         EXCEPT SELECT id FROM d INTERSECT SELECT id FROM e"
+    );
+}
+
+#[test]
+fn test_v2_set_operations_reject_bad_operands() {
+    for query in [
+        "get a fields id union del b",
+        "get a fields id union get b fields id, name",
+        "get a fields id union",
+        "del a where id = 1 union get b fields id",
+    ] {
+        assert!(parse(query).is_err(), "bad set operation parsed: {query}");
+    }
+}
+
+#[test]
+fn test_parse_error_reports_byte_offset() {
+    let err = parse("get users[active = true").unwrap_err();
+    assert_eq!(err.position(), Some(9), "{err}");
+
+    let err = parse("  get users fields id limit x").unwrap_err();
+    assert_eq!(err.position(), Some(22), "{err}");
+}
+
+#[test]
+fn test_v2_in_list_literals_in_bracket_filter() {
+    let cmd = parse("get users[status in ('active', 'pending', 'trialing')] fields id").unwrap();
+    let cond = &cmd.cages[0].conditions[0];
+    assert_eq!(cond.op, Operator::In);
+    assert_eq!(
+        cond.value,
+        Value::Array(vec![
+            Value::String("active".to_string()),
+            Value::String("pending".to_string()),
+            Value::String("trialing".to_string()),
+        ])
+    );
+
+    let cmd = parse("get users[id not in (1,2,3)]").unwrap();
+    let cond = &cmd.cages[0].conditions[0];
+    assert_eq!(cond.op, Operator::NotIn);
+    assert_eq!(
+        cond.value,
+        Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+    );
+
+    let cmd = parse("get users fields id where score in (1, 2.5, $1, null)").unwrap();
+    assert!(matches!(&cmd.cages[0].conditions[0].value, Value::Array(v) if v.len() == 4));
+
+    assert!(parse("get users[id in ()]").is_err());
+}
+
+#[test]
+fn test_v2_in_list_rejects_mixed_literal_types() {
+    for query in [
+        "get users fields id where id in (1, 'two')",
+        "get users[status in ('active', true)]",
+        "get users fields count(id) filter (where id not in ('a', 2))",
+    ] {
+        assert!(parse(query).is_err(), "mixed IN list parsed: {query}");
+    }
+}
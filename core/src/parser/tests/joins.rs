@@ -45,3 +45,74 @@ fn test_join_rejects_malformed_identifiers() {
         );
     }
 }
+
+#[test]
+fn test_join_composite_on_conditions() {
+    use crate::transpiler::ToSql;
+
+    for query in [
+        "get users inner join orders on users.id = orders.user_id and users.tenant = orders.tenant fields id",
+        "get users inner join orders on (users.id = orders.user_id and users.tenant = orders.tenant) fields id",
+    ] {
+        let cmd = parse(query).unwrap();
+        let on = cmd.joins[0].on.as_ref().unwrap();
+        assert_eq!(on.len(), 2, "{query}");
+        assert_eq!(on[1].left, Expr::Named("users.tenant".to_string()));
+        assert_eq!(on[1].value, Value::Column("orders.tenant".to_string()));
+        assert_eq!(
+            cmd.to_sql(),
+            "SELECT id FROM users INNER JOIN orders ON users.id = orders.user_id AND users.tenant = orders.tenant"
+        );
+    }
+}
+
+#[test]
+fn test_join_composite_on_round_trips_through_formatter() {
+    let cmd = parse(
+        "get users left join orders on users.id = orders.user_id and users.tenant = orders.tenant",
+    )
+    .unwrap();
+    let formatted = crate::fmt::Formatter::new().format(&cmd).unwrap();
+    assert_eq!(parse(&formatted).unwrap().joins, cmd.joins);
+}
+
+#[test]
+fn test_join_lateral_subquery() {
+    use crate::transpiler::ToSql;
+
+    let cmd = parse(
+        "get users left join lateral \
+         (get orders fields total where user_id = users.id order by created_at desc limit 3) \
+         as recent on true fields users.name, recent.total",
+    )
+    .unwrap();
+    let join = &cmd.joins[0];
+    assert_eq!(join.kind, JoinKind::Lateral);
+    assert_eq!(join.table, "recent");
+    assert!(join.on_true);
+    assert_eq!(join.subquery.as_ref().unwrap().table, "orders");
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT users.name, recent.total FROM users LEFT JOIN LATERAL \
+         (SELECT total FROM orders WHERE user_id = users.id ORDER BY created_at DESC LIMIT 3) \
+         recent ON TRUE"
+    );
+
+    let inner = parse("get users inner join lateral (get orders limit 1) last on true").unwrap();
+    assert_eq!(inner.joins[0].kind, JoinKind::LateralInner);
+    assert_eq!(inner.joins[0].table, "last");
+
+    assert!(parse("get users right join lateral (get orders) o on true").is_err());
+}
+
+#[test]
+fn test_join_lateral_round_trips_through_formatter() {
+    let cmd = parse(
+        "get users left join lateral \
+         (get orders fields total where user_id = users.id limit 3) as recent on true",
+    )
+    .unwrap();
+    let formatted = crate::fmt::Formatter::new().format(&cmd).unwrap();
+    assert!(formatted.contains("left join lateral ("), "{formatted}");
+    assert_eq!(parse(&formatted.replace('\n', " ")).unwrap(), cmd);
+}
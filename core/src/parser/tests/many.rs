@@ -0,0 +1,49 @@
+use crate::ast::*;
+use crate::error::QailError;
+use crate::parser::parse_many;
+
+#[test]
+fn test_parse_many_three_statements() {
+    let cmds = parse_many(
+        "get users fields id; \
+         set users values active = false where id = 1;\n\
+         del sessions where expired = true",
+    )
+    .unwrap();
+    assert_eq!(cmds.len(), 3);
+    assert_eq!(cmds[0].action, Action::Get);
+    assert_eq!(cmds[1].action, Action::Set);
+    assert_eq!(cmds[2].action, Action::Del);
+    assert_eq!(cmds[2].table, "sessions");
+}
+
+#[test]
+fn test_parse_many_trailing_separator_and_comments() {
+    let cmds = parse_many("get users;\nget orders;\n-- done\n").unwrap();
+    assert_eq!(cmds.len(), 2);
+    assert_eq!(cmds[1].table, "orders");
+    assert!(parse_many(" ; ;\n").unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_many_separator_inside_literal_does_not_split() {
+    let cmds = parse_many("get notes where body = 'a;b' -- x;y\n; get users").unwrap();
+    assert_eq!(cmds.len(), 2);
+    assert_eq!(
+        cmds[0].cages[0].conditions[0].value,
+        Value::String("a;b".to_string())
+    );
+}
+
+#[test]
+fn test_parse_many_reports_failing_statement() {
+    let input = "get users; get orders where; get items";
+    let err = parse_many(input).unwrap_err();
+    match err {
+        QailError::Parse { position, message } => {
+            assert!(message.starts_with("statement 2:"), "{message}");
+            assert!(position >= input.find("get orders").unwrap());
+        }
+        other => panic!("expected parse error, got {other:?}"),
+    }
+}
@@ -6,6 +6,7 @@ mod export;
 mod get;
 mod index;
 mod joins;
+mod many;
 mod merge;
 mod tokens;
 mod txn;
@@ -61,6 +61,19 @@ fn check_ident(field: &str, value: &str) -> Result<(), SanitizeError> {
     }
 }
 
+/// Validate a collation name. A quoted part such as `"en_US.utf8"` is one
+/// name, so its dotted pieces are checked like a qualified identifier.
+fn check_collation(field: &str, value: &str) -> Result<(), SanitizeError> {
+    match crate::transpiler::split_collation_name(value) {
+        Some(parts) if parts.iter().all(|part| is_safe_identifier(part)) => Ok(()),
+        _ => Err(SanitizeError {
+            field: field.to_string(),
+            value: value.chars().take(40).collect(),
+            reason: "collation parts must match [a-zA-Z0-9_] and be ≤63 chars".to_string(),
+        }),
+    }
+}
+
 fn check_named_param(field: &str, value: &str) -> Result<(), SanitizeError> {
     let mut chars = value.chars();
     let Some(first) = chars.next() else {
@@ -418,7 +431,7 @@ fn check_expr(field: &str, expr: &Expr) -> Result<(), SanitizeError> {
             alias,
         } => {
             check_expr(&format!("{field}.collate_expr"), expr)?;
-            check_ident(&format!("{field}.collation"), collation)?;
+            check_collation(&format!("{field}.collation"), collation)?;
             if let Some(a) = alias {
                 check_ident(&format!("{field}.alias"), a)?;
             }
@@ -584,6 +597,10 @@ pub fn validate_ast(cmd: &Qail) -> Result<(), SanitizeError> {
         for col in &oc.columns {
             check_ident("on_conflict.column", col)?;
         }
+        for cond in &oc.predicate {
+            check_expr("on_conflict.predicate", &cond.left)?;
+            check_value("on_conflict.predicate", &cond.value)?;
+        }
         if let ConflictAction::DoUpdate { assignments } = &oc.action {
             for (col, expr) in assignments {
                 check_ident("on_conflict.assignment.column", col)?;
@@ -678,12 +695,14 @@ pub fn validate_ast(cmd: &Qail) -> Result<(), SanitizeError> {
             };
             check_ident("channel", name)?;
 
-            if matches!(cmd.action, Action::AlterAddConstraint) {
+            if matches!(cmd.action, Action::AlterAddConstraint) && cmd.table_constraints.is_empty()
+            {
                 let Some(ref expr) = cmd.payload else {
                     return Err(SanitizeError {
                         field: "payload".to_string(),
                         value: String::new(),
-                        reason: "add constraint requires a check expression".to_string(),
+                        reason: "add constraint requires a check expression or table constraint"
+                            .to_string(),
                     });
                 };
                 check_sql_expr_fragment("payload", expr)?;
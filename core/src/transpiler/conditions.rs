@@ -1,7 +1,9 @@
 use super::ToSql;
 use super::traits::{SqlGenerator, escape_sql_string_literal};
 use crate::ast::*;
-use crate::transpiler::identifier::{qualifier_for_column_path, qualifier_for_column_reference};
+use crate::transpiler::identifier::{
+    qualifier_for_column_path, qualifier_for_column_reference, render_collation,
+};
 
 /// Context for parameterized query building.
 #[derive(Debug, Default)]
@@ -27,7 +29,7 @@ impl ParamContext {
     /// Add a value and return the placeholder for it.
     pub fn add_param(&mut self, value: Value, generator: &dyn SqlGenerator) -> String {
         self.index += 1;
-        self.params.push(value);
+        self.params.push(generator.bind_value(value));
         generator.placeholder(self.index)
     }
 
@@ -159,6 +161,22 @@ fn resolve_text_search_vector(
     }
 }
 
+/// Text searched by the wildcard fallback for dialects without full-text
+/// search: the same coalesced columns, parenthesized when several are
+/// concatenated so the match applies to the whole string.
+fn text_search_fallback_operand(
+    expr: &Expr,
+    generator: &dyn SqlGenerator,
+    context: Option<&Qail>,
+    col: &str,
+) -> String {
+    match resolve_text_search_vector(expr, generator, context) {
+        Some(text) if text.contains(" || ") => format!("({})", text),
+        Some(text) => text,
+        None => col.to_string(),
+    }
+}
+
 fn condition_left_sql(expr: &Expr, generator: &dyn SqlGenerator, context: Option<&Qail>) -> String {
     match expr {
         Expr::Named(name) => {
@@ -176,6 +194,36 @@ fn condition_left_sql(expr: &Expr, generator: &dyn SqlGenerator, context: Option
             ..
         } => render_json_access(column, path_segments, generator),
         Expr::Literal(value) => condition_value_sql_with_context(value, generator, context),
+        Expr::Aggregate {
+            col,
+            func,
+            distinct,
+            filter,
+            ..
+        } => {
+            // Aggregate left-hand sides appear in HAVING: SUM(total) > 1000
+            let col_expr = if col == "*" {
+                "*".to_string()
+            } else {
+                condition_left_sql(&Expr::Named(col.clone()), generator, context)
+            };
+            let mut sql = if *distinct {
+                format!("{func}(DISTINCT {col_expr})")
+            } else {
+                format!("{func}({col_expr})")
+            };
+            if let Some(conditions) = filter
+                && !conditions.is_empty()
+            {
+                let filter_parts = conditions
+                    .iter()
+                    .map(|condition| condition.to_sql(generator, context))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                sql.push_str(&format!(" FILTER (WHERE {filter_parts})"));
+            }
+            sql
+        }
         Expr::Case {
             when_clauses,
             else_value,
@@ -206,6 +254,7 @@ fn condition_left_sql(expr: &Expr, generator: &dyn SqlGenerator, context: Option
             match op {
                 BinaryOp::IsNull => format!("({left} IS NULL)"),
                 BinaryOp::IsNotNull => format!("({left} IS NOT NULL)"),
+                BinaryOp::AtTimeZone => generator.at_time_zone(&left, &right),
                 _ => format!("({left} {op} {right})"),
             }
         }
@@ -255,7 +304,7 @@ fn condition_left_sql(expr: &Expr, generator: &dyn SqlGenerator, context: Option
         } => format!(
             "{} COLLATE {}",
             condition_left_sql(expr, generator, context),
-            render_qualified_identifier(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
@@ -341,6 +390,11 @@ pub(crate) fn validate_read_only_subquery(query: &Qail) -> Option<String> {
                 }
             }
         }
+        if let Some(subquery) = &join.subquery
+            && let Some(error) = validate_read_only_subquery(subquery)
+        {
+            return Some(error);
+        }
     }
     for cte in &query.ctes {
         if let Some(error) = validate_read_only_subquery(&cte.base_query) {
@@ -623,6 +677,18 @@ fn in_condition_sql(
     }
 }
 
+/// Operators whose right-hand JSON operand must be typed as `jsonb`.
+fn is_jsonb_operator(op: Operator) -> bool {
+    matches!(
+        op,
+        Operator::Contains
+            | Operator::ContainedBy
+            | Operator::KeyExists
+            | Operator::KeyExistsAny
+            | Operator::KeyExistsAll
+    )
+}
+
 fn invalid_exists_condition_sql() -> String {
     "FALSE /* ERROR: EXISTS condition requires subquery value */".to_string()
 }
@@ -681,6 +747,18 @@ impl ConditionToSql for Condition {
             );
         }
 
+        if let Some(op) = self.null_test() {
+            return format!("{} {}", col, op.sql_symbol());
+        }
+
+        // Raw conditions ({...}, op=Eq, value=Null) render as their content.
+        if self.op == Operator::Eq
+            && matches!(self.value, Value::Null)
+            && matches!(&self.left, Expr::Named(name) if name.starts_with('{') && name.ends_with('}'))
+        {
+            return col;
+        }
+
         // Normal conditions
         // Simple binary operators use sql_symbol() for unified handling
         if self.op.is_simple_binary() {
@@ -693,6 +771,18 @@ impl ConditionToSql for Condition {
                 let val = fuzzy_pattern_sql(&self.value, generator);
                 format!("{} {} {}", col, generator.fuzzy_operator(), val)
             }
+            Operator::Similar if generator.supports_trigram_similarity() => {
+                format!("{} % {}", col, value_sql())
+            }
+            Operator::Similar => {
+                let val = fuzzy_pattern_sql(&self.value, generator);
+                format!("{} {} {}", col, generator.fuzzy_operator(), val)
+            }
+            Operator::TextSearch if !generator.supports_full_text_search() => {
+                let text = text_search_fallback_operand(&self.left, generator, context, &col);
+                let val = fuzzy_pattern_sql(&self.value, generator);
+                format!("{} {} {}", text, generator.fuzzy_operator(), val)
+            }
             Operator::TextSearch => {
                 let vector = resolve_text_search_vector(&self.left, generator, context)
                     .unwrap_or_else(|| col.clone());
@@ -707,7 +797,27 @@ impl ConditionToSql for Condition {
             }
             Operator::IsNull => format!("{} IS NULL", col),
             Operator::IsNotNull => format!("{} IS NOT NULL", col),
-            Operator::Contains => generator.json_contains(&col, &value_sql()),
+            Operator::Contains => match self.value.as_json_document() {
+                Some(json) => generator.json_contains(
+                    &col,
+                    &condition_value_sql_with_context(&json, generator, context),
+                ),
+                None => generator.json_contains(&col, &value_sql()),
+            },
+            Operator::Overlaps => {
+                let value = match &self.value {
+                    Value::Array(values) => format!(
+                        "ARRAY[{}]",
+                        values
+                            .iter()
+                            .map(|v| condition_value_sql_with_context(v, generator, context))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    _ => value_sql(),
+                };
+                generator.array_overlap(&col, &value)
+            }
             Operator::KeyExists => generator.json_key_exists(&col, &value_sql()),
             // Postgres 17+ SQL/JSON standard functions
             Operator::JsonExists => {
@@ -795,6 +905,16 @@ impl ConditionToSql for Condition {
                 other => p.add_param(other.clone(), generator),
             }
         };
+        // JSON values bind untyped unless a jsonb operator needs the cast, so
+        // comparisons against `json` columns keep working.
+        let operand_placeholder = |v: &Value, p: &mut ParamContext| -> String {
+            let placeholder = value_placeholder(v, p);
+            if is_jsonb_operator(self.op) && matches!(v, Value::Json(_)) {
+                generator.json_placeholder(&placeholder)
+            } else {
+                placeholder
+            }
+        };
 
         if self.is_array_unnest {
             let inner_condition = match self.op {
@@ -825,6 +945,10 @@ impl ConditionToSql for Condition {
             );
         }
 
+        if let Some(op) = self.null_test() {
+            return format!("{} {}", col, op.sql_symbol());
+        }
+
         match self.op {
             Operator::Eq => {
                 // Raw conditions ({...}, op=Eq, value=Null) are now handled at col resolution
@@ -843,6 +967,21 @@ impl ConditionToSql for Condition {
                 let pattern = generator.string_concat(&["'%'", &placeholder, "'%'"]);
                 format!("{} {} {}", col, generator.fuzzy_operator(), pattern)
             }
+            Operator::Similar => {
+                let placeholder = value_placeholder(&self.value, params);
+                if generator.supports_trigram_similarity() {
+                    format!("{} % {}", col, placeholder)
+                } else {
+                    let pattern = generator.string_concat(&["'%'", &placeholder, "'%'"]);
+                    format!("{} {} {}", col, generator.fuzzy_operator(), pattern)
+                }
+            }
+            Operator::TextSearch if !generator.supports_full_text_search() => {
+                let text = text_search_fallback_operand(&self.left, generator, context, &col);
+                let placeholder = value_placeholder(&self.value, params);
+                let pattern = generator.string_concat(&["'%'", &placeholder, "'%'"]);
+                format!("{} {} {}", text, generator.fuzzy_operator(), pattern)
+            }
             Operator::TextSearch => {
                 let vector = resolve_text_search_vector(&self.left, generator, context)
                     .unwrap_or_else(|| col.clone());
@@ -880,10 +1019,15 @@ impl ConditionToSql for Condition {
                 _ => invalid_in_condition_sql(),
             },
             Operator::Contains => {
-                generator.json_contains(&col, &value_placeholder(&self.value, params))
+                let value = self.value.as_json_document();
+                let value = value.as_ref().unwrap_or(&self.value);
+                generator.json_contains(&col, &operand_placeholder(value, params))
+            }
+            Operator::Overlaps => {
+                generator.array_overlap(&col, &value_placeholder(&self.value, params))
             }
             Operator::KeyExists => {
-                generator.json_key_exists(&col, &value_placeholder(&self.value, params))
+                generator.json_key_exists(&col, &operand_placeholder(&self.value, params))
             }
             Operator::JsonExists => {
                 let path = value_placeholder(&self.value, params);
@@ -938,7 +1082,7 @@ impl ConditionToSql for Condition {
                 "{} {} {}",
                 col,
                 self.op.sql_symbol(),
-                value_placeholder(&self.value, params)
+                operand_placeholder(&self.value, params)
             ),
         }
     }
@@ -639,8 +639,18 @@ pub fn build_alter_table(cmd: &Qail, dialect: Dialect) -> String {
 
     for col in &cmd.columns {
         match col {
-            Expr::Mod { kind, col } => match kind {
-                ModKind::Add => {
+            Expr::Mod { kind, col } => {
+                let (add, guard) = match kind {
+                    ModKind::Add => (true, ""),
+                    ModKind::Drop => (false, ""),
+                    ModKind::AddIfNotExists => (true, "IF NOT EXISTS "),
+                    ModKind::DropIfExists => (false, "IF EXISTS "),
+                };
+                if !guard.is_empty() && !generator.supports_alter_column_if_exists() {
+                    return "/* ERROR: ALTER TABLE column IF [NOT] EXISTS is not supported by this dialect */"
+                        .to_string();
+                }
+                if add {
                     if let Expr::Def {
                         name,
                         data_type,
@@ -649,8 +659,9 @@ pub fn build_alter_table(cmd: &Qail, dialect: Dialect) -> String {
                     {
                         let sql_type = data_type_to_sql(data_type);
                         let mut line = format!(
-                            "ALTER TABLE {} ADD COLUMN {} {}",
+                            "ALTER TABLE {} ADD COLUMN {}{} {}",
                             table_name,
+                            guard,
                             generator.quote_identifier(name),
                             sql_type
                         );
@@ -665,17 +676,15 @@ pub fn build_alter_table(cmd: &Qail, dialect: Dialect) -> String {
                         }
                         stmts.push(line);
                     }
+                } else if let Expr::Named(name) = col.as_ref() {
+                    stmts.push(format!(
+                        "ALTER TABLE {} DROP COLUMN {}{}",
+                        table_name,
+                        guard,
+                        generator.quote_identifier(name)
+                    ));
                 }
-                ModKind::Drop => {
-                    if let Expr::Named(name) = col.as_ref() {
-                        stmts.push(format!(
-                            "ALTER TABLE {} DROP COLUMN {}",
-                            table_name,
-                            generator.quote_identifier(name)
-                        ));
-                    }
-                }
-            },
+            }
             Expr::Named(rename_expr) if rename_expr.contains(" -> ") => {
                 let parts: Vec<&str> = rename_expr.split(" -> ").collect();
                 if parts.len() == 2 {
@@ -1022,6 +1031,50 @@ pub fn build_alter_add_check_constraint(cmd: &Qail, dialect: Dialect) -> String
     )
 }
 
+/// Generate ALTER TABLE ADD CONSTRAINT SQL.
+///
+/// A single `table_constraints` entry (UNIQUE, PRIMARY KEY or FOREIGN KEY)
+/// is added under the name in `channel`; without one, `payload` is taken as
+/// a CHECK expression.
+pub fn build_alter_add_constraint(cmd: &Qail, dialect: Dialect) -> String {
+    let constraint = match cmd.table_constraints.as_slice() {
+        [] => return build_alter_add_check_constraint(cmd, dialect),
+        [constraint] if cmd.payload.is_none() => constraint,
+        [_] => {
+            return "/* ERROR: ALTER ADD CONSTRAINT takes either a check expression or a table constraint */"
+                .to_string();
+        }
+        _ => return "/* ERROR: ALTER ADD CONSTRAINT takes a single constraint */".to_string(),
+    };
+    let generator = dialect.generator();
+    let Some(name) = cmd
+        .channel
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    else {
+        return "/* ERROR: ALTER ADD CONSTRAINT requires a constraint name */".to_string();
+    };
+    if matches!(
+        constraint,
+        TableConstraint::ForeignKey { name: Some(_), .. }
+    ) {
+        return "/* ERROR: ALTER ADD CONSTRAINT names the constraint itself; leave the foreign key unnamed */"
+            .to_string();
+    }
+    let body = match table_constraint_to_sql(constraint, generator.as_ref()) {
+        Ok(body) => body,
+        Err(err) => return err,
+    };
+
+    format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} {}",
+        generator.quote_identifier(&cmd.table),
+        generator.quote_identifier(name),
+        body
+    )
+}
+
 /// Generate ALTER TABLE DROP CONSTRAINT SQL.
 pub fn build_alter_drop_constraint(cmd: &Qail, dialect: Dialect) -> String {
     let generator = dialect.generator();
@@ -6,7 +6,21 @@ use crate::transpiler::dialect::Dialect;
 use crate::transpiler::identifier::render_table_reference;
 
 /// Generate DELETE FROM SQL with optional USING and WHERE clauses.
+///
+/// Inner joins become USING tables; see [`Qail::fold_mutation_joins`].
 pub fn build_delete(cmd: &Qail, dialect: Dialect) -> String {
+    let folded;
+    let cmd = if cmd.joins.is_empty() {
+        cmd
+    } else {
+        match cmd.fold_mutation_joins() {
+            Ok(cmd) => {
+                folded = cmd;
+                &folded
+            }
+            Err(error) => return format!("/* ERROR: {} */", error),
+        }
+    };
     let generator = dialect.generator();
     let mut sql = if cmd.only_table {
         String::from("DELETE FROM ONLY ")
@@ -57,5 +71,7 @@ pub fn build_delete(cmd: &Qail, dialect: Dialect) -> String {
         sql.push_str(&where_groups.join(" AND "));
     }
 
+    sql.push_str(&super::render_dml_limit(cmd, dialect, "DELETE"));
+
     sql
 }
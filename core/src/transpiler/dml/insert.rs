@@ -4,6 +4,7 @@ use crate::ast::*;
 use crate::transpiler::SqlGenerator;
 use crate::transpiler::conditions::ConditionToSql;
 use crate::transpiler::dialect::Dialect;
+use crate::transpiler::identifier::render_collation;
 
 /// Generate INSERT INTO SQL with VALUES, ON CONFLICT, and RETURNING clauses.
 pub fn build_insert(cmd: &Qail, dialect: Dialect) -> String {
@@ -40,18 +41,34 @@ pub fn build_insert(cmd: &Qail, dialect: Dialect) -> String {
         use crate::transpiler::ToSql;
         sql.push(' ');
         sql.push_str(&source_query.to_sql_with_dialect(dialect));
-    } else if let Some(cage) = cmd.cages.first() {
-        // Traditional INSERT with VALUES
-        let values: Vec<String> = cage
-            .conditions
+    } else {
+        // Traditional INSERT with VALUES, one row per Payload cage
+        let rows: Vec<&Cage> = cmd
+            .cages
             .iter()
-            .map(|c| c.to_value_sql(generator.as_ref()))
+            .filter(|cage| cage.kind == CageKind::Payload)
             .collect();
 
-        if !values.is_empty() {
-            sql.push_str(" VALUES (");
-            sql.push_str(&values.join(", "));
-            sql.push(')');
+        if rows.len() > 1
+            && rows
+                .iter()
+                .any(|row| row.conditions.len() != rows[0].conditions.len())
+        {
+            sql.push_str(" /* ERROR: multi-row INSERT rows must have the same number of values */");
+        } else if rows.first().is_some_and(|row| !row.conditions.is_empty()) {
+            let rows: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    let values: Vec<String> = row
+                        .conditions
+                        .iter()
+                        .map(|c| c.to_value_sql(generator.as_ref()))
+                        .collect();
+                    format!("({})", values.join(", "))
+                })
+                .collect();
+            sql.push_str(" VALUES ");
+            sql.push_str(&rows.join(", "));
         }
     }
 
@@ -90,7 +107,7 @@ fn build_on_conflict(
     build_on_conflict_postgres(on_conflict, generator)
 }
 
-/// PostgreSQL style: ON CONFLICT (cols) DO UPDATE SET ... or DO NOTHING
+/// PostgreSQL style: ON CONFLICT (cols) [WHERE ...] DO UPDATE SET ... or DO NOTHING
 fn build_on_conflict_postgres(on_conflict: &OnConflict, generator: &dyn SqlGenerator) -> String {
     let mut sql = String::from(" ON CONFLICT (");
     let cols: Vec<String> = on_conflict
@@ -101,6 +118,16 @@ fn build_on_conflict_postgres(on_conflict: &OnConflict, generator: &dyn SqlGener
     sql.push_str(&cols.join(", "));
     sql.push(')');
 
+    if !on_conflict.predicate.is_empty() {
+        let predicate: Vec<String> = on_conflict
+            .predicate
+            .iter()
+            .map(|c| c.to_sql(generator, None))
+            .collect();
+        sql.push_str(" WHERE ");
+        sql.push_str(&predicate.join(" AND "));
+    }
+
     match &on_conflict.action {
         ConflictAction::DoNothing => {
             sql.push_str(" DO NOTHING");
@@ -141,6 +168,10 @@ fn render_sql_expr(expr: &Expr, generator: &dyn SqlGenerator) -> String {
         } => match op {
             BinaryOp::IsNull => format!("({} IS NULL)", render_sql_expr(left, generator)),
             BinaryOp::IsNotNull => format!("({} IS NOT NULL)", render_sql_expr(left, generator)),
+            BinaryOp::AtTimeZone => generator.at_time_zone(
+                &render_sql_expr(left, generator),
+                &render_sql_expr(right, generator),
+            ),
             _ => format!(
                 "({} {} {})",
                 render_sql_expr(left, generator),
@@ -177,7 +208,7 @@ fn render_sql_expr(expr: &Expr, generator: &dyn SqlGenerator) -> String {
         } => format!(
             "{} COLLATE {}",
             render_sql_expr(expr, generator),
-            render_qualified_identifier(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
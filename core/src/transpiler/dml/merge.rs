@@ -7,7 +7,7 @@ use crate::transpiler::conditions::{
     ConditionToSql, read_only_subquery_sql, resolve_known_col_syntax, validate_read_only_subquery,
 };
 use crate::transpiler::dialect::Dialect;
-use crate::transpiler::identifier::render_table_reference;
+use crate::transpiler::identifier::{render_collation, render_table_reference};
 use crate::transpiler::traits::escape_sql_string_literal;
 use crate::transpiler::{SqlGenerator, ToSql};
 use std::collections::HashSet;
@@ -185,11 +185,15 @@ fn condition_sql(condition: &Condition, generator: &dyn SqlGenerator, context: &
     }
 
     let left = expr_sql(&condition.left, generator, context);
-    match condition.op {
+    match condition.null_test().unwrap_or(condition.op) {
         Operator::Fuzzy => {
             let value = fuzzy_pattern_sql(&condition.value, generator, context);
             format!("{left} {} {value}", generator.fuzzy_operator())
         }
+        Operator::Similar if !generator.supports_trigram_similarity() => {
+            let value = fuzzy_pattern_sql(&condition.value, generator, context);
+            format!("{left} {} {value}", generator.fuzzy_operator())
+        }
         Operator::IsNull => format!("{left} IS NULL"),
         Operator::IsNotNull => format!("{left} IS NOT NULL"),
         Operator::In | Operator::NotIn => in_condition_sql(condition, &left, generator, context),
@@ -438,6 +442,10 @@ fn expr_sql(expr: &Expr, generator: &dyn SqlGenerator, context: &Qail) -> String
             crate::ast::BinaryOp::IsNotNull => {
                 format!("({} IS NOT NULL)", expr_sql(left, generator, context))
             }
+            crate::ast::BinaryOp::AtTimeZone => generator.at_time_zone(
+                &expr_sql(left, generator, context),
+                &expr_sql(right, generator, context),
+            ),
             _ => format!(
                 "({} {} {})",
                 expr_sql(left, generator, context),
@@ -508,7 +516,7 @@ fn expr_sql(expr: &Expr, generator: &dyn SqlGenerator, context: &Qail) -> String
         } => format!(
             "{} COLLATE {}",
             expr_sql(expr, generator, context),
-            render_identifier_or_error(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
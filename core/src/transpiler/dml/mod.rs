@@ -11,4 +11,29 @@ pub mod merge;
 pub mod select;
 pub mod update;
 pub mod upsert;
+pub mod values;
 pub mod window;
+
+use crate::ast::{CageKind, Qail};
+use crate::transpiler::dialect::Dialect;
+
+/// Render a `LIMIT` cage attached to a DELETE or UPDATE.
+///
+/// SQLite accepts `DELETE ... LIMIT n` / `UPDATE ... LIMIT n`. PostgreSQL has
+/// no row limit on DML, so an error comment is rendered instead of silently
+/// touching every matching row.
+pub(crate) fn render_dml_limit(cmd: &Qail, dialect: Dialect, verb: &str) -> String {
+    let Some(limit) = cmd.cages.iter().rev().find_map(|cage| match cage.kind {
+        CageKind::Limit(n) => Some(n),
+        _ => None,
+    }) else {
+        return String::new();
+    };
+
+    match dialect {
+        Dialect::SQLite => format!(" LIMIT {limit}"),
+        Dialect::Postgres => {
+            format!(" /* ERROR: PostgreSQL does not support LIMIT on {verb} */")
+        }
+    }
+}
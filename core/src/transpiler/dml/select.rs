@@ -1,12 +1,13 @@
 //! SELECT SQL generation.
 
 use crate::ast::*;
+use crate::transpiler::ToSql;
 use crate::transpiler::conditions::{
-    ConditionToSql, read_only_subquery_sql, resolve_known_col_syntax,
+    ConditionToSql, read_only_subquery_sql, resolve_known_col_syntax, validate_read_only_subquery,
 };
 use crate::transpiler::dialect::Dialect;
 use crate::transpiler::identifier::{
-    render_table_reference, table_reference_base, table_reference_sql_qualifier,
+    render_collation, render_table_reference, table_reference_base, table_reference_sql_qualifier,
 };
 use crate::transpiler::traits::{SqlGenerator, escape_sql_string_literal};
 
@@ -263,7 +264,7 @@ fn build_select_inner(
                         alias,
                     } => {
                         let expr = render_expr_for_orderby(expr, generator.as_ref(), cmd);
-                        let collation = render_qualified_identifier(collation, generator.as_ref());
+                        let collation = render_collation(collation, generator.as_ref());
                         let collate_expr = format!("{expr} COLLATE {collation}");
                         if let Some(a) = alias {
                             format!("{} AS {}", collate_expr, generator.quote_identifier(a))
@@ -395,19 +396,33 @@ fn build_select_inner(
                         if let Some(fr) = frame {
                             over_clause.push(' ');
                             match fr {
-                                WindowFrame::Rows { start, end } => {
+                                WindowFrame::Rows {
+                                    start,
+                                    end,
+                                    exclude,
+                                } => {
                                     over_clause.push_str(&format!(
                                         "ROWS BETWEEN {} AND {}",
                                         bound_to_sql(start),
                                         bound_to_sql(end)
                                     ));
+                                    if let Some(exclude) = exclude {
+                                        over_clause.push_str(&format!(" {}", exclude));
+                                    }
                                 }
-                                WindowFrame::Range { start, end } => {
+                                WindowFrame::Range {
+                                    start,
+                                    end,
+                                    exclude,
+                                } => {
                                     over_clause.push_str(&format!(
                                         "RANGE BETWEEN {} AND {}",
                                         bound_to_sql(start),
                                         bound_to_sql(end)
                                     ));
+                                    if let Some(exclude) = exclude {
+                                        over_clause.push_str(&format!(" {}", exclude));
+                                    }
                                 }
                             }
                         }
@@ -436,7 +451,27 @@ fn build_select_inner(
     } else {
         sql.push_str(" FROM ");
     }
-    sql.push_str(&render_table_reference(&cmd.table, generator.as_ref()));
+    if let Some(source) = &cmd.source_query {
+        // Derived table: FROM (<subquery>) alias
+        match validate_read_only_subquery(source) {
+            Some(error) => sql.push_str(&format!("/* ERROR: {error} */")),
+            None => sql.push_str(&format!(
+                "({}) {}",
+                source.to_sql_with_dialect(dialect),
+                generator.quote_identifier(&cmd.table)
+            )),
+        }
+    } else if let Some(function) = &cmd.table_function {
+        sql.push_str(&render_table_function(
+            function,
+            false,
+            dialect,
+            generator.as_ref(),
+            cmd,
+        ));
+    } else {
+        sql.push_str(&render_table_reference(&cmd.table, generator.as_ref()));
+    }
 
     // TABLESAMPLE
     let sample = cmd.sample.or_else(|| {
@@ -457,16 +492,35 @@ fn build_select_inner(
         }
     }
 
+    // Extra set-returning sources: FROM t, UNNEST(tags) AS tag
+    for (function, with_ordinality) in &cmd.from_functions {
+        sql.push_str(", ");
+        sql.push_str(&render_table_function(
+            function,
+            *with_ordinality,
+            dialect,
+            generator.as_ref(),
+            cmd,
+        ));
+    }
+
     // JOINS
-    for join in &cmd.joins {
+    for (idx, join) in cmd.joins.iter().enumerate() {
         let (kind, needs_on) = match join.kind {
-            JoinKind::Inner => ("INNER", true),
-            JoinKind::Left => ("LEFT", true),
+            JoinKind::Inner | JoinKind::LateralInner => ("INNER", true),
+            JoinKind::Left | JoinKind::Lateral => ("LEFT", true),
             JoinKind::Right => ("RIGHT", true),
-            JoinKind::Lateral => ("LATERAL", true),
             JoinKind::Full => ("FULL OUTER", true),
             JoinKind::Cross => ("CROSS", false),
         };
+        let lateral = matches!(join.kind, JoinKind::Lateral | JoinKind::LateralInner);
+        if lateral && dialect != Dialect::Postgres {
+            sql.push_str(&format!(
+                " {} JOIN /* ERROR: LATERAL joins require PostgreSQL */",
+                kind
+            ));
+            continue;
+        }
         // Join: target.source_singular_id = source.id
         let source_base = table_reference_base(&cmd.table);
         let source_singular = source_base
@@ -475,7 +529,27 @@ fn build_select_inner(
             .unwrap_or(source_base)
             .trim_end_matches('s');
 
-        let target_table = render_table_reference(&join.table, generator.as_ref());
+        let mut target_table = match &join.subquery {
+            // Derived table: JOIN (<subquery>) alias
+            Some(subquery) => match validate_read_only_subquery(subquery) {
+                Some(error) => format!("/* ERROR: {error} */"),
+                None if lateral => format!(
+                    "({}) {}",
+                    correlate_outer_columns(subquery, cmd, generator.as_ref())
+                        .to_sql_with_dialect(dialect),
+                    generator.quote_identifier(&join.table)
+                ),
+                None => format!(
+                    "({}) {}",
+                    subquery.to_sql_with_dialect(dialect),
+                    generator.quote_identifier(&join.table)
+                ),
+            },
+            None => render_table_reference(&join.table, generator.as_ref()),
+        };
+        if lateral {
+            target_table.insert_str(0, "LATERAL ");
+        }
         let target_qualifier = table_reference_sql_qualifier(&join.table)
             .map(|qualifier| generator.quote_identifier(qualifier))
             .unwrap_or_else(|| generator.quote_identifier(&join.table));
@@ -484,7 +558,55 @@ fn build_select_inner(
             .map(|qualifier| generator.quote_identifier(qualifier))
             .unwrap_or_else(|| generator.quote_identifier(&cmd.table));
 
-        if let Some(on_conds) = &join.on {
+        if !join.using.is_empty() {
+            let cols: Vec<String> = join
+                .using
+                .iter()
+                .map(|c| generator.quote_identifier(c))
+                .collect();
+            if join.on.as_ref().is_some_and(|conds| !conds.is_empty()) {
+                // USING cannot be combined with ON, and rewriting it into
+                // equalities would change which side each column comes from.
+                sql.push_str(&format!(
+                    " {} JOIN {} /* ERROR: join on '{}' combines USING with ON conditions */",
+                    kind, target_table, join.table
+                ));
+            } else if generator.supports_join_using() {
+                sql.push_str(&format!(
+                    " {} JOIN {} USING ({})",
+                    kind,
+                    target_table,
+                    cols.join(", ")
+                ));
+            } else if idx == 0 {
+                let on_sql: Vec<String> = cols
+                    .iter()
+                    .map(|col| format!("{}.{} = {}.{}", source_table, col, target_qualifier, col))
+                    .collect();
+                sql.push_str(&format!(
+                    " {} JOIN {} ON {}",
+                    kind,
+                    target_table,
+                    on_sql.join(" AND ")
+                ));
+            } else {
+                // After another join the left side of USING is the merged
+                // result, which has no single table to qualify against.
+                sql.push_str(&format!(
+                    " {} JOIN {} /* ERROR: USING join on '{}' after another join cannot be expanded to ON */",
+                    kind, target_table, join.table
+                ));
+            }
+        } else if needs_on
+            && !join.on_true
+            && join.on.as_ref().is_some_and(|conds| conds.is_empty())
+        {
+            let err = crate::error::QailError::MissingJoinCondition(join.table.clone());
+            sql.push_str(&format!(
+                " {} JOIN {} /* ERROR: {} */",
+                kind, target_table, err
+            ));
+        } else if let Some(on_conds) = &join.on {
             let on_sql: Vec<String> = on_conds
                 .iter()
                 .map(|c| c.to_sql(generator.as_ref(), Some(cmd)))
@@ -515,7 +637,14 @@ fn build_select_inner(
     // Prepare for GROUP BY check
     let has_aggregates = columns.iter().any(|c| matches!(c, Expr::Aggregate { .. }));
     let mut non_aggregated_cols = Vec::new();
-    if has_aggregates {
+    // Explicit GROUP BY (Partition cage) takes precedence over auto-extraction
+    if let Some(cage) = cmd.cages.iter().find(|c| c.kind == CageKind::Partition) {
+        non_aggregated_cols.extend(
+            cage.conditions
+                .iter()
+                .map(|cond| render_expr_for_orderby(&cond.left, generator.as_ref(), cmd)),
+        );
+    } else if has_aggregates {
         for col in columns {
             match col {
                 Expr::Named(name) => {
@@ -600,7 +729,7 @@ fn build_select_inner(
                 // Will be processed separately after ORDER BY for QUALIFY clause
             }
             CageKind::Partition => {
-                // Handled in window function OVER clause
+                // Handled in GROUP BY below
             }
         }
     }
@@ -684,6 +813,14 @@ fn build_select_inner(
 
     // FOR UPDATE/SHARE (row locking)
     if let Some(lock) = &cmd.lock_mode {
+        if dialect == Dialect::SQLite {
+            sql.push_str(" /* ERROR: SQLite does not support row locking clauses */");
+            return sql;
+        }
+        if cmd.skip_locked && cmd.nowait {
+            sql.push_str(" /* ERROR: SKIP LOCKED and NOWAIT are mutually exclusive */");
+            return sql;
+        }
         match lock {
             LockMode::Update => sql.push_str(" FOR UPDATE"),
             LockMode::NoKeyUpdate => sql.push_str(" FOR NO KEY UPDATE"),
@@ -693,13 +830,20 @@ fn build_select_inner(
         if cmd.skip_locked {
             sql.push_str(" SKIP LOCKED");
         }
+        if cmd.nowait {
+            sql.push_str(" NOWAIT");
+        }
     }
 
     sql
 }
 
 pub(super) fn build_set_operand(cmd: &Qail, dialect: Dialect) -> String {
-    let sql = build_select(cmd, dialect);
+    let sql = match cmd.action {
+        Action::Table => super::values::build_table(cmd, dialect),
+        Action::Values => super::values::build_values(cmd, dialect),
+        _ => build_select(cmd, dialect),
+    };
     if set_operand_needs_wrapper(cmd) {
         wrap_set_operand_sql(sql, dialect)
     } else {
@@ -823,6 +967,7 @@ fn render_expr_for_orderby(
             match op {
                 BinaryOp::IsNull => format!("({} IS NULL)", left_sql),
                 BinaryOp::IsNotNull => format!("({} IS NOT NULL)", left_sql),
+                BinaryOp::AtTimeZone => generator.at_time_zone(&left_sql, &right_sql),
                 _ => format!("({} {} {})", left_sql, op, right_sql),
             }
         }
@@ -876,7 +1021,7 @@ fn render_expr_for_orderby(
         } => format!(
             "{} COLLATE {}",
             render_expr_for_orderby(expr, generator, cmd),
-            render_qualified_identifier(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
@@ -982,6 +1127,37 @@ fn render_value_for_expression(
     }
 }
 
+/// Render a set-returning function FROM source: `GENERATE_SERIES(1, 10) AS n`.
+fn render_table_function(
+    function: &Expr,
+    with_ordinality: bool,
+    dialect: Dialect,
+    generator: &dyn SqlGenerator,
+    cmd: &Qail,
+) -> String {
+    if dialect != Dialect::Postgres {
+        return "/* ERROR: table-function sources require PostgreSQL */".to_string();
+    }
+    let Expr::FunctionCall { name, args, alias } = function else {
+        return "/* ERROR: table-function source must be a function call */".to_string();
+    };
+    let Some(function) = render_function_name(name) else {
+        return "/* ERROR: Invalid function name */".to_string();
+    };
+    let args_sql: Vec<String> = args
+        .iter()
+        .map(|arg| render_expr_for_orderby(arg, generator, cmd))
+        .collect();
+    let mut sql = format!("{}({})", function, args_sql.join(", "));
+    if with_ordinality {
+        sql.push_str(" WITH ORDINALITY");
+    }
+    if let Some(alias) = alias {
+        sql.push_str(&format!(" AS {}", generator.quote_identifier(alias)));
+    }
+    sql
+}
+
 fn render_function_name(name: &str) -> Option<String> {
     if name.is_empty()
         || name.contains('\0')
@@ -1095,3 +1271,19 @@ fn bound_to_sql(bound: &FrameBound) -> String {
         FrameBound::Following(n) => format!("{} FOLLOWING", n),
     }
 }
+
+/// Pin column values that name an outer FROM/JOIN table (`users.id` inside a
+/// lateral subquery) to that table, instead of the JSON-path fallback the
+/// subquery would apply on its own.
+fn correlate_outer_columns(subquery: &Qail, outer: &Qail, generator: &dyn SqlGenerator) -> Qail {
+    let mut correlated = subquery.clone();
+    correlated.visit_values_mut(&mut |value| {
+        if let Value::Column(col) = value
+            && resolve_known_col_syntax(col, subquery, generator).is_none()
+            && let Some(sql) = resolve_known_col_syntax(col, outer, generator)
+        {
+            *value = Value::Column(format!("{{{sql}}}"));
+        }
+    });
+    correlated
+}
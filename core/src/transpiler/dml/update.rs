@@ -3,10 +3,24 @@
 use crate::ast::*;
 use crate::transpiler::conditions::ConditionToSql;
 use crate::transpiler::dialect::Dialect;
-use crate::transpiler::identifier::render_table_reference;
+use crate::transpiler::identifier::{render_collation, render_table_reference};
 
 /// Generate UPDATE SQL with SET, FROM, and WHERE clauses.
+///
+/// Inner joins become FROM tables; see [`Qail::fold_mutation_joins`].
 pub fn build_update(cmd: &Qail, dialect: Dialect) -> String {
+    let folded;
+    let cmd = if cmd.joins.is_empty() {
+        cmd
+    } else {
+        match cmd.fold_mutation_joins() {
+            Ok(cmd) => {
+                folded = cmd;
+                &folded
+            }
+            Err(error) => return format!("/* ERROR: {} */", error),
+        }
+    };
     let generator = dialect.generator();
     let mut sql = if cmd.only_table {
         String::from("UPDATE ONLY ")
@@ -93,6 +107,9 @@ pub fn build_update(cmd: &Qail, dialect: Dialect) -> String {
         sql.push_str(&cols.join(", "));
     }
 
+    // SQLite places ORDER BY / LIMIT after RETURNING on limited UPDATEs.
+    sql.push_str(&super::render_dml_limit(cmd, dialect, "UPDATE"));
+
     sql
 }
 
@@ -123,7 +140,7 @@ fn render_returning_expr(expr: &Expr, generator: &dyn crate::transpiler::SqlGene
         } => format!(
             "{} COLLATE {}",
             render_returning_expr(expr, generator),
-            render_qualified_identifier(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
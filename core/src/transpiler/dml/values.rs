@@ -0,0 +1,52 @@
+//! Standalone TABLE and VALUES SQL generation.
+
+use crate::ast::*;
+use crate::transpiler::conditions::ConditionToSql;
+use crate::transpiler::dialect::Dialect;
+
+/// Generate `TABLE name`, or `SELECT * FROM name` for dialects without it.
+pub fn build_table(cmd: &Qail, dialect: Dialect) -> String {
+    let generator = dialect.generator();
+    if cmd.table.is_empty() || cmd.table.as_bytes().contains(&0) {
+        return "/* ERROR: TABLE requires a table name */".to_string();
+    }
+    let table = generator.quote_identifier(&cmd.table);
+    match dialect {
+        Dialect::Postgres => format!("TABLE {table}"),
+        Dialect::SQLite => format!("SELECT * FROM {table}"),
+    }
+}
+
+/// Generate a standalone `VALUES (...), (...)` row list.
+///
+/// Each `Payload` cage is one row.
+pub fn build_values(cmd: &Qail, dialect: Dialect) -> String {
+    let generator = dialect.generator();
+    let rows: Vec<&Cage> = cmd
+        .cages
+        .iter()
+        .filter(|cage| cage.kind == CageKind::Payload)
+        .collect();
+
+    let Some(first) = rows.first() else {
+        return "/* ERROR: VALUES requires at least one row */".to_string();
+    };
+    let width = first.conditions.len();
+    if width == 0 || rows.iter().any(|row| row.conditions.len() != width) {
+        return "/* ERROR: VALUES rows must have the same non-zero number of columns */"
+            .to_string();
+    }
+
+    let rows: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let values: Vec<String> = row
+                .conditions
+                .iter()
+                .map(|c| c.to_value_sql(generator.as_ref()))
+                .collect();
+            format!("({})", values.join(", "))
+        })
+        .collect();
+    format!("VALUES {}", rows.join(", "))
+}
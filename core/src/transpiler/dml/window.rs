@@ -3,7 +3,7 @@
 use crate::ast::*;
 use crate::transpiler::conditions::ConditionToSql;
 use crate::transpiler::dialect::Dialect;
-use crate::transpiler::identifier::render_table_reference;
+use crate::transpiler::identifier::{render_collation, render_table_reference};
 use crate::transpiler::{SqlGenerator, escape_sql_string_literal};
 
 /// Generate Window Function SQL (Pillar 8).
@@ -88,19 +88,33 @@ pub fn build_window(cmd: &Qail, dialect: Dialect) -> String {
                 if let Some(fr) = frame {
                     over_clause.push(' ');
                     match fr {
-                        WindowFrame::Rows { start, end } => {
+                        WindowFrame::Rows {
+                            start,
+                            end,
+                            exclude,
+                        } => {
                             over_clause.push_str(&format!(
                                 "ROWS BETWEEN {} AND {}",
                                 bound_to_sql(start),
                                 bound_to_sql(end)
                             ));
+                            if let Some(exclude) = exclude {
+                                over_clause.push_str(&format!(" {}", exclude));
+                            }
                         }
-                        WindowFrame::Range { start, end } => {
+                        WindowFrame::Range {
+                            start,
+                            end,
+                            exclude,
+                        } => {
                             over_clause.push_str(&format!(
                                 "RANGE BETWEEN {} AND {}",
                                 bound_to_sql(start),
                                 bound_to_sql(end)
                             ));
+                            if let Some(exclude) = exclude {
+                                over_clause.push_str(&format!(" {}", exclude));
+                            }
                         }
                     }
                 }
@@ -206,6 +220,7 @@ fn render_window_expr(expr: &Expr, generator: &dyn SqlGenerator, cmd: &Qail) ->
             match op {
                 BinaryOp::IsNull => format!("({left_sql} IS NULL)"),
                 BinaryOp::IsNotNull => format!("({left_sql} IS NOT NULL)"),
+                BinaryOp::AtTimeZone => generator.at_time_zone(&left_sql, &right_sql),
                 _ => format!("({left_sql} {op} {right_sql})"),
             }
         }
@@ -242,7 +257,7 @@ fn render_window_expr(expr: &Expr, generator: &dyn SqlGenerator, cmd: &Qail) ->
         } => format!(
             "{} COLLATE {}",
             render_window_expr(expr, generator, cmd),
-            render_qualified_identifier(collation, generator)
+            render_collation(collation, generator)
         ),
         Expr::FieldAccess { expr, field, .. } => format!(
             "({}).{}",
@@ -1,4 +1,4 @@
-use crate::transpiler::traits::SqlGenerator;
+use crate::transpiler::traits::{SqlGenerator, split_collation_name};
 
 pub(crate) fn split_table_reference(reference: &str) -> Option<(&str, Option<&str>)> {
     let parts = reference.split_whitespace().collect::<Vec<_>>();
@@ -25,6 +25,14 @@ pub(crate) fn render_table_reference(reference: &str, generator: &dyn SqlGenerat
     }
 }
 
+pub(crate) fn render_collation(collation: &str, generator: &dyn SqlGenerator) -> String {
+    if collation.as_bytes().contains(&0) || split_collation_name(collation).is_none() {
+        "/* ERROR: Invalid collation */".to_string()
+    } else {
+        generator.quote_collation(collation)
+    }
+}
+
 pub(crate) fn table_reference_base(reference: &str) -> &str {
     split_table_reference(reference)
         .map(|(table, _)| table)
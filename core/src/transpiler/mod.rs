@@ -12,6 +12,8 @@ pub mod dml;
 pub(crate) mod identifier;
 /// RLS policy transpilation (CREATE POLICY).
 pub mod policy;
+/// Multi-line SQL layout (`to_sql_pretty`).
+pub mod pretty;
 /// Core SQL generation utilities.
 pub mod sql;
 /// Transpiler traits (SqlGenerator, escape_identifier).
@@ -30,7 +32,7 @@ use crate::ast::*;
 pub use conditions::ConditionToSql;
 pub use dialect::Dialect;
 pub use traits::SqlGenerator;
-pub use traits::{escape_identifier, escape_sql_string_literal};
+pub use traits::{escape_identifier, escape_sql_string_literal, split_collation_name};
 
 /// Result of transpilation with extracted parameters.
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -63,6 +65,64 @@ impl TranspileResult {
     }
 }
 
+/// Rendering options beyond the target dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TranspileOptions {
+    /// Target SQL dialect.
+    pub dialect: Dialect,
+    /// Row cap appended to row-returning SELECTs without a LIMIT; see
+    /// [`Qail::with_auto_limit`]. `None` leaves queries unbounded.
+    pub auto_limit: Option<usize>,
+}
+
+impl TranspileOptions {
+    /// Options for `dialect` with no auto-limit.
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            auto_limit: None,
+        }
+    }
+
+    /// Cap unbounded row-returning SELECTs at `max_rows`.
+    pub fn auto_limit(mut self, max_rows: usize) -> Self {
+        self.auto_limit = Some(max_rows);
+        self
+    }
+}
+
+impl Qail {
+    /// Convert to SQL with one top-level clause per line.
+    ///
+    /// Same SQL as [`ToSql::to_sql`], laid out by [`pretty::pretty_sql`] for
+    /// migration previews and debugging.
+    pub fn to_sql_pretty(&self) -> String {
+        pretty::pretty_sql(&self.to_sql())
+    }
+
+    /// Convert to SQL applying [`TranspileOptions`].
+    pub fn to_sql_with_options(&self, options: TranspileOptions) -> String {
+        match options.auto_limit {
+            Some(max_rows) => self
+                .clone()
+                .with_auto_limit(max_rows)
+                .to_sql_with_dialect(options.dialect),
+            None => self.to_sql_with_dialect(options.dialect),
+        }
+    }
+
+    /// Convert to parameterized SQL applying [`TranspileOptions`].
+    pub fn to_sql_parameterized_with_options(&self, options: TranspileOptions) -> TranspileResult {
+        match options.auto_limit {
+            Some(max_rows) => self
+                .clone()
+                .with_auto_limit(max_rows)
+                .to_sql_parameterized_with_dialect(options.dialect),
+            None => self.to_sql_parameterized_with_dialect(options.dialect),
+        }
+    }
+}
+
 /// Trait for converting AST nodes to parameterized SQL.
 pub trait ToSqlParameterized {
     /// Convert to SQL with extracted parameters (default dialect).
@@ -110,7 +170,7 @@ impl ToSql for Qail {
             Action::Index => ddl::build_create_index(self, dialect),
             Action::DropIndex => format!("DROP INDEX IF EXISTS {}", escape_identifier(&self.table)),
             Action::Alter => ddl::build_alter_add_column(self, dialect),
-            Action::AlterAddConstraint => ddl::build_alter_add_check_constraint(self, dialect),
+            Action::AlterAddConstraint => ddl::build_alter_add_constraint(self, dialect),
             Action::AlterDropConstraint => ddl::build_alter_drop_constraint(self, dialect),
             Action::AlterDrop => ddl::build_alter_drop_column(self, dialect),
             Action::AlterType => ddl::build_alter_column_type(self, dialect),
@@ -123,10 +183,21 @@ impl ToSql for Qail {
             Action::DropCol | Action::RenameCol => ddl::build_alter_column(self, dialect),
             // JSON features
             Action::JsonTable => dml::json_table::build_json_table(self, dialect),
-            // COPY protocol (AST-native in qail-pg, generates SELECT for fallback)
-            Action::Export => dml::select::build_select(self, dialect),
+            // COPY protocol (AST-native in qail-pg, generates SELECT for fallback
+            // unless format options ask for the COPY statement itself)
+            Action::Export => match (&self.copy_options, dialect) {
+                (Some(options), Dialect::Postgres) => format!(
+                    "COPY ({}) TO STDOUT {}",
+                    dml::select::build_select(self, dialect),
+                    options
+                ),
+                (Some(_), _) => "/* ERROR: COPY export options require PostgreSQL */".to_string(),
+                (None, _) => dml::select::build_select(self, dialect),
+            },
             // TRUNCATE TABLE
             Action::Truncate => format!("TRUNCATE TABLE {}", escape_identifier(&self.table)),
+            Action::Table => dml::values::build_table(self, dialect),
+            Action::Values => dml::values::build_values(self, dialect),
             // EXPLAIN - wrap SELECT query
             Action::Explain => format!("EXPLAIN {}", dml::select::build_select(self, dialect)),
             // EXPLAIN ANALYZE - execute and analyze query
@@ -851,225 +922,15 @@ fn is_valid_session_setting_name(name: &str) -> bool {
 
 impl ToSqlParameterized for Qail {
     fn to_sql_parameterized_with_dialect(&self, dialect: Dialect) -> TranspileResult {
-        // Use the full ToSql implementation which handles CTEs, JOINs, etc.
-        // Then post-process to extract named parameters for binding
-        let full_sql = self.to_sql_with_dialect(dialect);
-        let (sql, named_params) = replace_named_params_outside_sql_literals(&full_sql);
+        // Resolve `:name` values to positional params on the AST, then render
+        // through the full ToSql implementation which handles CTEs, JOINs, etc.
+        let mut bound = self.clone();
+        let named_params = bound.bind_named_params();
 
         TranspileResult {
-            sql,
+            sql: bound.to_sql_with_dialect(dialect),
             params: Vec::new(), // Positional params not used, named_params provides mapping
             named_params,
         }
     }
 }
-
-fn replace_named_params_outside_sql_literals(sql: &str) -> (String, Vec<String>) {
-    let mut named_params: Vec<String> = Vec::new();
-    let mut seen_params: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
-    let mut result = String::with_capacity(sql.len());
-    let mut param_index = 1;
-    let mut i = 0;
-    let mut state = SqlScanState::Normal;
-
-    while i < sql.len() {
-        match &state {
-            SqlScanState::Normal => {
-                if sql[i..].starts_with("--") {
-                    result.push_str("--");
-                    i += 2;
-                    state = SqlScanState::LineComment;
-                    continue;
-                }
-                if sql[i..].starts_with("/*") {
-                    result.push_str("/*");
-                    i += 2;
-                    state = SqlScanState::BlockComment;
-                    continue;
-                }
-                if sql[i..].starts_with("::") {
-                    result.push_str("::");
-                    i += 2;
-                    continue;
-                }
-                if let Some(delimiter) = sql_dollar_quote_delimiter_at(sql, i) {
-                    result.push_str(&delimiter);
-                    i += delimiter.len();
-                    state = SqlScanState::DollarQuoted(delimiter);
-                    continue;
-                }
-
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                match ch {
-                    '\'' => {
-                        result.push(ch);
-                        i = next_i;
-                        state = SqlScanState::SingleQuoted;
-                    }
-                    '"' => {
-                        result.push(ch);
-                        i = next_i;
-                        state = SqlScanState::DoubleQuoted;
-                    }
-                    ':' => {
-                        let Some((next, mut cursor)) = next_sql_char(sql, next_i) else {
-                            result.push(ch);
-                            i = next_i;
-                            continue;
-                        };
-                        if is_named_param_start(next) {
-                            let mut param_name = String::new();
-                            param_name.push(next);
-                            while let Some((candidate, candidate_next)) = next_sql_char(sql, cursor)
-                            {
-                                if is_named_param_continue(candidate) {
-                                    param_name.push(candidate);
-                                    cursor = candidate_next;
-                                } else {
-                                    break;
-                                }
-                            }
-
-                            let idx = if let Some(&existing) = seen_params.get(&param_name) {
-                                existing
-                            } else {
-                                let idx = param_index;
-                                seen_params.insert(param_name.clone(), idx);
-                                named_params.push(param_name);
-                                param_index += 1;
-                                idx
-                            };
-                            result.push('$');
-                            result.push_str(&idx.to_string());
-                            i = cursor;
-                        } else {
-                            result.push(ch);
-                            i = next_i;
-                        }
-                    }
-                    _ => {
-                        result.push(ch);
-                        i = next_i;
-                    }
-                }
-            }
-            SqlScanState::SingleQuoted => {
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                result.push(ch);
-                i = next_i;
-                if ch == '\'' {
-                    if sql[i..].starts_with('\'') {
-                        result.push('\'');
-                        i += 1;
-                    } else {
-                        state = SqlScanState::Normal;
-                    }
-                }
-            }
-            SqlScanState::DoubleQuoted => {
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                result.push(ch);
-                i = next_i;
-                if ch == '"' {
-                    if sql[i..].starts_with('"') {
-                        result.push('"');
-                        i += 1;
-                    } else {
-                        state = SqlScanState::Normal;
-                    }
-                }
-            }
-            SqlScanState::LineComment => {
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                result.push(ch);
-                i = next_i;
-                if ch == '\n' {
-                    state = SqlScanState::Normal;
-                }
-            }
-            SqlScanState::BlockComment => {
-                if sql[i..].starts_with("*/") {
-                    result.push_str("*/");
-                    i += 2;
-                    state = SqlScanState::Normal;
-                    continue;
-                }
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                result.push(ch);
-                i = next_i;
-            }
-            SqlScanState::DollarQuoted(delimiter) => {
-                if sql[i..].starts_with(delimiter) {
-                    result.push_str(delimiter);
-                    i += delimiter.len();
-                    state = SqlScanState::Normal;
-                    continue;
-                }
-                let Some((ch, next_i)) = next_sql_char(sql, i) else {
-                    break;
-                };
-                result.push(ch);
-                i = next_i;
-            }
-        }
-    }
-
-    (result, named_params)
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum SqlScanState {
-    Normal,
-    SingleQuoted,
-    DoubleQuoted,
-    LineComment,
-    BlockComment,
-    DollarQuoted(String),
-}
-
-fn next_sql_char(sql: &str, idx: usize) -> Option<(char, usize)> {
-    let ch = sql.get(idx..)?.chars().next()?;
-    Some((ch, idx + ch.len_utf8()))
-}
-
-fn is_named_param_start(ch: char) -> bool {
-    ch.is_ascii_alphabetic() || ch == '_'
-}
-
-fn is_named_param_continue(ch: char) -> bool {
-    ch.is_ascii_alphanumeric() || ch == '_'
-}
-
-fn sql_dollar_quote_delimiter_at(sql: &str, idx: usize) -> Option<String> {
-    if !sql.get(idx..)?.starts_with('$') {
-        return None;
-    }
-    let rest = sql.get(idx + 1..)?;
-    for (offset, ch) in rest.char_indices() {
-        if ch == '$' {
-            let tag = &rest[..offset];
-            if tag.is_empty()
-                || (is_named_param_start(tag.chars().next()?)
-                    && tag.chars().all(is_named_param_continue))
-            {
-                return Some(sql[idx..idx + offset + 2].to_string());
-            }
-            return None;
-        }
-        if !is_named_param_continue(ch) {
-            return None;
-        }
-    }
-    None
-}
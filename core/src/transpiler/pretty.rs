@@ -0,0 +1,109 @@
+//! Multi-line SQL layout for previews and debugging.
+
+/// Top-level clause keywords that start a new line, longest first so that
+/// `LEFT JOIN` wins over `JOIN` and `UNION ALL` over `UNION`.
+const CLAUSES: &[(&str, bool)] = &[
+    ("LEFT OUTER JOIN", true),
+    ("RIGHT OUTER JOIN", true),
+    ("FULL OUTER JOIN", true),
+    ("INNER JOIN", true),
+    ("LEFT JOIN", true),
+    ("RIGHT JOIN", true),
+    ("FULL JOIN", true),
+    ("CROSS JOIN", true),
+    ("JOIN", true),
+    ("ON CONFLICT", false),
+    ("UNION ALL", false),
+    ("GROUP BY", false),
+    ("ORDER BY", false),
+    ("RETURNING", false),
+    ("INTERSECT", false),
+    ("EXCEPT", false),
+    ("SELECT", false),
+    ("HAVING", false),
+    ("WINDOW", false),
+    ("OFFSET", false),
+    ("VALUES", false),
+    ("UNION", false),
+    ("WHERE", false),
+    ("LIMIT", false),
+    ("FROM", false),
+    ("SET", false),
+];
+
+/// Lay out SQL one clause per line.
+///
+/// Only top-level clauses break: text inside parentheses (subqueries,
+/// function arguments, `OVER (...)`), string literals and quoted identifiers
+/// is copied unchanged. JOINs are indented two spaces under `FROM`.
+pub fn pretty_sql(sql: &str) -> String {
+    let bytes = sql.as_bytes();
+    let mut out = String::with_capacity(sql.len() + 16);
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'\'' | b'"' => {
+                let end = quoted_end(bytes, i);
+                out.push_str(&sql[i..end]);
+                i = end;
+                continue;
+            }
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b' ' if depth == 0 => {
+                if let Some((keyword, indent)) = clause_at(sql, i + 1)
+                    && !continues_previous_word(&out, keyword)
+                {
+                    out.push('\n');
+                    if indent {
+                        out.push_str("  ");
+                    }
+                    out.push_str(keyword);
+                    i += 1 + keyword.len();
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        let ch = sql[i..].chars().next().unwrap_or_default();
+        out.push(ch);
+        i += ch.len_utf8();
+    }
+    out
+}
+
+/// Index just past the literal or quoted identifier opening at `start`,
+/// treating a doubled quote as an escape.
+fn quoted_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+fn clause_at(sql: &str, at: usize) -> Option<(&'static str, bool)> {
+    let rest = &sql[at..];
+    CLAUSES.iter().copied().find(|(keyword, _)| {
+        rest.starts_with(keyword)
+            && rest[keyword.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| c == ' ' || c == '(')
+    })
+}
+
+/// `DELETE FROM` and `IS [NOT] DISTINCT FROM` are one clause, not two.
+fn continues_previous_word(out: &str, keyword: &str) -> bool {
+    keyword == "FROM" && (out.ends_with("DELETE") || out.ends_with("DISTINCT"))
+}
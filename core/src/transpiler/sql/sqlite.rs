@@ -1,4 +1,5 @@
 use super::super::traits::SqlGenerator;
+use crate::ast::Value;
 
 /// SQLite-specific SQL generator.
 pub struct SqliteGenerator;
@@ -15,10 +16,38 @@ impl SqlGenerator for SqliteGenerator {
         "?".to_string()
     }
 
+    fn json_placeholder(&self, placeholder: &str) -> String {
+        format!("json({})", placeholder)
+    }
+
+    fn array_overlap(&self, _col: &str, _value: &str) -> String {
+        "FALSE /* ERROR: SQLite does not support array/range overlap (&&) */".to_string()
+    }
+
+    fn json_contains(&self, _col: &str, _value: &str) -> String {
+        "FALSE /* ERROR: SQLite does not support JSON containment (@>) */".to_string()
+    }
+
+    fn at_time_zone(&self, _expr: &str, _zone: &str) -> String {
+        "NULL /* ERROR: SQLite does not support AT TIME ZONE */".to_string()
+    }
+
     fn fuzzy_operator(&self) -> &str {
         "LIKE"
     }
 
+    fn supports_trigram_similarity(&self) -> bool {
+        false
+    }
+
+    fn supports_full_text_search(&self) -> bool {
+        false
+    }
+
+    fn supports_alter_column_if_exists(&self) -> bool {
+        false
+    }
+
     fn bool_literal(&self, val: bool) -> String {
         if val {
             "1".to_string()
@@ -27,6 +56,13 @@ impl SqlGenerator for SqliteGenerator {
         }
     }
 
+    fn bind_value(&self, value: Value) -> Value {
+        match value {
+            Value::Bool(b) => Value::Int(i64::from(b)),
+            other => other,
+        }
+    }
+
     fn string_concat(&self, parts: &[&str]) -> String {
         parts.join(" || ")
     }
@@ -30,6 +30,128 @@ fn test_count_action_uses_count_projection() {
     );
 }
 
+#[test]
+fn test_set_op_builders_append_branches() {
+    use crate::ast::Qail;
+
+    let cmd = Qail::get("a")
+        .columns(["id", "name"])
+        .union(Qail::get("b").columns(["id", "name"]))
+        .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id, name FROM a UNION SELECT id, name FROM b"
+    );
+
+    let cmd = Qail::get("a")
+        .column("id")
+        .union_all(Qail::get("b").column("id"))
+        .unwrap()
+        .except(Qail::get("c").column("id"))
+        .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM a UNION ALL SELECT id FROM b EXCEPT SELECT id FROM c"
+    );
+
+    // `*` projections have unknown arity and are not checked.
+    assert!(
+        Qail::get("a")
+            .intersect(Qail::get("b").column("id"))
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_set_op_builders_reject_mismatched_arity() {
+    use crate::ast::Qail;
+
+    let err = Qail::get("a")
+        .columns(["id", "name"])
+        .union(Qail::get("b").column("id"))
+        .unwrap_err();
+    assert!(err.to_string().contains("2 vs 1"), "{err}");
+}
+
+#[test]
+fn test_count_only_wraps_query_and_drops_order_and_limit() {
+    use crate::ast::{Operator, Qail, SortOrder};
+
+    let cmd = Qail::get("orders")
+        .columns(["id", "total"])
+        .filter("status", Operator::Eq, "paid")
+        .left_join("users", "orders.user_id", "users.id")
+        .order_by("created_at", SortOrder::Desc)
+        .limit(20)
+        .offset(40)
+        .count_only();
+
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT COUNT(*) FROM (SELECT id, total FROM orders \
+         LEFT JOIN users ON orders.user_id = users.id \
+         WHERE status = 'paid') _sub"
+    );
+
+    let parsed = parse("count users where active = true order by id limit 5")
+        .unwrap()
+        .count_only();
+    assert_eq!(
+        parsed.to_sql(),
+        "SELECT COUNT(*) FROM (SELECT * FROM users WHERE active = true) _sub"
+    );
+}
+
+#[test]
+fn test_auto_limit_caps_unbounded_select() {
+    use crate::transpiler::{Dialect, TranspileOptions};
+
+    let options = TranspileOptions::new(Dialect::Postgres).auto_limit(1000);
+
+    let cmd = parse("get orders fields id, total where status = 'paid'").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_options(options),
+        "SELECT id, total FROM orders WHERE status = 'paid' LIMIT 1000"
+    );
+    assert_eq!(
+        cmd.to_sql_with_options(TranspileOptions::new(Dialect::Postgres)),
+        "SELECT id, total FROM orders WHERE status = 'paid'"
+    );
+
+    let limited = parse("get orders fields id limit 5").unwrap();
+    assert_eq!(
+        limited.to_sql_with_options(options),
+        "SELECT id FROM orders LIMIT 5"
+    );
+
+    let grouped = parse("get orders fields status, count(id)").unwrap();
+    assert!(
+        grouped
+            .to_sql_with_options(options)
+            .ends_with(" LIMIT 1000")
+    );
+}
+
+#[test]
+fn test_auto_limit_leaves_single_row_queries_alone() {
+    use crate::ast::Qail;
+    use crate::transpiler::{Dialect, TranspileOptions};
+
+    let options = TranspileOptions::new(Dialect::Postgres).auto_limit(1000);
+
+    let aggregate = parse("get orders fields sum(total), count(id)").unwrap();
+    assert_eq!(aggregate.to_sql_with_options(options), aggregate.to_sql());
+
+    let count = parse("count orders where status = 'paid'").unwrap();
+    assert_eq!(count.to_sql_with_options(options), count.to_sql());
+
+    let count_only = Qail::get("orders").column("id").count_only();
+    assert_eq!(count_only.to_sql_with_options(options), count_only.to_sql());
+
+    let delete = parse("del orders where status = 'void'").unwrap();
+    assert_eq!(delete.to_sql_with_options(options), delete.to_sql());
+}
+
 #[test]
 fn test_in_literal_list_uses_sql_in() {
     let cmd = parse("get users fields * where name in (\"O'Reilly\", \"Ada\")").unwrap();
@@ -48,6 +170,53 @@ fn test_not_in_literal_list_uses_sql_not_in() {
     );
 }
 
+#[test]
+fn test_eq_null_rewrites_to_null_test() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get users[deleted_at = null] fields id").unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM users WHERE deleted_at IS NULL"
+    );
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(result.sql, "SELECT id FROM users WHERE deleted_at IS NULL");
+    assert!(result.params.is_empty());
+
+    let cmd = parse("get users fields id where deleted_at != null").unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM users WHERE deleted_at IS NOT NULL"
+    );
+    assert_eq!(
+        cmd.to_sql_parameterized().sql,
+        "SELECT id FROM users WHERE deleted_at IS NOT NULL"
+    );
+}
+
+#[test]
+fn test_raw_condition_is_not_rewritten_to_null_test() {
+    use crate::ast::*;
+    use crate::transpiler::ToSqlParameterized;
+
+    let mut cmd = Qail::get("users").column("id");
+    cmd.cages.push(Cage {
+        kind: CageKind::Filter,
+        conditions: vec![Condition {
+            left: Expr::Named("{age > 5}".to_string()),
+            op: Operator::Eq,
+            value: Value::Null,
+            is_array_unnest: false,
+        }],
+        logical_op: LogicalOp::And,
+    });
+
+    assert_eq!(cmd.to_sql(), "SELECT id FROM users WHERE age > 5");
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(result.sql, "SELECT id FROM users WHERE age > 5");
+    assert!(result.params.is_empty());
+}
+
 #[test]
 fn test_in_param_keeps_any_array_binding() {
     use crate::ast::*;
@@ -160,6 +329,69 @@ fn test_delete() {
     assert_eq!(cmd.to_sql(), "DELETE FROM users WHERE id = $1");
 }
 
+#[test]
+fn test_mutation_joins_render_as_using_and_from() {
+    let cmd =
+        parse("del orders inner join users on orders.user_id = users.id where users.banned = true")
+            .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "DELETE FROM orders USING users WHERE orders.user_id = users.id AND users.banned = true"
+    );
+
+    let cmd = parse(
+        "set orders inner join users on orders.user_id = users.id \
+         values status = 'void' where users.banned = true",
+    )
+    .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "UPDATE orders SET status = 'void' FROM users \
+         WHERE orders.user_id = users.id AND users.banned = true"
+    );
+
+    // A bare `join` is a LEFT join, which DELETE cannot express.
+    let cmd = parse("del orders join users on orders.user_id = users.id").unwrap();
+    assert!(
+        cmd.to_sql()
+            .contains("/* ERROR: Validation error: DELETE supports only inner joins")
+    );
+}
+
+#[test]
+fn test_insert_multi_row_values() {
+    use crate::ast::{Qail, Value};
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = Qail::add("users")
+        .columns(["email", "name"])
+        .values_rows([
+            vec![Value::from("a@x.io"), Value::from("Ana")],
+            vec![Value::from("b@x.io"), Value::from("Bo")],
+        ])
+        .returning(Vec::<&str>::new());
+    assert_eq!(
+        cmd.to_sql(),
+        "INSERT INTO users (email, name) VALUES ('a@x.io', 'Ana'), ('b@x.io', 'Bo')"
+    );
+
+    let cmd =
+        parse("add users fields email, name values (:e1, :n1), (:e2, :n2), (:e3, :n3)").unwrap();
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(
+        result.sql,
+        "INSERT INTO users (email, name) VALUES ($1, $2), ($3, $4), ($5, $6) RETURNING *"
+    );
+    assert_eq!(result.named_params, ["e1", "n1", "e2", "n2", "e3", "n3"]);
+
+    let ragged = Qail::add("users").values_rows([vec![1, 2], vec![3]]);
+    assert!(
+        ragged
+            .to_sql()
+            .contains("/* ERROR: multi-row INSERT rows must have the same number of values */")
+    );
+}
+
 #[test]
 fn test_delete_with_where_or() {
     let cmd = parse("del users where id = $1 or email = :email").unwrap();
@@ -169,6 +401,63 @@ fn test_delete_with_where_or() {
     );
 }
 
+#[test]
+fn test_parameterized_delete_with_multi_condition_where() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("del users where org_id = :org and id = :id and deleted_at != null").unwrap();
+    assert_eq!(cmd.cages.len(), 1);
+    assert_eq!(cmd.cages[0].conditions.len(), 3);
+
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(
+        result.sql,
+        "DELETE FROM users WHERE org_id = $1 AND id = $2 AND deleted_at IS NOT NULL"
+    );
+    assert_eq!(result.named_params, vec!["org", "id"]);
+}
+
+#[test]
+fn test_parameterized_update_with_multi_condition_where() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse(
+        "set users values status = :status where org_id = :org and id = :id and archived_at = null",
+    )
+    .unwrap();
+
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(
+        result.sql,
+        "UPDATE users SET status = $1 WHERE org_id = $2 AND id = $3 AND archived_at IS NULL"
+    );
+    assert_eq!(result.named_params, vec!["status", "org", "id"]);
+}
+
+#[test]
+fn test_mutation_filters_match_select_filters() {
+    let get = parse("get users where org_id = :org and id = :id and deleted_at = null").unwrap();
+    let del = parse("del users where org_id = :org and id = :id and deleted_at = null").unwrap();
+    let set = parse(
+        "set users values status = 'x' where org_id = :org and id = :id and deleted_at = null",
+    )
+    .unwrap();
+
+    let get_filter = get
+        .cages
+        .iter()
+        .find(|cage| matches!(cage.kind, crate::ast::CageKind::Filter))
+        .unwrap();
+    for cmd in [&del, &set] {
+        let filter = cmd
+            .cages
+            .iter()
+            .find(|cage| matches!(cage.kind, crate::ast::CageKind::Filter))
+            .unwrap();
+        assert_eq!(filter, get_filter);
+    }
+}
+
 #[test]
 fn test_fuzzy_match() {
     let cmd = parse("get users fields * where name ~ $1").unwrap();
@@ -178,6 +467,22 @@ fn test_fuzzy_match() {
     );
 }
 
+#[test]
+fn test_like_passes_pattern_through_unlike_fuzzy() {
+    let like = parse("get users fields * where name like 'ab%'").unwrap();
+    assert_eq!(like.cages[0].conditions[0].op, crate::ast::Operator::Like);
+    assert_eq!(like.to_sql(), "SELECT * FROM users WHERE name LIKE 'ab%'");
+
+    let ilike = parse("get users fields * where name ilike 'ab%'").unwrap();
+    assert_eq!(ilike.to_sql(), "SELECT * FROM users WHERE name ILIKE 'ab%'");
+
+    let fuzzy = parse("get users fields * where name ~ 'ab'").unwrap();
+    assert_eq!(
+        fuzzy.to_sql(),
+        "SELECT * FROM users WHERE name ILIKE '%ab%'"
+    );
+}
+
 #[test]
 fn test_parameterized_fuzzy_match_wraps_placeholder() {
     use crate::transpiler::ToSqlParameterized;
@@ -204,6 +509,36 @@ fn test_text_search_multiple_columns_to_sql() {
     );
 }
 
+#[test]
+fn test_text_search_parses_and_falls_back_to_like_on_sqlite() {
+    use crate::ast::{Operator, Qail};
+    use crate::transpiler::{Dialect, ToSqlParameterized};
+
+    let cmd = parse("get docs[body @@ 'cat & dog'] fields id").unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM docs WHERE to_tsvector('english', coalesce(body, '')) @@ websearch_to_tsquery('english', 'cat & dog')"
+    );
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT \"id\" FROM \"docs\" WHERE coalesce(\"body\", '') LIKE '%cat & dog%'"
+    );
+
+    let cmd = Qail::get("products").filter("name,description", Operator::TextSearch, "ferry");
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT * FROM \"products\" WHERE (coalesce(\"name\", '') || ' ' || coalesce(\"description\", '')) LIKE '%ferry%'"
+    );
+
+    let cmd = parse("get products fields * where name @@ :term").unwrap();
+    let result = cmd.to_sql_parameterized_with_dialect(Dialect::SQLite);
+    assert_eq!(
+        result.sql,
+        "SELECT * FROM \"products\" WHERE coalesce(\"name\", '') LIKE '%' || ? || '%'"
+    );
+    assert_eq!(result.named_params, vec!["term"]);
+}
+
 #[test]
 fn test_timestamp_literal_escapes_quotes() {
     use crate::ast::{Operator, Qail, Value};
@@ -557,6 +892,8 @@ fn test_left_join() {
         kind: JoinKind::Left,
         on: None,
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
     let sql = cmd.to_sql();
     assert!(sql.contains("LEFT JOIN"));
@@ -572,6 +909,8 @@ fn test_right_join() {
         kind: JoinKind::Right,
         on: None,
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
     let sql = cmd.to_sql();
     assert!(sql.contains("RIGHT JOIN"));
@@ -638,6 +977,41 @@ fn test_parameterized_sql() {
     assert_eq!(result.named_params[1], "age");
 }
 
+#[test]
+fn test_positional_param_mixed_with_literal() {
+    use crate::ast::Value;
+    use crate::transpiler::{Dialect, ToSqlParameterized};
+
+    let cmd = parse("get users where id = $1 and status = 'active'").unwrap();
+    assert_eq!(cmd.cages[0].conditions[0].value, Value::Param(1));
+
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(
+        result.sql,
+        "SELECT * FROM users WHERE id = $1 AND status = 'active'"
+    );
+    assert!(result.named_params.is_empty());
+
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT * FROM \"users\" WHERE \"id\" = ? AND \"status\" = 'active'"
+    );
+}
+
+#[test]
+fn test_named_params_are_numbered_after_positional_params() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get users where org = $2 and name = :name and tag = '$9'").unwrap();
+    let result = cmd.to_sql_parameterized();
+
+    assert_eq!(
+        result.sql,
+        "SELECT * FROM users WHERE org = $2 AND name = $3 AND tag = '$9'"
+    );
+    assert_eq!(result.named_params, vec!["name"]);
+}
+
 #[test]
 fn test_parameterized_sql_ignores_param_markers_inside_literals() {
     use crate::transpiler::ToSqlParameterized;
@@ -659,3 +1033,83 @@ fn test_parameterized_sql_ignores_param_markers_inside_literals() {
     );
     assert_eq!(result.named_params, vec!["owner"]);
 }
+
+#[test]
+fn test_named_params_come_from_ast_not_sql_text() {
+    use crate::ast::Value;
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get users fields id where name = :name").unwrap();
+    let result = cmd.to_sql_parameterized();
+
+    assert_eq!(result.named_params, cmd.param_names());
+    assert_eq!(result.named_params, vec!["name"]);
+    assert!(result.sql.contains("name = $1"), "{}", result.sql);
+    assert_eq!(
+        cmd.cages[0].conditions[0].value,
+        Value::NamedParam("name".to_string()),
+        "binding must not mutate the caller's command"
+    );
+}
+
+#[test]
+fn test_named_params_leave_casts_untouched() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get users fields id::text, created_at::date where id::text = :id").unwrap();
+    let result = cmd.to_sql_parameterized();
+
+    assert!(result.sql.contains("id::text"), "{}", result.sql);
+    assert!(result.sql.contains("created_at::date"), "{}", result.sql);
+    assert!(result.sql.contains("= $1"), "{}", result.sql);
+    assert_eq!(result.named_params, vec!["id"]);
+}
+
+#[test]
+fn test_order_by_nulls_placement_renders_all_variants() {
+    for (clause, sql) in [
+        ("created nulls first", "ORDER BY created ASC NULLS FIRST"),
+        ("created asc nulls last", "ORDER BY created ASC NULLS LAST"),
+        (
+            "created desc nulls first",
+            "ORDER BY created DESC NULLS FIRST",
+        ),
+        (
+            "created desc nulls last",
+            "ORDER BY created DESC NULLS LAST",
+        ),
+    ] {
+        let cmd = parse(&format!("get events order by {clause} limit 5")).unwrap();
+        let rendered = cmd.to_sql();
+        assert!(rendered.contains(sql), "{clause}: {rendered}");
+        assert!(rendered.ends_with("LIMIT 5"), "{rendered}");
+
+        // The formatter writes the same keywords back out.
+        let reparsed = parse(&cmd.to_string()).unwrap();
+        assert_eq!(reparsed.cages, cmd.cages);
+    }
+
+    let cmd =
+        parse("get events fields rank() over (order by score desc nulls last) as r order by id")
+            .unwrap();
+    assert!(
+        cmd.to_sql()
+            .contains("OVER (ORDER BY score DESC NULLS LAST)")
+    );
+}
+
+#[test]
+fn test_identifier_escaping_quotes() {
+    use crate::ast::Qail;
+    use crate::transpiler::escape_identifier;
+
+    assert_eq!(escape_identifier("we\"ird"), "\"we\"\"ird\"");
+    assert_eq!(escape_identifier("order"), "\"order\"");
+    assert_eq!(escape_identifier("public.user"), "public.\"user\"");
+
+    let cmd = Qail::get("events").columns(["we\"ird", "order", "id"]);
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT \"we\"\"ird\", \"order\", id FROM events"
+    );
+}
@@ -33,3 +33,229 @@ fn sqlite_identifier_quoting_escapes_embedded_quotes() {
         "SELECT \"na\"\"me\" FROM \"users\"\"; DROP TABLE audit; --\""
     );
 }
+
+#[test]
+fn dml_limit_renders_for_sqlite() {
+    let del = Qail::del("sessions").eq("expired", true).limit(100);
+    assert_eq!(
+        del.to_sql_with_dialect(Dialect::SQLite),
+        "DELETE FROM \"sessions\" WHERE \"expired\" = 1 LIMIT 100"
+    );
+
+    let upd = Qail::set("jobs")
+        .set_value("claimed", true)
+        .eq("claimed", false)
+        .limit(10);
+    assert_eq!(
+        upd.to_sql_with_dialect(Dialect::SQLite),
+        "UPDATE \"jobs\" SET \"claimed\" = 1 WHERE \"claimed\" = 0 LIMIT 10"
+    );
+}
+
+#[test]
+fn dml_limit_is_an_error_for_postgres() {
+    let del = Qail::del("sessions").eq("expired", true).limit(100);
+    assert_eq!(
+        del.to_sql(),
+        "DELETE FROM sessions WHERE expired = true \
+         /* ERROR: PostgreSQL does not support LIMIT on DELETE */"
+    );
+
+    let upd = Qail::set("jobs").set_value("claimed", true).limit(10);
+    assert!(
+        upd.to_sql()
+            .ends_with("/* ERROR: PostgreSQL does not support LIMIT on UPDATE */")
+    );
+}
+
+#[test]
+fn sqlite_boolean_filters_use_integers() {
+    use crate::ast::Value;
+    use crate::transpiler::conditions::{ConditionToSql, ParamContext};
+
+    let cmd = parse("get users[active=true] fields id").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT \"id\" FROM \"users\" WHERE \"active\" = 1"
+    );
+    let cmd = parse("get users[active=false] fields id").unwrap();
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .ends_with("\"active\" = 0")
+    );
+
+    let cond = &cmd.cages[0].conditions[0];
+    let sqlite = Dialect::SQLite.generator();
+    let mut params = ParamContext::new();
+    assert_eq!(
+        cond.to_sql_parameterized(sqlite.as_ref(), Some(&cmd), &mut params),
+        "\"active\" = ?"
+    );
+    assert_eq!(params.params, vec![Value::Int(0)]);
+
+    let postgres = Dialect::Postgres.generator();
+    let mut params = ParamContext::new();
+    cond.to_sql_parameterized(postgres.as_ref(), Some(&cmd), &mut params);
+    assert_eq!(params.params, vec![Value::Bool(false)]);
+}
+
+#[test]
+fn trigram_similarity_binds_raw_value_on_postgres() {
+    use crate::transpiler::conditions::{ConditionToSql, ParamContext};
+
+    let cmd = parse("get users fields id where name ~% 'jon'").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT id FROM users WHERE name % 'jon'"
+    );
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT \"id\" FROM \"users\" WHERE \"name\" LIKE '%jon%'"
+    );
+
+    let cond = &cmd.cages[0].conditions[0];
+    let postgres = Dialect::Postgres.generator();
+    let mut params = ParamContext::new();
+    assert_eq!(
+        cond.to_sql_parameterized(postgres.as_ref(), Some(&cmd), &mut params),
+        "name % $1"
+    );
+    assert_eq!(params.params, vec![crate::ast::Value::String("jon".into())]);
+
+    let sqlite = Dialect::SQLite.generator();
+    let mut params = ParamContext::new();
+    assert_eq!(
+        cond.to_sql_parameterized(sqlite.as_ref(), Some(&cmd), &mut params),
+        "\"name\" LIKE '%' || ? || '%'"
+    );
+}
+
+#[test]
+fn order_by_collation_is_quoted_per_dialect() {
+    let cmd = parse("get users fields name order by name collate 'en_US' desc").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT name FROM users ORDER BY name COLLATE \"en_US\" DESC"
+    );
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT \"name\" FROM \"users\" ORDER BY \"name\" COLLATE \"en_US\" DESC"
+    );
+
+    let cmd = parse("get users fields name order by name collate NOCASE").unwrap();
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .ends_with("ORDER BY \"name\" COLLATE \"NOCASE\" ASC")
+    );
+}
+
+#[test]
+fn quoted_collation_with_dot_stays_one_identifier() {
+    let cmd = parse("get users fields name order by name collate 'en_US.utf8'").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT name FROM users ORDER BY name COLLATE \"en_US.utf8\" ASC"
+    );
+    assert_eq!(parse(&cmd.to_string()).unwrap(), cmd);
+
+    let cmd = parse("get users fields name order by name collate pg_catalog.en_US").unwrap();
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT name FROM users ORDER BY name COLLATE \"pg_catalog\".\"en_US\" ASC"
+    );
+    assert_eq!(parse(&cmd.to_string()).unwrap(), cmd);
+}
+
+#[test]
+fn table_command_degrades_to_select_star_off_postgres() {
+    let cmd = parse("table users").unwrap();
+    assert_eq!(cmd.action, Action::Table);
+    assert_eq!(cmd.to_sql_with_dialect(Dialect::Postgres), "TABLE users");
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "SELECT * FROM \"users\""
+    );
+}
+
+#[test]
+fn standalone_values_render_and_act_as_sources() {
+    use crate::ast::Value;
+
+    let cmd = parse("values (1, 'a'), (2, 'b')").unwrap();
+    assert_eq!(
+        cmd,
+        Qail::values_list()
+            .values([Value::Int(1), Value::String("a".to_string())])
+            .values([Value::Int(2), Value::String("b".to_string())])
+    );
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "VALUES (1, 'a'), (2, 'b')"
+    );
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::SQLite),
+        "VALUES (1, 'a'), (2, 'b')"
+    );
+
+    let cte = parse("with v as (values (1, 2), (3, 4)) get v").unwrap();
+    assert_eq!(
+        cte.to_sql_with_dialect(Dialect::Postgres),
+        "WITH v AS (VALUES (1, 2), (3, 4)) SELECT * FROM v"
+    );
+    let insert = parse("add pairs fields a, b from (values (1, 2))").unwrap();
+    assert!(
+        insert
+            .to_sql_with_dialect(Dialect::SQLite)
+            .starts_with("INSERT INTO \"pairs\" (\"a\", \"b\") VALUES (1, 2)")
+    );
+
+    let ragged = parse("values (1), (2, 3)").unwrap();
+    assert!(ragged.to_sql().starts_with("/* ERROR:"));
+}
+
+#[test]
+fn alter_column_if_exists_per_dialect() {
+    use crate::ast::{Constraint, ModKind};
+
+    let cmd = Qail {
+        action: Action::Mod,
+        table: "users".to_string(),
+        columns: vec![
+            Expr::Mod {
+                kind: ModKind::AddIfNotExists,
+                col: Box::new(Expr::Def {
+                    name: "phone".to_string(),
+                    data_type: "str".to_string(),
+                    constraints: vec![Constraint::Nullable],
+                }),
+            },
+            Expr::Mod {
+                kind: ModKind::DropIfExists,
+                col: Box::new(Expr::Named("legacy".to_string())),
+            },
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "ALTER TABLE users ADD COLUMN IF NOT EXISTS phone VARCHAR(255);\n\
+         ALTER TABLE users DROP COLUMN IF EXISTS legacy"
+    );
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .starts_with("/* ERROR: ALTER TABLE column IF [NOT] EXISTS"),
+    );
+
+    let plain = Qail {
+        columns: vec![Expr::Mod {
+            kind: ModKind::Drop,
+            col: Box::new(Expr::Named("legacy".to_string())),
+        }],
+        ..cmd
+    };
+    assert_eq!(
+        plain.to_sql_with_dialect(Dialect::SQLite),
+        "ALTER TABLE \"users\" DROP COLUMN \"legacy\""
+    );
+}
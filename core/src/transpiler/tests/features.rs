@@ -9,6 +9,55 @@ use crate::transpiler::{ConditionToSql, Dialect, ToSql};
 
 // ============= DDL Tests =============
 
+#[test]
+fn test_create_table_foreign_key_constraints() {
+    let cmd = parse(
+        "make posts id:uuid:pk, user_id:uuid \
+         foreign key (user_id) references users (id) on delete cascade",
+    )
+    .unwrap();
+    let sql = cmd.to_sql();
+    assert!(
+        sql.contains("FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE"),
+        "{sql}"
+    );
+
+    let cmd = Qail {
+        action: Action::Make,
+        table: "trips".to_string(),
+        columns: vec![
+            Expr::Def {
+                name: "route_id".to_string(),
+                data_type: "int".to_string(),
+                constraints: vec![],
+            },
+            Expr::Def {
+                name: "schedule_id".to_string(),
+                data_type: "int".to_string(),
+                constraints: vec![],
+            },
+        ],
+        table_constraints: vec![TableConstraint::ForeignKey {
+            name: Some("fk_trips_schedule".to_string()),
+            columns: vec!["route_id".to_string(), "schedule_id".to_string()],
+            ref_table: "schedules".to_string(),
+            ref_columns: vec!["route_id".to_string(), "id".to_string()],
+            on_delete: Some("restrict".to_string()),
+            on_update: Some("set_null".to_string()),
+            deferrable: None,
+        }],
+        ..Default::default()
+    };
+    let sql = cmd.to_sql();
+    assert!(
+        sql.contains(
+            "CONSTRAINT fk_trips_schedule FOREIGN KEY (route_id, schedule_id) \
+             REFERENCES schedules(route_id, id) ON DELETE RESTRICT ON UPDATE SET NULL"
+        ),
+        "{sql}"
+    );
+}
+
 #[test]
 fn test_index_sql_basic() {
     let cmd = parse("index idx_email on users email").unwrap();
@@ -226,6 +275,197 @@ fn test_composite_pk_sql() {
     assert!(sql.contains("PRIMARY KEY (order_id, item_id)"));
 }
 
+#[test]
+fn test_fk_referential_actions_sql() {
+    let cases = [
+        (
+            "on_delete=cascade",
+            "REFERENCES users(id) ON DELETE CASCADE",
+        ),
+        (
+            "on_delete=restrict",
+            "REFERENCES users(id) ON DELETE RESTRICT",
+        ),
+        (
+            "on_update=cascade",
+            "REFERENCES users(id) ON UPDATE CASCADE",
+        ),
+        (
+            "on_update=set_null",
+            "REFERENCES users(id) ON UPDATE SET NULL",
+        ),
+        (
+            "on_delete=set_default",
+            "REFERENCES users(id) ON DELETE SET DEFAULT",
+        ),
+        (
+            "on_delete=no_action",
+            "REFERENCES users(id) ON DELETE NO ACTION",
+        ),
+    ];
+    for (option, expected) in cases {
+        let cmd = parse(&format!("make posts user_id:uuid:ref(users.id, {option})")).unwrap();
+        let sql = cmd.to_sql();
+        assert!(sql.contains(expected), "{option}: {sql}");
+    }
+}
+
+#[test]
+fn test_fk_deferrable_clause_sql() {
+    let cmd = parse(
+        "make posts id:uuid:pk, \
+         user_id:uuid:ref(users.id, on_delete=cascade, on_update=set_null, deferred)",
+    )
+    .unwrap();
+    assert!(cmd.to_sql().contains(
+        "user_id UUID NOT NULL REFERENCES users(id) \
+         ON DELETE CASCADE ON UPDATE SET NULL DEFERRABLE INITIALLY DEFERRED"
+    ));
+
+    let cmd = parse("make a b_id:int:nullable:^ref(b.id, immediate)").unwrap();
+    assert!(
+        cmd.to_sql()
+            .contains("b_id INT REFERENCES b(id) DEFERRABLE INITIALLY IMMEDIATE")
+    );
+
+    assert!(parse("make a b_id:int:ref(b.id, on_delete=explode)").is_err());
+    assert!(parse("make a b_id:int:ref(b.id, deferred, immediate)").is_err());
+    assert!(parse("make a b_id:int:ref(b.id, on_delete=cascade, on_delete=restrict)").is_err());
+}
+
+#[test]
+fn test_row_lock_clauses_per_mode() {
+    let cases = [
+        ("for update", "FOR UPDATE"),
+        ("for update skip locked", "FOR UPDATE SKIP LOCKED"),
+        ("for update nowait", "FOR UPDATE NOWAIT"),
+        ("for no key update", "FOR NO KEY UPDATE"),
+        ("for share nowait", "FOR SHARE NOWAIT"),
+        ("for key share skip locked", "FOR KEY SHARE SKIP LOCKED"),
+    ];
+    for (clause, expected) in cases {
+        let cmd = parse(&format!(
+            "get jobs fields id where status = 'queued' order by id limit 10 {clause}"
+        ))
+        .unwrap();
+        assert_eq!(
+            cmd.to_sql(),
+            format!(
+                "SELECT id FROM jobs WHERE status = 'queued' ORDER BY id ASC LIMIT 10 {expected}"
+            ),
+            "{clause}"
+        );
+    }
+}
+
+#[test]
+fn test_row_lock_rejected_for_sqlite_and_conflicting_waits() {
+    let cmd = parse("get jobs fields id for update skip locked").unwrap();
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .contains("/* ERROR: SQLite does not support row locking clauses */")
+    );
+
+    let both = Qail::get("jobs").for_update_skip_locked().nowait();
+    assert!(both.to_sql().contains("/* ERROR: SKIP LOCKED and NOWAIT"));
+}
+
+#[test]
+fn test_overlap_operator_postgres() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get bookings fields id where slot <&> :slot").unwrap();
+    assert_eq!(cmd.cages[0].conditions[0].op, Operator::Overlaps);
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(result.sql, "SELECT id FROM bookings WHERE slot && $1");
+    assert_eq!(result.named_params, vec!["slot".to_string()]);
+
+    let cmd = Qail::get("rooms")
+        .column("id")
+        .filter_cond(crate::ast::builders::overlaps("tags", ["wifi", "tv"]));
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM rooms WHERE tags && ARRAY['wifi', 'tv']"
+    );
+
+    let mut params = ParamContext::new();
+    let sql = cmd.cages[0].conditions[0].to_sql_parameterized(
+        &PostgresGenerator,
+        Some(&cmd),
+        &mut params,
+    );
+    assert_eq!(sql, "tags && $1");
+    assert_eq!(
+        params.params,
+        vec![Value::Array(vec![Value::from("wifi"), Value::from("tv")])]
+    );
+
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .contains("/* ERROR: SQLite does not support array/range overlap")
+    );
+}
+
+#[test]
+fn test_generate_series_numeric_source() {
+    let cmd = parse("get generate_series(1, 10) as n fields n where n > 5").unwrap();
+    assert_eq!(cmd.table, "n");
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT n FROM GENERATE_SERIES(1, 10) AS n WHERE n > 5"
+    );
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .contains("/* ERROR: table-function sources require PostgreSQL */")
+    );
+
+    let reparsed = parse(&cmd.to_string()).unwrap();
+    assert_eq!(reparsed, cmd);
+}
+
+#[test]
+fn test_series_date_source_with_params() {
+    use crate::transpiler::ToSqlParameterized;
+
+    let cmd = parse("get series(:start, :end, '1 day'::interval) as day").unwrap();
+    let result = cmd.to_sql_parameterized();
+    assert_eq!(
+        result.sql,
+        "SELECT * FROM GENERATE_SERIES($1, $2, '1 day'::interval) AS day"
+    );
+    assert_eq!(result.named_params, vec!["start", "end"]);
+}
+
+#[test]
+fn test_unnest_from_source_projection() {
+    let cmd = parse("get events, unnest(tags) as tag fields id, tag").unwrap();
+    assert_eq!(cmd.table, "events");
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id, tag FROM events, UNNEST(tags) AS tag"
+    );
+
+    let cmd = parse(
+        "get events, unnest(tags) with ordinality as tag fields id, tag, ordinality \
+         where ordinality <= 3",
+    )
+    .unwrap();
+    assert_eq!(cmd.from_functions.len(), 1);
+    assert!(cmd.from_functions[0].1);
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id, tag, ordinality FROM events, UNNEST(tags) WITH ORDINALITY AS tag \
+         WHERE ordinality <= 3"
+    );
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .contains("/* ERROR: table-function sources require PostgreSQL */")
+    );
+
+    let reparsed = parse(&cmd.to_string()).unwrap();
+    assert_eq!(reparsed, cmd);
+}
+
 #[test]
 fn test_drop_column() {
     // Manual construction for DROP COLUMN
@@ -1150,6 +1390,21 @@ fn test_upsert_single_reserved_pk_column_quotes_fallback_update() {
     );
 }
 
+#[test]
+fn test_upsert_partial_index_conflict_target() {
+    let cmd = crate::parser::parse(
+        "add users fields email, name values 'a@b.com', 'Ana' \
+         conflict (email) where active = true update name = excluded.name",
+    )
+    .unwrap();
+
+    assert_eq!(
+        cmd.to_sql(),
+        "INSERT INTO users (email, name) VALUES ('a@b.com', 'Ana') \
+         ON CONFLICT (email) WHERE active = true DO UPDATE SET name = excluded.name RETURNING *"
+    );
+}
+
 #[test]
 fn test_merge_postgres_builder() {
     let cmd = Qail::merge_into("users")
@@ -2103,7 +2358,7 @@ fn test_json_contains() {
         logical_op: LogicalOp::And,
     });
     let sql = cmd.to_sql_with_dialect(Dialect::Postgres);
-    assert!(sql.contains(r#"@> '{"theme": "dark"}'"#));
+    assert!(sql.contains(r#"@> '{"theme": "dark"}'::jsonb"#));
 }
 
 #[test]
@@ -2230,10 +2485,54 @@ fn test_lateral_join() {
         kind: JoinKind::Lateral,
         on: None,
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
 
     let sql = cmd.to_sql_with_dialect(Dialect::Postgres);
-    assert!(sql.contains("LATERAL JOIN"));
+    assert!(sql.contains("LEFT JOIN LATERAL orders ON"), "{sql}");
+}
+
+#[test]
+fn test_lateral_subquery_join_builders() {
+    let recent = Qail::get("orders")
+        .column("total")
+        .filter(
+            "user_id",
+            Operator::Eq,
+            Value::Column("users.id".to_string()),
+        )
+        .limit(3);
+    let cmd = Qail::get("users")
+        .columns(["users.name", "recent.total"])
+        .left_join_lateral(recent.clone(), "recent");
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT users.name, recent.total FROM users LEFT JOIN LATERAL \
+         (SELECT total FROM orders WHERE user_id = users.id LIMIT 3) recent ON TRUE"
+    );
+
+    let inner = Qail::get("users")
+        .column("users.name")
+        .inner_join_lateral(recent.clone(), "recent");
+    assert!(
+        inner
+            .to_sql_with_dialect(Dialect::Postgres)
+            .contains("INNER JOIN LATERAL (SELECT total FROM orders"),
+    );
+
+    let sqlite = cmd.to_sql_with_dialect(Dialect::SQLite);
+    assert!(
+        sqlite.contains("/* ERROR: LATERAL joins require PostgreSQL */"),
+        "{sqlite}"
+    );
+
+    let write = Qail::get("users").left_join_lateral(Qail::del("orders"), "gone");
+    assert!(
+        write
+            .to_sql_with_dialect(Dialect::Postgres)
+            .contains("/* ERROR: subquery must be read-only SELECT"),
+    );
 }
 
 // ============= SQL/JSON Standard Functions (Postgres 17+) =============
@@ -2548,6 +2847,21 @@ fn test_having_clause() {
     assert!(sql.contains("SUM(total)"));
 }
 
+#[test]
+fn test_parsed_having_clause() {
+    let cmd = parse(
+        "get orders fields status, sum(total) where region = 'eu' group by status \
+         having sum(total) > 1000 and count(*) >= 5",
+    )
+    .unwrap();
+    assert_eq!(cmd.having.len(), 2);
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT status, SUM(total) FROM orders WHERE region = 'eu' GROUP BY status \
+         HAVING SUM(total) > 1000 AND COUNT(*) >= 5"
+    );
+}
+
 // ============= ROLLUP / CUBE =============
 
 #[test]
@@ -2588,6 +2902,32 @@ fn test_group_by_cube() {
     assert!(sql.contains("GROUP BY CUBE("));
 }
 
+#[test]
+fn test_parsed_group_by_rollup_and_cube() {
+    let cmd = parse(
+        "get sales fields region, month, sum(amount) where year = 2024 group by rollup(region, month)",
+    )
+    .unwrap();
+    assert_eq!(cmd.group_by_mode, GroupByMode::Rollup);
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT region, month, SUM(amount) FROM sales WHERE year = 2024 GROUP BY ROLLUP(region, month)"
+    );
+
+    let cmd =
+        parse("get sales fields region, product, sum(amount) group by cube (region, product)")
+            .unwrap();
+    assert_eq!(cmd.group_by_mode, GroupByMode::Cube);
+    assert_eq!(
+        cmd.to_sql_with_dialect(Dialect::Postgres),
+        "SELECT region, product, SUM(amount) FROM sales GROUP BY CUBE(region, product)"
+    );
+
+    // An explicit list wins over columns auto-extracted from the projection
+    let cmd = parse("get sales fields region, month, sum(amount) group by rollup(region)").unwrap();
+    assert!(cmd.to_sql().ends_with("GROUP BY ROLLUP(region)"));
+}
+
 // ============= AGGREGATE FILTER =============
 
 #[test]
@@ -2616,6 +2956,28 @@ fn test_aggregate_filter() {
     assert!(sql.contains("direction"));
 }
 
+#[test]
+fn test_sum_coalesced_defaults_null_to_zero() {
+    use crate::ast::builders::{eq, int, sum, sum_coalesced};
+
+    let cmd = Qail::get("payments").column_expr(sum_coalesced("amount", int(0)).alias("total"));
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT COALESCE(SUM(amount), 0) AS total FROM payments"
+    );
+
+    let cmd = Qail::get("payments").column_expr(
+        sum("amount")
+            .filter(vec![eq("kind", "refund")])
+            .or_default(int(0))
+            .alias("refunded"),
+    );
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT COALESCE(SUM(amount) FILTER (WHERE kind = 'refund'), 0) AS refunded FROM payments"
+    );
+}
+
 // ============= RECURSIVE CTEs =============
 
 #[test]
@@ -2793,6 +3155,8 @@ fn test_custom_join_on() {
             is_array_unnest: false,
         }]),
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
     let sql = cmd.to_sql();
     // Identifiers are unquoted if safe in Postgres dialect implementation used
@@ -2824,6 +3188,8 @@ fn test_custom_join_multiple_conditions() {
             },
         ]),
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
     let sql = cmd.to_sql();
     assert!(
@@ -2854,6 +3220,40 @@ fn test_distinct_on() {
     );
 }
 
+#[test]
+fn test_distinct_on_parsed_expressions() {
+    let cmd = parse(
+        "get distinct on (data->'meta'->>'k', lower(email)) events fields id order by data->'meta'->>'k'",
+    )
+    .unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT DISTINCT ON (data->'meta'->>'k', LOWER(email)) id FROM events ORDER BY data->'meta'->>'k' ASC"
+    );
+}
+
+#[test]
+fn test_distinct_on_drops_select_alias() {
+    let mut cmd = Qail::get("events");
+    cmd.distinct_on = vec![
+        Expr::Aliased {
+            name: "events.kind".to_string(),
+            alias: "k".to_string(),
+        },
+        Expr::JsonAccess {
+            column: "data".to_string(),
+            path_segments: vec![("k".to_string(), true)],
+            alias: Some("key".to_string()),
+        },
+    ];
+    let sql = cmd.to_sql();
+    assert!(
+        sql.starts_with("SELECT DISTINCT ON (events.kind, data->>'k') "),
+        "SQL was: {}",
+        sql
+    );
+}
+
 #[test]
 fn test_table_alias_renders_as_reference_and_qualifies_filters() {
     let cmd = Qail::get("users").table_alias("u").eq("u.active", true);
@@ -3062,3 +3462,236 @@ fn test_condition_parameterized_preserves_column_rhs() {
     assert!(params.params.is_empty());
     assert!(params.named_params.is_empty());
 }
+
+#[test]
+fn test_nested_json_contains_literal_binds_as_jsonb() {
+    let cmd =
+        parse(r#"get docs fields id where metadata @> {"tags":["a",{"b":true}],"n":1}"#).unwrap();
+    let condition = &cmd.cages[0].conditions[0];
+    assert_eq!(condition.op, Operator::Contains);
+
+    let mut params = ParamContext::new();
+    let sql = condition.to_sql_parameterized(&PostgresGenerator::new(), Some(&cmd), &mut params);
+    assert_eq!(sql, "metadata @> $1::jsonb");
+    assert_eq!(
+        params.params,
+        vec![Value::Json(
+            r#"{"tags":["a",{"b":true}],"n":1}"#.to_string()
+        )]
+    );
+
+    let mut params = ParamContext::new();
+    let sql = condition.to_sql_parameterized(
+        &crate::transpiler::sql::sqlite::SqliteGenerator,
+        Some(&cmd),
+        &mut params,
+    );
+    assert_eq!(
+        sql,
+        "FALSE /* ERROR: SQLite does not support JSON containment (@>) */"
+    );
+
+    assert_eq!(
+        cmd.to_sql(),
+        r#"SELECT id FROM docs WHERE metadata @> '{"tags":["a",{"b":true}],"n":1}'::jsonb"#
+    );
+}
+
+#[test]
+fn test_json_parameter_outside_jsonb_operators_binds_untyped() {
+    let condition = Condition {
+        left: Expr::Named("payload".to_string()),
+        op: Operator::Eq,
+        value: Value::Json(r#"{"a":1}"#.to_string()),
+        is_array_unnest: false,
+    };
+
+    let mut params = ParamContext::new();
+    let sql = condition.to_sql_parameterized(&PostgresGenerator::new(), None, &mut params);
+    assert_eq!(sql, "payload = $1");
+}
+
+#[test]
+fn test_json_contains_quoted_document_casts_to_jsonb() {
+    let cmd = parse(r#"get users fields id where data @> '{"role":"admin"}'"#).unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        r#"SELECT id FROM users WHERE data @> '{"role":"admin"}'::jsonb"#
+    );
+    let condition = &cmd.cages[0].conditions[0];
+    let mut params = ParamContext::new();
+    let sql = condition.to_sql_parameterized(&PostgresGenerator::new(), Some(&cmd), &mut params);
+    assert_eq!(sql, "data @> $1::jsonb");
+    assert_eq!(
+        params.params,
+        vec![Value::Json(r#"{"role":"admin"}"#.to_string())]
+    );
+
+    // Quoted arrays may be range literals, so they stay untyped.
+    let cmd = parse("get bookings fields id where slot @> '[1,5]'").unwrap();
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT id FROM bookings WHERE slot @> '[1,5]'"
+    );
+    let condition = &cmd.cages[0].conditions[0];
+    let mut params = ParamContext::new();
+    let sql = condition.to_sql_parameterized(&PostgresGenerator::new(), Some(&cmd), &mut params);
+    assert_eq!(sql, "slot @> $1");
+
+    // PostgreSQL array literals are not JSON and stay untyped.
+    let cmd = parse("get users fields id where tags @> '{a,b}'").unwrap();
+    assert_eq!(cmd.to_sql(), "SELECT id FROM users WHERE tags @> '{a,b}'");
+}
+
+#[test]
+fn test_cross_join_renders_without_on() {
+    let cmd = Qail::get("sizes")
+        .columns(["sizes.name", "colors.name"])
+        .cross_join("colors");
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT sizes.name, colors.name FROM sizes CROSS JOIN colors"
+    );
+
+    let parsed = parse("get sizes cross join colors fields sizes.name, colors.name").unwrap();
+    assert_eq!(parsed.joins, cmd.joins);
+}
+
+#[test]
+fn test_join_with_empty_on_reports_missing_condition() {
+    let cmd = Qail::get("orders")
+        .column("id")
+        .inner_join_conds("payments", vec![]);
+    let err = crate::error::QailError::MissingJoinCondition("payments".to_string());
+
+    assert_eq!(
+        cmd.to_sql(),
+        format!("SELECT id FROM orders INNER JOIN payments /* ERROR: {err} */")
+    );
+}
+
+#[test]
+fn test_join_using_single_and_multi_column() {
+    let cmd = parse("get orders inner join users using (user_id) fields orders.id").unwrap();
+    assert_eq!(cmd.joins[0].using, vec!["user_id".to_string()]);
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT orders.id FROM orders INNER JOIN users USING (user_id)"
+    );
+
+    let cmd = Qail::get("line_items").column("sku").join_using(
+        JoinKind::Left,
+        "prices",
+        ["tenant_id", "sku"],
+    );
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT sku FROM line_items LEFT JOIN prices USING (tenant_id, sku)"
+    );
+    assert_eq!(
+        parse("get line_items left join prices using (tenant_id, sku) fields sku").unwrap(),
+        cmd
+    );
+}
+
+#[test]
+fn test_join_using_with_extra_on_reports_error() {
+    let mut cmd =
+        Qail::get("orders o")
+            .column("o.id")
+            .join_using(JoinKind::Inner, "users u", ["user_id"]);
+    cmd.joins[0].on = Some(vec![Condition {
+        left: Expr::Named("u.tenant_id".to_string()),
+        op: Operator::Eq,
+        value: Value::Int(7),
+        is_array_unnest: false,
+    }]);
+
+    assert_eq!(
+        cmd.to_sql(),
+        "SELECT o.id FROM orders o INNER JOIN users u \
+         /* ERROR: join on 'users u' combines USING with ON conditions */"
+    );
+}
+
+#[test]
+fn test_to_sql_pretty_breaks_top_level_clauses() {
+    let cmd = parse(
+        "get users left join orders on users.id = orders.user_id \
+         fields users.id, orders.total \
+         where users.name = 'a FROM b' and users.id in (get admins fields id where active = true) \
+         order by orders.total desc limit 10",
+    )
+    .unwrap();
+    let compact = cmd.to_sql();
+    let pretty = cmd.to_sql_pretty();
+    assert!(!compact.contains('\n'));
+    assert_eq!(pretty.replace("\n  ", " ").replace('\n', " "), compact);
+
+    let lines: Vec<&str> = pretty.lines().collect();
+    assert_eq!(lines.len(), 6, "{pretty}");
+    assert!(lines[0].starts_with("SELECT "));
+    assert_eq!(lines[1], "FROM users");
+    assert!(lines[2].starts_with("  LEFT JOIN orders ON "));
+    assert!(lines[3].starts_with("WHERE "));
+    assert!(lines[3].contains("'a FROM b'"));
+    assert!(lines[3].contains("(SELECT id FROM admins WHERE "));
+    assert_eq!(lines[4], "ORDER BY orders.total DESC");
+    assert_eq!(lines[5], "LIMIT 10");
+}
+
+#[test]
+fn test_export_csv_with_header_renders_copy() {
+    let cmd =
+        Qail::export_csv("orders")
+            .columns(["id", "total"])
+            .filter("status", Operator::Eq, "paid");
+    assert_eq!(
+        cmd.to_sql(),
+        "COPY (SELECT id, total FROM orders WHERE status = 'paid') TO STDOUT \
+         WITH (FORMAT csv, HEADER true)"
+    );
+
+    let text = Qail::export("orders").copy_options(CopyOptions::default());
+    assert_eq!(
+        text.to_sql(),
+        "COPY (SELECT * FROM orders) TO STDOUT WITH (FORMAT text)"
+    );
+
+    // Plain exports keep the SELECT fallback.
+    assert_eq!(Qail::export("orders").to_sql(), "SELECT * FROM orders");
+    assert!(
+        cmd.to_sql_with_dialect(Dialect::SQLite)
+            .starts_with("/* ERROR:")
+    );
+}
+
+#[test]
+fn test_window_frame_exclusion_variants() {
+    for (clause, sql) in [
+        ("exclude current row", "EXCLUDE CURRENT ROW"),
+        ("exclude group", "EXCLUDE GROUP"),
+        ("exclude ties", "EXCLUDE TIES"),
+        ("exclude no others", "EXCLUDE NO OTHERS"),
+    ] {
+        let cmd = parse(&format!(
+            "get orders fields avg(amount) over (order by created_at \
+             rows between 3 preceding and current row {clause}) as moving_avg"
+        ))
+        .unwrap();
+        assert_eq!(
+            cmd.to_sql(),
+            format!(
+                "SELECT AVG(amount) OVER (ORDER BY created_at ASC ROWS BETWEEN 3 PRECEDING \
+                 AND CURRENT ROW {sql}) AS moving_avg FROM orders"
+            )
+        );
+    }
+
+    let cmd = parse(
+        "get orders fields sum(amount) over (order by created_at \
+         range between unbounded preceding and current row) as running",
+    )
+    .unwrap();
+    assert!(!cmd.to_sql().contains("EXCLUDE"));
+}
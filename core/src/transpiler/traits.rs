@@ -66,6 +66,48 @@ pub fn escape_identifier(name: &str) -> String {
     escape_single_identifier(name)
 }
 
+/// Split a `COLLATE` name into its parts.
+///
+/// Unquoted dots separate schema-qualified parts (`pg_catalog.en_US`); a part
+/// written in double quotes (`"en_US.utf8"`, `""` for an embedded quote) is
+/// one part even when it contains dots. Returns `None` for an empty part or a
+/// malformed quoted part.
+pub fn split_collation_name(name: &str) -> Option<Vec<String>> {
+    let mut parts = Vec::new();
+    let mut rest = name;
+    loop {
+        let part = if let Some(quoted) = rest.strip_prefix('"') {
+            let mut part = String::new();
+            let mut chars = quoted.char_indices().peekable();
+            let end = loop {
+                match chars.next()? {
+                    (_, '"') if chars.peek().is_some_and(|&(_, c)| c == '"') => {
+                        chars.next();
+                        part.push('"');
+                    }
+                    (i, '"') => break i + 1,
+                    (_, c) => part.push(c),
+                }
+            };
+            rest = &quoted[end..];
+            part
+        } else {
+            let end = rest.find('.').unwrap_or(rest.len());
+            let part = rest[..end].to_string();
+            rest = &rest[end..];
+            part
+        };
+        if part.is_empty() {
+            return None;
+        }
+        parts.push(part);
+        if rest.is_empty() {
+            return Some(parts);
+        }
+        rest = rest.strip_prefix('.')?;
+    }
+}
+
 /// Escape a single identifier part (no dots).
 fn escape_single_identifier(name: &str) -> String {
     let lower = name.to_lowercase();
@@ -134,11 +176,66 @@ pub trait SqlGenerator {
         }
         parts.join(".")
     }
+    /// Generate array/range overlap expression.
+    /// Default implementation returns Postgres-compatible `col && value`.
+    fn array_overlap(&self, col: &str, value: &str) -> String {
+        format!("{} && {}", col, value)
+    }
+    /// Generate a time zone conversion.
+    /// Default implementation returns Postgres-compatible `(expr AT TIME ZONE zone)`.
+    fn at_time_zone(&self, expr: &str, zone: &str) -> String {
+        format!("({} AT TIME ZONE {})", expr, zone)
+    }
     /// Generate JSON/Array contains expression.
     /// Default implementation returns Postgres-compatible `col @> value`.
     fn json_contains(&self, col: &str, value: &str) -> String {
         format!("{} @> {}", col, value)
     }
+    /// Quote a collation name for `COLLATE`.
+    /// Default implementation always double-quotes each part from
+    /// [`split_collation_name`], since collation names such as `en_US` are
+    /// case-sensitive.
+    fn quote_collation(&self, name: &str) -> String {
+        split_collation_name(name)
+            .unwrap_or_else(|| vec![name.to_string()])
+            .iter()
+            .map(|part| format!("\"{}\"", part.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+    /// Whether the pg_trgm similarity operator `%` is available.
+    /// When false, similarity filters fall back to the fuzzy wildcard match.
+    fn supports_trigram_similarity(&self) -> bool {
+        true
+    }
+    /// Whether `to_tsvector(..) @@ websearch_to_tsquery(..)` full-text search
+    /// is available. When false, text-search filters fall back to the fuzzy
+    /// wildcard match over the same columns.
+    fn supports_full_text_search(&self) -> bool {
+        true
+    }
+    /// Whether `ALTER TABLE` accepts `ADD COLUMN IF NOT EXISTS` and
+    /// `DROP COLUMN IF EXISTS`.
+    fn supports_alter_column_if_exists(&self) -> bool {
+        true
+    }
+    /// Whether `JOIN ... USING (col)` is rendered as-is.
+    /// When false, a USING join directly after the source table is expanded
+    /// to `ON source.col = target.col`; later USING joins report an error.
+    fn supports_join_using(&self) -> bool {
+        true
+    }
+    /// Convert a value extracted as a bind parameter into the form this dialect binds.
+    /// Default implementation binds the value unchanged.
+    fn bind_value(&self, value: crate::ast::Value) -> crate::ast::Value {
+        value
+    }
+    /// Wrap a bound placeholder carrying a structured JSON value on the right
+    /// of a jsonb operator (`@>`, `<@`, `?`, `?|`, `?&`).
+    /// Default implementation returns Postgres-compatible `$n::jsonb`.
+    fn json_placeholder(&self, placeholder: &str) -> String {
+        format!("{}::jsonb", placeholder)
+    }
     /// Generate JSON key exists expression.
     /// Default implementation returns Postgres-compatible `col ? 'key'`.
     fn json_key_exists(&self, col: &str, key: &str) -> String {
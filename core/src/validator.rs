@@ -4,6 +4,7 @@
 //! Used by CLI, LSP, and the encoder to catch errors before they hit the wire.
 
 use crate::ast::{Expr, Qail};
+use crate::error::{QailError, QailResult};
 use std::collections::HashMap;
 use strsim::levenshtein;
 
@@ -46,6 +47,13 @@ pub enum ValidationError {
         /// Explanation.
         reason: String,
     },
+    /// Unqualified column defined by more than one table of a join.
+    AmbiguousColumn {
+        /// Column name.
+        column: String,
+        /// Tables that define the column.
+        candidates: Vec<String>,
+    },
 }
 
 impl std::fmt::Display for ValidationError {
@@ -96,6 +104,14 @@ impl std::fmt::Display for ValidationError {
                     operator, column, reason
                 )
             }
+            ValidationError::AmbiguousColumn { column, candidates } => {
+                write!(
+                    f,
+                    "Column '{}' is ambiguous; it exists in {}",
+                    column,
+                    candidates.join(", ")
+                )
+            }
         }
     }
 }
@@ -334,7 +350,7 @@ impl Validator {
             Value::String(_) => "TEXT",
             Value::Uuid(_) => "UUID",
             Value::Column(_) => return Ok(()), // Column reference, type checked elsewhere
-            Value::Interval { .. } => "INTERVAL",
+            Value::Interval { .. } | Value::IntervalParts { .. } => "INTERVAL",
             Value::Timestamp(_) => "TIMESTAMP",
             Value::Bytes(_) => "BYTEA",
             Value::Vector(_) => "VECTOR",
@@ -525,7 +541,12 @@ impl Validator {
         }
 
         for join in &cmd.joins {
-            if let Err(e) = self.validate_table(&join.table) {
+            // A derived-table join names an alias, not a table; validate its query instead.
+            if let Some(subquery) = &join.subquery {
+                if let Err(sub_errors) = self.validate_command(subquery) {
+                    errors.extend(sub_errors);
+                }
+            } else if let Err(e) = self.validate_table(&join.table) {
                 errors.push(e);
             }
 
@@ -566,6 +587,12 @@ impl Validator {
             }
         }
 
+        if let Err(QailError::AmbiguousColumn { column, candidates }) =
+            self.check_ambiguous_columns(cmd)
+        {
+            errors.push(ValidationError::AmbiguousColumn { column, candidates });
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -573,6 +600,75 @@ impl Validator {
         }
     }
 
+    /// Reject unqualified column references that more than one table of a
+    /// joined query defines.
+    ///
+    /// Checks the select list, filters, sorts and `HAVING`. Sort keys matching
+    /// a select-list alias are skipped, since only `ORDER BY` sees output
+    /// aliases. Tables unknown to this validator are skipped too, so queries
+    /// without a schema pass.
+    pub fn check_ambiguous_columns(&self, cmd: &Qail) -> QailResult<()> {
+        if cmd.joins.is_empty() {
+            return Ok(());
+        }
+        let tables: Vec<&str> = std::iter::once(cmd.table.as_str())
+            .chain(cmd.joins.iter().map(|join| join.table.as_str()))
+            .filter_map(|table| table.split_whitespace().next())
+            .collect();
+        let aliases: Vec<&str> = cmd
+            .columns
+            .iter()
+            .filter_map(|col| match col {
+                Expr::Aliased { alias, .. } => Some(alias.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        // Output aliases are visible only to ORDER BY.
+        let conditions = cmd
+            .cages
+            .iter()
+            .flat_map(|cage| {
+                let is_sort = matches!(cage.kind, crate::ast::CageKind::Sort(_));
+                cage.conditions
+                    .iter()
+                    .map(move |cond| (&cond.left, is_sort))
+            })
+            .chain(cmd.having.iter().map(|cond| (&cond.left, false)));
+        let names = cmd
+            .columns
+            .iter()
+            .map(|col| (col, false))
+            .chain(conditions)
+            .filter_map(|(expr, is_sort)| Some((Self::extract_column_name(expr)?, is_sort)));
+
+        for (name, is_sort) in names {
+            if name.contains('.')
+                || name.contains('(')
+                || name == "*"
+                || (is_sort && aliases.contains(&name.as_str()))
+            {
+                continue;
+            }
+            let candidates: Vec<String> = tables
+                .iter()
+                .filter(|table| {
+                    self.columns
+                        .get(**table)
+                        .is_some_and(|cols| cols.contains(&name))
+                })
+                .map(|table| table.to_string())
+                .collect();
+            if candidates.len() > 1 {
+                return Err(QailError::AmbiguousColumn {
+                    column: name,
+                    candidates,
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Find the best match with Levenshtein distance within threshold.
     fn did_you_mean(&self, input: &str, candidates: &[impl AsRef<str>]) -> Option<String> {
         let mut best_match = None;
@@ -603,6 +699,75 @@ impl Validator {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ambiguous_unqualified_join_column() {
+        use crate::parser::parse;
+
+        let mut v = Validator::new();
+        v.add_table("users", &["id", "name", "team_id"]);
+        v.add_table("teams", &["id", "name"]);
+
+        let cmd =
+            parse("get users inner join teams on users.team_id = teams.id fields name").unwrap();
+        let err = v.check_ambiguous_columns(&cmd).unwrap_err();
+        assert!(matches!(
+            err,
+            QailError::AmbiguousColumn { ref column, ref candidates }
+                if column == "name" && candidates == &["users", "teams"]
+        ));
+
+        let cmd = parse(
+            "get users inner join teams on users.team_id = teams.id fields users.name, team_id where teams.name = 'core'",
+        )
+        .unwrap();
+        assert!(v.check_ambiguous_columns(&cmd).is_ok());
+
+        // Unknown tables (no schema) are skipped.
+        let cmd = parse("get a inner join b on a.id = b.a_id fields name").unwrap();
+        assert!(Validator::new().check_ambiguous_columns(&cmd).is_ok());
+    }
+
+    #[test]
+    fn test_validate_command_reports_ambiguous_columns() {
+        use crate::ast::{Expr, JoinKind, Operator, Qail, Value};
+
+        let mut v = Validator::new();
+        v.add_table("users", &["id", "name", "team_id"]);
+        v.add_table("teams", &["id", "name"]);
+
+        let cmd = Qail::get("users")
+            .join(JoinKind::Inner, "teams", "users.team_id", "teams.id")
+            .column("name");
+        let errors = v.validate_command(&cmd).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::AmbiguousColumn { column, candidates }
+                if column == "name" && candidates == &["users", "teams"]
+        )));
+
+        // WHERE does not see output aliases, so the filter stays ambiguous.
+        let alias = Expr::Aliased {
+            name: "teams.name".into(),
+            alias: "name".into(),
+        };
+        let mut cmd = Qail::get("users")
+            .join(JoinKind::Inner, "teams", "users.team_id", "teams.id")
+            .filter("name", Operator::Eq, Value::String("core".into()));
+        cmd.columns.push(alias.clone());
+        let errors = v.validate_command(&cmd).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::AmbiguousColumn { column, .. } if column == "name"
+        )));
+
+        // ORDER BY resolves the alias first.
+        let mut cmd = Qail::get("users")
+            .join(JoinKind::Inner, "teams", "users.team_id", "teams.id")
+            .order_asc("name");
+        cmd.columns.push(alias);
+        assert!(v.validate_command(&cmd).is_ok());
+    }
+
     #[test]
     fn test_did_you_mean_table() {
         let mut v = Validator::new();
@@ -663,6 +828,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_derived_join_checks_subquery_not_alias() {
+        let mut v = Validator::new();
+        v.add_table("users", &["id", "name"]);
+        v.add_table("orders", &["id", "user_id", "total"]);
+
+        let recent = Qail::get("orders").columns(["total"]).limit(3);
+        let cmd = Qail::get("users")
+            .columns(["name", "recent.total"])
+            .left_join_lateral(recent, "recent");
+        assert!(v.validate_command(&cmd).is_ok());
+
+        let typo = Qail::get("orders").columns(["totl"]);
+        let cmd = Qail::get("users").left_join_lateral(typo, "recent");
+        let errors = v.validate_command(&cmd).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            matches!(&errors[0], ValidationError::ColumnNotFound { column, .. } if column == "totl")
+        );
+    }
+
     #[test]
     fn test_validate_having_columns() {
         let mut v = Validator::new();
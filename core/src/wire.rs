@@ -238,6 +238,17 @@ fn validate_qail_limits(cmd: &Qail, depth: usize, state: &mut AstLimitState) ->
     for expr in &cmd.columns {
         validate_expr_limits(expr, depth + 1, state)?;
     }
+    if let Some(function) = &cmd.table_function {
+        validate_expr_limits(function, depth + 1, state)?;
+    }
+    ensure_len(
+        "qail.from_functions",
+        cmd.from_functions.len(),
+        MAX_AST_COLLECTION_LEN,
+    )?;
+    for (function, _) in &cmd.from_functions {
+        validate_expr_limits(function, depth + 1, state)?;
+    }
 
     ensure_len("qail.joins", cmd.joins.len(), MAX_AST_COLLECTION_LEN)?;
     for join in &cmd.joins {
@@ -378,6 +389,14 @@ fn validate_qail_limits(cmd: &Qail, depth: usize, state: &mut AstLimitState) ->
         for col in &on_conflict.columns {
             ensure_str("qail.on_conflict.column", col)?;
         }
+        ensure_len(
+            "qail.on_conflict.predicate",
+            on_conflict.predicate.len(),
+            MAX_AST_COLLECTION_LEN,
+        )?;
+        for cond in &on_conflict.predicate {
+            validate_condition_limits(cond, depth + 1, state)?;
+        }
         if let Some(assignments) = on_conflict.action.update_assignments() {
             ensure_len(
                 "qail.on_conflict.assignments",
@@ -475,6 +494,9 @@ fn validate_qail_limits(cmd: &Qail, depth: usize, state: &mut AstLimitState) ->
     if let Some(savepoint_name) = &cmd.savepoint_name {
         ensure_str("qail.savepoint_name", savepoint_name)?;
     }
+    if let Some(doc) = &cmd.doc {
+        ensure_str("qail.doc", doc)?;
+    }
 
     ensure_len(
         "qail.from_tables",
@@ -527,12 +549,19 @@ fn validate_join_limits(
     ensure_depth(depth, "Join")?;
     state.bump("Join")?;
     ensure_str("join.table", &join.table)?;
+    ensure_len("join.using", join.using.len(), MAX_AST_COLLECTION_LEN)?;
+    for column in &join.using {
+        ensure_str("join.using", column)?;
+    }
     if let Some(on) = &join.on {
         ensure_len("join.on", on.len(), MAX_AST_COLLECTION_LEN)?;
         for cond in on {
             validate_condition_limits(cond, depth + 1, state)?;
         }
     }
+    if let Some(subquery) = &join.subquery {
+        validate_qail_limits(subquery, depth + 1, state)?;
+    }
     Ok(())
 }
 
@@ -829,6 +858,12 @@ fn validate_value_limits(
         | Value::Column(v)
         | Value::Timestamp(v)
         | Value::Json(v) => ensure_str("value.string", v)?,
+        Value::BigInt(v) => {
+            ensure_str("value.big_int", v)?;
+            if !crate::ast::values::is_integer_literal(v) {
+                return Err("value.big_int must be a decimal integer".to_string());
+            }
+        }
         Value::Array(values) => {
             ensure_len("value.array", values.len(), MAX_AST_COLLECTION_LEN)?;
             for v in values {
@@ -836,7 +871,8 @@ fn validate_value_limits(
             }
         }
         Value::Subquery(q) => validate_qail_limits(q, depth + 1, state)?,
-        Value::Uuid(_) | Value::NullUuid | Value::Interval { .. } => {}
+        Value::Uuid(_) | Value::NullUuid | Value::Interval { .. } | Value::IntervalParts { .. } => {
+        }
         Value::Bytes(bytes) => ensure_len("value.bytes", bytes.len(), MAX_AST_BINARY_VALUE_LEN)?,
         Value::Expr(expr) => validate_expr_limits(expr, depth + 1, state)?,
         Value::Vector(values) => ensure_len("value.vector", values.len(), MAX_AST_VECTOR_LEN)?,
@@ -1557,6 +1557,8 @@ fn combined_join_injection() {
                 Value::Column("users; DROP TABLE orders.id".to_string()),
             )]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         }],
         ..Default::default()
     };
@@ -331,6 +331,8 @@ fn select_with_inner_join() {
                 Value::Column("users.id".to_string()),
             )]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         }],
         ..Default::default()
     };
@@ -354,6 +356,8 @@ fn select_with_left_join() {
                 Value::Column("payments.order_id".to_string()),
             )]),
             on_true: false,
+            using: Vec::new(),
+            subquery: None,
         }],
         ..Default::default()
     };
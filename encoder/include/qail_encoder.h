@@ -22,8 +22,23 @@ typedef struct QailResponse QailResponse;
 const char *qail_version(void);
 
 char *qail_transpile(const char *qail);
+
+/* dialect: 0 = PostgreSQL, 2 = SQLite. Other codes return NULL. */
+char *qail_transpile_dialect(const char *qail, int32_t dialect);
 int32_t qail_validate(const char *qail);
 
+/*
+ * Returns 0 if valid, 1 if invalid. On failure out_line/out_col receive the
+ * 1-based position of the error and *out_msg_ptr a message to free with
+ * qail_free.
+ */
+int32_t qail_validate_detailed(
+    const char *qail,
+    size_t *out_line,
+    size_t *out_col,
+    char **out_msg_ptr
+);
+
 int32_t qail_encode_get(
     const char *table,
     const char *columns,
@@ -41,6 +56,19 @@ int32_t qail_encode_uniform_batch(
     size_t *out_len
 );
 
+/*
+ * columns is comma-separated and must name exactly count columns. values must
+ * point to an array with at least count entries. Null entries insert SQL NULL.
+ */
+int32_t qail_encode_insert(
+    const char *table,
+    const char *columns,
+    const char *const *values,
+    size_t count,
+    uint8_t **out_ptr,
+    size_t *out_len
+);
+
 void qail_free(char *ptr);
 void qail_free_bytes(uint8_t *ptr, size_t len);
 
@@ -9,8 +9,9 @@
 //!
 //! ## Features
 //!
-//! - **Transpiler** — QAIL text → SQL string (`qail_transpile`, `qail_validate`)
-//! - **Simple Query** — AST → PostgreSQL `'Q'` message bytes (`qail_encode_get`)
+//! - **Transpiler** — QAIL text → SQL string (`qail_transpile`, `qail_transpile_dialect`, `qail_validate`, `qail_validate_detailed`)
+//! - **Simple Query** — AST → PostgreSQL `'Q'` message bytes (`qail_encode_get`, `qail_encode_insert`)
+//! - **Safe Rust API** — `encode_get_into` / `encode_cmd_into` append to a `Vec<u8>`
 //! - **Extended Query Protocol** — `Parse`/`Bind`/`Execute`/`Sync` message encoding
 //! - **Pipeline batching** — uniform batch + Bind/Execute batch for prepared statements
 //! - **Response parsing** — decode `DataRow`, `CommandComplete`, `ErrorResponse` (feature-gated: `response`)
@@ -22,7 +23,7 @@
 //! - Caller-owned memory with explicit `qail_free` / `qail_free_bytes` deallocation
 //! - Thread-local error reporting via `qail_last_error()`
 
-use qail_core::transpiler::ToSql;
+use qail_core::transpiler::{Dialect, ToSql};
 use std::cell::RefCell;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
@@ -232,9 +233,39 @@ pub extern "C" fn qail_version() -> *const c_char {
 /// `qail` must be a valid, NUL-terminated C string pointer.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn qail_transpile(qail: *const c_char) -> *mut c_char {
+    // SAFETY: same contract as `qail_transpile_dialect`, forwarded unchanged.
+    unsafe { qail_transpile_dialect(qail, 0) }
+}
+
+/// Transpile QAIL text to SQL for a specific dialect.
+/// `dialect` is 0 for PostgreSQL or 2 for SQLite; 1 is reserved for MySQL,
+/// which the transpiler does not support.
+/// Returns NULL on error, including an unknown dialect code.
+/// Caller must free with qail_free().
+///
+/// # Safety
+///
+/// `qail` must be a valid, NUL-terminated C string pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qail_transpile_dialect(qail: *const c_char, dialect: i32) -> *mut c_char {
     ffi_catch!(std::ptr::null_mut(), {
         clear_error();
 
+        let dialect = match dialect {
+            0 => Dialect::Postgres,
+            2 => Dialect::SQLite,
+            1 => {
+                set_error("Unsupported dialect 1 (MySQL)".to_string());
+                return std::ptr::null_mut();
+            }
+            other => {
+                set_error(format!(
+                    "Unknown dialect code {other}: use 0 (Postgres) or 2 (SQLite)"
+                ));
+                return std::ptr::null_mut();
+            }
+        };
+
         if qail.is_null() {
             set_error("NULL input".to_string());
             return std::ptr::null_mut();
@@ -242,8 +273,7 @@ pub unsafe extern "C" fn qail_transpile(qail: *const c_char) -> *mut c_char {
 
         // SAFETY: `qail` is checked non-null above and the caller contract
         // requires it to point to a valid NUL-terminated C string.
-        let c_str = unsafe { CStr::from_ptr(qail) };
-        let qail_str = match c_str.to_str() {
+        let qail_str = match unsafe { CStr::from_ptr(qail) }.to_str() {
             Ok(s) => s,
             Err(e) => {
                 set_error(format!("Invalid UTF-8: {}", e));
@@ -252,16 +282,13 @@ pub unsafe extern "C" fn qail_transpile(qail: *const c_char) -> *mut c_char {
         };
 
         match qail_core::parse(qail_str) {
-            Ok(cmd) => {
-                let sql = cmd.to_sql();
-                match CString::new(sql) {
-                    Ok(c_string) => c_string.into_raw(),
-                    Err(e) => {
-                        set_error(format!("NUL byte in output: {}", e));
-                        std::ptr::null_mut()
-                    }
+            Ok(cmd) => match CString::new(cmd.to_sql_with_dialect(dialect)) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(e) => {
+                    set_error(format!("NUL byte in output: {}", e));
+                    std::ptr::null_mut()
                 }
-            }
+            },
             Err(e) => {
                 set_error(format!("{:?}", e));
                 std::ptr::null_mut()
@@ -306,6 +333,85 @@ pub unsafe extern "C" fn qail_validate(qail: *const c_char) -> i32 {
     })
 }
 
+/// Validate QAIL syntax and report where it fails.
+/// Returns 0 if valid, 1 if invalid, negative on bad arguments.
+///
+/// On failure, `out_line` / `out_col` receive the 1-based line and column
+/// (in characters) of the parse error and `out_msg_ptr` an error message the
+/// caller must free with qail_free(). On success they are set to 0, 0, NULL.
+///
+/// # Safety
+///
+/// `qail` must be a valid, NUL-terminated C string pointer.
+/// `out_line`, `out_col` and `out_msg_ptr` must be valid writable pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qail_validate_detailed(
+    qail: *const c_char,
+    out_line: *mut usize,
+    out_col: *mut usize,
+    out_msg_ptr: *mut *mut c_char,
+) -> i32 {
+    ffi_catch!(-99, {
+        clear_error();
+
+        if out_line.is_null() || out_col.is_null() || out_msg_ptr.is_null() {
+            set_error("NULL pointer argument".to_string());
+            return -1;
+        }
+        // SAFETY: The out pointers were checked non-null and are required by
+        // the FFI contract to be writable.
+        unsafe {
+            *out_line = 0;
+            *out_col = 0;
+            *out_msg_ptr = std::ptr::null_mut();
+        }
+        if qail.is_null() {
+            set_error("NULL input".to_string());
+            return -1;
+        }
+
+        // SAFETY: `qail` is checked non-null above and the caller contract
+        // requires it to point to a valid NUL-terminated C string.
+        let qail_str = match unsafe { CStr::from_ptr(qail) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_error(format!("Invalid UTF-8: {}", e));
+                return -2;
+            }
+        };
+
+        let err = match qail_core::parse(qail_str) {
+            Ok(_) => return 0,
+            Err(e) => e,
+        };
+        let (line, col) = line_col(qail_str, err.position().unwrap_or(0));
+        let message = CString::new(err.to_string().replace('\0', "\\0")).unwrap_or_default();
+        set_error(err.to_string());
+
+        // SAFETY: The out pointers were checked non-null above.
+        unsafe {
+            *out_line = line;
+            *out_col = col;
+            *out_msg_ptr = message.into_raw();
+        }
+        1
+    })
+}
+
+/// 1-based line and character column of byte `offset` in `source`.
+fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
 // ============================================================================
 // Wire Protocol Encoding
 // ============================================================================
@@ -502,18 +608,17 @@ pub unsafe extern "C" fn qail_encode_uniform_batch(
             return -5;
         }
 
-        // Encode SQL once, repeat count times
+        // Encode SQL once straight into the output buffer, then repeat that
+        // frame in place — a single allocation for the whole batch.
         let sql = base_cmd.to_sql();
-        let single_query = match encode_simple_query(&sql) {
-            Ok(bytes) => bytes,
+        let frame_len = match simple_query_wire_len(&sql) {
+            Ok(len) => len,
             Err(e) => {
                 set_error(e);
                 return -4;
             }
         };
-
-        // Batch: repeat the query `count` times
-        let batch_len = match checked_batch_capacity(single_query.len(), count, "uniform query") {
+        let batch_len = match checked_batch_capacity(frame_len, count, "uniform query") {
             Ok(len) => len,
             Err(e) => {
                 set_error(e);
@@ -521,8 +626,12 @@ pub unsafe extern "C" fn qail_encode_uniform_batch(
             }
         };
         let mut batch_bytes = Vec::with_capacity(batch_len);
-        for _ in 0..count {
-            batch_bytes.extend_from_slice(&single_query);
+        if let Err(e) = encode_simple_query_into(&mut batch_bytes, &sql) {
+            set_error(e);
+            return -4;
+        }
+        for _ in 1..count {
+            batch_bytes.extend_from_within(..frame_len);
         }
 
         let len = batch_bytes.len();
@@ -541,6 +650,129 @@ pub unsafe extern "C" fn qail_encode_uniform_batch(
     })
 }
 
+/// Encode a single-row INSERT to PostgreSQL wire protocol bytes.
+/// Returns 0 on success, non-zero on error.
+/// Caller must free with qail_free_bytes().
+///
+/// `columns` is comma-separated and must name exactly `count` columns;
+/// `values` holds one text value per column, and a NULL entry inserts SQL
+/// NULL. Text values are sent as quoted literals and coerced by the server.
+///
+/// # Safety
+///
+/// `table` and `columns` must be valid, NUL-terminated C strings.
+/// `values` must point to an array of at least `count` entries, each NULL or
+/// a valid, NUL-terminated C string.
+/// `out_ptr` and `out_len` must be valid writable pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qail_encode_insert(
+    table: *const c_char,
+    columns: *const c_char,       // comma-separated
+    values: *const *const c_char, // NULL entry = SQL NULL
+    count: usize,                 // number of values
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    ffi_catch!(-99, {
+        clear_error();
+
+        if out_ptr.is_null() || out_len.is_null() {
+            set_error("NULL pointer argument".to_string());
+            return -1;
+        }
+        // SAFETY: `out_ptr` and `out_len` were checked non-null and are
+        // required by the FFI contract to be writable output pointers.
+        unsafe { clear_byte_output(out_ptr, out_len) };
+        if table.is_null() || columns.is_null() || values.is_null() || count == 0 {
+            set_error("NULL pointer or zero count".to_string());
+            return -1;
+        }
+
+        // SAFETY: `table` is checked non-null above and the caller contract
+        // requires it to point to a valid NUL-terminated C string.
+        let table_str = match unsafe { CStr::from_ptr(table) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_error(format!("Invalid UTF-8 in table: {}", e));
+                return -2;
+            }
+        };
+
+        // SAFETY: `columns` is checked non-null above and the caller contract
+        // requires it to point to a valid NUL-terminated C string.
+        let cols_str = match unsafe { CStr::from_ptr(columns) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_error(format!("Invalid UTF-8 in columns: {}", e));
+                return -3;
+            }
+        };
+        let cols: Vec<&str> = cols_str
+            .split(',')
+            .map(str::trim)
+            .filter(|col| !col.is_empty())
+            .collect();
+        if cols.len() != count {
+            set_error(format!(
+                "Column/value count mismatch: {} columns, {} values",
+                cols.len(),
+                count
+            ));
+            return -6;
+        }
+
+        let mut cmd = qail_core::ast::Qail::add(table_str).columns(&cols);
+        for (i, col) in cols.iter().enumerate() {
+            // SAFETY: The caller contract requires `values` to point to an
+            // array containing at least `count` entries.
+            let v = unsafe { *values.add(i) };
+            let value = if v.is_null() {
+                qail_core::ast::Value::Null
+            } else {
+                // SAFETY: Non-null value entries are expected to point to
+                // valid NUL-terminated C strings.
+                match unsafe { CStr::from_ptr(v) }.to_str() {
+                    Ok(s) => qail_core::ast::Value::String(s.to_string()),
+                    Err(e) => {
+                        set_error(format!("Invalid UTF-8 in value {i}: {e}"));
+                        return -3;
+                    }
+                }
+            };
+            cmd = cmd.set_value(col, value);
+        }
+
+        if let Err(e) = validate_ffi_ast(&cmd) {
+            set_error(e);
+            return -5;
+        }
+
+        let sql = cmd.to_sql();
+        let wire_bytes = match encode_simple_query(&sql) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                set_error(e);
+                return -4;
+            }
+        };
+        let len = wire_bytes.len();
+
+        // Transfer ownership to caller
+        let mut boxed = wire_bytes.into_boxed_slice();
+        let ptr = boxed.as_mut_ptr();
+        std::mem::forget(boxed);
+
+        // SAFETY: `out_ptr` and `out_len` are checked non-null above and
+        // the caller contract requires them to be writable.
+        unsafe {
+            *out_ptr = ptr;
+            *out_len = len;
+        }
+
+        0
+    })
+}
+
 // ============================================================================
 // Memory Management
 // ============================================================================
@@ -600,6 +832,53 @@ pub extern "C" fn qail_last_error() -> *const c_char {
     })
 }
 
+// ============================================================================
+// Safe Rust API
+// ============================================================================
+
+/// Append the Simple Query bytes for a SELECT to `buf`.
+///
+/// Safe counterpart of [`qail_encode_get`]: an empty `columns` slice (or a
+/// lone `"*"`) selects all columns, `None` means no limit. Existing bytes in
+/// `buf` are preserved, and `buf` is unchanged on error.
+pub fn encode_get_into(
+    table: &str,
+    columns: &[&str],
+    limit: Option<i64>,
+    buf: &mut Vec<u8>,
+) -> Result<(), String> {
+    if limit.is_some_and(|n| n < 0) {
+        return Err("Invalid limit: must be non-negative".to_string());
+    }
+
+    let mut cmd = qail_core::ast::Qail::get(table);
+    if columns.is_empty() || matches!(columns, [col] if col.trim() == "*") {
+        cmd = cmd.select_all();
+    } else {
+        for col in columns {
+            let col = col.trim();
+            if !col.is_empty() {
+                cmd = cmd.column(col);
+            }
+        }
+    }
+    if let Some(limit) = limit {
+        cmd = cmd.limit(limit);
+    }
+
+    encode_cmd_into(&cmd, buf)
+}
+
+/// Validate `cmd` and append its Simple Query bytes to `buf`.
+///
+/// Existing bytes in `buf` are preserved, and `buf` is unchanged on error.
+pub fn encode_cmd_into(cmd: &qail_core::ast::Qail, buf: &mut Vec<u8>) -> Result<(), String> {
+    validate_ffi_ast(cmd)?;
+    let sql = cmd.to_sql();
+    buf.reserve(simple_query_wire_len(&sql)?);
+    encode_simple_query_into(buf, &sql)
+}
+
 // ============================================================================
 // Internal: Simple Query Encoding
 // ============================================================================
@@ -607,6 +886,23 @@ pub extern "C" fn qail_last_error() -> *const c_char {
 /// Encode a SQL string as PostgreSQL Simple Query message.
 /// Format: 'Q' + int32 length + query string + '\0'
 fn encode_simple_query(sql: &str) -> Result<Vec<u8>, String> {
+    let mut buf = Vec::with_capacity(simple_query_wire_len(sql)?);
+    encode_simple_query_into(&mut buf, sql)?;
+    Ok(buf)
+}
+
+/// Total wire size of a Simple Query message for `sql`, including the type byte.
+fn simple_query_wire_len(sql: &str) -> Result<usize, String> {
+    let content_len = sql
+        .len()
+        .checked_add(1)
+        .ok_or_else(|| "Simple query message size overflow".to_string())?;
+    let msg_len = checked_frontend_message_len(content_len, "Simple query")?;
+    Ok(1 + msg_len as usize)
+}
+
+/// Append a Simple Query message to `buf` without clearing it.
+fn encode_simple_query_into(buf: &mut Vec<u8>, sql: &str) -> Result<(), String> {
     let sql_bytes = sql.as_bytes();
     let content_len = sql_bytes
         .len()
@@ -614,13 +910,12 @@ fn encode_simple_query(sql: &str) -> Result<Vec<u8>, String> {
         .ok_or_else(|| "Simple query message size overflow".to_string())?;
     let msg_len = checked_frontend_message_len(content_len, "Simple query")?;
 
-    let mut buf = Vec::with_capacity(1 + msg_len as usize);
     buf.push(b'Q'); // Message type
     buf.extend_from_slice(&msg_len.to_be_bytes()); // Length (big-endian)
     buf.extend_from_slice(sql_bytes); // Query
     buf.push(0); // Null terminator
 
-    Ok(buf)
+    Ok(())
 }
 
 // ============================================================================
@@ -1840,9 +2135,12 @@ mod tests {
             vec![
                 "qail_version",
                 "qail_transpile",
+                "qail_transpile_dialect",
                 "qail_validate",
+                "qail_validate_detailed",
                 "qail_encode_get",
                 "qail_encode_uniform_batch",
+                "qail_encode_insert",
                 "qail_free",
                 "qail_free_bytes",
                 "qail_last_error",
@@ -2230,6 +2528,180 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_insert_two_columns() {
+        let table = CString::new("users").unwrap();
+        let columns = CString::new("name, email").unwrap();
+        let name = CString::new("O'Hara").unwrap();
+        let values = [name.as_ptr(), std::ptr::null()];
+        let mut out_ptr: *mut u8 = std::ptr::null_mut();
+        let mut out_len = 0usize;
+
+        let rc = unsafe {
+            qail_encode_insert(
+                table.as_ptr(),
+                columns.as_ptr(),
+                values.as_ptr(),
+                values.len(),
+                &mut out_ptr,
+                &mut out_len,
+            )
+        };
+        assert_eq!(rc, 0, "{}", last_error_string());
+        assert_last_error_clear();
+
+        let bytes = unsafe { std::slice::from_raw_parts(out_ptr, out_len) }.to_vec();
+        unsafe { qail_free_bytes(out_ptr, out_len) };
+
+        assert_eq!(bytes[0], b'Q');
+        assert_eq!(
+            i32::from_be_bytes(bytes[1..5].try_into().unwrap()) as usize,
+            bytes.len() - 1
+        );
+        assert_eq!(bytes.last(), Some(&0));
+        let sql = std::str::from_utf8(&bytes[5..bytes.len() - 1]).unwrap();
+        assert!(sql.contains("INSERT INTO"), "{sql}");
+        assert!(sql.contains("users"), "{sql}");
+        assert!(sql.contains("email"), "{sql}");
+        assert!(sql.contains("'O''Hara'"), "{sql}");
+        assert!(sql.contains("NULL"), "{sql}");
+    }
+
+    #[test]
+    fn test_encode_insert_rejects_count_mismatch() {
+        let table = CString::new("users").unwrap();
+        let columns = CString::new("name,email,age").unwrap();
+        let name = CString::new("Ana").unwrap();
+        let values = [name.as_ptr(), std::ptr::null()];
+        let mut out_ptr = std::ptr::dangling_mut::<u8>();
+        let mut out_len = usize::MAX;
+
+        let rc = unsafe {
+            qail_encode_insert(
+                table.as_ptr(),
+                columns.as_ptr(),
+                values.as_ptr(),
+                values.len(),
+                &mut out_ptr,
+                &mut out_len,
+            )
+        };
+
+        assert_eq!(rc, -6);
+        assert!(out_ptr.is_null());
+        assert_eq!(out_len, 0);
+        assert!(last_error_string().contains("3 columns, 2 values"));
+    }
+
+    #[test]
+    fn test_validate_detailed_reports_unterminated_bracket_offset() {
+        let ok = CString::new("get users fields id").unwrap();
+        let mut line = usize::MAX;
+        let mut col = usize::MAX;
+        let mut msg: *mut c_char = std::ptr::null_mut();
+        let rc = unsafe { qail_validate_detailed(ok.as_ptr(), &mut line, &mut col, &mut msg) };
+        assert_eq!(rc, 0);
+        assert_eq!((line, col), (0, 0));
+        assert!(msg.is_null());
+
+        let bad = CString::new("get orders\nfields id, total\nwhere total > 1 ]").unwrap();
+        let rc = unsafe { qail_validate_detailed(bad.as_ptr(), &mut line, &mut col, &mut msg) };
+        assert_eq!(rc, 1);
+        assert_eq!((line, col), (3, 17));
+        assert!(!msg.is_null());
+        let text = unsafe { CStr::from_ptr(msg) }.to_str().unwrap().to_string();
+        unsafe { qail_free(msg) };
+        assert!(text.contains("Parse error at position"), "{text}");
+        assert_eq!(last_error_string(), text);
+
+        let bad = CString::new("get users[active = true").unwrap();
+        let rc = unsafe { qail_validate_detailed(bad.as_ptr(), &mut line, &mut col, &mut msg) };
+        assert_eq!(rc, 1);
+        assert_eq!((line, col), (1, 10));
+        unsafe { qail_free(msg) };
+    }
+
+    #[test]
+    fn test_transpile_dialect_selects_quoting_and_rejects_unknown_codes() {
+        let qail = CString::new("get users fields id").unwrap();
+        let transpile = |dialect| {
+            let ptr = unsafe { qail_transpile_dialect(qail.as_ptr(), dialect) };
+            if ptr.is_null() {
+                return None;
+            }
+            let sql = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+            unsafe { qail_free(ptr) };
+            Some(sql)
+        };
+
+        assert_eq!(transpile(0).as_deref(), Some("SELECT id FROM users"));
+        assert_eq!(
+            transpile(2).as_deref(),
+            Some("SELECT \"id\" FROM \"users\"")
+        );
+        assert_last_error_clear();
+
+        assert_eq!(transpile(1), None);
+        assert!(last_error_string().contains("MySQL"));
+        assert_eq!(transpile(7), None);
+        assert!(last_error_string().contains("Unknown dialect code 7"));
+    }
+
+    fn ffi_encode_get(table: &str, columns: Option<&str>, limit: i64) -> Vec<u8> {
+        let table = CString::new(table).unwrap();
+        let columns = columns.map(|c| CString::new(c).unwrap());
+        let mut out_ptr: *mut u8 = std::ptr::null_mut();
+        let mut out_len = 0usize;
+
+        let rc = unsafe {
+            qail_encode_get(
+                table.as_ptr(),
+                columns.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                limit,
+                &mut out_ptr,
+                &mut out_len,
+            )
+        };
+        assert_eq!(rc, 0, "{}", last_error_string());
+
+        let bytes = unsafe { std::slice::from_raw_parts(out_ptr, out_len) }.to_vec();
+        unsafe { qail_free_bytes(out_ptr, out_len) };
+        bytes
+    }
+
+    #[test]
+    fn test_safe_encode_get_matches_ffi_bytes() {
+        let mut buf = Vec::new();
+        encode_get_into("users", &["id", "email"], Some(10), &mut buf).unwrap();
+        assert_eq!(buf, ffi_encode_get("users", Some("id,email"), 10));
+
+        buf.clear();
+        encode_get_into("users", &[], None, &mut buf).unwrap();
+        assert_eq!(buf, ffi_encode_get("users", None, -1));
+
+        buf.clear();
+        encode_get_into("users", &["*"], Some(0), &mut buf).unwrap();
+        assert_eq!(buf, ffi_encode_get("users", Some(" * "), 0));
+    }
+
+    #[test]
+    fn test_safe_encode_cmd_into_appends_and_preserves_on_error() {
+        let cmd = qail_core::ast::Qail::get("orders").column("total").limit(5);
+        let mut buf = b"prefix".to_vec();
+        encode_cmd_into(&cmd, &mut buf).unwrap();
+        assert_eq!(&buf[..6], b"prefix");
+        assert_eq!(&buf[6..], &ffi_encode_get("orders", Some("total"), 5)[..]);
+
+        let before = buf.clone();
+        let bad = qail_core::ast::Qail::get("users; DROP TABLE users; --");
+        let err = encode_cmd_into(&bad, &mut buf).unwrap_err();
+        assert!(err.contains("AST validation failed"));
+        assert_eq!(buf, before);
+
+        assert!(encode_get_into("users", &[], Some(-1), &mut buf).is_err());
+        assert_eq!(buf, before);
+    }
+
     #[test]
     fn test_uniform_batch_rejects_unsafe_table_identifier() {
         let table = CString::new("users; DROP TABLE users; --").unwrap();
@@ -2309,6 +2781,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_uniform_batch_repeats_single_frame() {
+        let table = CString::new("users").unwrap();
+        let columns = CString::new("id,email").unwrap();
+        let mut out_ptr: *mut u8 = std::ptr::null_mut();
+        let mut out_len = 0usize;
+
+        let rc = unsafe {
+            qail_encode_uniform_batch(
+                table.as_ptr(),
+                columns.as_ptr(),
+                10,
+                3,
+                &mut out_ptr,
+                &mut out_len,
+            )
+        };
+        assert_eq!(rc, 0);
+
+        let frame = encode_simple_query(
+            &qail_core::ast::Qail::get("users")
+                .column("id")
+                .column("email")
+                .limit(10)
+                .to_sql(),
+        )
+        .unwrap();
+        let bytes = unsafe { std::slice::from_raw_parts(out_ptr, out_len) };
+        assert_eq!(bytes.len(), frame.len() * 3);
+        for chunk in bytes.chunks(frame.len()) {
+            assert_eq!(chunk, &frame[..]);
+        }
+
+        unsafe {
+            qail_free_bytes(out_ptr, out_len);
+        }
+    }
+
     #[test]
     fn test_bind_execute_batch_rejects_invalid_statement_utf8() {
         let statement = b"\xff\0";
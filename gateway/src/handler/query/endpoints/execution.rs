@@ -363,6 +363,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cache_tables_and_complexity_include_join_subqueries() {
+        let recent = Qail::get("orders").eq("status", "paid").left_join(
+            "payments",
+            "orders.id",
+            "payments.order_id",
+        );
+        let cmd = Qail::get("users").left_join_lateral(recent, "recent");
+
+        assert_eq!(
+            cache_tables_for_qail(&cmd),
+            vec!["users", "orders", "payments"]
+        );
+        assert_eq!(query_complexity(&cmd), (1, 1, 2));
+        assert!(command_is_read_only_for_release(&cmd));
+
+        let cmd = Qail::get("users").left_join_lateral(Qail::del("orders"), "gone");
+        assert!(!command_is_read_only_for_release(&cmd));
+    }
+
     #[test]
     fn nested_mutating_cte_is_not_read_only_or_cacheable() {
         let cmd = Qail::get("audit_view").with("audit_view", Qail::add("audit_log"));
@@ -176,6 +176,9 @@ where
         validate_condition_subqueries(condition, validate)?;
     }
     for join in &cmd.joins {
+        if let Some(subquery) = &join.subquery {
+            validate(subquery)?;
+        }
         if let Some(conditions) = &join.on {
             for condition in conditions {
                 validate_condition_subqueries(condition, validate)?;
@@ -302,6 +305,11 @@ pub(crate) fn qail_command_is_read_only(cmd: &qail_core::ast::Qail) -> bool {
             .filter_map(|join| join.on.as_ref())
             .flatten()
             .all(condition_is_read_only)
+        && cmd
+            .joins
+            .iter()
+            .filter_map(|join| join.subquery.as_deref())
+            .all(qail_command_is_read_only)
         && cmd
             .on_conflict
             .as_ref()
@@ -478,6 +486,10 @@ pub(crate) fn query_complexity(cmd: &qail_core::ast::Qail) -> (usize, usize, usi
         );
     }
     for join in &cmd.joins {
+        if let Some(subquery) = &join.subquery {
+            let child = query_complexity(subquery);
+            add_complexity(&mut nested_complexity, (1 + child.0, child.1, child.2));
+        }
         if let Some(conditions) = &join.on {
             for condition in conditions {
                 add_complexity(
@@ -610,7 +622,9 @@ pub(crate) fn cache_tables_for_qail(cmd: &qail_core::ast::Qail) -> Vec<String> {
 
         for join in &cmd.joins {
             let join_table = qail_table_name(&join.table);
-            if !cte_names.iter().any(|name| *name == join_table) {
+            if let Some(ref subquery) = join.subquery {
+                collect(subquery, tables);
+            } else if !cte_names.iter().any(|name| *name == join_table) {
                 push_table(tables, &join.table);
             }
             if let Some(conditions) = &join.on {
@@ -196,6 +196,11 @@ pub(super) fn reject_ddl_in_transaction(cmd: &Qail) -> Result<(), ApiError> {
     for (_, set_query) in &cmd.set_ops {
         reject_ddl_in_transaction(set_query)?;
     }
+    for join in &cmd.joins {
+        if let Some(query) = &join.subquery {
+            reject_ddl_in_transaction(query)?;
+        }
+    }
     reject_embedded_subqueries(cmd)?;
 
     Ok(())
@@ -72,6 +72,9 @@ fn test_reject_ddl_blocks_nested_unsupported_actions() {
         .push((SetOp::UnionAll, Box::new(Qail::export("archived_orders"))));
     assert!(reject_ddl_in_transaction(&cmd).is_err());
 
+    let cmd = Qail::get("users").inner_join_lateral(Qail::export("orders"), "o");
+    assert!(reject_ddl_in_transaction(&cmd).is_err());
+
     let mut cmd = Qail::get("orders");
     cmd.columns.push(Expr::Subquery {
         query: Box::new(Qail::export("orders")),
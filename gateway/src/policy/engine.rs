@@ -145,8 +145,26 @@ impl PolicyEngine {
         join: &mut Join,
         filter: Condition,
     ) -> Result<(), GatewayError> {
+        // USING joins cannot carry ON conditions. For inner joins the filter
+        // is equivalent in WHERE; outer joins would change meaning there.
+        if !join.using.is_empty() {
+            return match join.kind {
+                JoinKind::Inner => {
+                    cmd.cages.push(Cage {
+                        kind: CageKind::Filter,
+                        conditions: vec![filter],
+                        logical_op: LogicalOp::And,
+                    });
+                    Ok(())
+                }
+                _ => Err(GatewayError::AccessDenied(format!(
+                    "Policy filters cannot be safely enforced on joined table '{}' through a {:?} USING join",
+                    join.table, join.kind
+                ))),
+            };
+        }
         match join.kind {
-            JoinKind::Inner | JoinKind::Left | JoinKind::Lateral => {
+            JoinKind::Inner | JoinKind::Left | JoinKind::Lateral | JoinKind::LateralInner => {
                 join.on_true = false;
                 join.on.get_or_insert_with(Vec::new).push(filter);
                 Ok(())
@@ -184,7 +202,11 @@ impl PolicyEngine {
         let mut rewritten_joins = Vec::with_capacity(cmd.joins.len());
         for mut join in std::mem::take(&mut cmd.joins) {
             let (join_table, qualifier) = Self::table_ref_name_and_qualifier(&join.table);
-            if join_table.is_empty() || cte_names.iter().any(|name| name == &join_table) {
+            // Derived-table joins are policed inside the subquery itself.
+            if join.subquery.is_some()
+                || join_table.is_empty()
+                || cte_names.iter().any(|name| name == &join_table)
+            {
                 rewritten_joins.push(join);
                 continue;
             }
@@ -1396,33 +1418,44 @@ impl PolicyEngine {
             );
         }
 
-        let payload_idx = cmd
+        // Multi-row inserts carry one payload cage per row; every row is constrained.
+        let payload_idxs: Vec<usize> = cmd
             .cages
             .iter()
-            .position(|cage| matches!(cage.kind, CageKind::Payload));
+            .enumerate()
+            .filter(|(_, cage)| matches!(cage.kind, CageKind::Payload))
+            .map(|(idx, _)| idx)
+            .collect();
 
-        let Some(idx) = payload_idx else {
+        if payload_idxs.is_empty() {
             cmd.cages.push(Cage {
                 kind: CageKind::Payload,
                 conditions: vec![Self::payload_condition(column, condition.value.clone())],
                 logical_op: LogicalOp::And,
             });
             return Ok(());
-        };
+        }
 
-        if Self::payload_is_positional(&cmd.cages[idx]) {
-            if cmd.columns.is_empty() {
-                return Err(GatewayError::AccessDenied(format!(
-                    "Create policy filter on '{}' requires explicit columns for positional INSERT payloads",
-                    column
-                )));
-            }
+        for idx in payload_idxs {
+            if Self::payload_is_positional(&cmd.cages[idx]) {
+                if cmd.columns.is_empty() {
+                    return Err(GatewayError::AccessDenied(format!(
+                        "Create policy filter on '{}' requires explicit columns for positional INSERT payloads",
+                        column
+                    )));
+                }
 
-            if let Some(col_idx) = cmd
-                .columns
-                .iter()
-                .position(|expr| Self::expr_named_eq(expr, &column))
-            {
+                let col_idx = match cmd
+                    .columns
+                    .iter()
+                    .position(|expr| Self::expr_named_eq(expr, &column))
+                {
+                    Some(col_idx) => col_idx,
+                    None => {
+                        cmd.columns.push(Expr::Named(column.clone()));
+                        cmd.columns.len() - 1
+                    }
+                };
                 let placeholder = format!("${}", col_idx + 1);
                 let cage = &mut cmd.cages[idx];
                 if let Some(cond) = cage
@@ -1437,23 +1470,17 @@ impl PolicyEngine {
                         condition.value.clone(),
                     ));
                 }
-                return Ok(());
+                continue;
             }
 
-            cmd.columns.push(Expr::Named(column));
-            let col_idx = cmd.columns.len() - 1;
-            cmd.cages[idx].conditions.push(Self::payload_condition(
-                format!("${}", col_idx + 1),
+            let cage = &mut cmd.cages[idx];
+            cage.conditions
+                .retain(|cond| !Self::expr_named_eq(&cond.left, &column));
+            cage.conditions.push(Self::payload_condition(
+                column.clone(),
                 condition.value.clone(),
             ));
-            return Ok(());
         }
-
-        let cage = &mut cmd.cages[idx];
-        cage.conditions
-            .retain(|cond| !Self::expr_named_eq(&cond.left, &column));
-        cage.conditions
-            .push(Self::payload_condition(column, condition.value.clone()));
         Ok(())
     }
 
@@ -1708,6 +1735,11 @@ impl PolicyEngine {
         if let Some(ref mut source_query) = cmd.source_query {
             self.apply_policies_inner(auth, source_query)?;
         }
+        for join in &mut cmd.joins {
+            if let Some(ref mut subquery) = join.subquery {
+                self.apply_policies_inner(auth, subquery)?;
+            }
+        }
 
         self.apply_embedded_subquery_policies(auth, cmd)?;
 
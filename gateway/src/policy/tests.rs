@@ -285,6 +285,54 @@ fn test_apply_policies_adds_joined_table_filter_to_join_clause() {
     }));
 }
 
+#[test]
+fn test_apply_policies_moves_using_join_filter_to_where() {
+    let mut engine = PolicyEngine::new();
+    engine.add_policy(PolicyDef {
+        name: "orders_read".to_string(),
+        table: "orders".to_string(),
+        filter: None,
+        role: None,
+        operations: vec![OperationType::Read],
+        allowed_columns: vec![],
+        denied_columns: vec![],
+    });
+    engine.add_policy(PolicyDef {
+        name: "users_tenant".to_string(),
+        table: "users".to_string(),
+        filter: Some("tenant_id = $tenant_id".to_string()),
+        role: None,
+        operations: vec![OperationType::Read],
+        allowed_columns: vec![],
+        denied_columns: vec![],
+    });
+
+    let auth = AuthContext {
+        user_id: "user_join".to_string(),
+        role: "user".to_string(),
+        tenant_id: Some("tenant-1".to_string()),
+        claims: std::collections::HashMap::new(),
+    };
+
+    let mut cmd =
+        Qail::get("orders").join_using(qail_core::ast::JoinKind::Inner, "users", ["user_id"]);
+    engine.apply_policies(&auth, &mut cmd).unwrap();
+
+    assert!(cmd.joins[0].on.is_none());
+    assert!(cmd.cages.iter().any(|cage| {
+        cage.kind == CageKind::Filter
+            && cage.conditions.iter().any(|condition| {
+                condition.left == Expr::Named("users.tenant_id".to_string())
+                    && condition.value == Value::String("tenant-1".to_string())
+            })
+    }));
+
+    let mut cmd =
+        Qail::get("orders").join_using(qail_core::ast::JoinKind::Left, "users", ["user_id"]);
+    let err = engine.apply_policies(&auth, &mut cmd).unwrap_err();
+    assert!(err.to_string().contains("USING join"), "{err}");
+}
+
 #[test]
 fn test_apply_policies_rejects_joined_table_column_policy() {
     let mut engine = PolicyEngine::new();
@@ -361,6 +409,52 @@ fn test_create_policy_filter_is_injected_into_insert_payload() {
     );
 }
 
+#[test]
+fn test_create_policy_filter_is_injected_into_every_insert_row() {
+    let mut engine = PolicyEngine::new();
+    engine.add_policy(PolicyDef {
+        name: "operator_create".to_string(),
+        table: "orders".to_string(),
+        filter: Some("operator_id = $user_id".to_string()),
+        role: None,
+        operations: vec![OperationType::Create],
+        allowed_columns: vec![],
+        denied_columns: vec![],
+    });
+
+    let auth = AuthContext {
+        user_id: "operator-1".to_string(),
+        role: "operator".to_string(),
+        tenant_id: Some("tenant-1".to_string()),
+        claims: std::collections::HashMap::new(),
+    };
+
+    let mut cmd = qail_core::parser::parse(
+        "add orders fields id, operator_id values (1, 'operator-1'), (2, 'attacker')",
+    )
+    .unwrap();
+    engine.apply_policies(&auth, &mut cmd).unwrap();
+
+    let payloads: Vec<_> = cmd
+        .cages
+        .iter()
+        .filter(|cage| matches!(cage.kind, CageKind::Payload))
+        .collect();
+    assert_eq!(payloads.len(), 2);
+    for payload in payloads {
+        assert!(payload.conditions.iter().any(|condition| {
+            condition.left == Expr::Named("$2".to_string())
+                && condition.value == Value::String("operator-1".to_string())
+        }));
+        assert!(
+            !payload
+                .conditions
+                .iter()
+                .any(|condition| condition.value == Value::String("attacker".to_string()))
+        );
+    }
+}
+
 #[test]
 fn test_create_policy_rejects_multiple_filtered_policies() {
     let mut engine = PolicyEngine::new();
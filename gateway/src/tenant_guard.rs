@@ -281,12 +281,16 @@ fn inject_tenant_payload(
     tenant_column: &str,
     tenant_id: &str,
 ) -> Result<(), TenantProjectionError> {
-    let payload_idx = cmd
+    // Multi-row inserts carry one payload cage per row; every row is scoped.
+    let payload_idxs: Vec<usize> = cmd
         .cages
         .iter()
-        .position(|cage| matches!(cage.kind, CageKind::Payload));
+        .enumerate()
+        .filter(|(_, cage)| matches!(cage.kind, CageKind::Payload))
+        .map(|(idx, _)| idx)
+        .collect();
 
-    let Some(idx) = payload_idx else {
+    if payload_idxs.is_empty() {
         cmd.cages.push(Cage {
             kind: CageKind::Payload,
             conditions: vec![tenant_filter_condition(
@@ -296,24 +300,31 @@ fn inject_tenant_payload(
             logical_op: LogicalOp::And,
         });
         return Ok(());
-    };
+    }
 
-    if payload_is_positional(&cmd.cages[idx]) {
-        if cmd.columns.is_empty() {
-            return Err(tenant_projection_error(
-                tenant_column,
-                format!(
-                    "Tenant-scoped {:?} requires explicit columns for positional payloads",
-                    cmd.action
-                ),
-            ));
-        }
+    for idx in payload_idxs {
+        if payload_is_positional(&cmd.cages[idx]) {
+            if cmd.columns.is_empty() {
+                return Err(tenant_projection_error(
+                    tenant_column,
+                    format!(
+                        "Tenant-scoped {:?} requires explicit columns for positional payloads",
+                        cmd.action
+                    ),
+                ));
+            }
 
-        if let Some(col_idx) = cmd
-            .columns
-            .iter()
-            .position(|expr| expr_named_eq(expr, tenant_column))
-        {
+            let col_idx = match cmd
+                .columns
+                .iter()
+                .position(|expr| expr_named_eq(expr, tenant_column))
+            {
+                Some(col_idx) => col_idx,
+                None => {
+                    cmd.columns.push(Expr::Named(tenant_column.to_string()));
+                    cmd.columns.len() - 1
+                }
+            };
             let placeholder = format!("${}", col_idx + 1);
             let cage = &mut cmd.cages[idx];
             if let Some(cond) = cage
@@ -326,24 +337,17 @@ fn inject_tenant_payload(
                 cage.conditions
                     .push(make_positional_payload_condition(col_idx, tenant_id));
             }
-            return Ok(());
+            continue;
         }
 
-        cmd.columns.push(Expr::Named(tenant_column.to_string()));
-        let col_idx = cmd.columns.len() - 1;
-        cmd.cages[idx]
-            .conditions
-            .push(make_positional_payload_condition(col_idx, tenant_id));
-        return Ok(());
+        let cage = &mut cmd.cages[idx];
+        cage.conditions
+            .retain(|cond| !expr_named_eq(&cond.left, tenant_column));
+        cage.conditions.push(tenant_filter_condition(
+            tenant_column.to_string(),
+            tenant_id,
+        ));
     }
-
-    let cage = &mut cmd.cages[idx];
-    cage.conditions
-        .retain(|cond| !expr_named_eq(&cond.left, tenant_column));
-    cage.conditions.push(tenant_filter_condition(
-        tenant_column.to_string(),
-        tenant_id,
-    ));
     Ok(())
 }
 
@@ -495,8 +499,25 @@ fn inject_join_tenant_filter(
     tenant_id: &str,
 ) -> Result<(), TenantProjectionError> {
     let condition = tenant_filter_condition(format!("{}.{}", qualifier, tenant_column), tenant_id);
+    // USING joins cannot carry ON conditions. For inner joins the filter is
+    // equivalent in WHERE; outer joins would change meaning there.
+    if !join.using.is_empty() {
+        return match join.kind {
+            JoinKind::Inner => {
+                inject_filter_condition(cmd, condition);
+                Ok(())
+            }
+            _ => Err(tenant_projection_error(
+                tenant_column,
+                format!(
+                    "Tenant guard cannot scope joined table '{}' through a {:?} USING join",
+                    join.table, join.kind
+                ),
+            )),
+        };
+    }
     match join.kind {
-        JoinKind::Inner | JoinKind::Left | JoinKind::Lateral => {
+        JoinKind::Inner | JoinKind::Left | JoinKind::Lateral | JoinKind::LateralInner => {
             join.on_true = false;
             join.on.get_or_insert_with(Vec::new).push(condition);
             Ok(())
@@ -1096,6 +1117,20 @@ fn prepare_tenant_guarded_query_inner(
     let cte_names: Vec<String> = cte_plans.iter().map(|(name, _)| name.clone()).collect();
     let mut rewritten_joins = Vec::with_capacity(cmd.joins.len());
     for mut join in std::mem::take(&mut cmd.joins) {
+        if let Some(ref mut subquery) = join.subquery {
+            // Derived-table join: scope the subquery itself; `table` is only its alias.
+            let join_plan = prepare_tenant_guarded_query_inner(
+                state,
+                auth,
+                subquery,
+                TenantGuardMode::InsertSource,
+            )?;
+            if let Some(join_plan) = join_plan {
+                merge_tenant_guard_plan(&mut plan, join_plan);
+            }
+            rewritten_joins.push(join);
+            continue;
+        }
         let (join_table, qualifier) = table_ref_name_and_qualifier(&join.table);
         if !join_table.is_empty()
             && !cte_names
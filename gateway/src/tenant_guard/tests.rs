@@ -296,6 +296,32 @@ fn inject_tenant_payload_extends_positional_insert_columns() {
     }));
 }
 
+#[test]
+fn inject_tenant_payload_scopes_every_multi_row_insert_row() {
+    let mut cmd = qail_core::parser::parse(
+        "add orders fields id, tenant_id values (1, 'tenant-1'), (2, 'tenant-2')",
+    )
+    .unwrap();
+
+    inject_tenant_payload(&mut cmd, "tenant_id", "tenant-1").unwrap();
+
+    let payloads: Vec<_> = cmd
+        .cages
+        .iter()
+        .filter(|cage| matches!(cage.kind, qail_core::ast::CageKind::Payload))
+        .collect();
+    assert_eq!(payloads.len(), 2);
+    for payload in payloads {
+        assert!(payload.conditions.iter().any(|condition| {
+            matches!(&condition.left, qail_core::ast::Expr::Named(name) if name == "$2")
+                && matches!(&condition.value, qail_core::ast::Value::String(value) if value == "tenant-1")
+        }));
+        assert!(!payload.conditions.iter().any(|condition| {
+            matches!(&condition.value, qail_core::ast::Value::String(value) if value == "tenant-2")
+        }));
+    }
+}
+
 #[test]
 fn inject_tenant_payload_from_source_query_overrides_target_tenant_column() {
     let mut cmd = qail_core::ast::Qail::add("orders").columns(["id", "tenant_id", "total"]);
@@ -539,6 +565,32 @@ fn inject_join_tenant_filter_scopes_left_join_on_clause() {
     }));
 }
 
+#[test]
+fn inject_join_tenant_filter_moves_inner_using_join_scope_to_where() {
+    use qail_core::ast::{CageKind, JoinKind};
+
+    let mut cmd =
+        qail_core::ast::Qail::get("orders").join_using(JoinKind::Inner, "users u", ["user_id"]);
+    let mut join = cmd.joins.pop().expect("join");
+
+    inject_join_tenant_filter(&mut cmd, &mut join, "u", "tenant_id", "tenant-1").unwrap();
+
+    assert!(join.on.is_none());
+    let filter = cmd
+        .cages
+        .iter()
+        .find(|cage| matches!(cage.kind, CageKind::Filter))
+        .expect("filter cage");
+    assert!(filter.conditions.iter().any(|condition| {
+        matches!(&condition.left, qail_core::ast::Expr::Named(name) if name == "u.tenant_id")
+    }));
+
+    let mut cmd =
+        qail_core::ast::Qail::get("orders").join_using(JoinKind::Left, "users u", ["user_id"]);
+    let mut join = cmd.joins.pop().expect("join");
+    assert!(inject_join_tenant_filter(&mut cmd, &mut join, "u", "tenant_id", "tenant-1").is_err());
+}
+
 #[tokio::test]
 async fn prepare_tenant_guarded_query_appends_base_alias_projection_for_join() {
     let state = build_tenant_guard_state().await;
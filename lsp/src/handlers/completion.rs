@@ -36,6 +36,8 @@ const QAIL_KEYWORDS: &[(&str, &str)] = &[
         "with",
         "CTE query - with recent as (get users fields id) get recent fields id",
     ),
+    ("table", "TABLE query - table users"),
+    ("values", "VALUES list - values (1, 'a'), (2, 'b')"),
     ("call", "CALL command - call refresh_materialized_views()"),
     (
         "do",
@@ -728,7 +728,8 @@ fn explain_query(cmd: &Qail) -> Value {
                     .as_ref()
                     .map(|conditions| conditions.iter().map(condition_text).collect::<Vec<_>>())
                     .unwrap_or_default(),
-                "onTrue": join.on_true
+                "onTrue": join.on_true,
+                "using": join.using
             })
         })
         .collect();
@@ -13,6 +13,7 @@ use super::types::{
     StatementCache, TlsConfig, has_logical_replication_startup_mode,
 };
 use crate::driver::stream::PgStream;
+use crate::driver::trace::OpSpan;
 use crate::driver::{AuthSettings, ConnectOptions, GssEncMode, PgError, PgResult, TlsMode};
 use crate::protocol::PROTOCOL_VERSION_3_0;
 use crate::protocol::wire::FrontendMessage;
@@ -537,17 +538,19 @@ impl PgConnection {
         let connect_started = Instant::now();
         let attempt_backend = plain_connect_attempt_backend(params.io_uring);
         record_connect_attempt(CONNECT_TRANSPORT_PLAIN, attempt_backend);
-        let result = tokio::time::timeout(
-            DEFAULT_CONNECT_TIMEOUT,
-            Self::connect_with_password_inner(params),
-        )
-        .await
-        .map_err(|_| {
-            PgError::Connection(format!(
-                "Connection timeout after {:?} (TCP connect + handshake)",
-                DEFAULT_CONNECT_TIMEOUT
+        let span = OpSpan::connect(CONNECT_TRANSPORT_PLAIN, params.host, params.port);
+        let result = span
+            .instrument(tokio::time::timeout(
+                DEFAULT_CONNECT_TIMEOUT,
+                Self::connect_with_password_inner(params),
             ))
-        })?;
+            .await
+            .map_err(|_| {
+                PgError::Connection(format!(
+                    "Connection timeout after {:?} (TCP connect + handshake)",
+                    DEFAULT_CONNECT_TIMEOUT
+                ))
+            })?;
         let backend = result
             .as_ref()
             .map(|conn| connect_backend_for_stream(&conn.stream))
@@ -558,6 +561,7 @@ impl PgConnection {
             &result,
             connect_started.elapsed(),
         );
+        span.finish(&result);
         result
     }
 
@@ -732,23 +736,26 @@ impl PgConnection {
     ) -> PgResult<Self> {
         let connect_started = Instant::now();
         record_connect_attempt(CONNECT_TRANSPORT_TLS, CONNECT_BACKEND_TOKIO);
-        let result = tokio::time::timeout(
-            DEFAULT_CONNECT_TIMEOUT,
-            Self::connect_tls_inner(params, ca_cert_pem),
-        )
-        .await
-        .map_err(|_| {
-            PgError::Connection(format!(
-                "TLS connection timeout after {:?}",
-                DEFAULT_CONNECT_TIMEOUT
+        let span = OpSpan::connect(CONNECT_TRANSPORT_TLS, params.host, params.port);
+        let result = span
+            .instrument(tokio::time::timeout(
+                DEFAULT_CONNECT_TIMEOUT,
+                Self::connect_tls_inner(params, ca_cert_pem),
             ))
-        })?;
+            .await
+            .map_err(|_| {
+                PgError::Connection(format!(
+                    "TLS connection timeout after {:?}",
+                    DEFAULT_CONNECT_TIMEOUT
+                ))
+            })?;
         record_connect_result(
             CONNECT_TRANSPORT_TLS,
             CONNECT_BACKEND_TOKIO,
             &result,
             connect_started.elapsed(),
         );
+        span.finish(&result);
         result
     }
 
@@ -957,23 +964,26 @@ impl PgConnection {
     ) -> PgResult<Self> {
         let connect_started = Instant::now();
         record_connect_attempt(CONNECT_TRANSPORT_MTLS, CONNECT_BACKEND_TOKIO);
-        let result = tokio::time::timeout(
-            DEFAULT_CONNECT_TIMEOUT,
-            Self::connect_mtls_inner(params, config),
-        )
-        .await
-        .map_err(|_| {
-            PgError::Connection(format!(
-                "mTLS connection timeout after {:?}",
-                DEFAULT_CONNECT_TIMEOUT
+        let span = OpSpan::connect(CONNECT_TRANSPORT_MTLS, params.host, params.port);
+        let result = span
+            .instrument(tokio::time::timeout(
+                DEFAULT_CONNECT_TIMEOUT,
+                Self::connect_mtls_inner(params, config),
             ))
-        })?;
+            .await
+            .map_err(|_| {
+                PgError::Connection(format!(
+                    "mTLS connection timeout after {:?}",
+                    DEFAULT_CONNECT_TIMEOUT
+                ))
+            })?;
         record_connect_result(
             CONNECT_TRANSPORT_MTLS,
             CONNECT_BACKEND_TOKIO,
             &result,
             connect_started.elapsed(),
         );
+        span.finish(&result);
         result
     }
 
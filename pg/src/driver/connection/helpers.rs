@@ -70,6 +70,7 @@ pub(super) fn connect_error_kind(error: &PgError) -> &'static str {
         PgError::Timeout(_) => "timeout",
         PgError::PoolExhausted { .. } => "pool_exhausted",
         PgError::PoolClosed => "pool_closed",
+        PgError::RowLimitExceeded { .. } => "row_limit_exceeded",
     }
 }
 
@@ -6,8 +6,8 @@ use crate::driver::stream::PgStream;
 use crate::driver::{
     AuthSettings, EnterpriseAuthMechanism, GssTokenProvider, GssTokenProviderEx, PgError, PgResult,
 };
+use crate::protocol::auth::tls_server_end_point;
 use crate::protocol::{BackendMessage, FrontendMessage, ScramClient, TransactionStatus};
-use sha2::{Digest, Sha256};
 use std::sync::atomic::Ordering;
 
 impl PgConnection {
@@ -490,7 +490,8 @@ impl PgConnection {
     /// Build SCRAM `tls-server-end-point` channel-binding bytes from the server leaf cert.
     ///
     /// PostgreSQL expects the hash of the peer certificate DER for
-    /// `SCRAM-SHA-256-PLUS` channel binding. We currently use SHA-256 here.
+    /// `SCRAM-SHA-256-PLUS` channel binding, using the certificate's
+    /// signature hash (RFC 5929).
     fn tls_server_end_point_channel_binding(&self) -> Option<Vec<u8>> {
         let PgStream::Tls(tls) = &self.stream else {
             return None;
@@ -500,9 +501,7 @@ impl PgConnection {
         let certs = conn.peer_certificates()?;
         let leaf_cert = certs.first()?;
 
-        let mut hasher = Sha256::new();
-        hasher.update(leaf_cert.as_ref());
-        Some(hasher.finalize().to_vec())
+        Some(tls_server_end_point(leaf_cert.as_ref()))
     }
 
     /// Gracefully close the connection by sending a Terminate message.
@@ -550,6 +549,54 @@ impl PgConnection {
         }
     }
 
+    /// Statement name for `sql` that never shadows a different statement.
+    ///
+    /// Names are derived from the SQL hash. If that name is already bound to
+    /// other SQL on this connection, `_1`, `_2`, ... suffixes are tried until
+    /// a free slot or one holding the same SQL is found.
+    pub(crate) fn prepared_stmt_name_for(&self, sql: &str) -> String {
+        let base = crate::driver::prepared::sql_bytes_to_stmt_name(sql.as_bytes());
+        self.unshadowed_stmt_name(base, sql)
+    }
+
+    /// [`prepared_stmt_name_for`](Self::prepared_stmt_name_for) for the
+    /// fetch paths that Describe a statement on first use. They keep their
+    /// own `qail_` names so they never try to re-Parse a statement that
+    /// `prepare` or `query_cached` already holds without column info.
+    pub(crate) fn described_stmt_name_for(&self, sql_hash: u64, sql: &str) -> String {
+        self.unshadowed_stmt_name(format!("qail_{:x}", sql_hash), sql)
+    }
+
+    fn unshadowed_stmt_name(&self, base: String, sql: &str) -> String {
+        let mut candidate = base.clone();
+        let mut suffix = 0usize;
+        while let Some(existing) = self.prepared_statements.get(&candidate) {
+            if existing == sql {
+                break;
+            }
+            suffix += 1;
+            candidate = format!("{base}_{suffix}");
+        }
+        candidate
+    }
+
+    /// Forget a prepared statement and queue its server-side `Close`.
+    ///
+    /// The `Close` is sent ahead of the next request on this connection.
+    /// Any statement-cache entry pointing at the same name is dropped too so
+    /// cached paths re-parse instead of binding a closed statement.
+    pub(crate) fn release_prepared(&mut self, stmt_name: &str) {
+        let Some(sql) = self.prepared_statements.remove(stmt_name) else {
+            return;
+        };
+        let sql_hash = crate::driver::prepared::sql_bytes_hash(sql.as_bytes());
+        if self.stmt_cache.peek(&sql_hash) == Some(stmt_name) {
+            self.stmt_cache.remove(&sql_hash);
+            self.column_info_cache.remove(&sql_hash);
+        }
+        self.pending_statement_closes.push(stmt_name.to_string());
+    }
+
     /// Clear all local prepared-statement state for this connection.
     ///
     /// Used by one-shot self-heal paths when server-side statement state
@@ -6,40 +6,12 @@ use crate::driver::{PgError, ScramChannelBindingMode};
 use {
     super::types::{PgConnection, StatementCache},
     crate::driver::ColumnInfo,
-    crate::driver::stream::PgStream,
-    bytes::BytesMut,
-    std::collections::{HashMap, VecDeque},
+    crate::driver::test_support::test_conn,
+    std::collections::HashMap,
     std::num::NonZeroUsize,
     std::sync::Arc,
-    tokio::net::UnixStream,
 };
 
-#[cfg(unix)]
-fn test_conn() -> PgConnection {
-    let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-    PgConnection {
-        stream: PgStream::Unix(unix_stream),
-        buffer: BytesMut::with_capacity(1024),
-        write_buf: BytesMut::with_capacity(1024),
-        sql_buf: BytesMut::with_capacity(256),
-        params_buf: Vec::new(),
-        prepared_statements: HashMap::new(),
-        stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-        column_info_cache: HashMap::new(),
-        process_id: 0,
-        cancel_key_bytes: Vec::new(),
-        requested_protocol_minor: PgConnection::default_protocol_minor(),
-        negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-        notifications: VecDeque::new(),
-        replication_stream_active: false,
-        replication_mode_enabled: false,
-        last_replication_wal_end: None,
-        io_desynced: false,
-        pending_statement_closes: Vec::new(),
-        draining_statement_closes: false,
-    }
-}
-
 #[test]
 fn test_md5_password_message_known_vector() {
     let hash = md5_password_message("postgres", "secret", [0x12, 0x34, 0x56, 0x78]);
@@ -240,3 +212,22 @@ async fn test_clear_prepared_statement_state_clears_pending_closes() {
     assert_eq!(conn.stmt_cache.len(), 0);
     assert!(conn.column_info_cache.is_empty());
 }
+
+#[test]
+fn test_ssl_and_gssenc_request_byte_layout() {
+    use super::types::{GSSENC_REQUEST, SSL_REQUEST};
+
+    assert_eq!(u32::from_be_bytes(SSL_REQUEST[..4].try_into().unwrap()), 8);
+    assert_eq!(
+        u32::from_be_bytes(SSL_REQUEST[4..].try_into().unwrap()),
+        0x04d2_162f
+    );
+    assert_eq!(
+        u32::from_be_bytes(GSSENC_REQUEST[..4].try_into().unwrap()),
+        8
+    );
+    assert_eq!(
+        u32::from_be_bytes(GSSENC_REQUEST[4..].try_into().unwrap()),
+        0x04d2_1630
+    );
+}
@@ -7,7 +7,7 @@ use super::{
 };
 use crate::protocol::{AstEncoder, BackendMessage, PgEncoder};
 use bytes::BytesMut;
-use qail_core::ast::{Action, Qail};
+use qail_core::ast::{Action, CopyFormat, Qail};
 use std::future::Future;
 
 /// Quote a single SQL identifier atom for COPY statements.
@@ -210,7 +210,7 @@ fn encode_copy_export_sql(cmd: &Qail) -> PgResult<String> {
         AstEncoder::encode_cmd_sql(cmd).map_err(|e| PgError::Encode(e.to_string()))?;
     if !params.is_empty() {
         return Err(PgError::Encode(format!(
-            "copy_export cannot encode parameterized export with {} bind parameter(s); use an unfiltered export, a prefiltered database view, or a raw COPY statement with trusted SQL",
+            "copy_export cannot encode parameterized export with {} bind parameter(s); WHERE filters are inlined, but other clauses (e.g. HAVING or join values) need a prefiltered database view or a raw COPY statement with trusted SQL",
             params.len()
         )));
     }
@@ -694,11 +694,20 @@ impl PgConnection {
     /// Stream COPY TO STDOUT rows using an AST-native `Qail::Export` command.
     ///
     /// Parses PostgreSQL COPY text lines into `Vec<String>` rows and invokes
-    /// `on_row` for each row without buffering the full result.
+    /// `on_row` for each row without buffering the full result. CSV exports
+    /// are rejected; stream them with [`Self::copy_export_stream_raw`].
     pub async fn copy_export_stream_rows<F>(&mut self, cmd: &Qail, mut on_row: F) -> PgResult<()>
     where
         F: FnMut(Vec<String>) -> PgResult<()>,
     {
+        if cmd
+            .copy_options
+            .is_some_and(|options| options.format != CopyFormat::Text || options.header)
+        {
+            return Err(PgError::Query(
+                "copy_export row parsing supports only text format without a header; use copy_export_stream_raw for CSV".to_string(),
+            ));
+        }
         let mut pending = Vec::new();
         self.copy_export_stream_raw(cmd, |chunk| {
             let res = drain_copy_text_rows(&mut pending, &chunk, &mut on_row);
@@ -750,41 +759,10 @@ mod tests {
         drain_copy_text_rows, encode_copy_export_sql, flush_pending_copy_text_row,
         parse_copy_text_row, quote_copy_column_ident, quote_copy_table_ref, return_with_desync,
     };
-    use crate::driver::{PgConnection, PgError, PgResult};
-    use qail_core::ast::{Operator, Qail};
-
     #[cfg(unix)]
-    fn test_conn() -> PgConnection {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-        PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: false,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        }
-    }
+    use crate::driver::test_support::{test_conn, test_conn_with_peer, write_backend_frame};
+    use crate::driver::{PgError, PgResult};
+    use qail_core::ast::{Condition, Expr, Operator, Qail, Value};
 
     #[test]
     fn parse_copy_text_row_splits_tabs() {
@@ -856,13 +834,68 @@ mod tests {
     }
 
     #[test]
-    fn copy_export_rejects_parameterized_ast_before_streaming() {
+    fn copy_export_inlines_filters() {
         let cmd = Qail::export("users").filter("active", Operator::Eq, true);
+
+        assert_eq!(
+            encode_copy_export_sql(&cmd).unwrap(),
+            "COPY (SELECT * FROM users WHERE active = TRUE) TO STDOUT"
+        );
+    }
+
+    #[test]
+    fn copy_export_rejects_parameterized_ast_before_streaming() {
+        let cmd = Qail::export("orders")
+            .columns(["user_id"])
+            .having_cond(Condition {
+                left: Expr::Named("user_id".to_string()),
+                op: Operator::Gt,
+                value: Value::Int(1),
+                is_array_unnest: false,
+            });
         let err = encode_copy_export_sql(&cmd).expect_err("bind params cannot be ignored");
 
         assert!(matches!(err, PgError::Encode(msg) if msg.contains("parameterized export")));
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn copy_export_streams_filtered_csv_export() {
+        use tokio::io::AsyncReadExt;
+
+        let (mut conn, mut peer) = test_conn_with_peer();
+        for (msg_type, payload) in [
+            (b'H', &[0u8, 0, 0][..]),
+            (b'd', b"id,name\n1,Ana\n"),
+            (b'c', b""),
+            (b'C', b"COPY 1\0"),
+            (b'Z', b"I"),
+        ] {
+            write_backend_frame(&mut conn.buffer, msg_type, payload);
+        }
+
+        let cmd =
+            Qail::export_csv("users")
+                .columns(["id", "name"])
+                .filter("active", Operator::Eq, true);
+        let mut exported = Vec::new();
+        conn.copy_export_stream_raw(&cmd, |chunk| {
+            exported.extend_from_slice(&chunk);
+            std::future::ready(Ok(()))
+        })
+        .await
+        .expect("filtered CSV export should stream");
+        assert_eq!(exported, b"id,name\n1,Ana\n");
+
+        let mut sent = vec![0u8; 512];
+        let n = peer.read(&mut sent).await.unwrap();
+        let sent = String::from_utf8_lossy(&sent[..n]);
+        assert!(
+            sent.contains("COPY (SELECT id, name FROM users WHERE active = TRUE) TO STDOUT"),
+            "{sent}"
+        );
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn copy_return_with_desync_marks_protocol_error() {
@@ -3,6 +3,7 @@
 
 use super::core::PgDriver;
 use super::prepared::PreparedAstQuery;
+use super::trace::OpSpan;
 use super::types::*;
 use qail_core::ast::Qail;
 use std::sync::Arc;
@@ -114,6 +115,19 @@ impl PgDriver {
         &mut self,
         prepared: &PreparedAstQuery,
         result_format: ResultFormat,
+    ) -> PgResult<Vec<PgRow>> {
+        let span = OpSpan::bind(&prepared.sql, prepared.params.len());
+        let result = span
+            .instrument(self.fetch_all_prepared_ast_inner(prepared, result_format))
+            .await;
+        span.finish_rows(&result);
+        result
+    }
+
+    async fn fetch_all_prepared_ast_inner(
+        &mut self,
+        prepared: &PreparedAstQuery,
+        result_format: ResultFormat,
     ) -> PgResult<Vec<PgRow>> {
         let mut retried = false;
 
@@ -324,6 +338,25 @@ impl PgDriver {
         }
     }
 
+    /// Execute a QAIL command and fetch at most `max_rows` rows (UNCACHED).
+    ///
+    /// Guards against runaway result sets: the server is asked for at most
+    /// `max_rows + 1` rows and [`PgError::RowLimitExceeded`] is returned if
+    /// the query would produce more than `max_rows`.
+    pub async fn fetch_all_with_max_rows(
+        &mut self,
+        cmd: &Qail,
+        max_rows: usize,
+    ) -> PgResult<Vec<PgRow>> {
+        use crate::protocol::AstEncoder;
+
+        let (sql, params) =
+            AstEncoder::encode_cmd_sql(cmd).map_err(|e| PgError::Encode(e.to_string()))?;
+        self.connection
+            .query_rows_with_max_rows(&sql, &params, ResultFormat::Text.as_wire_code(), max_rows)
+            .await
+    }
+
     /// Execute a QAIL command and fetch all rows (FAST VERSION).
     /// Uses optimized recv_with_data_fast for faster response parsing.
     /// Skips column metadata collection for maximum speed.
@@ -427,11 +460,25 @@ impl PgDriver {
         &mut self,
         cmd: &Qail,
         result_format: ResultFormat,
+    ) -> PgResult<Vec<PgRow>> {
+        let span = OpSpan::fetch();
+        let result = span
+            .instrument(self.fetch_all_cached_with_retry(cmd, result_format, &span))
+            .await;
+        span.finish_rows(&result);
+        result
+    }
+
+    async fn fetch_all_cached_with_retry(
+        &mut self,
+        cmd: &Qail,
+        result_format: ResultFormat,
+        span: &OpSpan,
     ) -> PgResult<Vec<PgRow>> {
         let mut retried = false;
         loop {
             match self
-                .fetch_all_cached_with_format_once(cmd, result_format)
+                .fetch_all_cached_with_format_once(cmd, result_format, span)
                 .await
             {
                 Ok(rows) => return Ok(rows),
@@ -454,6 +501,7 @@ impl PgDriver {
         &mut self,
         cmd: &Qail,
         result_format: ResultFormat,
+        span: &OpSpan,
     ) -> PgResult<Vec<PgRow>> {
         use crate::protocol::AstEncoder;
         use std::collections::hash_map::DefaultHasher;
@@ -467,6 +515,7 @@ impl PgDriver {
             // Fallback for unsupported actions
             let (sql, params) =
                 AstEncoder::encode_cmd_sql(cmd).map_err(|e| PgError::Encode(e.to_string()))?;
+            span.record_sql(sql.as_bytes(), params.len());
             let raw_rows = self
                 .connection
                 .query_cached_with_result_format(&sql, &params, result_format.as_wire_code())
@@ -480,6 +529,8 @@ impl PgDriver {
                 .collect());
         }
 
+        span.record_sql(&self.connection.sql_buf, self.connection.params_buf.len());
+
         let mut hasher = DefaultHasher::new();
         self.connection.sql_buf.hash(&mut hasher);
         let sql_hash = hasher.finish();
@@ -492,12 +543,11 @@ impl PgDriver {
         let stmt_name = if let Some(name) = self.connection.stmt_cache.get(&sql_hash) {
             name
         } else {
-            let name = format!("qail_{:x}", sql_hash);
-
             // Evict LRU before borrowing sql_buf to avoid borrow conflict
             self.connection.evict_prepared_if_full();
 
             let sql_str = encoded_sql_str(&self.connection.sql_buf)?;
+            let name = self.connection.described_stmt_name_for(sql_hash, sql_str);
 
             // Buffer Parse + Describe(Statement) for first call
             use crate::protocol::PgEncoder;
@@ -817,46 +867,13 @@ mod tests {
 
     #[cfg(unix)]
     fn test_driver_with_peer() -> (PgDriver, tokio::net::UnixStream) {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, peer) = UnixStream::pair().expect("unix stream pair");
-        let conn = super::super::PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: super::super::PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: super::super::PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: false,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        };
+        let (conn, peer) = crate::driver::test_support::test_conn_with_peer();
         (PgDriver::new(conn), peer)
     }
 
     #[cfg(unix)]
     fn push_backend_frame(driver: &mut PgDriver, msg_type: u8, payload: &[u8]) {
-        driver.connection.buffer.extend_from_slice(&[msg_type]);
-        driver
-            .connection
-            .buffer
-            .extend_from_slice(&((payload.len() + 4) as u32).to_be_bytes());
-        driver.connection.buffer.extend_from_slice(payload);
+        crate::driver::test_support::push_backend_frame(&mut driver.connection, msg_type, payload);
     }
 
     #[cfg(unix)]
@@ -1027,4 +1044,109 @@ mod tests {
         assert!(driver.connection.stmt_cache.contains(&prepared.sql_hash));
         assert!(!driver.connection.is_io_desynced());
     }
+
+    /// Span names by id, plus `(span name, "field=value")` pairs in record order.
+    #[cfg(all(unix, feature = "tracing"))]
+    type SpanRecords = (Vec<&'static str>, Vec<(&'static str, String)>);
+
+    /// Test subscriber that keeps every recorded span field.
+    #[cfg(all(unix, feature = "tracing"))]
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        records: Arc<std::sync::Mutex<SpanRecords>>,
+    }
+
+    #[cfg(all(unix, feature = "tracing"))]
+    impl SpanRecorder {
+        fn field(&self, span: &str, field: &str) -> Option<String> {
+            let records = self.records.lock().unwrap();
+            records
+                .1
+                .iter()
+                .rev()
+                .find(|(name, value)| *name == span && value.starts_with(&format!("{field}=")))
+                .map(|(_, value)| value[field.len() + 1..].to_string())
+        }
+    }
+
+    #[cfg(all(unix, feature = "tracing"))]
+    struct FieldVisitor<'a> {
+        span: &'static str,
+        out: &'a mut Vec<(&'static str, String)>,
+    }
+
+    #[cfg(all(unix, feature = "tracing"))]
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.out
+                .push((self.span, format!("{}={:?}", field.name(), value)));
+        }
+
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.out
+                .push((self.span, format!("{}={}", field.name(), value)));
+        }
+    }
+
+    #[cfg(all(unix, feature = "tracing"))]
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut records = self.records.lock().unwrap();
+            let (names, out) = &mut *records;
+            let span = attrs.metadata().name();
+            names.push(span);
+            attrs.record(&mut FieldVisitor { span, out });
+            tracing::span::Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, id: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            let mut records = self.records.lock().unwrap();
+            let (names, out) = &mut *records;
+            let span = names[id.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor { span, out });
+        }
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, _: &tracing::Event<'_>) {}
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(all(unix, feature = "tracing"))]
+    #[tokio::test]
+    async fn fetch_all_records_span_with_sql_and_row_count() {
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let (mut driver, _peer) = test_driver_with_peer();
+        push_backend_frame(&mut driver, b'1', &[]);
+        push_backend_frame(&mut driver, b't', &0i16.to_be_bytes());
+        push_text_row_description(&mut driver, "id");
+        push_backend_frame(&mut driver, b'2', &[]);
+        push_single_column_data_row(&mut driver, b"1");
+        push_single_column_data_row(&mut driver, b"2");
+        push_command_complete(&mut driver, "SELECT 2");
+        push_backend_frame(&mut driver, b'Z', b"I");
+
+        let rows = driver
+            .fetch_all(&Qail::get("users").columns(["id"]))
+            .await
+            .expect("fetch should succeed");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(recorder.field("pg.fetch", "rows").as_deref(), Some("2"));
+        assert_eq!(recorder.field("pg.fetch", "params").as_deref(), Some("0"));
+        assert_eq!(
+            recorder.field("pg.fetch", "sql").as_deref(),
+            Some("SELECT id FROM users")
+        );
+        assert!(recorder.field("pg.fetch", "elapsed_us").is_some());
+    }
 }
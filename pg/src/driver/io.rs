@@ -1678,38 +1678,8 @@ impl PgConnection {
 #[cfg(test)]
 mod tests {
     use super::*;
-
     #[cfg(unix)]
-    fn test_conn() -> PgConnection {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-        PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: false,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        }
-    }
+    use crate::driver::test_support::test_conn;
 
     fn build_data_row_payload(columns: &[Option<&[u8]>]) -> Bytes {
         let mut payload = Vec::new();
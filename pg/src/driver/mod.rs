@@ -42,6 +42,9 @@ mod replication;
 pub mod rls;
 mod row;
 mod stream;
+#[cfg(all(test, unix))]
+mod test_support;
+mod trace;
 mod transaction;
 mod types;
 #[cfg(all(target_os = "linux", feature = "io_uring"))]
@@ -70,8 +73,10 @@ pub use replication::{
 };
 pub use rls::RlsContext;
 pub use row::QailRow;
+pub use transaction::TransactionRetry;
 pub use types::{
     ColumnInfo, PgBytesRow, PgError, PgResult, PgRow, PgServerError, QueryResult, ResultFormat,
+    ResultSet,
 };
 
 // ── Crate-internal re-exports ───────────────────────────────────────
@@ -122,6 +122,12 @@ impl PgDriver {
         self.connection.simple_query(sql).await
     }
 
+    /// Execute trusted multi-statement SQL in one round-trip and return one
+    /// result set per statement (e.g. a whole migration file).
+    pub async fn fetch_multi(&mut self, sql: &str) -> PgResult<Vec<ResultSet>> {
+        self.connection.fetch_multi(sql).await
+    }
+
     // ==================== RLS (MULTI-TENANT) ====================
 
     /// Set the RLS context for multi-tenant data isolation.
@@ -276,6 +282,14 @@ impl PgDriver {
         &mut self,
         cmd: &Qail,
     ) -> PgResult<Option<crate::driver::explain::ExplainEstimate>> {
+        let json_output = self.explain_json(cmd).await?;
+        Ok(crate::driver::explain::parse_explain_json(&json_output))
+    }
+
+    /// Run `EXPLAIN (FORMAT JSON)` on a Qail AST command and return the raw plan JSON.
+    ///
+    /// The query is planned, not executed.
+    pub async fn explain_json(&mut self, cmd: &Qail) -> PgResult<String> {
         let (sql, params) =
             AstEncoder::encode_cmd_sql(cmd).map_err(|e| PgError::Encode(e.to_string()))?;
         let explain_sql = format!("EXPLAIN (FORMAT JSON) {}", sql);
@@ -290,7 +304,7 @@ impl PgDriver {
             }
         }
 
-        Ok(crate::driver::explain::parse_explain_json(&json_output))
+        Ok(json_output)
     }
 
     /// Prepare a SQL statement for repeated execution.
@@ -298,6 +312,11 @@ impl PgDriver {
         self.connection.prepare(sql).await
     }
 
+    /// Deallocate a prepared statement (the `Close` is sent with the next request).
+    pub fn deallocate(&mut self, stmt: PreparedStatement) {
+        self.connection.deallocate(stmt);
+    }
+
     /// Execute a prepared statement pipeline in FAST mode (count only).
     pub async fn pipeline_execute_prepared_count(
         &mut self,
@@ -87,8 +87,12 @@ fn rollback_new_cached_statements_from(
     start_idx: usize,
 ) {
     for sql_hash in &new_stmt_hashes[start_idx.min(new_stmt_hashes.len())..] {
-        conn.stmt_cache.remove(sql_hash);
-        let stmt_name = super::prepared::stmt_name_from_hash(*sql_hash);
+        // The cache holds the (possibly suffixed) name; an entry the LRU
+        // already dropped can only be found by its hash-derived name.
+        let stmt_name = conn
+            .stmt_cache
+            .remove(sql_hash)
+            .unwrap_or_else(|| super::prepared::stmt_name_from_hash(*sql_hash));
         conn.prepared_statements.remove(&stmt_name);
         conn.column_info_cache.remove(sql_hash);
     }
@@ -1099,7 +1103,7 @@ impl PgConnection {
             return Ok(0);
         }
 
-        use super::prepared::{sql_bytes_hash, stmt_name_from_hash};
+        use super::prepared::sql_bytes_hash;
 
         let mut buf = BytesMut::with_capacity(cmds.len() * 64);
         let mut sql_buf = BytesMut::with_capacity(256);
@@ -1117,22 +1121,19 @@ impl PgConnection {
             if self.stmt_cache.contains(&sql_hash) {
                 self.stmt_cache.touch_key(sql_hash);
             } else {
-                let stmt_name = stmt_name_from_hash(sql_hash);
+                let sql = match std::str::from_utf8(sql_buf.as_ref()) {
+                    Ok(sql) => sql.to_string(),
+                    Err(e) => {
+                        rollback_new_cached_statements(self, &new_stmt_hashes);
+                        return Err(PgError::Encode(format!("encoded SQL is not UTF-8: {}", e)));
+                    }
+                };
+                let stmt_name = self.prepared_stmt_name_for(&sql);
                 if self.prepared_statements.contains_key(&stmt_name) {
                     // Recover from old cache states where prepared_statements had
                     // entries that were not mirrored in stmt_cache.
                     self.stmt_cache.put(sql_hash, stmt_name.clone());
                 } else {
-                    let sql = match std::str::from_utf8(sql_buf.as_ref()) {
-                        Ok(sql) => sql.to_string(),
-                        Err(e) => {
-                            rollback_new_cached_statements(self, &new_stmt_hashes);
-                            return Err(PgError::Encode(format!(
-                                "encoded SQL is not UTF-8: {}",
-                                e
-                            )));
-                        }
-                    };
                     let parse_msg = match PgEncoder::try_encode_parse(&stmt_name, &sql, &[]) {
                         Ok(msg) => msg,
                         Err(e) => {
@@ -1308,9 +1309,14 @@ impl PgConnection {
     /// Prepare a statement and return a handle for fast execution.
     /// PreparedStatement handle for use with pipeline_execute_prepared_count.
     pub async fn prepare(&mut self, sql: &str) -> PgResult<super::PreparedStatement> {
-        use super::prepared::sql_bytes_to_stmt_name;
+        let span = super::trace::OpSpan::prepare(sql);
+        let result = span.instrument(self.prepare_inner(sql)).await;
+        span.finish(&result);
+        result
+    }
 
-        let stmt_name = sql_bytes_to_stmt_name(sql.as_bytes());
+    async fn prepare_inner(&mut self, sql: &str) -> PgResult<super::PreparedStatement> {
+        let stmt_name = self.prepared_stmt_name_for(sql);
 
         if !self.prepared_statements.contains_key(&stmt_name) {
             self.evict_prepared_if_full();
@@ -1371,6 +1377,15 @@ impl PgConnection {
         Ok(super::PreparedStatement { name: stmt_name })
     }
 
+    /// Deallocate a statement returned by [`prepare`](Self::prepare).
+    ///
+    /// The server-side `Close` is queued and sent before the next request on
+    /// this connection, so this never blocks. Deallocating a statement that
+    /// is no longer prepared is a no-op.
+    pub fn deallocate(&mut self, stmt: super::PreparedStatement) {
+        self.release_prepared(&stmt.name);
+    }
+
     /// Execute a prepared statement pipeline and return all row data.
     pub async fn pipeline_execute_prepared_rows(
         &mut self,
@@ -2075,6 +2090,8 @@ impl PgConnection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(unix)]
+    use crate::driver::test_support::{push_backend_frame, test_conn_with_peer};
     use qail_core::ast::Qail;
 
     #[test]
@@ -2104,34 +2121,10 @@ mod tests {
     #[cfg(unix)]
     fn make_test_conn_with_prepared() -> PgConnection {
         use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
         use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-        let mut conn = PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(16).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: false,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        };
+
+        let (mut conn, _peer) = test_conn_with_peer();
+        conn.stmt_cache = StatementCache::new(NonZeroUsize::new(16).expect("non-zero"));
         conn.prepared_statements
             .insert("s1".to_string(), "SELECT 1".to_string());
         conn.stmt_cache.put(1, "s1".to_string());
@@ -2306,4 +2299,63 @@ mod tests {
         assert_eq!(res, 0);
         assert!(!conn.is_io_desynced());
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn prepare_avoids_name_collision_and_deallocate_sends_close() {
+        use super::super::prepared::sql_bytes_to_stmt_name;
+        use tokio::io::AsyncReadExt;
+
+        let (mut conn, mut peer) = test_conn_with_peer();
+        let sql_a = "SELECT id FROM users WHERE id = $1";
+        let sql_b = "SELECT email FROM users WHERE id = $1";
+        // Simulate a hash collision: `sql_b`'s natural name is held by other SQL.
+        let taken = sql_bytes_to_stmt_name(sql_b.as_bytes());
+        conn.prepared_statements
+            .insert(taken.clone(), "SELECT 'other'".to_string());
+
+        for _ in 0..2 {
+            push_backend_frame(&mut conn, b'1', &[]);
+            push_backend_frame(&mut conn, b'Z', b"I");
+        }
+        let stmt_a = conn.prepare(sql_a).await.expect("prepare a");
+        let stmt_b = conn.prepare(sql_b).await.expect("prepare b");
+
+        assert_ne!(stmt_a.name(), stmt_b.name());
+        assert_eq!(stmt_b.name(), format!("{taken}_1"));
+        assert_eq!(conn.prepared_statements[&taken], "SELECT 'other'");
+        assert_eq!(conn.prepared_statements[stmt_b.name()], sql_b);
+
+        // Re-preparing the same SQL reuses the suffixed name without a round trip.
+        let again = conn.prepare(sql_b).await.expect("re-prepare b");
+        assert_eq!(again.name(), stmt_b.name());
+
+        let name_a = stmt_a.name().to_string();
+        conn.deallocate(stmt_a);
+        assert!(!conn.prepared_statements.contains_key(&name_a));
+        assert_eq!(conn.pending_statement_closes, vec![name_a.clone()]);
+
+        // The queued Close goes out ahead of the next request.
+        push_backend_frame(&mut conn, b'3', &[]);
+        push_backend_frame(&mut conn, b'Z', b"I");
+        push_backend_frame(&mut conn, b'1', &[]);
+        push_backend_frame(&mut conn, b'Z', b"I");
+        conn.prepare("SELECT 1")
+            .await
+            .expect("prepare after deallocate");
+        assert!(conn.pending_statement_closes.is_empty());
+
+        drop(conn);
+        let mut written = Vec::new();
+        peer.read_to_end(&mut written).await.expect("read peer");
+        let mut close = vec![b'C'];
+        close.extend_from_slice(&((4 + 1 + name_a.len() + 1) as u32).to_be_bytes());
+        close.push(b'S');
+        close.extend_from_slice(name_a.as_bytes());
+        close.push(0);
+        assert!(
+            written.windows(close.len()).any(|w| w == close.as_slice()),
+            "Close for {name_a} was not sent"
+        );
+    }
 }
@@ -589,11 +589,10 @@ impl PooledConnection {
         let stmt_name = if let Some(name) = conn.stmt_cache.get(&sql_hash) {
             name
         } else {
-            let name = format!("qail_{:x}", sql_hash);
-
             conn.evict_prepared_if_full();
 
             let sql_str = encoded_sql_str(&conn.sql_buf)?;
+            let name = conn.described_stmt_name_for(sql_hash, sql_str);
 
             use crate::protocol::PgEncoder;
             let parse_msg = PgEncoder::try_encode_parse(&name, sql_str, &[])?;
@@ -845,11 +844,10 @@ impl PooledConnection {
         let stmt_name = if let Some(name) = conn.stmt_cache.get(&sql_hash) {
             name
         } else {
-            let name = format!("qail_{:x}", sql_hash);
-
             conn.evict_prepared_if_full();
 
             let sql_str = encoded_sql_str(&conn.sql_buf)?;
+            let name = conn.described_stmt_name_for(sql_hash, sql_str);
 
             use crate::protocol::PgEncoder;
             let parse_msg = PgEncoder::try_encode_parse(&name, sql_str, &[])?;
@@ -1076,39 +1074,8 @@ impl PooledConnection {
 #[cfg(test)]
 mod tests {
     use super::{copy_export_table_sql, encoded_sql_str, return_with_desync};
-
     #[cfg(unix)]
-    fn test_conn() -> crate::driver::PgConnection {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-        crate::driver::PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: crate::driver::PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: crate::driver::PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: false,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        }
-    }
+    use crate::driver::test_support::test_conn;
 
     #[test]
     fn pool_copy_export_table_sql_preserves_schema_qualified_table() {
@@ -5,7 +5,9 @@ use crate::driver::pool::config::*;
 use crate::driver::pool::gss::*;
 use crate::driver::pool::lifecycle::*;
 use crate::driver::pool::{PgPool, PoolConfig, PooledConnection};
-use crate::driver::{AuthSettings, GssEncMode, PgConnection, PgError, TlsMode};
+#[cfg(unix)]
+use crate::driver::test_support::{test_conn_with_peer, write_backend_frame};
+use crate::driver::{AuthSettings, GssEncMode, PgError, TlsMode};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
@@ -315,35 +317,8 @@ async fn test_close_graceful_unblocks_waiting_acquire() {
 #[cfg(unix)]
 #[tokio::test]
 async fn test_release_drops_desynced_connection_without_commit() {
-    use crate::driver::connection::StatementCache;
-    use crate::driver::stream::PgStream;
-    use bytes::BytesMut;
-    use std::collections::{HashMap, VecDeque};
-    use std::num::NonZeroUsize;
-    use tokio::net::UnixStream;
-
-    let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-    let conn = PgConnection {
-        stream: PgStream::Unix(unix_stream),
-        buffer: BytesMut::with_capacity(1024),
-        write_buf: BytesMut::with_capacity(1024),
-        sql_buf: BytesMut::with_capacity(256),
-        params_buf: Vec::new(),
-        prepared_statements: HashMap::new(),
-        stmt_cache: StatementCache::new(NonZeroUsize::new(16).expect("non-zero")),
-        column_info_cache: HashMap::new(),
-        process_id: 0,
-        cancel_key_bytes: Vec::new(),
-        requested_protocol_minor: PgConnection::default_protocol_minor(),
-        negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-        notifications: VecDeque::new(),
-        replication_stream_active: false,
-        replication_mode_enabled: false,
-        last_replication_wal_end: None,
-        io_desynced: true,
-        pending_statement_closes: Vec::new(),
-        draining_statement_closes: false,
-    };
+    let (mut conn, _peer) = test_conn_with_peer();
+    conn.io_desynced = true;
 
     let pool = PgPool::connect(
         PoolConfig::new_dev("localhost", 5432, "user", "db")
@@ -379,19 +354,11 @@ async fn test_release_drops_desynced_connection_without_commit() {
 #[cfg(unix)]
 #[tokio::test]
 async fn test_release_raw_rolls_back_before_returning_connection() {
-    use crate::driver::connection::StatementCache;
-    use crate::driver::stream::PgStream;
-    use bytes::BytesMut;
-    use std::collections::{HashMap, VecDeque};
-    use std::num::NonZeroUsize;
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
-    use tokio::net::UnixStream;
 
     fn backend_frame(msg_type: u8, payload: &[u8]) -> Vec<u8> {
         let mut out = Vec::new();
-        out.push(msg_type);
-        out.extend_from_slice(&((payload.len() + 4) as u32).to_be_bytes());
-        out.extend_from_slice(payload);
+        write_backend_frame(&mut out, msg_type, payload);
         out
     }
 
@@ -401,28 +368,7 @@ async fn test_release_raw_rolls_back_before_returning_connection() {
         backend_frame(b'C', &payload)
     }
 
-    let (unix_stream, mut peer) = UnixStream::pair().expect("unix stream pair");
-    let conn = PgConnection {
-        stream: PgStream::Unix(unix_stream),
-        buffer: BytesMut::with_capacity(1024),
-        write_buf: BytesMut::with_capacity(1024),
-        sql_buf: BytesMut::with_capacity(256),
-        params_buf: Vec::new(),
-        prepared_statements: HashMap::new(),
-        stmt_cache: StatementCache::new(NonZeroUsize::new(16).expect("non-zero")),
-        column_info_cache: HashMap::new(),
-        process_id: 0,
-        cancel_key_bytes: Vec::new(),
-        requested_protocol_minor: PgConnection::default_protocol_minor(),
-        negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-        notifications: VecDeque::new(),
-        replication_stream_active: false,
-        replication_mode_enabled: false,
-        last_replication_wal_end: None,
-        io_desynced: false,
-        pending_statement_closes: Vec::new(),
-        draining_statement_closes: false,
-    };
+    let (conn, mut peer) = test_conn_with_peer();
 
     let pool = PgPool::connect(
         PoolConfig::new_dev("localhost", 5432, "user", "db")
@@ -475,35 +421,7 @@ async fn test_release_raw_rolls_back_before_returning_connection() {
 #[cfg(unix)]
 #[tokio::test]
 async fn test_execute_simple_with_timeout_marks_connection_desynced() {
-    use crate::driver::connection::StatementCache;
-    use crate::driver::stream::PgStream;
-    use bytes::BytesMut;
-    use std::collections::{HashMap, VecDeque};
-    use std::num::NonZeroUsize;
-    use tokio::net::UnixStream;
-
-    let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-    let mut conn = PgConnection {
-        stream: PgStream::Unix(unix_stream),
-        buffer: BytesMut::with_capacity(1024),
-        write_buf: BytesMut::with_capacity(1024),
-        sql_buf: BytesMut::with_capacity(256),
-        params_buf: Vec::new(),
-        prepared_statements: HashMap::new(),
-        stmt_cache: StatementCache::new(NonZeroUsize::new(16).expect("non-zero")),
-        column_info_cache: HashMap::new(),
-        process_id: 0,
-        cancel_key_bytes: Vec::new(),
-        requested_protocol_minor: PgConnection::default_protocol_minor(),
-        negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-        notifications: VecDeque::new(),
-        replication_stream_active: false,
-        replication_mode_enabled: false,
-        last_replication_wal_end: None,
-        io_desynced: false,
-        pending_statement_closes: Vec::new(),
-        draining_statement_closes: false,
-    };
+    let (mut conn, _peer) = test_conn_with_peer();
 
     let err = execute_simple_with_timeout(
         &mut conn,
@@ -9,6 +9,10 @@ use std::hash::{Hash, Hasher};
 /// A prepared statement handle with pre-computed statement name.
 /// This eliminates per-query hash computation and HashMap lookup.
 /// Create once, execute many times.
+///
+/// Dropping a handle does not deallocate the statement: handles are cheap
+/// clones with no connection reference, so any copy may still be in use.
+/// Release it explicitly with `PgConnection::deallocate`.
 /// # Example
 /// ```ignore
 /// // Prepare once (compute hash + register with PostgreSQL)
@@ -374,6 +374,78 @@ impl PgConnection {
         params: &[Option<Vec<u8>>],
         result_format: i16,
     ) -> PgResult<Vec<super::PgRow>> {
+        self.query_rows_capped(sql, param_types, params, result_format, None)
+            .await
+    }
+
+    /// Execute a query and return at most `max_rows` rows.
+    ///
+    /// Execute is sent with a row limit of `max_rows + 1`, so the server never
+    /// streams more than one row past the cap and nothing beyond `max_rows` is
+    /// buffered. If the query would return more rows,
+    /// [`PgError::RowLimitExceeded`] is returned instead of a truncated result.
+    pub async fn query_rows_with_max_rows(
+        &mut self,
+        sql: &str,
+        params: &[Option<Vec<u8>>],
+        result_format: i16,
+        max_rows: usize,
+    ) -> PgResult<Vec<super::PgRow>> {
+        self.query_rows_capped(sql, &[], params, result_format, Some(max_rows))
+            .await
+    }
+
+    /// Execute a query and hand each row to `on_row` as its DataRow arrives.
+    ///
+    /// Unlike [`query_rows`](Self::query_rows), nothing is accumulated: each
+    /// [`PgRow`](super::PgRow) carries the shared column metadata and is
+    /// dropped or kept by the callback, so memory stays bounded for
+    /// arbitrarily large result sets. Returns the number of rows visited.
+    /// An error from `on_row` aborts the query and marks the connection
+    /// desynchronized.
+    pub async fn query_for_each_row<F>(
+        &mut self,
+        sql: &str,
+        params: &[Option<Vec<u8>>],
+        result_format: i16,
+        on_row: F,
+    ) -> PgResult<usize>
+    where
+        F: FnMut(super::PgRow) -> PgResult<()>,
+    {
+        self.query_rows_visit(sql, &[], params, result_format, None, on_row)
+            .await
+    }
+
+    async fn query_rows_capped(
+        &mut self,
+        sql: &str,
+        param_types: &[u32],
+        params: &[Option<Vec<u8>>],
+        result_format: i16,
+        max_rows: Option<usize>,
+    ) -> PgResult<Vec<super::PgRow>> {
+        let mut rows = Vec::new();
+        self.query_rows_visit(sql, param_types, params, result_format, max_rows, |row| {
+            rows.push(row);
+            Ok(())
+        })
+        .await?;
+        Ok(rows)
+    }
+
+    async fn query_rows_visit<F>(
+        &mut self,
+        sql: &str,
+        param_types: &[u32],
+        params: &[Option<Vec<u8>>],
+        result_format: i16,
+        max_rows: Option<usize>,
+        mut on_row: F,
+    ) -> PgResult<usize>
+    where
+        F: FnMut(super::PgRow) -> PgResult<()>,
+    {
         use std::sync::Arc;
 
         Self::validate_param_type_arity(params, param_types)?;
@@ -391,13 +463,24 @@ impl PgConnection {
         let describe_msg =
             PgEncoder::try_encode_describe(true, "").map_err(|e| PgError::Encode(e.to_string()))?;
         self.write_buf.extend_from_slice(&describe_msg);
-        PgEncoder::encode_execute_to(&mut self.write_buf);
+        match max_rows {
+            Some(limit) => {
+                // One row past the cap tells "exactly `limit`" from "more".
+                // Caps beyond i32 fall back to an unbounded Execute.
+                let execute_limit = i32::try_from(limit.saturating_add(1)).unwrap_or(0);
+                let execute_msg = PgEncoder::try_encode_execute("", execute_limit)
+                    .map_err(|e| PgError::Encode(e.to_string()))?;
+                self.write_buf.extend_from_slice(&execute_msg);
+            }
+            None => PgEncoder::encode_execute_to(&mut self.write_buf),
+        }
         PgEncoder::encode_sync_to(&mut self.write_buf);
         self.flush_write_buf().await?;
 
-        let mut rows: Vec<super::PgRow> = Vec::new();
+        let mut row_count = 0usize;
         let mut column_info: Option<Arc<super::ColumnInfo>> = None;
         let mut error: Option<PgError> = None;
+        let mut limit_exceeded = false;
         let mut flow =
             ExtendedFlowTracker::new(ExtendedFlowConfig::parse_bind_describe_portal_execute());
 
@@ -414,19 +497,30 @@ impl PgConnection {
                 }
                 BackendMessage::DataRow(data) => {
                     if error.is_none() {
-                        rows.push(super::PgRow {
-                            columns: data,
-                            column_info: column_info.clone(),
-                        });
+                        if max_rows.is_some_and(|limit| row_count >= limit) {
+                            limit_exceeded = true;
+                        } else {
+                            let row = super::PgRow {
+                                columns: data,
+                                column_info: column_info.clone(),
+                            };
+                            if let Err(err) = on_row(row) {
+                                return return_callback_error_with_desync(self, err);
+                            }
+                            row_count += 1;
+                        }
                     }
                 }
-                BackendMessage::CommandComplete(_) => {}
+                BackendMessage::CommandComplete(_) | BackendMessage::PortalSuspended => {}
                 BackendMessage::NoData => {}
                 BackendMessage::ReadyForQuery(_) => {
                     if let Some(err) = error {
                         return Err(err);
                     }
-                    return Ok(rows);
+                    if limit_exceeded && let Some(limit) = max_rows {
+                        return Err(PgError::RowLimitExceeded { limit });
+                    }
+                    return Ok(row_count);
                 }
                 BackendMessage::ErrorResponse(err) => {
                     if error.is_none() {
@@ -741,7 +835,7 @@ impl PgConnection {
         params: &[Option<Vec<u8>>],
         result_format: i16,
     ) -> PgResult<Vec<Vec<Option<Vec<u8>>>>> {
-        let stmt_name = Self::sql_to_stmt_name(sql);
+        let stmt_name = self.prepared_stmt_name_for(sql);
         let is_new = !self.prepared_statements.contains_key(&stmt_name);
 
         let needed = prepared_bind_execute_sync_wire_len(&stmt_name, params, result_format)?;
@@ -870,12 +964,6 @@ impl PgConnection {
         }
     }
 
-    /// Generate a statement name from SQL hash.
-    /// Uses a simple hash to create a unique name like "stmt_12345abc".
-    pub(crate) fn sql_to_stmt_name(sql: &str) -> String {
-        super::prepared::sql_bytes_to_stmt_name(sql.as_bytes())
-    }
-
     /// Execute a simple SQL statement (no parameters).
     pub async fn execute_simple(&mut self, sql: &str) -> PgResult<()> {
         let bytes = PgEncoder::try_encode_query_string(sql)?;
@@ -1022,6 +1110,97 @@ impl PgConnection {
         }
     }
 
+    /// Run a multi-statement SQL string in one Simple Query round-trip.
+    ///
+    /// Returns one [`ResultSet`](super::ResultSet) per statement, in order,
+    /// collected until `ReadyForQuery`. The server runs the statements in an
+    /// implicit transaction, so the first error aborts the rest and is
+    /// returned instead of partial results.
+    ///
+    /// SECURITY: Capped at 10,000 rows in total, like `simple_query`.
+    pub async fn fetch_multi(&mut self, sql: &str) -> PgResult<Vec<super::ResultSet>> {
+        use std::sync::Arc;
+
+        const MAX_SIMPLE_QUERY_ROWS: usize = 10_000;
+
+        let bytes = PgEncoder::try_encode_query_string(sql)?;
+        self.send_bytes(&bytes).await?;
+
+        let mut sets: Vec<super::ResultSet> = Vec::new();
+        let mut rows: Vec<super::PgRow> = Vec::new();
+        let mut total_rows = 0usize;
+        let mut column_info: Option<Arc<super::ColumnInfo>> = None;
+        let mut error: Option<PgError> = None;
+        let mut flow = SimpleFlowTracker::new();
+
+        loop {
+            let msg = self.recv().await?;
+            match msg {
+                BackendMessage::RowDescription(fields) => {
+                    if let Err(err) = flow.on_row_description("simple-query multi") {
+                        return return_with_desync(self, err);
+                    }
+                    column_info = Some(Arc::new(super::ColumnInfo::from_fields(&fields)));
+                }
+                BackendMessage::DataRow(data) => {
+                    if let Err(err) = flow.on_data_row("simple-query multi") {
+                        return return_with_desync(self, err);
+                    }
+                    if error.is_none() {
+                        if total_rows >= MAX_SIMPLE_QUERY_ROWS {
+                            error = Some(PgError::Query(format!(
+                                "fetch_multi exceeded {} row safety cap",
+                                MAX_SIMPLE_QUERY_ROWS,
+                            )));
+                        } else {
+                            total_rows += 1;
+                            rows.push(super::PgRow {
+                                columns: data,
+                                column_info: column_info.clone(),
+                            });
+                        }
+                    }
+                }
+                BackendMessage::CommandComplete(tag) => {
+                    flow.on_command_complete();
+                    sets.push(super::ResultSet {
+                        column_info: column_info.take(),
+                        rows: std::mem::take(&mut rows),
+                        command_tag: tag,
+                    });
+                }
+                BackendMessage::EmptyQueryResponse => {
+                    if let Err(err) = flow.on_empty_query_response("simple-query multi") {
+                        return return_with_desync(self, err);
+                    }
+                    column_info = None;
+                }
+                BackendMessage::ReadyForQuery(_) => {
+                    if let Some(err) = error {
+                        return Err(err);
+                    }
+                    if let Err(err) = flow.on_ready_for_query("simple-query multi", error.is_some())
+                    {
+                        return return_with_desync(self, err);
+                    }
+                    return Ok(sets);
+                }
+                BackendMessage::ErrorResponse(err) => {
+                    if error.is_none() {
+                        error = Some(PgError::QueryServer(err.into()));
+                    }
+                }
+                msg if is_ignorable_session_message(&msg) => {}
+                other => {
+                    return return_with_desync(
+                        self,
+                        unexpected_backend_message("simple-query multi", &other),
+                    );
+                }
+            }
+        }
+    }
+
     /// ZERO-HASH sequential query using pre-computed PreparedStatement.
     /// This is the FASTEST sequential path because it skips:
     /// - SQL generation from AST (done once outside loop)
@@ -1854,55 +2033,10 @@ impl PgConnection {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[cfg(unix)]
-    fn test_conn_with_peer() -> (PgConnection, tokio::net::UnixStream) {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, peer) = UnixStream::pair().expect("unix stream pair");
-        (
-            PgConnection {
-                stream: PgStream::Unix(unix_stream),
-                buffer: BytesMut::with_capacity(1024),
-                write_buf: BytesMut::with_capacity(1024),
-                sql_buf: BytesMut::with_capacity(256),
-                params_buf: Vec::new(),
-                prepared_statements: HashMap::new(),
-                stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-                column_info_cache: HashMap::new(),
-                process_id: 0,
-                cancel_key_bytes: Vec::new(),
-                requested_protocol_minor: PgConnection::default_protocol_minor(),
-                negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-                notifications: VecDeque::new(),
-                replication_stream_active: false,
-                replication_mode_enabled: false,
-                last_replication_wal_end: None,
-                io_desynced: false,
-                pending_statement_closes: Vec::new(),
-                draining_statement_closes: false,
-            },
-            peer,
-        )
-    }
-
     #[cfg(unix)]
-    fn test_conn() -> PgConnection {
-        test_conn_with_peer().0
-    }
-
-    #[cfg(unix)]
-    fn push_backend_frame(conn: &mut PgConnection, msg_type: u8, payload: &[u8]) {
-        conn.buffer.extend_from_slice(&[msg_type]);
-        conn.buffer
-            .extend_from_slice(&((payload.len() + 4) as u32).to_be_bytes());
-        conn.buffer.extend_from_slice(payload);
-    }
+    use crate::driver::test_support::{
+        push_backend_frame, test_conn, test_conn_with_peer, write_backend_frame,
+    };
 
     fn error_response_payload(code: &str, message: &str) -> Vec<u8> {
         let mut payload = Vec::new();
@@ -1927,11 +2061,13 @@ mod tests {
         assert!(matches!(err, PgError::Encode(msg) if msg.contains("Too many parameters")));
     }
 
-    #[test]
-    fn sql_to_stmt_name_matches_prepared_statement_identity() {
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn prepared_stmt_name_matches_prepared_statement_identity() {
+        let conn = test_conn();
         let sql = "SELECT id, name FROM users WHERE id = $1";
         let stmt = super::super::PreparedStatement::from_sql(sql);
-        assert_eq!(PgConnection::sql_to_stmt_name(sql), stmt.name());
+        assert_eq!(conn.prepared_stmt_name_for(sql), stmt.name());
     }
 
     #[cfg(unix)]
@@ -1980,12 +2116,251 @@ mod tests {
         assert!(conn.is_io_desynced());
     }
 
+    #[cfg(unix)]
+    fn row_description_payload(names: &[&str]) -> Vec<u8> {
+        let mut payload = (names.len() as i16).to_be_bytes().to_vec();
+        for name in names {
+            payload.extend_from_slice(name.as_bytes());
+            payload.push(0);
+            payload.extend_from_slice(&0i32.to_be_bytes()); // table oid
+            payload.extend_from_slice(&0i16.to_be_bytes()); // column attr
+            payload.extend_from_slice(&23i32.to_be_bytes()); // int4
+            payload.extend_from_slice(&4i16.to_be_bytes()); // type size
+            payload.extend_from_slice(&(-1i32).to_be_bytes()); // typmod
+            payload.extend_from_slice(&0i16.to_be_bytes()); // text format
+        }
+        payload
+    }
+
+    #[cfg(unix)]
+    fn data_row_payload(values: &[&str]) -> Vec<u8> {
+        let mut payload = (values.len() as i16).to_be_bytes().to_vec();
+        for value in values {
+            payload.extend_from_slice(&(value.len() as i32).to_be_bytes());
+            payload.extend_from_slice(value.as_bytes());
+        }
+        payload
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn fetch_multi_splits_result_sets_per_statement() {
+        let (mut conn, _peer) = test_conn_with_peer();
+        push_backend_frame(&mut conn, b'T', &row_description_payload(&["a"]));
+        push_backend_frame(&mut conn, b'D', &data_row_payload(&["1"]));
+        push_backend_frame(&mut conn, b'C', b"SELECT 1\0");
+        push_backend_frame(&mut conn, b'T', &row_description_payload(&["b", "c"]));
+        push_backend_frame(&mut conn, b'D', &data_row_payload(&["2", "3"]));
+        push_backend_frame(&mut conn, b'D', &data_row_payload(&["4", "5"]));
+        push_backend_frame(&mut conn, b'C', b"SELECT 2\0");
+        push_backend_frame(&mut conn, b'Z', b"I");
+
+        let sets = conn
+            .fetch_multi("SELECT 1 AS a; SELECT * FROM (VALUES (2, 3), (4, 5)) v(b, c)")
+            .await
+            .expect("two result sets");
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].command_tag, "SELECT 1");
+        assert_eq!(sets[0].rows.len(), 1);
+        assert_eq!(sets[0].rows[0].columns, vec![Some(b"1".to_vec())]);
+        assert_eq!(sets[1].command_tag, "SELECT 2");
+        assert_eq!(sets[1].rows.len(), 2);
+        assert_eq!(
+            sets[1].rows[1].columns,
+            vec![Some(b"4".to_vec()), Some(b"5".to_vec())]
+        );
+        let info = sets[1].column_info.as_ref().expect("column info");
+        assert_eq!(info.name_to_index.get("c"), Some(&1));
+        assert!(!conn.is_io_desynced());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn fetch_multi_returns_server_error_after_ready_for_query() {
+        let (mut conn, _peer) = test_conn_with_peer();
+        push_backend_frame(&mut conn, b'C', b"CREATE TABLE\0");
+        push_backend_frame(
+            &mut conn,
+            b'E',
+            &error_response_payload("42P01", "relation \"missing\" does not exist"),
+        );
+        push_backend_frame(&mut conn, b'Z', b"I");
+
+        let err = conn
+            .fetch_multi("CREATE TABLE t (id int); SELECT * FROM missing")
+            .await
+            .err()
+            .expect("second statement fails");
+
+        assert!(err.to_string().contains("does not exist"), "{err}");
+        assert!(!conn.is_io_desynced());
+    }
+
+    /// Mock server: read frontend messages up to Sync, then answer with a
+    /// 100-row result that honors the Execute row limit like PostgreSQL.
+    /// Returns the row limit the client sent.
+    #[cfg(unix)]
+    async fn serve_hundred_rows(mut peer: tokio::net::UnixStream) -> i32 {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut max_rows = None;
+        loop {
+            let msg_type = peer.read_u8().await.expect("message type");
+            let len = peer.read_i32().await.expect("message length") as usize;
+            let mut body = vec![0u8; len - 4];
+            peer.read_exact(&mut body).await.expect("message body");
+            match msg_type {
+                b'E' => {
+                    let tail: [u8; 4] = body[body.len() - 4..].try_into().unwrap();
+                    max_rows = Some(i32::from_be_bytes(tail));
+                }
+                b'S' => break,
+                _ => {}
+            }
+        }
+        let max_rows = max_rows.expect("Execute before Sync");
+
+        let total = 100;
+        let sent = if max_rows == 0 {
+            total
+        } else {
+            total.min(max_rows)
+        };
+        let mut out = Vec::new();
+        write_backend_frame(&mut out, b'1', &[]);
+        write_backend_frame(&mut out, b'2', &[]);
+        write_backend_frame(&mut out, b'T', &row_description_payload(&["n"]));
+        for n in 0..sent {
+            write_backend_frame(&mut out, b'D', &data_row_payload(&[&n.to_string()]));
+        }
+        if sent < total {
+            write_backend_frame(&mut out, b's', &[]);
+        } else {
+            write_backend_frame(&mut out, b'C', format!("SELECT {total}\0").as_bytes());
+        }
+        write_backend_frame(&mut out, b'Z', b"I");
+        peer.write_all(&out).await.expect("write response");
+        max_rows
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn query_rows_with_max_rows_errors_past_cap() {
+        let (mut conn, peer) = test_conn_with_peer();
+        let server = tokio::spawn(serve_hundred_rows(peer));
+
+        let err = conn
+            .query_rows_with_max_rows(
+                "SELECT generate_series(0, 99) AS n",
+                &[],
+                PgEncoder::FORMAT_TEXT,
+                10,
+            )
+            .await
+            .err()
+            .expect("100 rows exceed a cap of 10");
+
+        assert!(
+            matches!(err, PgError::RowLimitExceeded { limit: 10 }),
+            "{err}"
+        );
+        // The server was asked for one row past the cap, not the full result.
+        assert_eq!(server.await.unwrap(), 11);
+        assert!(!conn.is_io_desynced());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn query_rows_with_max_rows_allows_result_at_cap() {
+        let (mut conn, peer) = test_conn_with_peer();
+        let server = tokio::spawn(serve_hundred_rows(peer));
+
+        let rows = conn
+            .query_rows_with_max_rows("SELECT n FROM t", &[], PgEncoder::FORMAT_TEXT, 100)
+            .await
+            .expect("exactly 100 rows fit a cap of 100");
+
+        assert_eq!(rows.len(), 100);
+        assert_eq!(server.await.unwrap(), 101);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn query_for_each_row_yields_rows_before_result_completes() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        const TOTAL: usize = 10_000;
+
+        fn data_rows(out: &mut Vec<u8>, range: std::ops::Range<usize>) {
+            for n in range {
+                write_backend_frame(out, b'D', &data_row_payload(&[&n.to_string()]));
+            }
+        }
+
+        let (mut conn, mut peer) = test_conn_with_peer();
+        let (first_row_tx, first_row_rx) = tokio::sync::oneshot::channel::<()>();
+
+        // The server withholds the second half of the result (and
+        // ReadyForQuery) until the client has seen a row, so a buffering
+        // implementation would never complete.
+        let server = tokio::spawn(async move {
+            loop {
+                let msg_type = peer.read_u8().await.expect("message type");
+                let len = peer.read_i32().await.expect("message length") as usize;
+                let mut body = vec![0u8; len - 4];
+                peer.read_exact(&mut body).await.expect("message body");
+                if msg_type == b'S' {
+                    break;
+                }
+            }
+            let mut out = Vec::new();
+            write_backend_frame(&mut out, b'1', &[]);
+            write_backend_frame(&mut out, b'2', &[]);
+            write_backend_frame(&mut out, b'T', &row_description_payload(&["n"]));
+            data_rows(&mut out, 0..TOTAL / 2);
+            peer.write_all(&out).await.expect("write first half");
+
+            first_row_rx.await.expect("client saw a row");
+
+            let mut out = Vec::new();
+            data_rows(&mut out, TOTAL / 2..TOTAL);
+            write_backend_frame(&mut out, b'C', format!("SELECT {TOTAL}\0").as_bytes());
+            write_backend_frame(&mut out, b'Z', b"I");
+            peer.write_all(&out).await.expect("write second half");
+            peer
+        });
+
+        let mut first_row_tx = Some(first_row_tx);
+        let mut next = 0usize;
+        let visited = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            conn.query_for_each_row("SELECT n FROM big", &[], PgEncoder::FORMAT_TEXT, |row| {
+                assert_eq!(row.get_string(0), Some(next.to_string()));
+                assert_eq!(row.column_index("n"), Some(0));
+                next += 1;
+                if let Some(tx) = first_row_tx.take() {
+                    tx.send(()).expect("server waiting");
+                }
+                Ok(())
+            }),
+        )
+        .await
+        .expect("rows must be yielded before the result completes")
+        .expect("query succeeds");
+
+        assert_eq!(visited, TOTAL);
+        assert_eq!(next, TOTAL);
+        server.await.expect("server task");
+        assert!(!conn.is_io_desynced());
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn query_cached_keeps_statement_after_post_parse_error() {
         let (mut conn, _peer) = test_conn_with_peer();
         let sql = "SELECT $1";
-        let stmt_name = PgConnection::sql_to_stmt_name(sql);
+        let stmt_name = conn.prepared_stmt_name_for(sql);
         let err_payload = error_response_payload("23514", "check constraint violation");
 
         push_backend_frame(&mut conn, b'1', &[]);
@@ -2002,12 +2377,34 @@ mod tests {
         assert!(!conn.is_io_desynced());
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn query_cached_does_not_shadow_a_statement_holding_other_sql() {
+        let (mut conn, _peer) = test_conn_with_peer();
+        let sql = "SELECT $1";
+        let taken = conn.prepared_stmt_name_for(sql);
+        conn.prepared_statements
+            .insert(taken.clone(), "SELECT 'other'".to_string());
+
+        push_backend_frame(&mut conn, b'1', &[]);
+        push_backend_frame(&mut conn, b'2', &[]);
+        push_backend_frame(&mut conn, b'C', b"SELECT 0\0");
+        push_backend_frame(&mut conn, b'Z', b"I");
+
+        conn.query_cached(sql, &[Some(b"1".to_vec())])
+            .await
+            .expect("cached query");
+
+        assert_eq!(conn.prepared_statements[&taken], "SELECT 'other'");
+        assert_eq!(conn.prepared_statements[&format!("{taken}_1")], sql);
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn query_cached_removes_new_statement_when_parse_fails() {
         let (mut conn, _peer) = test_conn_with_peer();
         let sql = "SELECT broken";
-        let stmt_name = PgConnection::sql_to_stmt_name(sql);
+        let stmt_name = conn.prepared_stmt_name_for(sql);
         let err_payload = error_response_payload("42601", "syntax error");
 
         push_backend_frame(&mut conn, b'E', &err_payload);
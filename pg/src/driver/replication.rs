@@ -732,38 +732,14 @@ impl PgDriver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(unix)]
+    use crate::driver::test_support::push_backend_frame;
 
     #[cfg(unix)]
     fn test_conn() -> PgConnection {
-        use crate::driver::connection::StatementCache;
-        use crate::driver::stream::PgStream;
-        use bytes::BytesMut;
-        use std::collections::{HashMap, VecDeque};
-        use std::num::NonZeroUsize;
-        use tokio::net::UnixStream;
-
-        let (unix_stream, _peer) = UnixStream::pair().expect("unix stream pair");
-        PgConnection {
-            stream: PgStream::Unix(unix_stream),
-            buffer: BytesMut::with_capacity(1024),
-            write_buf: BytesMut::with_capacity(1024),
-            sql_buf: BytesMut::with_capacity(256),
-            params_buf: Vec::new(),
-            prepared_statements: HashMap::new(),
-            stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
-            column_info_cache: HashMap::new(),
-            process_id: 0,
-            cancel_key_bytes: Vec::new(),
-            requested_protocol_minor: PgConnection::default_protocol_minor(),
-            negotiated_protocol_minor: PgConnection::default_protocol_minor(),
-            notifications: VecDeque::new(),
-            replication_stream_active: false,
-            replication_mode_enabled: true,
-            last_replication_wal_end: None,
-            io_desynced: false,
-            pending_statement_closes: Vec::new(),
-            draining_statement_closes: false,
-        }
+        let mut conn = crate::driver::test_support::test_conn();
+        conn.replication_mode_enabled = true;
+        conn
     }
 
     fn text_row(values: &[Option<&str>]) -> PgRow {
@@ -776,14 +752,6 @@ mod tests {
         }
     }
 
-    #[cfg(unix)]
-    fn push_backend_frame(conn: &mut PgConnection, msg_type: u8, payload: &[u8]) {
-        conn.buffer.extend_from_slice(&[msg_type]);
-        conn.buffer
-            .extend_from_slice(&((payload.len() + 4) as u32).to_be_bytes());
-        conn.buffer.extend_from_slice(payload);
-    }
-
     fn error_response_payload(code: &str, message: &str) -> Vec<u8> {
         let mut payload = Vec::new();
         payload.push(b'S');
@@ -0,0 +1,54 @@
+//! Shared fixtures for driver unit tests.
+
+use crate::driver::PgConnection;
+use crate::driver::connection::StatementCache;
+use crate::driver::stream::PgStream;
+use bytes::BytesMut;
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use tokio::net::UnixStream;
+
+/// A fresh connection over one end of a Unix socket pair, plus the peer end
+/// for tests that script or inspect the wire.
+pub(crate) fn test_conn_with_peer() -> (PgConnection, UnixStream) {
+    let (unix_stream, peer) = UnixStream::pair().expect("unix stream pair");
+    let conn = PgConnection {
+        stream: PgStream::Unix(unix_stream),
+        buffer: BytesMut::with_capacity(1024),
+        write_buf: BytesMut::with_capacity(1024),
+        sql_buf: BytesMut::with_capacity(256),
+        params_buf: Vec::new(),
+        prepared_statements: HashMap::new(),
+        stmt_cache: StatementCache::new(NonZeroUsize::new(2).expect("non-zero")),
+        column_info_cache: HashMap::new(),
+        process_id: 0,
+        cancel_key_bytes: Vec::new(),
+        requested_protocol_minor: PgConnection::default_protocol_minor(),
+        negotiated_protocol_minor: PgConnection::default_protocol_minor(),
+        notifications: VecDeque::new(),
+        replication_stream_active: false,
+        replication_mode_enabled: false,
+        last_replication_wal_end: None,
+        io_desynced: false,
+        pending_statement_closes: Vec::new(),
+        draining_statement_closes: false,
+    };
+    (conn, peer)
+}
+
+/// A fresh connection whose peer end is already closed.
+pub(crate) fn test_conn() -> PgConnection {
+    test_conn_with_peer().0
+}
+
+/// Append one backend message frame (type byte, length, payload) to `out`.
+pub(crate) fn write_backend_frame(out: &mut impl Extend<u8>, msg_type: u8, payload: &[u8]) {
+    out.extend([msg_type]);
+    out.extend(((payload.len() + 4) as u32).to_be_bytes());
+    out.extend(payload.iter().copied());
+}
+
+/// Queue one backend message frame in the connection's read buffer.
+pub(crate) fn push_backend_frame(conn: &mut PgConnection, msg_type: u8, payload: &[u8]) {
+    write_backend_frame(&mut conn.buffer, msg_type, payload);
+}
@@ -0,0 +1,154 @@
+//! Optional `tracing` spans around driver round-trips.
+//!
+//! With the `tracing` feature, connect/prepare/bind/fetch run inside a
+//! `debug`-level span recording the SQL, parameter count, row count and
+//! elapsed time, and server errors emit a `warn` event carrying the SQLSTATE.
+//! Without the feature [`OpSpan`] is zero-sized and every method is a no-op.
+
+use super::PgResult;
+use std::future::Future;
+
+/// Span guard for a single driver operation.
+///
+/// Elapsed time is recorded when the guard is dropped, so early returns are
+/// still measured.
+pub(crate) struct OpSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    started: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl OpSpan {
+    fn from_span(span: tracing::Span) -> Self {
+        Self {
+            span,
+            started: std::time::Instant::now(),
+        }
+    }
+
+    /// Span for establishing a connection.
+    pub(crate) fn connect(transport: &'static str, host: &str, port: u16) -> Self {
+        Self::from_span(tracing::debug_span!(
+            "pg.connect",
+            transport,
+            host,
+            port,
+            elapsed_us = tracing::field::Empty,
+        ))
+    }
+
+    /// Span for a Parse round-trip.
+    pub(crate) fn prepare(sql: &str) -> Self {
+        Self::from_span(tracing::debug_span!(
+            "pg.prepare",
+            sql,
+            elapsed_us = tracing::field::Empty,
+        ))
+    }
+
+    /// Span for binding and executing an already prepared statement.
+    pub(crate) fn bind(sql: &str, params: usize) -> Self {
+        Self::from_span(tracing::debug_span!(
+            "pg.bind",
+            sql,
+            params,
+            rows = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+        ))
+    }
+
+    /// Span for an encode + execute + fetch round-trip.
+    /// The SQL is recorded once encoded, via [`record_sql`](Self::record_sql).
+    pub(crate) fn fetch() -> Self {
+        Self::from_span(tracing::debug_span!(
+            "pg.fetch",
+            sql = tracing::field::Empty,
+            params = tracing::field::Empty,
+            rows = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+        ))
+    }
+
+    /// Record the encoded SQL text and bind parameter count.
+    pub(crate) fn record_sql(&self, sql: &[u8], params: usize) {
+        self.span
+            .record("sql", String::from_utf8_lossy(sql).as_ref());
+        self.span.record("params", params);
+    }
+
+    /// Run `fut` inside this span.
+    pub(crate) fn instrument<F: Future>(&self, fut: F) -> impl Future<Output = F::Output> {
+        tracing::Instrument::instrument(fut, self.span.clone())
+    }
+
+    /// Record the outcome, emitting a SQLSTATE event on a server error.
+    pub(crate) fn finish<T>(&self, result: &PgResult<T>) {
+        if let Err(err) = result
+            && let Some(server) = err.server_error()
+        {
+            self.span.in_scope(|| {
+                tracing::warn!(
+                    sqlstate = %server.code,
+                    severity = %server.severity,
+                    message = %server.message,
+                    "postgres server error"
+                );
+            });
+        }
+    }
+
+    /// Record the outcome of a row-returning operation, including the row count.
+    pub(crate) fn finish_rows<T>(&self, result: &PgResult<Vec<T>>) {
+        if let Ok(rows) = result {
+            self.span.record("rows", rows.len());
+        }
+        self.finish(result);
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for OpSpan {
+    fn drop(&mut self) {
+        let elapsed_us = u64::try_from(self.started.elapsed().as_micros()).unwrap_or(u64::MAX);
+        self.span.record("elapsed_us", elapsed_us);
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl OpSpan {
+    #[inline(always)]
+    pub(crate) fn connect(_transport: &'static str, _host: &str, _port: u16) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub(crate) fn prepare(_sql: &str) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub(crate) fn bind(_sql: &str, _params: usize) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub(crate) fn fetch() -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub(crate) fn record_sql(&self, _sql: &[u8], _params: usize) {}
+
+    #[inline(always)]
+    pub(crate) fn instrument<F: Future>(&self, fut: F) -> F {
+        fut
+    }
+
+    #[inline(always)]
+    pub(crate) fn finish<T>(&self, _result: &PgResult<T>) {}
+
+    #[inline(always)]
+    pub(crate) fn finish_rows<T>(&self, _result: &PgResult<Vec<T>>) {}
+}
@@ -1,6 +1,38 @@
 //! Transaction control methods for PostgreSQL connection.
 
-use super::{PgConnection, PgError, PgResult};
+use std::time::Duration;
+
+use super::{PgConnection, PgError, PgResult, ScopedPoolFuture};
+
+/// Retry policy for [`PgConnection::transaction_with_retry`].
+///
+/// Only serialization failures (`40001`) and deadlocks (`40P01`) are
+/// retried; PostgreSQL aborts the whole transaction for both, so re-running
+/// the closure from `BEGIN` is safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionRetry {
+    /// Retries after the first attempt (0 disables retrying).
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    pub base_backoff: Duration,
+}
+
+impl Default for TransactionRetry {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_backoff: Duration::from_millis(10),
+        }
+    }
+}
+
+impl TransactionRetry {
+    /// Backoff before retry number `retry` (1-based).
+    fn backoff(&self, retry: u32) -> Duration {
+        self.base_backoff
+            .saturating_mul(1u32 << retry.saturating_sub(1).min(16))
+    }
+}
 
 /// Quote a SQL identifier (for savepoint names).
 /// Wraps in double-quotes and escapes embedded double-quotes.
@@ -65,9 +97,72 @@ impl PgConnection {
     }
 }
 
+impl PgConnection {
+    /// Run `f` inside `BEGIN` / `COMMIT` with the default [`TransactionRetry`].
+    pub async fn transaction<T, F>(&mut self, f: F) -> PgResult<T>
+    where
+        F: for<'a> FnMut(&'a mut PgConnection) -> ScopedPoolFuture<'a, T>,
+    {
+        self.transaction_with_retry(TransactionRetry::default(), f)
+            .await
+    }
+
+    /// Run `f` inside `BEGIN` / `COMMIT`, re-running the whole transaction
+    /// when it fails with a serialization failure or deadlock.
+    ///
+    /// Any error rolls the transaction back. Other errors, and the last
+    /// retryable one once `retry.max_retries` is exhausted, are returned.
+    ///
+    /// ```ignore
+    /// conn.transaction(|conn| scope(async move {
+    ///     conn.execute_simple("UPDATE accounts SET balance = balance - 10 WHERE id = 1").await
+    /// }))
+    /// .await?;
+    /// ```
+    pub async fn transaction_with_retry<T, F>(
+        &mut self,
+        retry: TransactionRetry,
+        mut f: F,
+    ) -> PgResult<T>
+    where
+        F: for<'a> FnMut(&'a mut PgConnection) -> ScopedPoolFuture<'a, T>,
+    {
+        let mut retries = 0u32;
+        loop {
+            match self.run_transaction_once(&mut f).await {
+                Err(err) if err.is_serialization_failure() && retries < retry.max_retries => {
+                    retries += 1;
+                    let delay = retry.backoff(retries);
+                    if !delay.is_zero() {
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn run_transaction_once<T, F>(&mut self, f: &mut F) -> PgResult<T>
+    where
+        F: for<'a> FnMut(&'a mut PgConnection) -> ScopedPoolFuture<'a, T>,
+    {
+        self.begin_transaction().await?;
+        let result = match f(self).await {
+            Ok(value) => self.commit().await.map(|()| value),
+            Err(err) => Err(err),
+        };
+        if result.is_err() && !self.is_io_desynced() {
+            let _ = self.rollback().await;
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::quote_savepoint_name;
+    use super::*;
+    #[cfg(unix)]
+    use crate::driver::test_support::{push_backend_frame, test_conn_with_peer};
 
     #[test]
     fn quote_savepoint_name_escapes_quotes() {
@@ -79,4 +174,90 @@ mod tests {
         assert!(quote_savepoint_name("").is_err());
         assert!(quote_savepoint_name("sp\0shadow").is_err());
     }
+
+    #[cfg(unix)]
+    fn push_command(conn: &mut PgConnection, tag: &str, status: u8) {
+        push_backend_frame(conn, b'C', format!("{tag}\0").as_bytes());
+        push_backend_frame(conn, b'Z', &[status]);
+    }
+
+    #[cfg(unix)]
+    fn push_error(conn: &mut PgConnection, code: &str) {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(b"SERROR\0");
+        payload.push(b'C');
+        payload.extend_from_slice(code.as_bytes());
+        payload.push(0);
+        payload.extend_from_slice(b"Mcould not serialize access\0");
+        payload.push(0);
+        push_backend_frame(conn, b'E', &payload);
+        push_backend_frame(conn, b'Z', b"E");
+    }
+
+    #[test]
+    fn retry_backoff_doubles_from_base() {
+        let retry = TransactionRetry {
+            max_retries: 3,
+            base_backoff: Duration::from_millis(10),
+        };
+        assert_eq!(retry.backoff(1), Duration::from_millis(10));
+        assert_eq!(retry.backoff(2), Duration::from_millis(20));
+        assert_eq!(retry.backoff(3), Duration::from_millis(40));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn transaction_retries_after_serialization_failure() {
+        let (mut conn, _peer) = test_conn_with_peer();
+        // Attempt 1: BEGIN, UPDATE fails with 40001, ROLLBACK.
+        push_command(&mut conn, "BEGIN", b'T');
+        push_error(&mut conn, "40001");
+        push_command(&mut conn, "ROLLBACK", b'I');
+        // Attempt 2: BEGIN, UPDATE, COMMIT.
+        push_command(&mut conn, "BEGIN", b'T');
+        push_command(&mut conn, "UPDATE 1", b'T');
+        push_command(&mut conn, "COMMIT", b'I');
+
+        let retry = TransactionRetry {
+            max_retries: 2,
+            base_backoff: Duration::ZERO,
+        };
+        let mut attempts = 0u32;
+        let result = conn
+            .transaction_with_retry(retry, |conn| {
+                attempts += 1;
+                let attempt = attempts;
+                super::super::scope(async move {
+                    conn.execute_simple("UPDATE accounts SET n = n + 1").await?;
+                    Ok(attempt)
+                })
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 2);
+        assert!(conn.buffer.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn transaction_does_not_retry_other_errors() {
+        let (mut conn, _peer) = test_conn_with_peer();
+        push_command(&mut conn, "BEGIN", b'T');
+        push_error(&mut conn, "23505");
+        push_command(&mut conn, "ROLLBACK", b'I');
+
+        let mut attempts = 0u32;
+        let err = conn
+            .transaction(|conn| {
+                attempts += 1;
+                super::super::scope(
+                    async move { conn.execute_simple("INSERT INTO t VALUES (1)").await },
+                )
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.sqlstate(), Some("23505"));
+        assert_eq!(attempts, 1);
+    }
 }
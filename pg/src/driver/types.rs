@@ -1,4 +1,4 @@
-//! Core types: ColumnInfo, PgRow, PgError, PgResult, QueryResult, ResultFormat,
+//! Core types: ColumnInfo, PgRow, PgError, PgResult, QueryResult, ResultSet, ResultFormat,
 //! and wire-protocol message utilities.
 
 use bytes::Bytes;
@@ -89,6 +89,11 @@ pub enum PgError {
     },
     /// Pool is closed and no longer accepting requests
     PoolClosed,
+    /// Query returned more rows than the caller's `max_rows` cap.
+    RowLimitExceeded {
+        /// The cap that was exceeded.
+        limit: usize,
+    },
 }
 
 /// Structured PostgreSQL server error fields.
@@ -132,6 +137,13 @@ impl std::fmt::Display for PgError {
             PgError::Timeout(ctx) => write!(f, "Timeout: {}", ctx),
             PgError::PoolExhausted { max } => write!(f, "Pool exhausted ({} max connections)", max),
             PgError::PoolClosed => write!(f, "Connection pool is closed"),
+            PgError::RowLimitExceeded { limit } => {
+                write!(
+                    f,
+                    "Row limit exceeded: query returned more than {} rows",
+                    limit
+                )
+            }
         }
     }
 }
@@ -214,6 +226,14 @@ impl PgError {
         message.contains("prepared statement") && message.contains("already exists")
     }
 
+    /// Whether this is a serialization failure (`40001`) or deadlock (`40P01`).
+    ///
+    /// PostgreSQL aborts the whole transaction for both, so re-running it
+    /// from the start is safe.
+    pub fn is_serialization_failure(&self) -> bool {
+        matches!(self.sqlstate(), Some("40001" | "40P01"))
+    }
+
     /// True when the error is a transient server condition that may succeed
     /// on retry. Covers serialization failures, deadlocks, standby
     /// unavailability, connection exceptions, and prepared-statement staleness.
@@ -337,6 +357,16 @@ pub struct QueryResult {
     pub rows: Vec<Vec<Option<String>>>,
 }
 
+/// One statement's result from a multi-statement simple query.
+pub struct ResultSet {
+    /// Column metadata, `None` for statements that return no rows.
+    pub column_info: Option<Arc<ColumnInfo>>,
+    /// Rows returned by the statement.
+    pub rows: Vec<PgRow>,
+    /// `CommandComplete` tag, e.g. `SELECT 2` or `CREATE TABLE`.
+    pub command_tag: String,
+}
+
 /// PostgreSQL result-column wire format.
 ///
 /// - `Text` (0): server sends textual column values.
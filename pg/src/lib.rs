@@ -43,12 +43,13 @@ pub use driver::{
     PgPool, PgResult, PgRow, PgServerError, PoolConfig, PoolStats, PooledConnection,
     PreparedAstQuery, QailRow, QueryResult, ReplicationKeepalive, ReplicationOption,
     ReplicationSlotInfo, ReplicationStreamMessage, ReplicationStreamStart, ReplicationXLogData,
-    ResultFormat, ScopedPoolFuture, ScramChannelBindingMode, TlsConfig, TlsMode, scope,
-    spawn_pool_maintenance,
+    ResultFormat, ResultSet, ScopedPoolFuture, ScramChannelBindingMode, TlsConfig, TlsMode,
+    TransactionRetry, scope, spawn_pool_maintenance,
 };
 pub use protocol::PgEncoder;
 pub use types::{
-    Cidr, Date, FromPg, Inet, Json, MacAddr, Numeric, Time, Timestamp, ToPg, TypeError, Uuid,
+    Cidr, Date, FromPg, Inet, Interval, Json, MacAddr, Numeric, Time, Timestamp, ToPg, TypeError,
+    Uuid,
 };
 
 /// Generate the RLS SQL string for pipelined execution.
@@ -152,6 +152,57 @@ pub fn build_extended_query_into_with_result_format(
 
     buf.clear();
     buf.reserve(total_size);
+    write_extended_query(buf, sql, params, params_size, param_count, result_format)
+}
+
+/// Append Extended Query protocol messages to a caller buffer WITHOUT clearing it.
+///
+/// Unlike [`build_extended_query_into_with_result_format`], existing bytes in
+/// `buf` are preserved, so repeated calls produce back-to-back frames. On error
+/// the buffer is truncated back to its original length.
+pub fn append_extended_query_with_result_format(
+    buf: &mut BytesMut,
+    sql: &[u8],
+    params: &[Option<Vec<u8>>],
+    result_format: i16,
+) -> Result<(), EncodeError> {
+    if params.len() > i16::MAX as usize {
+        return Err(EncodeError::TooManyParameters(params.len()));
+    }
+    validate_sql_bytes(sql)?;
+
+    let params_size = params_wire_size(params)?;
+    let param_count = checked_i16_count(params.len())?;
+    let total_size = 9usize
+        .checked_add(sql.len())
+        .and_then(|v| v.checked_add(11))
+        .and_then(|v| v.checked_add(params_size))
+        .and_then(|v| v.checked_add(result_format_wire_len(result_format)))
+        .and_then(|v| v.checked_add(6))
+        .and_then(|v| v.checked_add(10))
+        .and_then(|v| v.checked_add(5))
+        .ok_or(EncodeError::MessageTooLarge(usize::MAX))?;
+
+    let start = buf.len();
+    buf.reserve(total_size);
+    let result = write_extended_query(buf, sql, params, params_size, param_count, result_format);
+    if result.is_err() {
+        buf.truncate(start);
+    }
+    result
+}
+
+/// Write Parse + Bind + Describe + Execute + Sync at the end of `buf`.
+#[inline(always)]
+fn write_extended_query(
+    buf: &mut BytesMut,
+    sql: &[u8],
+    params: &[Option<Vec<u8>>],
+    params_size: usize,
+    param_count: i16,
+    result_format: i16,
+) -> Result<(), EncodeError> {
+    let result_formats_size = result_format_wire_len(result_format);
 
     // ===== PARSE =====
     buf.extend_from_slice(b"P");
@@ -231,9 +282,13 @@ pub fn encode_batch_with_result_format(
     let mut total_buf = BytesMut::with_capacity(cmds.len() * 256);
     let result_formats_size = result_format_wire_len(result_format);
 
+    // Scratch buffers are reused across commands; only capacity carries over.
+    let mut sql_buf = BytesMut::with_capacity(256);
+    let mut params: Vec<Option<Vec<u8>>> = Vec::new();
+
     for cmd in cmds {
-        let mut sql_buf = BytesMut::with_capacity(256);
-        let mut params: Vec<Option<Vec<u8>>> = Vec::new();
+        sql_buf.clear();
+        params.clear();
 
         match cmd.action {
             Action::Get => encode_select(cmd, &mut sql_buf, &mut params),
@@ -250,8 +305,8 @@ pub fn encode_batch_with_result_format(
             }
         }?;
 
-        let sql_bytes = sql_buf.freeze();
-        validate_sql_bytes(&sql_bytes)?;
+        let sql_bytes = &sql_buf[..];
+        validate_sql_bytes(sql_bytes)?;
         let params_size = params_wire_size(&params)?;
         let param_count = checked_i16_count(params.len())?;
 
@@ -265,7 +320,7 @@ pub fn encode_batch_with_result_format(
         let parse_len = checked_wire_len(parse_content_len)?;
         total_buf.extend_from_slice(&parse_len.to_be_bytes());
         total_buf.extend_from_slice(&[0]);
-        total_buf.extend_from_slice(&sql_bytes);
+        total_buf.extend_from_slice(sql_bytes);
         total_buf.extend_from_slice(&[0]);
         total_buf.extend_from_slice(&0i16.to_be_bytes());
 
@@ -1093,6 +1093,57 @@ pub fn encode_alter_add_column(
     Ok(())
 }
 
+/// Encode ALTER TABLE ADD CONSTRAINT statement.
+///
+/// A single `table_constraints` entry is added under the name in `channel`;
+/// without one, `payload` is encoded as a CHECK constraint.
+pub fn encode_alter_add_constraint(
+    cmd: &Qail,
+    buf: &mut BytesMut,
+) -> Result<(), super::super::EncodeError> {
+    let constraint = match cmd.table_constraints.as_slice() {
+        [] => return encode_alter_add_check_constraint(cmd, buf),
+        [constraint] if cmd.payload.is_none() => constraint,
+        [_] => {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                "ALTER ADD CONSTRAINT takes either a check expression or a table constraint"
+                    .to_string(),
+            ));
+        }
+        _ => {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                "ALTER ADD CONSTRAINT takes a single constraint".to_string(),
+            ));
+        }
+    };
+    let name = cmd
+        .channel
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| {
+            crate::protocol::EncodeError::InvalidAst(
+                "ALTER ADD CONSTRAINT requires a constraint name".to_string(),
+            )
+        })?;
+    if matches!(
+        constraint,
+        TableConstraint::ForeignKey { name: Some(_), .. }
+    ) {
+        return Err(crate::protocol::EncodeError::InvalidAst(
+            "ALTER ADD CONSTRAINT names the constraint itself; leave the foreign key unnamed"
+                .to_string(),
+        ));
+    }
+
+    buf.extend_from_slice(b"ALTER TABLE ");
+    push_identifier(buf, &cmd.table);
+    buf.extend_from_slice(b" ADD CONSTRAINT ");
+    push_identifier(buf, name);
+    buf.extend_from_slice(b" ");
+    encode_table_constraint(constraint, buf)
+}
+
 /// Encode ALTER TABLE ADD CHECK CONSTRAINT statement.
 pub fn encode_alter_add_check_constraint(
     cmd: &Qail,
@@ -2116,6 +2167,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_alter_add_constraint_renders_unique_and_rejects_extra_check() {
+        let mut cmd = Qail {
+            action: Action::AlterAddConstraint,
+            table: "users".to_string(),
+            channel: Some("users_org_email_key".to_string()),
+            table_constraints: vec![TableConstraint::Unique(vec![
+                "org_id".to_string(),
+                "email".to_string(),
+            ])],
+            ..Default::default()
+        };
+        let mut buf = BytesMut::new();
+
+        encode_alter_add_constraint(&cmd, &mut buf).unwrap();
+
+        let sql = String::from_utf8(buf.to_vec()).expect("encoded SQL should be UTF-8");
+        assert_eq!(
+            sql,
+            "ALTER TABLE users ADD CONSTRAINT users_org_email_key UNIQUE (org_id, email)"
+        );
+
+        cmd.payload = Some("email <> ''".to_string());
+        let err = encode_alter_add_constraint(&cmd, &mut BytesMut::new()).unwrap_err();
+        assert!(matches!(err, crate::protocol::EncodeError::InvalidAst(_)));
+    }
+
     #[test]
     fn encode_alter_add_check_constraint_renders_sql() {
         let cmd = Qail {
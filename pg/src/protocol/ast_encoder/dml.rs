@@ -8,13 +8,13 @@ use qail_core::ast::{
     GroupByMode, JoinKind, LockMode, LogicalOp, Merge, MergeAction, MergeMatchKind, MergeSource,
     Operator, OverridingKind, Qail, SampleMethod, SetOp, SortOrder, Value,
 };
-use qail_core::transpiler::escape_identifier;
+use qail_core::transpiler::{escape_identifier, split_collation_name};
 use std::collections::HashSet;
 
 use super::helpers::write_usize;
 use super::values::{
-    encode_columns, encode_columns_with_params, encode_conditions, encode_expr,
-    encode_expr_with_params, encode_join_value, encode_operator, encode_value,
+    encode_columns, encode_columns_with_params, encode_conditions, encode_conditions_inline,
+    encode_expr, encode_expr_with_params, encode_join_value, encode_operator, encode_value,
 };
 
 const MAX_IDENT_LEN: usize = 63;
@@ -298,7 +298,14 @@ fn payload_shape(conditions: &[Condition]) -> PayloadShape {
 }
 
 fn payload_cage(cmd: &Qail) -> Option<&qail_core::ast::Cage> {
-    cmd.cages.iter().find(|cage| cage.kind == CageKind::Payload)
+    payload_cages(cmd).next()
+}
+
+/// Payload cages in order; an INSERT has one per VALUES row.
+fn payload_cages(cmd: &Qail) -> impl Iterator<Item = &qail_core::ast::Cage> {
+    cmd.cages
+        .iter()
+        .filter(|cage| cage.kind == CageKind::Payload)
 }
 
 fn validate_write_column_expr(
@@ -343,6 +350,21 @@ fn validate_insert_shape(cmd: &Qail) -> Result<(), crate::protocol::EncodeError>
         ));
     }
 
+    for row in payload_cages(cmd).skip(1) {
+        if payload_shape != PayloadShape::Positional
+            || !row
+                .conditions
+                .iter()
+                .all(|condition| is_positional_placeholder(&condition.left))
+            || row.conditions.len() != payload_len
+        {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                "multi-row INSERT requires positional values with the same count per row"
+                    .to_string(),
+            ));
+        }
+    }
+
     if cmd.default_values {
         if cmd.source_query.is_some() || payload_len > 0 {
             return Err(crate::protocol::EncodeError::InvalidAst(
@@ -389,6 +411,20 @@ fn validate_insert_shape(cmd: &Qail) -> Result<(), crate::protocol::EncodeError>
     Ok(())
 }
 
+/// PostgreSQL has no `LIMIT` on DELETE/UPDATE; refuse rather than drop it.
+fn reject_dml_limit(cmd: &Qail, verb: &str) -> Result<(), crate::protocol::EncodeError> {
+    if cmd
+        .cages
+        .iter()
+        .any(|cage| matches!(cage.kind, CageKind::Limit(_)))
+    {
+        return Err(crate::protocol::EncodeError::InvalidAst(format!(
+            "PostgreSQL does not support LIMIT on {verb}"
+        )));
+    }
+    Ok(())
+}
+
 fn validate_update_shape(cmd: &Qail) -> Result<(), crate::protocol::EncodeError> {
     let payload = payload_cage(cmd).ok_or_else(|| {
         crate::protocol::EncodeError::InvalidAst(
@@ -472,6 +508,13 @@ fn validate_on_conflict_shape(cmd: &Qail) -> Result<(), crate::protocol::EncodeE
         }
     }
 
+    if !on_conflict.predicate.is_empty() && on_conflict.columns.is_empty() {
+        return Err(crate::protocol::EncodeError::InvalidAst(
+            "ON CONFLICT WHERE requires at least one conflict target".to_string(),
+        ));
+    }
+    validate_conditions("on_conflict.predicate", &on_conflict.predicate)?;
+
     if let ConflictAction::DoUpdate { assignments } = &on_conflict.action {
         if on_conflict.columns.is_empty() {
             return Err(crate::protocol::EncodeError::InvalidAst(
@@ -653,7 +696,12 @@ pub(crate) fn validate_expr_ref(
             alias,
         } => {
             validate_expr_ref(&format!("{field}.collate"), expr)?;
-            validate_qualified_ident(&format!("{field}.collation"), collation, false)?;
+            let field = format!("{field}.collation");
+            let parts = split_collation_name(collation)
+                .ok_or_else(|| invalid_identifier(&field, collation, "malformed collation"))?;
+            for part in parts {
+                validate_qualified_ident(&field, &part, false)?;
+            }
             if let Some(alias) = alias {
                 validate_ident_atom(&format!("{field}.alias"), alias)?;
             }
@@ -814,6 +862,16 @@ fn validate_dml_command(
             "SKIP LOCKED requires a row lock mode".to_string(),
         ));
     }
+    if cmd.nowait && cmd.lock_mode.is_none() {
+        return Err(crate::protocol::EncodeError::InvalidAst(
+            "NOWAIT requires a row lock mode".to_string(),
+        ));
+    }
+    if cmd.skip_locked && cmd.nowait {
+        return Err(crate::protocol::EncodeError::InvalidAst(
+            "SKIP LOCKED and NOWAIT are mutually exclusive".to_string(),
+        ));
+    }
 
     if let Some((_, true)) = cmd.fetch {
         let has_order_by = cmd
@@ -836,7 +894,15 @@ fn validate_dml_command(
     }
 
     for join in &cmd.joins {
-        validate_table_ref("join.table", &join.table)?;
+        if let Some(subquery) = &join.subquery {
+            validate_ident_atom("join.alias", &join.table)?;
+            validate_dml_command(subquery, &subquery.columns)?;
+        } else {
+            validate_table_ref("join.table", &join.table)?;
+        }
+        for column in &join.using {
+            validate_ident_atom("join.using", column)?;
+        }
         if let Some(conditions) = &join.on {
             for condition in conditions {
                 validate_join_condition("join.on", condition)?;
@@ -965,7 +1031,11 @@ pub fn encode_select(
     params: &mut Vec<Option<Vec<u8>>>,
 ) -> Result<(), crate::protocol::EncodeError> {
     validate_read_only_select_query(cmd)?;
-    encode_select_with_columns(cmd, &cmd.columns, buf, params)
+    match cmd.action {
+        Action::Values => encode_values(cmd, buf, params),
+        Action::Table => encode_table(cmd, buf),
+        _ => encode_select_with_columns(cmd, &cmd.columns, buf, params),
+    }
 }
 
 fn validate_read_only_select_query(query: &Qail) -> Result<(), crate::protocol::EncodeError> {
@@ -979,7 +1049,10 @@ fn validate_read_only_select_query_with_message(
     query: &Qail,
     message: &str,
 ) -> Result<(), crate::protocol::EncodeError> {
-    if !matches!(query.action, Action::Get | Action::With) {
+    if !matches!(
+        query.action,
+        Action::Get | Action::With | Action::Values | Action::Table
+    ) {
         return Err(crate::protocol::EncodeError::InvalidAst(format!(
             "{message}, got {}",
             query.action
@@ -998,6 +1071,11 @@ fn validate_read_only_select_query_with_message(
     if let Some(ref source_query) = query.source_query {
         validate_read_only_select_query_with_message(source_query, message)?;
     }
+    for join in &query.joins {
+        if let Some(ref subquery) = join.subquery {
+            validate_read_only_select_query_with_message(subquery, message)?;
+        }
+    }
 
     Ok(())
 }
@@ -1058,14 +1136,65 @@ fn encode_select_with_columns(
 
     // FROM
     buf.extend_from_slice(b" FROM ");
-    if cmd.only_table {
-        buf.extend_from_slice(b"ONLY ");
+    if let Some(source_query) = &cmd.source_query {
+        // Derived table: FROM (<subquery>) alias
+        buf.extend_from_slice(b"(");
+        encode_select(source_query, buf, params)?;
+        buf.extend_from_slice(b") ");
+        push_identifier_ref(buf, &cmd.table, false);
+    } else if let Some(function) = &cmd.table_function {
+        if !matches!(function, Expr::FunctionCall { .. }) {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                "table-function source must be a function call".to_string(),
+            ));
+        }
+        encode_expr_with_params(function, buf, params)?;
+    } else {
+        if cmd.only_table {
+            buf.extend_from_slice(b"ONLY ");
+        }
+        push_table_ref(buf, &cmd.table);
     }
-    push_table_ref(buf, &cmd.table);
     append_table_sample_clause(cmd, buf);
 
+    // Extra set-returning sources: FROM t, UNNEST(tags) AS tag
+    for (function, with_ordinality) in &cmd.from_functions {
+        let Expr::FunctionCall { name, args, alias } = function else {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                "FROM function source must be a function call".to_string(),
+            ));
+        };
+        buf.extend_from_slice(b", ");
+        let call = Expr::FunctionCall {
+            name: name.clone(),
+            args: args.clone(),
+            alias: None,
+        };
+        encode_expr_with_params(&call, buf, params)?;
+        if *with_ordinality {
+            buf.extend_from_slice(b" WITH ORDINALITY");
+        }
+        if let Some(alias) = alias {
+            buf.extend_from_slice(b" AS ");
+            push_identifier_ref(buf, alias, false);
+        }
+    }
+
     // JOINs
     for join in &cmd.joins {
+        let has_on = join.on.as_ref().is_some_and(|conds| !conds.is_empty());
+        if has_on && !join.using.is_empty() {
+            return Err(crate::protocol::EncodeError::InvalidAst(format!(
+                "join on '{}' combines USING with ON conditions",
+                join.table
+            )));
+        }
+        let has_condition = join.on_true || has_on || !join.using.is_empty();
+        if join.kind != JoinKind::Cross && !has_condition {
+            return Err(crate::protocol::EncodeError::InvalidAst(
+                qail_core::error::QailError::MissingJoinCondition(join.table.clone()).to_string(),
+            ));
+        }
         match join.kind {
             JoinKind::Inner => buf.extend_from_slice(b" INNER JOIN "),
             JoinKind::Left => buf.extend_from_slice(b" LEFT JOIN "),
@@ -1073,10 +1202,27 @@ fn encode_select_with_columns(
             JoinKind::Full => buf.extend_from_slice(b" FULL OUTER JOIN "),
             JoinKind::Cross => buf.extend_from_slice(b" CROSS JOIN "),
             JoinKind::Lateral => buf.extend_from_slice(b" LEFT JOIN LATERAL "),
+            JoinKind::LateralInner => buf.extend_from_slice(b" INNER JOIN LATERAL "),
+        }
+        if let Some(subquery) = &join.subquery {
+            buf.extend_from_slice(b"(");
+            encode_select(subquery, buf, params)?;
+            buf.extend_from_slice(b") ");
+            push_identifier_ref(buf, &join.table, false);
+        } else {
+            push_table_ref(buf, &join.table);
         }
-        push_table_ref(buf, &join.table);
 
-        if join.on_true {
+        if !join.using.is_empty() {
+            buf.extend_from_slice(b" USING (");
+            for (i, column) in join.using.iter().enumerate() {
+                if i > 0 {
+                    buf.extend_from_slice(b", ");
+                }
+                push_identifier_ref(buf, column, false);
+            }
+            buf.extend_from_slice(b")");
+        } else if join.on_true {
             buf.extend_from_slice(b" ON TRUE");
         } else if let Some(conditions) = &join.on
             && !conditions.is_empty()
@@ -1105,11 +1251,27 @@ fn encode_select_with_columns(
         // Explicit GROUP BY from .group_by() or .group_by_expr()
         if !cage.conditions.is_empty() {
             buf.extend_from_slice(b" GROUP BY ");
-            for (i, cond) in cage.conditions.iter().enumerate() {
-                if i > 0 {
-                    buf.extend_from_slice(b", ");
+            if let GroupByMode::GroupingSets(sets) = &cmd.group_by_mode {
+                // GROUPING SETS lists its own column sets.
+                encode_grouping_sets(sets, buf);
+            } else {
+                let wrapper: Option<&[u8]> = match &cmd.group_by_mode {
+                    GroupByMode::Rollup => Some(b"ROLLUP("),
+                    GroupByMode::Cube => Some(b"CUBE("),
+                    _ => None,
+                };
+                if let Some(wrapper) = wrapper {
+                    buf.extend_from_slice(wrapper);
+                }
+                for (i, cond) in cage.conditions.iter().enumerate() {
+                    if i > 0 {
+                        buf.extend_from_slice(b", ");
+                    }
+                    encode_expr(&cond.left, buf)?;
+                }
+                if wrapper.is_some() {
+                    buf.extend_from_slice(b")");
                 }
-                encode_expr(&cond.left, buf)?;
             }
         }
     } else {
@@ -1155,27 +1317,17 @@ fn encode_select_with_columns(
                     }
                     buf.extend_from_slice(b")");
                 }
-                GroupByMode::GroupingSets(sets) => {
-                    buf.extend_from_slice(b"GROUPING SETS (");
-                    for (i, set) in sets.iter().enumerate() {
-                        if i > 0 {
-                            buf.extend_from_slice(b", ");
-                        }
-                        buf.extend_from_slice(b"(");
-                        for (j, col) in set.iter().enumerate() {
-                            if j > 0 {
-                                buf.extend_from_slice(b", ");
-                            }
-                            push_identifier_ref(buf, col, true);
-                        }
-                        buf.extend_from_slice(b")");
-                    }
-                    buf.extend_from_slice(b")");
-                }
+                GroupByMode::GroupingSets(sets) => encode_grouping_sets(sets, buf),
             }
         }
     }
 
+    // HAVING
+    if !cmd.having.is_empty() {
+        buf.extend_from_slice(b" HAVING ");
+        encode_condition_group(&cmd.having, b" AND ", buf, params)?;
+    }
+
     // ORDER BY - collect ALL sort cages and output them together
     let sort_cages: Vec<_> = cmd
         .cages
@@ -1243,6 +1395,24 @@ fn encode_select_with_columns(
     Ok(())
 }
 
+fn encode_grouping_sets(sets: &[Vec<String>], buf: &mut BytesMut) {
+    buf.extend_from_slice(b"GROUPING SETS (");
+    for (i, set) in sets.iter().enumerate() {
+        if i > 0 {
+            buf.extend_from_slice(b", ");
+        }
+        buf.extend_from_slice(b"(");
+        for (j, col) in set.iter().enumerate() {
+            if j > 0 {
+                buf.extend_from_slice(b", ");
+            }
+            push_identifier_ref(buf, col, true);
+        }
+        buf.extend_from_slice(b")");
+    }
+    buf.extend_from_slice(b")");
+}
+
 fn encode_set_operand(
     query: &Qail,
     buf: &mut BytesMut,
@@ -1346,6 +1516,9 @@ fn append_lock_clause(cmd: &Qail, buf: &mut BytesMut) {
     if cmd.skip_locked {
         buf.extend_from_slice(b" SKIP LOCKED");
     }
+    if cmd.nowait {
+        buf.extend_from_slice(b" NOWAIT");
+    }
 }
 
 /// Fast path for the dominant read shape:
@@ -1366,6 +1539,7 @@ fn try_encode_simple_select_fast(
         || !cmd.joins.is_empty()
         || !cmd.set_ops.is_empty()
         || !cmd.having.is_empty()
+        || cmd.source_query.is_some()
         || cmd.fetch.is_some()
         || cmd.lock_mode.is_some()
         || cmd.sample.is_some()
@@ -1374,6 +1548,8 @@ fn try_encode_simple_select_fast(
             .iter()
             .any(|cage| matches!(cage.kind, CageKind::Sample(_)))
         || cmd.only_table
+        || cmd.table_function.is_some()
+        || !cmd.from_functions.is_empty()
         || !matches!(cmd.group_by_mode, GroupByMode::Simple)
     {
         return Ok(false);
@@ -1571,15 +1747,21 @@ pub fn encode_insert(
     } else if let Some(source_query) = &cmd.source_query {
         buf.extend_from_slice(b" ");
         encode_select(source_query, buf, params)?;
-    } else if let Some(cage) = payload_cage {
-        buf.extend_from_slice(b" VALUES (");
-        for (i, cond) in cage.conditions.iter().enumerate() {
-            if i > 0 {
+    } else if payload_cage.is_some() {
+        buf.extend_from_slice(b" VALUES ");
+        for (row, cage) in payload_cages(cmd).enumerate() {
+            if row > 0 {
                 buf.extend_from_slice(b", ");
             }
-            encode_value(&cond.value, buf, params)?;
+            buf.extend_from_slice(b"(");
+            for (i, cond) in cage.conditions.iter().enumerate() {
+                if i > 0 {
+                    buf.extend_from_slice(b", ");
+                }
+                encode_value(&cond.value, buf, params)?;
+            }
+            buf.extend_from_slice(b")");
         }
-        buf.extend_from_slice(b")");
     }
 
     // ON CONFLICT clause (UPSERT support)
@@ -1600,6 +1782,14 @@ pub fn encode_insert(
             buf.extend_from_slice(b") ");
         }
 
+        // Partial-index predicate. PostgreSQL matches the arbiter index by
+        // the predicate's text, so values are inlined rather than bound.
+        if !on_conflict.predicate.is_empty() {
+            buf.extend_from_slice(b"WHERE ");
+            encode_conditions_inline(&on_conflict.predicate, buf)?;
+            buf.extend_from_slice(b" ");
+        }
+
         // Conflict action
         match &on_conflict.action {
             ConflictAction::DoNothing => {
@@ -1641,8 +1831,18 @@ pub fn encode_update(
     buf: &mut BytesMut,
     params: &mut Vec<Option<Vec<u8>>>,
 ) -> Result<(), crate::protocol::EncodeError> {
+    let folded;
+    let cmd = if cmd.joins.is_empty() {
+        cmd
+    } else {
+        folded = cmd
+            .fold_mutation_joins()
+            .map_err(|e| crate::protocol::EncodeError::InvalidAst(e.to_string()))?;
+        &folded
+    };
     validate_dml_command(cmd, &cmd.columns)?;
     validate_update_shape(cmd)?;
+    reject_dml_limit(cmd, "UPDATE")?;
 
     buf.extend_from_slice(b"UPDATE ");
     if cmd.only_table {
@@ -1714,7 +1914,17 @@ pub fn encode_delete(
     buf: &mut BytesMut,
     params: &mut Vec<Option<Vec<u8>>>,
 ) -> Result<(), crate::protocol::EncodeError> {
+    let folded;
+    let cmd = if cmd.joins.is_empty() {
+        cmd
+    } else {
+        folded = cmd
+            .fold_mutation_joins()
+            .map_err(|e| crate::protocol::EncodeError::InvalidAst(e.to_string()))?;
+        &folded
+    };
     validate_dml_command(cmd, &cmd.columns)?;
+    reject_dml_limit(cmd, "DELETE")?;
 
     buf.extend_from_slice(b"DELETE FROM ");
     if cmd.only_table {
@@ -1964,7 +2174,7 @@ fn encode_merge_action(
     Ok(())
 }
 
-/// Encode an EXPORT command as `COPY (SELECT ...) TO STDOUT`.
+/// Encode an EXPORT command as `COPY (SELECT ...) TO STDOUT [WITH (...)]`.
 ///
 /// # Arguments
 ///
@@ -1979,6 +2189,56 @@ pub fn encode_export(
     buf.extend_from_slice(b"COPY (");
     encode_select_with_columns(cmd, &cmd.columns, buf, params)?;
     buf.extend_from_slice(b") TO STDOUT");
+    if let Some(options) = &cmd.copy_options {
+        buf.extend_from_slice(b" ");
+        buf.extend_from_slice(options.to_string().as_bytes());
+    }
+    Ok(())
+}
+
+/// Encode a `TABLE name` statement.
+pub fn encode_table(cmd: &Qail, buf: &mut BytesMut) -> Result<(), crate::protocol::EncodeError> {
+    validate_qualified_ident("table", &cmd.table, false)?;
+    buf.extend_from_slice(b"TABLE ");
+    push_identifier_ref(buf, &cmd.table, false);
+    Ok(())
+}
+
+/// Encode a standalone `VALUES (...), (...)` statement.
+///
+/// Each `Payload` cage is one row; values are bound as parameters.
+pub fn encode_values(
+    cmd: &Qail,
+    buf: &mut BytesMut,
+    params: &mut Vec<Option<Vec<u8>>>,
+) -> Result<(), crate::protocol::EncodeError> {
+    let rows: Vec<_> = cmd
+        .cages
+        .iter()
+        .filter(|cage| cage.kind == CageKind::Payload)
+        .collect();
+    let width = rows.first().map_or(0, |row| row.conditions.len());
+    if width == 0 || rows.iter().any(|row| row.conditions.len() != width) {
+        return Err(crate::protocol::EncodeError::InvalidAst(
+            "VALUES requires at least one row and the same non-zero column count per row"
+                .to_string(),
+        ));
+    }
+
+    buf.extend_from_slice(b"VALUES ");
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            buf.extend_from_slice(b", ");
+        }
+        buf.extend_from_slice(b"(");
+        for (j, cond) in row.conditions.iter().enumerate() {
+            if j > 0 {
+                buf.extend_from_slice(b", ");
+            }
+            encode_value(&cond.value, buf, params)?;
+        }
+        buf.extend_from_slice(b")");
+    }
     Ok(())
 }
 
@@ -1997,6 +2257,20 @@ fn encode_condition_group(
     Ok(())
 }
 
+fn encode_inline_condition_group(
+    conditions: &[Condition],
+    joiner: &[u8],
+    buf: &mut BytesMut,
+) -> Result<(), crate::protocol::EncodeError> {
+    for (idx, condition) in conditions.iter().enumerate() {
+        if idx > 0 {
+            buf.extend_from_slice(joiner);
+        }
+        encode_conditions_inline(std::slice::from_ref(condition), buf)?;
+    }
+    Ok(())
+}
+
 /// Encode a WHERE clause that preserves each filter cage as its own group.
 ///
 /// - AND cages are emitted first and joined internally with `AND`.
@@ -2006,6 +2280,8 @@ fn encode_condition_group(
 ///   policy/user OR groups do not widen each other.
 ///
 /// Example output: `WHERE is_active = $1 AND (topic ILIKE $2 OR question ILIKE $3)`
+///
+/// `COPY` cannot take bind parameters, so export filters are written inline.
 fn encode_where(
     cmd: &Qail,
     buf: &mut BytesMut,
@@ -2021,6 +2297,14 @@ fn encode_where(
 
     buf.extend_from_slice(b" WHERE ");
 
+    let inline = cmd.action == Action::Export;
+    let mut encode_group = |conditions: &[Condition], joiner: &[u8], buf: &mut BytesMut| {
+        if inline {
+            encode_inline_condition_group(conditions, joiner, buf)
+        } else {
+            encode_condition_group(conditions, joiner, buf, params)
+        }
+    };
     let mut wrote_clause = false;
     for target_op in [LogicalOp::And, LogicalOp::Or] {
         for cage in &cmd.cages {
@@ -2036,11 +2320,11 @@ fn encode_where(
 
             match target_op {
                 LogicalOp::And => {
-                    encode_condition_group(&cage.conditions, b" AND ", buf, params)?;
+                    encode_group(&cage.conditions, b" AND ", buf)?;
                 }
                 LogicalOp::Or => {
                     buf.extend_from_slice(b"(");
-                    encode_condition_group(&cage.conditions, b" OR ", buf, params)?;
+                    encode_group(&cage.conditions, b" OR ", buf)?;
                     buf.extend_from_slice(b")");
                 }
             }
@@ -13,14 +13,18 @@
 //! - `dml` - SELECT, INSERT, UPDATE, DELETE, EXPORT
 //! - `values` - Expression, operator, and value encoding
 //! - `batch` - Batch and wire protocol encoding
+//! - `reuse` - Long-lived [`Encoder`] that keeps scratch buffers across calls
 
 mod batch;
 mod ddl;
 pub(crate) mod dml; // pub(crate) for internal use in driver
 pub use crate::protocol::EncodeError;
 mod helpers;
+mod reuse;
 mod values;
 
+pub use reuse::Encoder;
+
 use bytes::BytesMut;
 use qail_core::ast::{Action, Qail};
 
@@ -140,6 +144,24 @@ impl AstEncoder {
         Ok(())
     }
 
+    /// Encode a Qail and APPEND its wire bytes to `wire_buf` (no clear).
+    ///
+    /// `sql_buf` and `params` are scratch buffers that are cleared and reused.
+    /// Repeated calls with the same `wire_buf` produce concatenated
+    /// Parse/Bind/Describe/Execute/Sync frames. On error `wire_buf` is left
+    /// unchanged.
+    #[inline]
+    pub fn encode_cmd_append(
+        cmd: &Qail,
+        sql_buf: &mut BytesMut,
+        params: &mut Vec<Option<Vec<u8>>>,
+        wire_buf: &mut BytesMut,
+        result_format: i16,
+    ) -> Result<(), EncodeError> {
+        Self::encode_cmd_sql_to(cmd, sql_buf, params)?;
+        batch::append_extended_query_with_result_format(wire_buf, sql_buf, params, result_format)
+    }
+
     /// Internal helper: encode AST to SQL bytes + params (shared by both reuse variants).
     #[inline]
     fn encode_cmd_sql_to(
@@ -158,6 +180,8 @@ impl AstEncoder {
             Action::Cnt => {
                 dml::encode_count(cmd, sql_buf, params)?;
             }
+            Action::Table => dml::encode_table(cmd, sql_buf)?,
+            Action::Values => dml::encode_values(cmd, sql_buf, params)?,
             Action::Add => {
                 dml::encode_insert(cmd, sql_buf, params)?;
             }
@@ -178,7 +202,7 @@ impl AstEncoder {
             Action::Drop => ddl::encode_drop_table(cmd, sql_buf),
             Action::DropIndex => ddl::encode_drop_index(cmd, sql_buf),
             Action::Alter => ddl::encode_alter_add_column(cmd, sql_buf)?,
-            Action::AlterAddConstraint => ddl::encode_alter_add_check_constraint(cmd, sql_buf)?,
+            Action::AlterAddConstraint => ddl::encode_alter_add_constraint(cmd, sql_buf)?,
             Action::AlterDropConstraint => ddl::encode_alter_drop_constraint(cmd, sql_buf)?,
             Action::AlterDrop => ddl::encode_alter_drop_column(cmd, sql_buf)?,
             Action::AlterType => ddl::encode_alter_column_type(cmd, sql_buf)?,
@@ -245,6 +269,8 @@ impl AstEncoder {
             Action::Cnt => {
                 dml::encode_count(cmd, &mut sql_buf, &mut params)?;
             }
+            Action::Table => dml::encode_table(cmd, &mut sql_buf)?,
+            Action::Values => dml::encode_values(cmd, &mut sql_buf, &mut params)?,
             Action::Add => {
                 dml::encode_insert(cmd, &mut sql_buf, &mut params)?;
             }
@@ -265,9 +291,7 @@ impl AstEncoder {
             Action::Drop => ddl::encode_drop_table(cmd, &mut sql_buf),
             Action::DropIndex => ddl::encode_drop_index(cmd, &mut sql_buf),
             Action::Alter => ddl::encode_alter_add_column(cmd, &mut sql_buf)?,
-            Action::AlterAddConstraint => {
-                ddl::encode_alter_add_check_constraint(cmd, &mut sql_buf)?
-            }
+            Action::AlterAddConstraint => ddl::encode_alter_add_constraint(cmd, &mut sql_buf)?,
             Action::AlterDropConstraint => ddl::encode_alter_drop_constraint(cmd, &mut sql_buf)?,
             Action::AlterDrop => ddl::encode_alter_drop_column(cmd, &mut sql_buf)?,
             Action::AlterType => ddl::encode_alter_column_type(cmd, &mut sql_buf)?,
@@ -348,6 +372,8 @@ impl AstEncoder {
             Action::Cnt => {
                 dml::encode_count(cmd, sql_buf, params)?;
             }
+            Action::Table => dml::encode_table(cmd, sql_buf)?,
+            Action::Values => dml::encode_values(cmd, sql_buf, params)?,
             Action::Add => {
                 dml::encode_insert(cmd, sql_buf, params)?;
             }
@@ -402,6 +428,9 @@ impl AstEncoder {
             Action::Merge => {
                 dml::encode_merge(cmd, &mut sql_buf, &mut params)?;
             }
+            Action::Values => {
+                dml::encode_values(cmd, &mut sql_buf, &mut params)?;
+            }
             _ => {}
         }
 
@@ -881,6 +910,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_table_and_values_commands() {
+        let (sql, params) = AstEncoder::encode_cmd_sql(&Qail::table("users")).unwrap();
+        assert_eq!(sql, "TABLE users");
+        assert!(params.is_empty());
+
+        let cmd = Qail::values_list().values([1, 2]).values([3, 4]);
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(sql, "VALUES ($1, $2), ($3, $4)");
+        assert_eq!(params.len(), 4);
+
+        let ragged = Qail::values_list().values([1, 2]).values([3]);
+        assert!(AstEncoder::encode_cmd_sql(&ragged).is_err());
+    }
+
+    #[test]
+    fn test_encode_values_and_table_in_query_slots() {
+        let rows = Qail::values_list().values([1, 2]).values([3, 4]);
+        let cmd = Qail::get("pairs")
+            .with_cte(qail_core::ast::CTEDef {
+                name: "pairs".to_string(),
+                recursive: false,
+                columns: vec!["a".to_string(), "b".to_string()],
+                base_query: Box::new(rows.clone()),
+                recursive_query: None,
+                source_table: None,
+            })
+            .column("a");
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(
+            sql,
+            "WITH pairs(a, b) AS (VALUES ($1, $2), ($3, $4)) SELECT a FROM pairs"
+        );
+        assert_eq!(params.len(), 4);
+
+        let mut insert = Qail::add("pairs").columns(["a", "b"]);
+        insert.source_query = Some(Box::new(rows));
+        let (sql, params) = AstEncoder::encode_cmd_sql(&insert).unwrap();
+        assert!(
+            sql.starts_with("INSERT INTO pairs (a, b) VALUES ($1, $2), ($3, $4)"),
+            "{sql}"
+        );
+        assert_eq!(params.len(), 4);
+
+        let mut copy = Qail::add("users_archive");
+        copy.source_query = Some(Box::new(Qail::table("users")));
+        let (sql, _) = AstEncoder::encode_cmd_sql(&copy).unwrap();
+        assert!(
+            sql.starts_with("INSERT INTO users_archive TABLE users"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_encode_group_by_grouping_sets() {
+        let cmd = qail_core::parser::parse(
+            "get sales fields region, month, sum(amount) \
+             group by grouping sets((region, month), (region), ())",
+        )
+        .unwrap();
+
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT region, month, SUM(amount) FROM sales \
+             GROUP BY GROUPING SETS ((region, month), (region), ())"
+        );
+    }
+
+    #[test]
+    fn test_encode_group_by_rollup_and_cube() {
+        for (mode, expected) in [("rollup", "ROLLUP"), ("cube", "CUBE")] {
+            let cmd = qail_core::parser::parse(&format!(
+                "get sales fields region, month, sum(amount) group by {mode}(region, month)"
+            ))
+            .unwrap();
+
+            let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+            assert_eq!(
+                sql,
+                format!(
+                    "SELECT region, month, SUM(amount) FROM sales GROUP BY {expected}(region, month)"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_order_by_collation_is_quoted() {
+        let cmd =
+            qail_core::parser::parse("get users fields name order by name collate 'en_US' desc")
+                .unwrap();
+
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT name FROM users ORDER BY name COLLATE \"en_US\" DESC"
+        );
+    }
+
+    #[test]
+    fn test_encode_collation_splits_only_unquoted_names() {
+        let quoted =
+            qail_core::parser::parse("get users fields name order by name collate 'en_US.utf8'")
+                .unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&quoted).unwrap();
+        assert!(sql.ends_with("COLLATE \"en_US.utf8\""), "{sql}");
+
+        let qualified =
+            qail_core::parser::parse("get users fields name order by name collate pg_catalog.C")
+                .unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&qualified).unwrap();
+        assert!(sql.ends_with("COLLATE \"pg_catalog\".\"C\""), "{sql}");
+    }
+
     #[test]
     fn test_encode_select_sort_nulls_and_table_modifiers() {
         use qail_core::ast::{Cage, CageKind, LogicalOp, SortOrder};
@@ -930,6 +1077,10 @@ mod tests {
                 Qail::get("jobs").columns(["id"]).for_key_share(),
                 "FOR KEY SHARE",
             ),
+            (
+                Qail::get("jobs").columns(["id"]).for_share().nowait(),
+                "FOR SHARE NOWAIT",
+            ),
         ];
 
         for (cmd, suffix) in cases {
@@ -939,6 +1090,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_overlap_binds_array_param() {
+        let cmd = Qail::get("rooms")
+            .columns(["id"])
+            .filter_cond(qail_core::ast::builders::overlaps("tags", ["wifi", "tv"]));
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(sql, "SELECT id FROM rooms WHERE tags && $1");
+        assert_eq!(params, vec![Some(br#"{"wifi","tv"}"#.to_vec())]);
+    }
+
+    #[test]
+    fn test_encode_table_function_source() {
+        let cmd = qail_core::parse("get generate_series(1, 10) as n fields n").unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(sql, "SELECT n FROM GENERATE_SERIES(1, 10) AS n");
+    }
+
+    #[test]
+    fn test_encode_unnest_from_source() {
+        let cmd =
+            qail_core::parse("get events, unnest(tags) with ordinality as tag fields id, tag")
+                .unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(
+            sql,
+            "SELECT id, tag FROM events, UNNEST(tags) WITH ORDINALITY AS tag"
+        );
+    }
+
+    #[test]
+    fn test_encode_window_frame_exclusion() {
+        let cmd = qail_core::parse(
+            "get orders fields avg(amount) over (order by created_at \
+             rows between 3 preceding and current row exclude ties) as moving_avg",
+        )
+        .unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert!(
+            sql.contains("ROWS BETWEEN 3 PRECEDING AND CURRENT ROW EXCLUDE TIES)"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_encode_json_contains_string_document_binds_jsonb() {
+        let cmd =
+            qail_core::parse(r#"get users fields id where data @> '{"role":"admin"}'"#).unwrap();
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(sql, "SELECT id FROM users WHERE data @> $1::jsonb");
+        assert_eq!(params, vec![Some(br#"{"role":"admin"}"#.to_vec())]);
+
+        let cmd = qail_core::parse("get users fields id where tags @> '{a,b}'").unwrap();
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(sql, "SELECT id FROM users WHERE tags @> $1");
+    }
+
+    #[test]
+    fn test_encode_select_rejects_conflicting_lock_wait_policies() {
+        let cmd = Qail::get("jobs")
+            .columns(["id"])
+            .for_update_skip_locked()
+            .nowait();
+        let err = AstEncoder::encode_cmd_sql(&cmd).expect_err("conflicting wait policies");
+        assert!(err.to_string().contains("mutually exclusive"));
+
+        let cmd = Qail::get("jobs").columns(["id"]).nowait();
+        let err = AstEncoder::encode_cmd_sql(&cmd).expect_err("NOWAIT without lock");
+        assert!(err.to_string().contains("NOWAIT requires a row lock mode"));
+    }
+
     #[test]
     fn test_encode_select_rejects_invalid_table_sample_percent() {
         let cmd = Qail::get("events")
@@ -1274,6 +1495,50 @@ mod tests {
         assert_eq!(params.len(), 1);
     }
 
+    #[test]
+    fn test_encode_eq_null_as_null_test() {
+        use qail_core::ast::{Operator, Value};
+
+        let cmd = Qail::get("users")
+            .filter("deleted_at", Operator::Eq, Value::Null)
+            .filter("archived_at", Operator::Ne, Value::Null);
+
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM users WHERE deleted_at IS NULL AND archived_at IS NOT NULL"
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_encode_distinct_on_expressions_without_alias() {
+        use qail_core::ast::Expr;
+
+        let mut cmd = qail_core::parser::parse(
+            "get distinct on (data->>'k', lower(email)) events fields id order by data->>'k'",
+        )
+        .unwrap();
+        cmd.distinct_on.push(Expr::JsonAccess {
+            column: "data".to_string(),
+            path_segments: vec![("z".to_string(), true)],
+            alias: Some("z".to_string()),
+        });
+        cmd.distinct_on.push(Expr::Aliased {
+            name: "events.id".to_string(),
+            alias: "event_id".to_string(),
+        });
+
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT DISTINCT ON ((data->>'k'), LOWER(email), (data->>'z'), events.id) id \
+             FROM events ORDER BY (data->>'k')"
+        );
+    }
+
     #[test]
     fn test_encode_fuzzy_wraps_search_term() {
         use qail_core::ast::Operator;
@@ -1763,6 +2028,28 @@ mod tests {
         assert_eq!(params.len(), 3);
     }
 
+    #[test]
+    fn test_encode_insert_conflict_partial_index_predicate() {
+        let cmd = qail_core::parser::parse(
+            "add users fields email, name values 'a@b.com', 'Ana' \
+             conflict (email) where active = true update name = excluded.name",
+        )
+        .unwrap();
+
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert!(
+            sql.contains(
+                "ON CONFLICT (email) WHERE active = TRUE DO UPDATE SET name = excluded.name"
+            ),
+            "{}",
+            sql
+        );
+        let arbiter = &sql[sql.find("ON CONFLICT").unwrap()..sql.find(" DO UPDATE").unwrap()];
+        assert!(!arbiter.contains('$'), "{arbiter}");
+        assert_eq!(params.len(), 2);
+    }
+
     #[test]
     fn test_encode_insert_select_source_query() {
         let mut cmd = Qail::add("archived_orders").columns(["id", "total"]);
@@ -1787,6 +2074,43 @@ mod tests {
         assert_eq!(params, vec![Some(b"1".to_vec()), Some(b"2".to_vec())]);
     }
 
+    #[test]
+    fn test_encode_insert_json_value_into_json_column() {
+        // `payload` is a `json` column; the bind stays untyped so the server
+        // infers `json` and the document text is sent as-is.
+        let cmd = Qail::add("audit_log")
+            .values([qail_core::ast::Value::Json(r#"{"b":1, "a":2}"#.to_string())]);
+
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(sql, "INSERT INTO audit_log VALUES ($1)");
+        assert_eq!(params, vec![Some(br#"{"b":1, "a":2}"#.to_vec())]);
+    }
+
+    #[test]
+    fn test_encode_insert_multi_row_values() {
+        let cmd =
+            Qail::add("events")
+                .columns(["id", "kind"])
+                .values_rows([[1, 10], [2, 20], [3, 30]]);
+
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "INSERT INTO events (id, kind) VALUES ($1, $2), ($3, $4), ($5, $6)"
+        );
+        assert_eq!(params.len(), 6);
+        assert_eq!(params[4], Some(b"3".to_vec()));
+
+        let ragged = Qail::add("events")
+            .columns(["id", "kind"])
+            .values_rows([vec![1, 10], vec![2]]);
+        let err = AstEncoder::encode_cmd_sql(&ragged)
+            .expect_err("ragged multi-row insert must fail closed");
+        assert!(err.to_string().contains("same count per row"));
+    }
+
     #[test]
     fn test_encode_insert_default_values_and_overriding() {
         let default_cmd = Qail::add("events").default_values();
@@ -1941,6 +2265,35 @@ mod tests {
         assert_eq!(params.len(), 2);
     }
 
+    #[test]
+    fn test_encode_mutation_joins_as_using_and_from() {
+        let cmd = qail_core::parse(
+            "del orders inner join users on orders.user_id = users.id where users.banned = true",
+        )
+        .unwrap();
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(
+            sql,
+            "DELETE FROM orders USING users WHERE orders.user_id = users.id AND users.banned = $1"
+        );
+        assert_eq!(params.len(), 1);
+
+        let cmd = Qail::set("orders").set_value("status", "void").inner_join(
+            "users",
+            "orders.user_id",
+            "users.id",
+        );
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(
+            sql,
+            "UPDATE orders SET status = $1 FROM users WHERE orders.user_id = users.id"
+        );
+
+        let cmd = qail_core::parse("del orders join users on orders.user_id = users.id").unwrap();
+        let err = AstEncoder::encode_cmd_sql(&cmd).expect_err("LEFT join on DELETE");
+        assert!(err.to_string().contains("supports only inner joins"));
+    }
+
     #[test]
     fn test_encode_delete_using_tables() {
         let cmd = Qail::del("orders")
@@ -1957,6 +2310,138 @@ mod tests {
         assert_eq!(params.len(), 1);
     }
 
+    #[test]
+    fn test_encode_join_without_condition_is_rejected() {
+        let cmd = Qail::get("orders")
+            .column("id")
+            .inner_join_conds("payments", vec![]);
+        let err = AstEncoder::encode_cmd_sql(&cmd).unwrap_err();
+        assert!(err.to_string().contains("no ON condition"), "{err}");
+
+        let cross = Qail::get("sizes").column("sizes.name").cross_join("colors");
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cross).unwrap();
+        assert!(sql.ends_with("FROM sizes CROSS JOIN colors"), "{sql}");
+    }
+
+    #[test]
+    fn test_encode_join_using() {
+        use qail_core::ast::JoinKind;
+
+        let cmd = Qail::get("line_items").column("sku").join_using(
+            JoinKind::Inner,
+            "prices",
+            ["tenant_id", "sku"],
+        );
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert!(
+            sql.ends_with("FROM line_items INNER JOIN prices USING (tenant_id, sku)"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_encode_join_using_rejects_extra_on() {
+        use qail_core::ast::{Condition, Expr, JoinKind, Operator, Value};
+
+        let mut cmd = Qail::get("orders o").column("o.id").join_using(
+            JoinKind::Inner,
+            "users u",
+            ["user_id"],
+        );
+        cmd.joins[0].on = Some(vec![Condition {
+            left: Expr::Named("u.visible".to_string()),
+            op: Operator::Eq,
+            value: Value::Bool(true),
+            is_array_unnest: false,
+        }]);
+        let err = AstEncoder::encode_cmd_sql(&cmd).unwrap_err();
+        assert!(err.to_string().contains("combines USING with ON"), "{err}");
+    }
+
+    #[test]
+    fn test_encode_rewritten_insert_target_has_no_alias() {
+        let mapping =
+            std::collections::HashMap::from([("users".to_string(), "users_shard_3".to_string())]);
+        let mut cmd = Qail::add("users").columns(["name"]).values(["ada"]);
+        cmd.rewrite_table(&mapping);
+        let (sql, _) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert!(sql.starts_with("INSERT INTO users_shard_3 (name)"), "{sql}");
+    }
+
+    #[test]
+    fn test_encode_count_only_wraps_source_query() {
+        let cmd = Qail::get("orders")
+            .column("id")
+            .filter("status", qail_core::ast::Operator::Eq, "paid")
+            .limit(10)
+            .count_only();
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM (SELECT id FROM orders WHERE status = $1) _sub"
+        );
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn test_encode_having_after_group_by() {
+        let cmd = qail_core::parse(
+            "get orders fields status, sum(total) group by status having sum(total) > 100 \
+             order by status",
+        )
+        .unwrap();
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert!(
+            sql.ends_with("GROUP BY status HAVING SUM(total) > $1 ORDER BY status"),
+            "{sql}"
+        );
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn test_encode_lateral_subquery_join() {
+        use qail_core::ast::{Operator, Value};
+
+        let recent = Qail::get("orders")
+            .column("total")
+            .filter(
+                "user_id",
+                Operator::Eq,
+                Value::Column("users.id".to_string()),
+            )
+            .filter("status", Operator::Eq, "paid")
+            .limit(3);
+        let cmd = Qail::get("users")
+            .column("users.name")
+            .left_join_lateral(recent.clone(), "recent");
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+        assert!(
+            sql.contains(
+                "FROM users LEFT JOIN LATERAL (SELECT total FROM orders WHERE user_id = users.id AND status = $1 LIMIT 3) recent ON TRUE"
+            ),
+            "{sql}"
+        );
+        assert_eq!(params.len(), 1);
+
+        let inner = Qail::get("users").inner_join_lateral(recent, "recent");
+        let (sql, _) = AstEncoder::encode_cmd_sql(&inner).unwrap();
+        assert!(sql.contains("INNER JOIN LATERAL (SELECT"), "{sql}");
+
+        let write = Qail::get("users").left_join_lateral(Qail::del("orders"), "gone");
+        assert!(AstEncoder::encode_cmd_sql(&write).is_err());
+    }
+
+    #[test]
+    fn test_encode_dml_limit_is_rejected() {
+        let delete = Qail::del("sessions").eq("expired", true).limit(100);
+        let err = AstEncoder::encode_cmd_sql(&delete).unwrap_err();
+        assert!(err.to_string().contains("LIMIT on DELETE"), "{err}");
+
+        let update = Qail::set("jobs").set_value("claimed", true).limit(10);
+        let err = AstEncoder::encode_cmd_sql(&update).unwrap_err();
+        assert!(err.to_string().contains("LIMIT on UPDATE"), "{err}");
+    }
+
     #[test]
     fn test_encode_delete_with_or_filter() {
         use qail_core::ast::Operator;
@@ -1997,11 +2482,31 @@ mod tests {
 
         let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
 
-        assert!(sql.contains("COPY (SELECT"));
-        assert!(sql.contains("WHERE"));
-        assert!(sql.contains("$1"));
-        assert!(sql.ends_with(") TO STDOUT"));
-        assert_eq!(params.len(), 1);
+        // COPY has no bind parameters, so the filter is written inline.
+        assert_eq!(
+            sql,
+            "COPY (SELECT id, name FROM users WHERE active = TRUE) TO STDOUT"
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_encode_export_csv_with_header() {
+        use qail_core::ast::Operator;
+
+        let cmd =
+            Qail::export_csv("users")
+                .columns(["id", "name"])
+                .filter("active", Operator::Eq, true);
+
+        let (sql, params) = AstEncoder::encode_cmd_sql(&cmd).unwrap();
+
+        assert_eq!(
+            sql,
+            "COPY (SELECT id, name FROM users WHERE active = TRUE) \
+             TO STDOUT WITH (FORMAT csv, HEADER true)"
+        );
+        assert!(params.is_empty());
     }
 
     #[test]
@@ -2386,6 +2891,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_mod_expr_if_exists() {
+        use qail_core::ast::{Expr, ModKind};
+
+        for (kind, expected) in [
+            (ModKind::AddIfNotExists, "ADD COLUMN IF NOT EXISTS phone"),
+            (ModKind::DropIfExists, "DROP COLUMN IF EXISTS phone"),
+        ] {
+            let expr = Expr::Mod {
+                kind,
+                col: Box::new(Expr::Named("phone".to_string())),
+            };
+            let mut buf = bytes::BytesMut::with_capacity(64);
+            super::values::encode_column_expr(&expr, &mut buf).unwrap();
+            assert_eq!(String::from_utf8_lossy(&buf), expected);
+        }
+    }
+
     #[test]
     fn test_encode_batch_cnt() {
         let cmd = Qail {
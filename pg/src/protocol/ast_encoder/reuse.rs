@@ -0,0 +1,122 @@
+//! Reusable encoder for hot loops.
+//!
+//! Holds the SQL and parameter scratch buffers across calls so that
+//! encoding N commands does not allocate N fresh buffers.
+
+use bytes::BytesMut;
+use qail_core::ast::Qail;
+
+use super::{AstEncoder, EncodeError};
+
+/// Long-lived AST encoder that keeps its scratch buffers between calls.
+///
+/// ```ignore
+/// let mut encoder = Encoder::new();
+/// let mut wire = BytesMut::with_capacity(64 * 1024);
+/// for cmd in &cmds {
+///     encoder.encode_cmd_into(cmd, &mut wire)?;
+/// }
+/// conn.send_raw(&wire).await?;
+/// ```
+#[derive(Debug, Default)]
+pub struct Encoder {
+    sql_buf: BytesMut,
+    params: Vec<Option<Vec<u8>>>,
+    result_format: i16,
+}
+
+impl Encoder {
+    /// Create an encoder with empty scratch buffers (text result format).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an encoder with a pre-sized SQL scratch buffer.
+    pub fn with_capacity(sql_capacity: usize) -> Self {
+        Self {
+            sql_buf: BytesMut::with_capacity(sql_capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Set the result-column format used for every encoded command.
+    /// `result_format`: 0 = text, 1 = binary.
+    pub fn result_format(mut self, result_format: i16) -> Self {
+        self.result_format = result_format;
+        self
+    }
+
+    /// Encode `cmd` and append Parse/Bind/Describe/Execute/Sync to `buf`.
+    ///
+    /// Existing bytes in `buf` are preserved. On error `buf` is unchanged.
+    #[inline]
+    pub fn encode_cmd_into(&mut self, cmd: &Qail, buf: &mut BytesMut) -> Result<(), EncodeError> {
+        AstEncoder::encode_cmd_append(
+            cmd,
+            &mut self.sql_buf,
+            &mut self.params,
+            buf,
+            self.result_format,
+        )
+    }
+
+    /// Parameters extracted by the most recent successful encode.
+    pub fn last_params(&self) -> &[Option<Vec<u8>>] {
+        &self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_encode_cmd_into_appends_concatenated_frames() {
+        let first = Qail::get("users").columns(["id", "email"]).eq("id", 7);
+        let second = Qail::get("orders").column("total").limit(5);
+
+        let mut encoder = Encoder::new();
+        let mut wire = BytesMut::new();
+        encoder.encode_cmd_into(&first, &mut wire).unwrap();
+        let first_len = wire.len();
+        encoder.encode_cmd_into(&second, &mut wire).unwrap();
+        encoder.encode_cmd_into(&first, &mut wire).unwrap();
+
+        let (expected_first, _) = AstEncoder::encode_cmd(&first).unwrap();
+        let (expected_second, _) = AstEncoder::encode_cmd(&second).unwrap();
+
+        let mut expected = BytesMut::new();
+        expected.extend_from_slice(&expected_first);
+        expected.extend_from_slice(&expected_second);
+        expected.extend_from_slice(&expected_first);
+
+        assert_eq!(first_len, expected_first.len());
+        assert_eq!(&wire[..], &expected[..]);
+        assert_eq!(encoder.last_params(), &[Some(b"7".to_vec())]);
+    }
+
+    #[test]
+    fn encode_cmd_into_leaves_buffer_untouched_on_error() {
+        let mut encoder = Encoder::new();
+        let mut wire = BytesMut::new();
+        encoder
+            .encode_cmd_into(&Qail::get("users").column("id"), &mut wire)
+            .unwrap();
+        let before = wire.clone();
+
+        let bad = Qail::get("users\0").column("id");
+        assert!(encoder.encode_cmd_into(&bad, &mut wire).is_err());
+        assert_eq!(wire, before);
+    }
+
+    #[test]
+    fn binary_result_format_matches_one_shot_encoder() {
+        let cmd = Qail::get("users").column("id");
+        let mut encoder = Encoder::with_capacity(128).result_format(1);
+        let mut wire = BytesMut::new();
+        encoder.encode_cmd_into(&cmd, &mut wire).unwrap();
+
+        let (expected, _) = AstEncoder::encode_cmd_with_result_format(&cmd, 1).unwrap();
+        assert_eq!(&wire[..], &expected[..]);
+    }
+}
@@ -7,9 +7,10 @@ use qail_core::ast::{
     CageKind, Condition, Constraint, Expr, FrameBound, ModKind, Operator, SortOrder, Value,
     WindowFrame,
 };
-use qail_core::transpiler::escape_identifier;
+use qail_core::transpiler::{escape_identifier, split_collation_name};
 
 use super::super::helpers::{NUMERIC_VALUES, i64_to_bytes, write_param_placeholder};
+use crate::protocol::error::checked_big_int;
 
 fn reject_non_finite_f64(label: &str, value: f64) -> Result<(), crate::protocol::EncodeError> {
     if value.is_finite() {
@@ -39,6 +40,25 @@ fn push_identifier_ref(buf: &mut BytesMut, ident: &str, allow_star: bool) {
     }
 }
 
+/// Collation names are case-sensitive, so every part is always double-quoted.
+fn push_collation_ref(
+    buf: &mut BytesMut,
+    collation: &str,
+) -> Result<(), crate::protocol::EncodeError> {
+    let parts = split_collation_name(collation).ok_or_else(|| {
+        crate::protocol::EncodeError::InvalidAst(format!("invalid collation name {collation:?}"))
+    })?;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            buf.extend_from_slice(b".");
+        }
+        buf.extend_from_slice(b"\"");
+        buf.extend_from_slice(part.replace('"', "\"\"").as_bytes());
+        buf.extend_from_slice(b"\"");
+    }
+    Ok(())
+}
+
 fn encode_text_search_vector(
     expr: &Expr,
     buf: &mut BytesMut,
@@ -423,7 +443,7 @@ fn encode_column_expr_inner(
         } => {
             encode_column_expr_inner(expr, buf, params.as_deref_mut())?;
             buf.extend_from_slice(b" COLLATE ");
-            push_identifier_ref(buf, collation, false);
+            push_collation_ref(buf, collation)?;
             if let Some(a) = alias {
                 buf.extend_from_slice(b" AS ");
                 push_identifier_ref(buf, a, false);
@@ -539,6 +559,14 @@ fn encode_column_expr_inner(
                 buf.extend_from_slice(b"DROP COLUMN ");
                 encode_column_expr_inner(col, buf, params)?;
             }
+            ModKind::AddIfNotExists => {
+                buf.extend_from_slice(b"ADD COLUMN IF NOT EXISTS ");
+                encode_column_expr_inner(col, buf, params)?;
+            }
+            ModKind::DropIfExists => {
+                buf.extend_from_slice(b"DROP COLUMN IF EXISTS ");
+                encode_column_expr_inner(col, buf, params)?;
+            }
         },
     }
     Ok(())
@@ -567,6 +595,7 @@ pub fn encode_operator(op: &Operator, buf: &mut BytesMut) {
         Operator::Regex => b"~",
         Operator::RegexI => b"~*",
         Operator::SimilarTo => b"SIMILAR TO",
+        Operator::Similar => b"%",
         Operator::Contains => b"@>",
         Operator::ContainedBy => b"<@",
         Operator::Overlaps => b"&&",
@@ -625,7 +654,8 @@ fn encode_case_condition_value(
     Ok(())
 }
 
-fn encode_conditions_inline(
+/// Encode AND-joined conditions with literal values instead of bind params.
+pub fn encode_conditions_inline(
     conditions: &[Condition],
     buf: &mut BytesMut,
 ) -> Result<(), crate::protocol::EncodeError> {
@@ -712,9 +742,22 @@ fn encode_conditions_inline(
                     ));
                 }
             }
+            Operator::Contains => {
+                // Same JSON-document detection as the bound path.
+                buf.extend_from_slice(b" ");
+                let json = cond.value.as_json_document();
+                let value = json.as_ref().unwrap_or(&cond.value);
+                encode_inline_value(value, buf)?;
+                if matches!(value, Value::Json(_)) {
+                    buf.extend_from_slice(b"::jsonb");
+                }
+            }
             _ => {
                 buf.extend_from_slice(b" ");
                 encode_inline_value(&cond.value, buf)?;
+                if is_jsonb_operator(cond.op) && matches!(cond.value, Value::Json(_)) {
+                    buf.extend_from_slice(b"::jsonb");
+                }
             }
         }
     }
@@ -740,7 +783,7 @@ fn encode_inline_value(
             }
             buf.extend_from_slice(b"'");
             buf.extend_from_slice(value.replace('\'', "''").as_bytes());
-            buf.extend_from_slice(b"'::jsonb");
+            buf.extend_from_slice(b"'");
         }
         Value::Bool(value) => buf.extend_from_slice(if *value { b"TRUE" } else { b"FALSE" }),
         Value::Column(column) => push_identifier_ref(buf, column, false),
@@ -790,6 +833,7 @@ fn encode_inline_value(
             }
             buf.extend_from_slice(b")");
         }
+        Value::BigInt(value) => buf.extend_from_slice(checked_big_int(value)?.as_bytes()),
         Value::Float(value) => {
             reject_non_finite_f64("inline float value", *value)?;
             buf.extend_from_slice(value.to_string().as_bytes());
@@ -816,8 +860,9 @@ pub fn encode_expr(expr: &Expr, buf: &mut BytesMut) -> Result<(), crate::protoco
         Expr::Named(name) => push_identifier_ref(buf, name, true),
         Expr::Star => buf.extend_from_slice(b"*"),
         Expr::Aliased { name, .. } => push_identifier_ref(buf, name, true),
-        // Delegate complex expressions to the full encoder
-        _ => encode_column_expr(expr, buf)?,
+        // Delegate complex expressions to the full encoder, without the
+        // select-list alias (invalid in WHERE, ORDER BY and DISTINCT ON)
+        _ => encode_column_expr(&expr.without_alias(), buf)?,
     }
     Ok(())
 }
@@ -919,7 +964,7 @@ pub fn encode_conditions(
         let left_start = buf.len();
         encode_expr(&cond.left, buf)?;
 
-        match cond.op {
+        match cond.null_test().unwrap_or(cond.op) {
             Operator::Eq => buf.extend_from_slice(b" = "),
             Operator::Ne => buf.extend_from_slice(b" != "),
             Operator::Gt => buf.extend_from_slice(b" > "),
@@ -1019,7 +1064,16 @@ pub fn encode_conditions(
             Operator::Regex => buf.extend_from_slice(b" ~ "),
             Operator::RegexI => buf.extend_from_slice(b" ~* "),
             Operator::SimilarTo => buf.extend_from_slice(b" SIMILAR TO "),
-            Operator::Contains => buf.extend_from_slice(b" @> "),
+            Operator::Similar => buf.extend_from_slice(b" % "),
+            Operator::Contains => {
+                buf.extend_from_slice(b" @> ");
+                // A JSON document written as a string literal binds as jsonb.
+                if let Some(json) = cond.value.as_json_document() {
+                    encode_value(&json, buf, params)?;
+                    buf.extend_from_slice(b"::jsonb");
+                    continue;
+                }
+            }
             Operator::ContainedBy => buf.extend_from_slice(b" <@ "),
             Operator::Overlaps => buf.extend_from_slice(b" && "),
             Operator::Fuzzy => {
@@ -1088,10 +1142,25 @@ pub fn encode_conditions(
         }
 
         encode_value(&cond.value, buf, params)?;
+        if is_jsonb_operator(cond.op) && matches!(cond.value, Value::Json(_)) {
+            buf.extend_from_slice(b"::jsonb");
+        }
     }
     Ok(())
 }
 
+/// Operators whose right-hand JSON operand must be typed as `jsonb`.
+fn is_jsonb_operator(op: Operator) -> bool {
+    matches!(
+        op,
+        Operator::Contains
+            | Operator::ContainedBy
+            | Operator::KeyExists
+            | Operator::KeyExistsAny
+            | Operator::KeyExistsAll
+    )
+}
+
 fn encode_json_sql_condition(
     op: Operator,
     left: &[u8],
@@ -1161,6 +1230,10 @@ pub fn encode_value(
             params.push(Some(i64_to_bytes(*n)));
             write_param_placeholder(buf, params.len());
         }
+        Value::BigInt(n) => {
+            params.push(Some(checked_big_int(n)?.as_bytes().to_vec()));
+            write_param_placeholder(buf, params.len());
+        }
         Value::Float(f) => {
             reject_non_finite_f64("float parameter", *f)?;
             params.push(Some(f.to_string().into_bytes()));
@@ -1245,6 +1318,18 @@ pub fn encode_value(
             params.push(Some(interval_buf));
             write_param_placeholder(buf, params.len());
         }
+        Value::IntervalParts {
+            months,
+            days,
+            micros,
+        } => {
+            // Bind declares one format code for every parameter and the AST
+            // path uses text, so this sends interval text rather than the
+            // 16-byte binary layout `Interval::to_pg` produces.
+            let text = qail_core::ast::values::interval_text(*months, *days, *micros);
+            params.push(Some(text.into_bytes()));
+            write_param_placeholder(buf, params.len());
+        }
         Value::NullUuid => {
             params.push(None);
             write_param_placeholder(buf, params.len());
@@ -1272,7 +1357,11 @@ pub fn encode_value(
             write_param_placeholder(buf, params.len());
         }
         Value::Json(json) => {
-            // JSONB: encode as text parameter with escaping
+            // Untyped, so `json` and `jsonb` positions both accept it; jsonb
+            // operators add the cast in `encode_conditions`.
+            if json.as_bytes().contains(&0) {
+                return Err(EncodeError::NullByte);
+            }
             params.push(Some(json.as_bytes().to_vec()));
             write_param_placeholder(buf, params.len());
         }
@@ -1295,6 +1384,7 @@ pub fn write_value_to_array(
                 buf.extend_from_slice(n.to_string().as_bytes());
             }
         }
+        Value::BigInt(n) => buf.extend_from_slice(checked_big_int(n)?.as_bytes()),
         Value::String(s) | Value::Timestamp(s) | Value::Json(s) => {
             write_quoted_array_element(buf, s)?
         }
@@ -1308,6 +1398,14 @@ pub fn write_value_to_array(
         Value::Interval { amount, unit } => {
             write_quoted_array_element(buf, &format!("{amount} {unit}"))?;
         }
+        Value::IntervalParts {
+            months,
+            days,
+            micros,
+        } => {
+            let text = qail_core::ast::values::interval_text(*months, *days, *micros);
+            write_quoted_array_element(buf, &text)?;
+        }
         Value::Param(n) => {
             return Err(EncodeError::InvalidAst(format!(
                 "unresolved positional parameter ${n} cannot be encoded inside array data"
@@ -1352,21 +1450,35 @@ fn write_quoted_array_element(
     Ok(())
 }
 
-/// Encode window frame (ROWS/RANGE BETWEEN ... AND ...)
+/// Encode window frame (ROWS/RANGE BETWEEN ... AND ... [EXCLUDE ...])
 fn encode_window_frame(frame: &WindowFrame, buf: &mut BytesMut) {
-    match frame {
-        WindowFrame::Rows { start, end } => {
+    let exclude = match frame {
+        WindowFrame::Rows {
+            start,
+            end,
+            exclude,
+        } => {
             buf.extend_from_slice(b"ROWS BETWEEN ");
             encode_frame_bound(start, buf);
             buf.extend_from_slice(b" AND ");
             encode_frame_bound(end, buf);
+            exclude
         }
-        WindowFrame::Range { start, end } => {
+        WindowFrame::Range {
+            start,
+            end,
+            exclude,
+        } => {
             buf.extend_from_slice(b"RANGE BETWEEN ");
             encode_frame_bound(start, buf);
             buf.extend_from_slice(b" AND ");
             encode_frame_bound(end, buf);
+            exclude
         }
+    };
+    if let Some(exclude) = exclude {
+        buf.extend_from_slice(b" ");
+        buf.extend_from_slice(exclude.to_string().as_bytes());
     }
 }
 
@@ -1389,7 +1501,7 @@ fn encode_frame_bound(bound: &FrameBound, buf: &mut BytesMut) {
 
 #[cfg(test)]
 mod tests {
-    use super::{encode_conditions, encode_value};
+    use super::{encode_conditions, encode_conditions_inline, encode_value};
     use bytes::BytesMut;
     use qail_core::ast::{Condition, Expr, Operator, Qail, Value};
     use uuid::Uuid;
@@ -1411,6 +1523,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_interval_parts_parameter_binds_as_text() {
+        let value = Value::IntervalParts {
+            months: 1,
+            days: 7,
+            micros: 3 * 3_600_000_000,
+        };
+        let mut sql = BytesMut::new();
+        let mut params = Vec::new();
+
+        encode_value(&value, &mut sql, &mut params).unwrap();
+
+        assert_eq!(sql.as_ref(), b"$1");
+        assert_eq!(
+            params[0].as_deref(),
+            Some(b"1 mon 7 days 03:00:00".as_slice())
+        );
+    }
+
+    #[test]
+    fn inline_contains_casts_only_json_objects() {
+        let contains = |value: &str| Condition {
+            left: Expr::Named("data".to_string()),
+            op: Operator::Contains,
+            value: Value::String(value.to_string()),
+            is_array_unnest: false,
+        };
+        let mut sql = BytesMut::new();
+
+        encode_conditions_inline(
+            &[contains(r#"{"role":"admin"}"#), contains("[1,5]")],
+            &mut sql,
+        )
+        .unwrap();
+
+        assert_eq!(
+            std::str::from_utf8(&sql).unwrap(),
+            r#"data @> '{"role":"admin"}'::jsonb AND data @> '[1,5]'"#
+        );
+    }
+
+    #[test]
+    fn encode_json_parameter_binds_untyped() {
+        let value = Value::Json(r#"{"tags":["a",{"b":true}]}"#.to_string());
+        let mut sql = BytesMut::new();
+        let mut params = Vec::new();
+
+        encode_value(&value, &mut sql, &mut params).unwrap();
+
+        assert_eq!(sql.as_ref(), b"$1");
+        assert_eq!(
+            params[0].as_deref(),
+            Some(br#"{"tags":["a",{"b":true}]}"#.as_slice())
+        );
+    }
+
+    #[test]
+    fn encode_json_parameter_casts_only_under_jsonb_operators() {
+        let json = Value::Json(r#"{"a":1}"#.to_string());
+        let conditions = vec![
+            Condition {
+                left: Expr::Named("doc".to_string()),
+                op: Operator::Eq,
+                value: json.clone(),
+                is_array_unnest: false,
+            },
+            Condition {
+                left: Expr::Named("doc".to_string()),
+                op: Operator::ContainedBy,
+                value: json,
+                is_array_unnest: false,
+            },
+        ];
+        let mut sql = BytesMut::new();
+        let mut params = Vec::new();
+
+        encode_conditions(&conditions, &mut sql, &mut params).unwrap();
+
+        assert_eq!(
+            std::str::from_utf8(&sql).unwrap(),
+            "doc = $1 AND doc <@ $2::jsonb"
+        );
+    }
+
+    #[test]
+    fn encode_big_int_parameter_sends_digits_verbatim() {
+        let value = Value::BigInt("123456789012345678901234567890".to_string());
+        let mut sql = BytesMut::new();
+        let mut params = Vec::new();
+
+        encode_value(&value, &mut sql, &mut params).unwrap();
+
+        assert_eq!(sql.as_ref(), b"$1");
+        assert_eq!(
+            params[0].as_deref(),
+            Some(b"123456789012345678901234567890".as_slice())
+        );
+
+        let bad = Value::BigInt("1; DROP TABLE users".to_string());
+        assert!(encode_value(&bad, &mut sql, &mut params).is_err());
+    }
+
     #[test]
     fn encode_array_string_parameter_escapes_backslashes_and_quotes() {
         let value = Value::Array(vec![Value::String("a\\b\"c".to_string())]);
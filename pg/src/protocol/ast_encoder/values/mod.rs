@@ -9,6 +9,7 @@ mod expressions;
 #[cfg(test)]
 pub use expressions::encode_column_expr;
 pub use expressions::encode_conditions;
+pub use expressions::encode_conditions_inline;
 pub use expressions::encode_expr;
 pub use expressions::encode_expr_with_params;
 pub use expressions::encode_join_value;
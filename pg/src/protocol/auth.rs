@@ -12,6 +12,87 @@ type HmacSha256 = Hmac<Sha256>;
 const GS2_HEADER_NO_CHANNEL_BINDING: &str = "n,,";
 const GS2_HEADER_TLS_SERVER_END_POINT: &str = "p=tls-server-end-point,,";
 
+/// Hash functions used for `tls-server-end-point` channel binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EndPointHash {
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+/// Compute `tls-server-end-point` channel-binding data for a DER server certificate.
+///
+/// Per RFC 5929 §4.1 the certificate is hashed with the hash function of its
+/// signature algorithm, with MD5 and SHA-1 upgraded to SHA-256. Algorithms
+/// without a single associated hash (RSASSA-PSS, EdDSA) or an unparsable
+/// certificate fall back to SHA-256.
+pub fn tls_server_end_point(cert_der: &[u8]) -> Vec<u8> {
+    match certificate_signature_hash(cert_der).unwrap_or(EndPointHash::Sha256) {
+        EndPointHash::Sha224 => sha2::Sha224::digest(cert_der).to_vec(),
+        EndPointHash::Sha256 => Sha256::digest(cert_der).to_vec(),
+        EndPointHash::Sha384 => sha2::Sha384::digest(cert_der).to_vec(),
+        EndPointHash::Sha512 => sha2::Sha512::digest(cert_der).to_vec(),
+    }
+}
+
+/// Read `Certificate.signatureAlgorithm` and map its OID to the binding hash.
+fn certificate_signature_hash(cert_der: &[u8]) -> Option<EndPointHash> {
+    const RSA_PKCS1: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01];
+    const ECDSA_SHA1: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x01];
+    const ECDSA_SHA2: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03];
+
+    // Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signatureValue }
+    let (0x30, certificate, _) = der_tlv(cert_der)? else {
+        return None;
+    };
+    let (_, _, after_tbs) = der_tlv(certificate)?;
+    let (0x30, algorithm, _) = der_tlv(after_tbs)? else {
+        return None;
+    };
+    let (0x06, oid, _) = der_tlv(algorithm)? else {
+        return None;
+    };
+
+    if oid == ECDSA_SHA1 {
+        return Some(EndPointHash::Sha256);
+    }
+    let (&last, prefix) = oid.split_last()?;
+    match (prefix, last) {
+        // md5WithRSAEncryption, sha1WithRSAEncryption
+        (RSA_PKCS1, 0x04 | 0x05) => Some(EndPointHash::Sha256),
+        (RSA_PKCS1, 0x0b) | (ECDSA_SHA2, 0x02) => Some(EndPointHash::Sha256),
+        (RSA_PKCS1, 0x0c) | (ECDSA_SHA2, 0x03) => Some(EndPointHash::Sha384),
+        (RSA_PKCS1, 0x0d) | (ECDSA_SHA2, 0x04) => Some(EndPointHash::Sha512),
+        (RSA_PKCS1, 0x0e) | (ECDSA_SHA2, 0x01) => Some(EndPointHash::Sha224),
+        _ => None,
+    }
+}
+
+/// Split one DER TLV into `(tag, contents, rest)`.
+fn der_tlv(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first_len, rest) = rest.split_first()?;
+    let (len, rest) = if first_len < 0x80 {
+        (usize::from(first_len), rest)
+    } else {
+        let len_bytes = usize::from(first_len & 0x7f);
+        if len_bytes == 0 || len_bytes > 4 || rest.len() < len_bytes {
+            return None;
+        }
+        let (len_bytes, rest) = rest.split_at(len_bytes);
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |len, &byte| (len << 8) | usize::from(byte));
+        (len, rest)
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (contents, rest) = rest.split_at(len);
+    Some((tag, contents, rest))
+}
+
 /// SCRAM-SHA-256 client state machine.
 pub struct ScramClient {
     username: String,
@@ -41,6 +122,8 @@ impl ScramClient {
     }
 
     /// Create a SCRAM client using `tls-server-end-point` channel binding.
+    ///
+    /// `channel_binding_data` is the certificate hash from [`tls_server_end_point`].
     pub fn new_with_tls_server_end_point(
         username: &str,
         password: &str,
@@ -307,4 +390,84 @@ mod tests {
         expected.extend_from_slice(&cb_data);
         assert_eq!(decoded, expected);
     }
+
+    /// Minimal DER certificate: empty tbsCertificate, the given
+    /// signatureAlgorithm OID with NULL parameters, and an empty signature.
+    fn cert_with_signature_oid(oid: &[u8]) -> Vec<u8> {
+        let mut algorithm = vec![0x06, oid.len() as u8];
+        algorithm.extend_from_slice(oid);
+        algorithm.extend_from_slice(&[0x05, 0x00]);
+
+        let mut body = vec![0x30, 0x00, 0x30, algorithm.len() as u8];
+        body.extend_from_slice(&algorithm);
+        body.extend_from_slice(&[0x03, 0x01, 0x00]);
+
+        let mut cert = vec![0x30, body.len() as u8];
+        cert.extend_from_slice(&body);
+        cert
+    }
+
+    #[test]
+    fn test_tls_server_end_point_uses_signature_hash() {
+        // sha384WithRSAEncryption (1.2.840.113549.1.1.12)
+        let cert = cert_with_signature_oid(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c]);
+        assert_eq!(
+            tls_server_end_point(&cert),
+            sha2::Sha384::digest(&cert).to_vec()
+        );
+
+        // ecdsa-with-SHA512 (1.2.840.10045.4.3.4)
+        let cert = cert_with_signature_oid(&[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04]);
+        assert_eq!(
+            tls_server_end_point(&cert),
+            sha2::Sha512::digest(&cert).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_tls_server_end_point_upgrades_md5_and_sha1_to_sha256() {
+        // RFC 5929 §4.1: MD5 and SHA-1 signatures bind with SHA-256.
+        for last in [0x04, 0x05] {
+            let cert =
+                cert_with_signature_oid(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, last]);
+            assert_eq!(tls_server_end_point(&cert), Sha256::digest(&cert).to_vec());
+        }
+
+        let garbage = [0x30, 0x05, 0x01];
+        assert_eq!(
+            tls_server_end_point(&garbage),
+            Sha256::digest(garbage).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_scram_plus_cbind_input_rfc5929() {
+        // RFC 5929 §4 + RFC 5802 §7: cbind-input = gs2-header || cert hash,
+        // sent base64-encoded as the `c=` attribute.
+        let cert = cert_with_signature_oid(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b]);
+        let binding = tls_server_end_point(&cert);
+        let mut client = ScramClient::new_with_tls_server_end_point("u", "p", binding.clone());
+
+        let first = String::from_utf8(client.client_first_message()).unwrap();
+        assert_eq!(
+            first,
+            format!("p=tls-server-end-point,,n=u,r={}", client.client_nonce)
+        );
+
+        let server_first = format!(
+            "r={}srv,s={},i=4096",
+            client.client_nonce,
+            BASE64.encode(b"salt")
+        );
+        let final_msg = String::from_utf8(
+            client
+                .process_server_first(server_first.as_bytes())
+                .unwrap(),
+        )
+        .unwrap();
+
+        let mut cbind_input = b"p=tls-server-end-point,,".to_vec();
+        cbind_input.extend_from_slice(&binding);
+        assert!(final_msg.starts_with(&format!("c={},r=", BASE64.encode(&cbind_input))));
+    }
 }
@@ -7,6 +7,7 @@ use bytes::BytesMut;
 use qail_core::ast::Value;
 
 use crate::protocol::EncodeError;
+use crate::protocol::error::checked_big_int;
 
 /// Encode a data Value directly into COPY text format (no SQL quoting).
 ///
@@ -40,6 +41,8 @@ pub fn try_encode_copy_value(buf: &mut BytesMut, value: &Value) -> Result<(), En
             buf.extend_from_slice(tmp.format(*n).as_bytes());
         }
 
+        Value::BigInt(n) => buf.extend_from_slice(checked_big_int(n)?.as_bytes()),
+
         Value::Float(n) => {
             if !n.is_finite() {
                 return Err(EncodeError::InvalidAst(format!(
@@ -94,6 +97,15 @@ pub fn try_encode_copy_value(buf: &mut BytesMut, value: &Value) -> Result<(), En
             buf.extend_from_slice(unit.to_string().as_bytes());
         }
 
+        Value::IntervalParts {
+            months,
+            days,
+            micros,
+        } => {
+            let text = qail_core::ast::values::interval_text(*months, *days, *micros);
+            buf.extend_from_slice(text.as_bytes());
+        }
+
         Value::Bytes(bytes) => {
             // PostgreSQL bytea hex format: \x followed by hex digits
             buf.extend_from_slice(b"\\\\x");
@@ -154,6 +166,7 @@ fn write_copy_array_value(buf: &mut Vec<u8>, value: &Value) -> Result<(), Encode
         Value::Null | Value::NullUuid => buf.extend_from_slice(b"NULL"),
         Value::Bool(value) => buf.extend_from_slice(if *value { b"t" } else { b"f" }),
         Value::Int(value) => buf.extend_from_slice(value.to_string().as_bytes()),
+        Value::BigInt(value) => buf.extend_from_slice(checked_big_int(value)?.as_bytes()),
         Value::Float(value) => {
             if !value.is_finite() {
                 return Err(EncodeError::InvalidAst(format!(
@@ -169,6 +182,14 @@ fn write_copy_array_value(buf: &mut Vec<u8>, value: &Value) -> Result<(), Encode
         Value::Interval { amount, unit } => {
             write_quoted_array_element(buf, &format!("{amount} {unit}"))?;
         }
+        Value::IntervalParts {
+            months,
+            days,
+            micros,
+        } => {
+            let text = qail_core::ast::values::interval_text(*months, *days, *micros);
+            write_quoted_array_element(buf, &text)?;
+        }
         Value::Column(_)
         | Value::Function(_)
         | Value::Param(_)
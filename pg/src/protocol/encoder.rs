@@ -479,6 +479,36 @@ impl PgEncoder {
         params: &[Option<Vec<u8>>],
         param_format: i16,
         result_format: i16,
+    ) -> Result<BytesMut, EncodeError> {
+        Self::encode_extended_query_pipeline(sql, params, &[], param_format, result_format)
+    }
+
+    /// Encode a complete extended query pipeline with explicit parameter type OIDs.
+    ///
+    /// `param_types` is written into the Parse message so the server does not
+    /// have to infer types (e.g. a timestamp sent as text). It may be shorter
+    /// than `params`; missing or `0` entries are left for the server to infer.
+    /// See [`oid`](crate::protocol::types::oid) for the constants.
+    pub fn encode_extended_query_typed(
+        sql: &str,
+        params: &[Option<Vec<u8>>],
+        param_types: &[u32],
+    ) -> Result<BytesMut, EncodeError> {
+        Self::encode_extended_query_pipeline(
+            sql,
+            params,
+            param_types,
+            Self::FORMAT_TEXT,
+            Self::FORMAT_TEXT,
+        )
+    }
+
+    fn encode_extended_query_pipeline(
+        sql: &str,
+        params: &[Option<Vec<u8>>],
+        param_types: &[u32],
+        param_format: i16,
+        result_format: i16,
     ) -> Result<BytesMut, EncodeError> {
         Self::validate_format_codes(param_format, result_format)?;
         if Self::has_nul(sql) {
@@ -487,6 +517,9 @@ impl PgEncoder {
         if params.len() > i16::MAX as usize {
             return Err(EncodeError::TooManyParameters(params.len()));
         }
+        if param_types.len() > i16::MAX as usize {
+            return Err(EncodeError::TooManyParameters(param_types.len()));
+        }
 
         // Calculate total size upfront to avoid reallocations
         // Bind: 1 + 4 + 1 + 1 + param_formats + 2 + params_data + result_formats
@@ -501,8 +534,10 @@ impl PgEncoder {
         })?;
         let param_formats_size = Self::param_format_wire_len(param_format);
         let result_formats_size = Self::result_format_wire_len(result_format);
+        let param_types_size = param_types.len() * 4;
         let total_size = 9usize
             .checked_add(sql.len())
+            .and_then(|v| v.checked_add(param_types_size))
             .and_then(|v| v.checked_add(9))
             .and_then(|v| v.checked_add(params_size))
             .and_then(|v| v.checked_add(param_formats_size))
@@ -519,13 +554,19 @@ impl PgEncoder {
             .checked_add(sql.len())
             .and_then(|v| v.checked_add(1))
             .and_then(|v| v.checked_add(2))
+            .and_then(|v| v.checked_add(param_types_size))
             .ok_or(EncodeError::MessageTooLarge(usize::MAX))?;
         let parse_len = Self::content_len_to_wire_len(parse_content_len)?;
         buf.extend_from_slice(&parse_len.to_be_bytes());
         buf.extend_from_slice(&[0]); // Unnamed statement
         buf.extend_from_slice(sql.as_bytes());
         buf.extend_from_slice(&[0]); // Null terminator
-        buf.extend_from_slice(&0i16.to_be_bytes()); // No param types (infer)
+        // Param types; an empty list lets the server infer them
+        let type_count = Self::usize_to_i16(param_types.len())?;
+        buf.extend_from_slice(&type_count.to_be_bytes());
+        for &oid in param_types {
+            buf.extend_from_slice(&oid.to_be_bytes());
+        }
 
         // ===== BIND =====
         buf.extend_from_slice(b"B");
@@ -1014,6 +1055,38 @@ mod tests {
         assert!(bytes.windows(1).any(|w| w == [b'S']));
     }
 
+    #[test]
+    fn test_encode_extended_query_typed_writes_param_oids() {
+        use crate::protocol::types::oid;
+
+        let params = vec![Some(b"2026-01-01 00:00:00".to_vec()), Some(b"7".to_vec())];
+        let sql = "SELECT $1, $2";
+        let bytes =
+            PgEncoder::encode_extended_query_typed(sql, &params, &[oid::TIMESTAMP, oid::INT4])
+                .unwrap();
+
+        assert_eq!(bytes[0], b'P');
+        let parse_len = i32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]) as usize;
+        assert_eq!(parse_len, 4 + 1 + sql.len() + 1 + 2 + 2 * 4);
+        let types_start = 5 + 1 + sql.len() + 1;
+        assert_eq!(&bytes[types_start..types_start + 2], &2i16.to_be_bytes());
+        assert_eq!(
+            &bytes[types_start + 2..types_start + 6],
+            &oid::TIMESTAMP.to_be_bytes()
+        );
+        assert_eq!(
+            &bytes[types_start + 6..types_start + 10],
+            &oid::INT4.to_be_bytes()
+        );
+        assert_eq!(bytes[1 + parse_len], b'B');
+
+        // Everything after Parse matches the untyped pipeline.
+        let untyped = PgEncoder::encode_extended_query(sql, &params).unwrap();
+        let untyped_parse_len =
+            i32::from_be_bytes([untyped[1], untyped[2], untyped[3], untyped[4]]) as usize;
+        assert_eq!(&bytes[1 + parse_len..], &untyped[1 + untyped_parse_len..]);
+    }
+
     #[test]
     fn test_encode_extended_query_binary_result_format() {
         let bytes = PgEncoder::encode_extended_query_with_result_format(
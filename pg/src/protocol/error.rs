@@ -25,6 +25,17 @@ pub enum EncodeError {
     UnsafeExpression(String),
 }
 
+/// Reject `Value::BigInt` payloads that are not plain decimal integers.
+pub(crate) fn checked_big_int(digits: &str) -> Result<&str, EncodeError> {
+    if qail_core::ast::values::is_integer_literal(digits) {
+        Ok(digits)
+    } else {
+        Err(EncodeError::InvalidAst(format!(
+            "big integer value must be a decimal integer, got {digits:?}"
+        )))
+    }
+}
+
 impl fmt::Display for EncodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
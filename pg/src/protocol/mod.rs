@@ -12,7 +12,7 @@ pub mod wire;
 
 pub use error::EncodeError;
 
-pub use ast_encoder::AstEncoder;
+pub use ast_encoder::{AstEncoder, Encoder};
 pub use auth::ScramClient;
 pub use copy_encoder::{
     encode_copy_batch, encode_copy_value, try_encode_copy_batch, try_encode_copy_value,
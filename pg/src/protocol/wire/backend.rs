@@ -14,6 +14,57 @@ fn decode_utf8(bytes: &[u8], context: &str) -> Result<String, String> {
         .map_err(|e| format!("{} is not valid UTF-8: {}", context, e))
 }
 
+/// Read `N` bytes starting at `pos`, failing instead of panicking on short input.
+#[inline]
+fn read_array<const N: usize>(
+    payload: &[u8],
+    pos: usize,
+    context: &str,
+) -> Result<[u8; N], String> {
+    pos.checked_add(N)
+        .and_then(|end| payload.get(pos..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("{} truncated", context))
+}
+
+#[inline]
+fn read_i16(payload: &[u8], pos: usize, context: &str) -> Result<i16, String> {
+    read_array::<2>(payload, pos, context).map(i16::from_be_bytes)
+}
+
+#[inline]
+fn read_i32(payload: &[u8], pos: usize, context: &str) -> Result<i32, String> {
+    read_array::<4>(payload, pos, context).map(i32::from_be_bytes)
+}
+
+#[inline]
+fn read_u32(payload: &[u8], pos: usize, context: &str) -> Result<u32, String> {
+    read_array::<4>(payload, pos, context).map(u32::from_be_bytes)
+}
+
+/// Borrow `payload[start..end]`, failing instead of panicking on bad bounds.
+#[inline]
+fn slice<'a>(
+    payload: &'a [u8],
+    start: usize,
+    end: usize,
+    context: &str,
+) -> Result<&'a [u8], String> {
+    payload
+        .get(start..end)
+        .ok_or_else(|| format!("{} truncated", context))
+}
+
+/// Absolute index of the NUL terminating the C string that starts at `pos`.
+#[inline]
+fn cstr_end(payload: &[u8], pos: usize, missing: &str) -> Result<usize, String> {
+    payload
+        .get(pos..)
+        .and_then(|rest| rest.iter().position(|&b| b == 0))
+        .map(|rel| pos + rel)
+        .ok_or_else(|| missing.to_string())
+}
+
 impl BackendMessage {
     /// Decode a message from wire bytes.
     pub fn decode(buf: &[u8]) -> Result<(Self, usize), String> {
@@ -22,7 +73,7 @@ impl BackendMessage {
         }
 
         let msg_type = buf[0];
-        let len = u32::from_be_bytes([buf[1], buf[2], buf[3], buf[4]]) as usize;
+        let len = read_u32(buf, 1, "Message header")? as usize;
 
         // PG protocol: length includes itself (4 bytes), so minimum valid length is 4.
         // Anything less is a malformed message.
@@ -44,7 +95,7 @@ impl BackendMessage {
             return Err("Incomplete message".to_string());
         }
 
-        let payload = &buf[5..frame_len];
+        let payload = slice(buf, 5, frame_len, "Message payload")?;
 
         let message = match msg_type {
             b'R' => Self::decode_auth(payload)?,
@@ -115,7 +166,7 @@ impl BackendMessage {
         if payload.len() < 4 {
             return Err("Auth payload too short".to_string());
         }
-        let auth_type = i32::from_be_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let auth_type = read_i32(payload, 0, "Auth payload")?;
         match auth_type {
             0 => {
                 if payload.len() != 4 {
@@ -148,8 +199,7 @@ impl BackendMessage {
                 if payload.len() != 8 {
                     return Err("MD5 auth payload too short (need salt)".to_string());
                 }
-                let mut salt = [0u8; 4];
-                salt.copy_from_slice(&payload[4..8]);
+                let salt = read_array::<4>(payload, 4, "MD5 auth salt")?;
                 Ok(BackendMessage::AuthenticationMD5Password(salt))
             }
             6 => {
@@ -171,7 +221,7 @@ impl BackendMessage {
                 Ok(BackendMessage::AuthenticationGSS)
             }
             8 => Ok(BackendMessage::AuthenticationGSSContinue(
-                payload[4..].to_vec(),
+                slice(payload, 4, payload.len(), "AuthenticationGSSContinue")?.to_vec(),
             )),
             9 => {
                 if payload.len() != 4 {
@@ -186,16 +236,16 @@ impl BackendMessage {
                 // SASL - parse mechanism list
                 let mut mechanisms = Vec::new();
                 let mut pos = 4;
-                while pos < payload.len() {
-                    if payload[pos] == 0 {
+                while let Some(&byte) = payload.get(pos) {
+                    if byte == 0 {
                         break; // list terminator
                     }
-                    let end = payload[pos..]
-                        .iter()
-                        .position(|&b| b == 0)
-                        .map(|p| pos + p)
-                        .ok_or("SASL mechanism list missing null terminator")?;
-                    mechanisms.push(decode_utf8(&payload[pos..end], "SASL mechanism")?);
+                    let end =
+                        cstr_end(payload, pos, "SASL mechanism list missing null terminator")?;
+                    mechanisms.push(decode_utf8(
+                        slice(payload, pos, end, "SASL mechanism")?,
+                        "SASL mechanism",
+                    )?);
                     pos = end + 1;
                 }
                 if pos >= payload.len() {
@@ -212,13 +262,13 @@ impl BackendMessage {
             11 => {
                 // SASL Continue - server challenge
                 Ok(BackendMessage::AuthenticationSASLContinue(
-                    payload[4..].to_vec(),
+                    slice(payload, 4, payload.len(), "AuthenticationSASLContinue")?.to_vec(),
                 ))
             }
             12 => {
                 // SASL Final - server signature
                 Ok(BackendMessage::AuthenticationSASLFinal(
-                    payload[4..].to_vec(),
+                    slice(payload, 4, payload.len(), "AuthenticationSASLFinal")?.to_vec(),
                 ))
             }
             _ => Err(format!("Unknown auth type: {}", auth_type)),
@@ -237,17 +287,23 @@ impl BackendMessage {
         if value_start > payload.len() {
             return Err("ParameterStatus missing value".to_string());
         }
-        let value_end_rel = payload[value_start..]
-            .iter()
-            .position(|&b| b == 0)
-            .ok_or("ParameterStatus missing value terminator")?;
-        let value_end = value_start + value_end_rel;
+        let value_end = cstr_end(
+            payload,
+            value_start,
+            "ParameterStatus missing value terminator",
+        )?;
         if value_end + 1 != payload.len() {
             return Err("ParameterStatus has trailing bytes".to_string());
         }
         Ok(BackendMessage::ParameterStatus {
-            name: decode_utf8(&payload[..name_end], "ParameterStatus name")?,
-            value: decode_utf8(&payload[value_start..value_end], "ParameterStatus value")?,
+            name: decode_utf8(
+                slice(payload, 0, name_end, "ParameterStatus name")?,
+                "ParameterStatus name",
+            )?,
+            value: decode_utf8(
+                slice(payload, value_start, value_end, "ParameterStatus value")?,
+                "ParameterStatus value",
+            )?,
         })
     }
 
@@ -262,13 +318,13 @@ impl BackendMessage {
                 key_len
             ));
         }
-        let process_id = i32::from_be_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let process_id = read_i32(payload, 0, "BackendKeyData")?;
         if process_id <= 0 {
             return Err(format!("BackendKeyData invalid process id: {process_id}"));
         }
         Ok(BackendMessage::BackendKeyData {
             process_id,
-            secret_key: payload[4..].to_vec(),
+            secret_key: slice(payload, 4, payload.len(), "BackendKeyData secret key")?.to_vec(),
         })
     }
 
@@ -277,14 +333,12 @@ impl BackendMessage {
             return Err("NegotiateProtocolVersion payload too short".to_string());
         }
 
-        let newest_minor_supported =
-            i32::from_be_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let newest_minor_supported = read_i32(payload, 0, "NegotiateProtocolVersion")?;
         if newest_minor_supported < 0 {
             return Err("NegotiateProtocolVersion newest_minor_supported is negative".to_string());
         }
 
-        let unrecognized_count =
-            i32::from_be_bytes([payload[4], payload[5], payload[6], payload[7]]);
+        let unrecognized_count = read_i32(payload, 4, "NegotiateProtocolVersion")?;
         if unrecognized_count < 0 {
             return Err(
                 "NegotiateProtocolVersion unrecognized option count is negative".to_string(),
@@ -307,13 +361,13 @@ impl BackendMessage {
             if pos >= payload.len() {
                 return Err("NegotiateProtocolVersion missing option string terminator".to_string());
             }
-            let rel_end = payload[pos..]
-                .iter()
-                .position(|&b| b == 0)
-                .ok_or("NegotiateProtocolVersion option missing null terminator")?;
-            let end = pos + rel_end;
+            let end = cstr_end(
+                payload,
+                pos,
+                "NegotiateProtocolVersion option missing null terminator",
+            )?;
             options.push(decode_utf8(
-                &payload[pos..end],
+                slice(payload, pos, end, "NegotiateProtocolVersion option")?,
                 "NegotiateProtocolVersion option",
             )?);
             pos = end + 1;
@@ -333,7 +387,7 @@ impl BackendMessage {
         if payload.len() != 1 {
             return Err("ReadyForQuery payload empty".to_string());
         }
-        let status = match payload[0] {
+        let status = match read_array::<1>(payload, 0, "ReadyForQuery")?[0] {
             b'I' => TransactionStatus::Idle,
             b'T' => TransactionStatus::InBlock,
             b'E' => TransactionStatus::Failed,
@@ -347,7 +401,7 @@ impl BackendMessage {
             return Err("RowDescription payload too short".to_string());
         }
 
-        let raw_count = i16::from_be_bytes([payload[0], payload[1]]);
+        let raw_count = read_i16(payload, 0, "RowDescription")?;
         if raw_count < 0 {
             return Err(format!("RowDescription invalid field count: {}", raw_count));
         }
@@ -357,49 +411,34 @@ impl BackendMessage {
 
         for _ in 0..field_count {
             // Field name (null-terminated string)
-            let name_end = payload[pos..]
-                .iter()
-                .position(|&b| b == 0)
-                .ok_or("Missing null terminator in field name")?;
-            let name = decode_utf8(&payload[pos..pos + name_end], "RowDescription field name")?;
-            pos += name_end + 1; // Skip null terminator
+            let name_end = cstr_end(payload, pos, "Missing null terminator in field name")?;
+            let name = decode_utf8(
+                slice(payload, pos, name_end, "RowDescription field name")?,
+                "RowDescription field name",
+            )?;
+            pos = name_end + 1; // Skip null terminator
 
             // Ensure we have enough bytes for the fixed fields
             if pos + 18 > payload.len() {
                 return Err("RowDescription field truncated".to_string());
             }
 
-            let table_oid = u32::from_be_bytes([
-                payload[pos],
-                payload[pos + 1],
-                payload[pos + 2],
-                payload[pos + 3],
-            ]);
+            let table_oid = read_u32(payload, pos, "RowDescription field")?;
             pos += 4;
 
-            let column_attr = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let column_attr = read_i16(payload, pos, "RowDescription field")?;
             pos += 2;
 
-            let type_oid = u32::from_be_bytes([
-                payload[pos],
-                payload[pos + 1],
-                payload[pos + 2],
-                payload[pos + 3],
-            ]);
+            let type_oid = read_u32(payload, pos, "RowDescription field")?;
             pos += 4;
 
-            let type_size = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let type_size = read_i16(payload, pos, "RowDescription field")?;
             pos += 2;
 
-            let type_modifier = i32::from_be_bytes([
-                payload[pos],
-                payload[pos + 1],
-                payload[pos + 2],
-                payload[pos + 3],
-            ]);
+            let type_modifier = read_i32(payload, pos, "RowDescription field")?;
             pos += 4;
 
-            let format = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let format = read_i16(payload, pos, "RowDescription field")?;
             if !(0..=1).contains(&format) {
                 return Err(format!("RowDescription invalid format code: {}", format));
             }
@@ -428,7 +467,7 @@ impl BackendMessage {
             return Err("DataRow payload too short".to_string());
         }
 
-        let raw_count = i16::from_be_bytes([payload[0], payload[1]]);
+        let raw_count = read_i16(payload, 0, "DataRow")?;
         if raw_count < 0 {
             return Err(format!("DataRow invalid column count: {}", raw_count));
         }
@@ -449,12 +488,7 @@ impl BackendMessage {
                 return Err("DataRow truncated".to_string());
             }
 
-            let len = i32::from_be_bytes([
-                payload[pos],
-                payload[pos + 1],
-                payload[pos + 2],
-                payload[pos + 3],
-            ]);
+            let len = read_i32(payload, pos, "DataRow")?;
             pos += 4;
 
             if len == -1 {
@@ -468,7 +502,7 @@ impl BackendMessage {
                 if len > payload.len().saturating_sub(pos) {
                     return Err("DataRow column data truncated".to_string());
                 }
-                let data = payload[pos..pos + len].to_vec();
+                let data = slice(payload, pos, pos + len, "DataRow column data")?.to_vec();
                 pos += len;
                 columns.push(Some(data));
             }
@@ -482,10 +516,9 @@ impl BackendMessage {
     }
 
     fn decode_command_complete(payload: &[u8]) -> Result<Self, String> {
-        if payload.last().copied() != Some(0) {
+        let Some((&0, tag_bytes)) = payload.split_last() else {
             return Err("CommandComplete missing null terminator".to_string());
-        }
-        let tag_bytes = &payload[..payload.len() - 1];
+        };
         if tag_bytes.is_empty() {
             return Err("CommandComplete tag is empty".to_string());
         }
@@ -508,15 +541,16 @@ impl BackendMessage {
         }
         let mut fields = ErrorFields::default();
         let mut i = 0;
-        while i < payload.len() && payload[i] != 0 {
-            let field_type = payload[i];
+        while let Some(&field_type) = payload.get(i) {
+            if field_type == 0 {
+                break;
+            }
             i += 1;
-            let end = payload[i..]
-                .iter()
-                .position(|&b| b == 0)
-                .map(|p| p + i)
-                .ok_or("ErrorResponse field missing null terminator")?;
-            let value = decode_utf8(&payload[i..end], "ErrorResponse field")?;
+            let end = cstr_end(payload, i, "ErrorResponse field missing null terminator")?;
+            let value = decode_utf8(
+                slice(payload, i, end, "ErrorResponse field")?,
+                "ErrorResponse field",
+            )?;
             i = end + 1;
 
             match field_type {
@@ -538,7 +572,7 @@ impl BackendMessage {
         if payload.len() < 2 {
             return Err("ParameterDescription payload too short".to_string());
         }
-        let raw_count = i16::from_be_bytes([payload[0], payload[1]]);
+        let raw_count = read_i16(payload, 0, "ParameterDescription")?;
         if raw_count < 0 {
             return Err(format!("ParameterDescription invalid count: {}", raw_count));
         }
@@ -554,12 +588,7 @@ impl BackendMessage {
         let mut oids = Vec::with_capacity(count);
         let mut pos = 2;
         for _ in 0..count {
-            oids.push(u32::from_be_bytes([
-                payload[pos],
-                payload[pos + 1],
-                payload[pos + 2],
-                payload[pos + 3],
-            ]));
+            oids.push(read_u32(payload, pos, "ParameterDescription")?);
             pos += 4;
         }
         if pos != payload.len() {
@@ -572,7 +601,7 @@ impl BackendMessage {
         if payload.len() < 3 {
             return Err("CopyInResponse payload too short".to_string());
         }
-        let format = payload[0];
+        let format = read_array::<1>(payload, 0, "CopyInResponse")?[0];
         if format > 1 {
             return Err(format!(
                 "CopyInResponse invalid overall format code: {}",
@@ -580,7 +609,7 @@ impl BackendMessage {
             ));
         }
         let num_columns = if payload.len() >= 3 {
-            let raw = i16::from_be_bytes([payload[1], payload[2]]);
+            let raw = read_i16(payload, 1, "CopyInResponse")?;
             if raw < 0 {
                 return Err(format!(
                     "CopyInResponse invalid negative column count: {}",
@@ -597,7 +626,7 @@ impl BackendMessage {
             if pos + 2 > payload.len() {
                 return Err("CopyInResponse truncated column format list".to_string());
             }
-            let raw = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let raw = read_i16(payload, pos, "CopyInResponse column format list")?;
             if !(0..=1).contains(&raw) {
                 return Err(format!("CopyInResponse invalid format code: {}", raw));
             }
@@ -617,7 +646,7 @@ impl BackendMessage {
         if payload.len() < 3 {
             return Err("CopyOutResponse payload too short".to_string());
         }
-        let format = payload[0];
+        let format = read_array::<1>(payload, 0, "CopyOutResponse")?[0];
         if format > 1 {
             return Err(format!(
                 "CopyOutResponse invalid overall format code: {}",
@@ -625,7 +654,7 @@ impl BackendMessage {
             ));
         }
         let num_columns = if payload.len() >= 3 {
-            let raw = i16::from_be_bytes([payload[1], payload[2]]);
+            let raw = read_i16(payload, 1, "CopyOutResponse")?;
             if raw < 0 {
                 return Err(format!(
                     "CopyOutResponse invalid negative column count: {}",
@@ -642,7 +671,7 @@ impl BackendMessage {
             if pos + 2 > payload.len() {
                 return Err("CopyOutResponse truncated column format list".to_string());
             }
-            let raw = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let raw = read_i16(payload, pos, "CopyOutResponse column format list")?;
             if !(0..=1).contains(&raw) {
                 return Err(format!("CopyOutResponse invalid format code: {}", raw));
             }
@@ -662,7 +691,7 @@ impl BackendMessage {
         if payload.len() < 3 {
             return Err("CopyBothResponse payload too short".to_string());
         }
-        let format = payload[0];
+        let format = read_array::<1>(payload, 0, "CopyBothResponse")?[0];
         if format > 1 {
             return Err(format!(
                 "CopyBothResponse invalid overall format code: {}",
@@ -670,7 +699,7 @@ impl BackendMessage {
             ));
         }
         let num_columns = if payload.len() >= 3 {
-            let raw = i16::from_be_bytes([payload[1], payload[2]]);
+            let raw = read_i16(payload, 1, "CopyBothResponse")?;
             if raw < 0 {
                 return Err(format!(
                     "CopyBothResponse invalid negative column count: {}",
@@ -687,7 +716,7 @@ impl BackendMessage {
             if pos + 2 > payload.len() {
                 return Err("CopyBothResponse truncated column format list".to_string());
             }
-            let raw = i16::from_be_bytes([payload[pos], payload[pos + 1]]);
+            let raw = read_i16(payload, pos, "CopyBothResponse column format list")?;
             if !(0..=1).contains(&raw) {
                 return Err(format!("CopyBothResponse invalid format code: {}", raw));
             }
@@ -708,7 +737,7 @@ impl BackendMessage {
             // Minimum: 4 (process_id) + 1 (channel NUL) + 1 (payload NUL)
             return Err("NotificationResponse too short".to_string());
         }
-        let process_id = i32::from_be_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let process_id = read_i32(payload, 0, "NotificationResponse")?;
 
         // Channel name (null-terminated)
         let mut i = 4;
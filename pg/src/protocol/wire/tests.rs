@@ -57,6 +57,112 @@ fn decode_unknown_message_type_returns_error() {
     assert!(result.unwrap_err().contains("Unknown message type"));
 }
 
+/// Valid frames covering every payload-bearing backend message shape.
+fn sample_backend_frames() -> Vec<Vec<u8>> {
+    let mut row_desc = vec![0, 1];
+    row_desc.extend_from_slice(b"id\0");
+    row_desc.extend_from_slice(&0u32.to_be_bytes());
+    row_desc.extend_from_slice(&0i16.to_be_bytes());
+    row_desc.extend_from_slice(&23u32.to_be_bytes());
+    row_desc.extend_from_slice(&4i16.to_be_bytes());
+    row_desc.extend_from_slice(&(-1i32).to_be_bytes());
+    row_desc.extend_from_slice(&0i16.to_be_bytes());
+
+    let mut data_row = vec![0, 2];
+    data_row.extend_from_slice(&3i32.to_be_bytes());
+    data_row.extend_from_slice(b"abc");
+    data_row.extend_from_slice(&(-1i32).to_be_bytes());
+
+    let mut md5 = 5i32.to_be_bytes().to_vec();
+    md5.extend_from_slice(&[1, 2, 3, 4]);
+    let mut sasl = 10i32.to_be_bytes().to_vec();
+    sasl.extend_from_slice(b"SCRAM-SHA-256\0\0");
+    let mut key = 42i32.to_be_bytes().to_vec();
+    key.extend_from_slice(&7u32.to_be_bytes());
+    let mut negotiate = 0i32.to_be_bytes().to_vec();
+    negotiate.extend_from_slice(&1i32.to_be_bytes());
+    negotiate.extend_from_slice(b"_pq_.x\0");
+    let mut notify = 9i32.to_be_bytes().to_vec();
+    notify.extend_from_slice(b"chan\0hello\0");
+    let mut param_desc = vec![0, 2];
+    param_desc.extend_from_slice(&23u32.to_be_bytes());
+    param_desc.extend_from_slice(&25u32.to_be_bytes());
+
+    vec![
+        wire_msg(b'R', &md5),
+        wire_msg(b'R', &sasl),
+        wire_msg(b'S', b"TimeZone\0UTC\0"),
+        wire_msg(b'K', &key),
+        wire_msg(b'v', &negotiate),
+        wire_msg(b'Z', b"I"),
+        wire_msg(b'T', &row_desc),
+        wire_msg(b'D', &data_row),
+        wire_msg(b'C', b"SELECT 1\0"),
+        wire_msg(b'E', b"SERROR\0C42P01\0Mmissing\0\0"),
+        wire_msg(b't', &param_desc),
+        wire_msg(b'G', &[0, 0, 1, 0, 0]),
+        wire_msg(b'H', &[1, 0, 1, 0, 1]),
+        wire_msg(b'W', &[0, 0, 0]),
+        wire_msg(b'A', &notify),
+    ]
+}
+
+#[test]
+fn decode_truncated_buffers_return_error_without_panic() {
+    for frame in sample_backend_frames() {
+        assert!(
+            BackendMessage::decode(&frame).is_ok(),
+            "sample frame {:?} should decode",
+            frame[0] as char
+        );
+        // Header still claims the full length, but bytes are missing.
+        for cut in 0..frame.len() {
+            assert!(
+                BackendMessage::decode(&frame[..cut]).is_err(),
+                "{:?} truncated to {} bytes must fail",
+                frame[0] as char,
+                cut
+            );
+        }
+    }
+}
+
+#[test]
+fn decode_short_declared_length_returns_error_without_panic() {
+    for frame in sample_backend_frames() {
+        let payload_len = frame.len() - 5;
+        // Shrink the declared length so the payload ends mid-field.
+        for shrink in 1..=payload_len {
+            let mut buf = frame.clone();
+            let len = (payload_len - shrink + 4) as u32;
+            buf[1..5].copy_from_slice(&len.to_be_bytes());
+            if let Ok((_, consumed)) = BackendMessage::decode(&buf) {
+                assert_eq!(consumed, len as usize + 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn decode_length_prefix_claiming_more_than_present_returns_error() {
+    // DataRow column claims 1000 bytes but only 3 follow.
+    let mut payload = vec![0, 1];
+    payload.extend_from_slice(&1000i32.to_be_bytes());
+    payload.extend_from_slice(b"abc");
+    let err = BackendMessage::decode(&wire_msg(b'D', &payload)).unwrap_err();
+    assert!(err.contains("truncated"), "{err}");
+
+    // ParameterDescription claims 3 OIDs but carries one.
+    let mut payload = vec![0, 3];
+    payload.extend_from_slice(&23u32.to_be_bytes());
+    let err = BackendMessage::decode(&wire_msg(b't', &payload)).unwrap_err();
+    assert!(err.contains("truncated"), "{err}");
+
+    // CopyOutResponse claims 4 column formats but carries one.
+    let err = BackendMessage::decode(&wire_msg(b'H', &[0, 0, 4, 0, 0])).unwrap_err();
+    assert!(err.contains("truncated"), "{err}");
+}
+
 // ========== Auth decode tests ==========
 
 #[test]
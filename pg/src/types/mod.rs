@@ -6,7 +6,9 @@ pub mod numeric;
 pub mod temporal;
 
 pub use numeric::Numeric;
-pub use temporal::{Date, Time, Timestamp};
+#[cfg(feature = "chrono")]
+pub use temporal::encode_timestamptz;
+pub use temporal::{Date, Interval, Time, Timestamp};
 
 use crate::protocol::types::{decode_json, decode_jsonb, decode_uuid, oid, try_decode_text_array};
 
@@ -135,6 +135,19 @@ impl ToPg for chrono::DateTime<chrono::Utc> {
     }
 }
 
+/// Encode a `timestamptz` parameter in the requested wire format.
+///
+/// `format` 0 yields the text literal (`2024-01-02 03:04:05+00`), 1 the
+/// binary form (big-endian microseconds since 2000-01-01 UTC).
+#[cfg(feature = "chrono")]
+pub fn encode_timestamptz(dt: &chrono::DateTime<chrono::Utc>, format: i16) -> Vec<u8> {
+    if format == 1 {
+        dt.to_pg().0
+    } else {
+        qail_core::ast::values::timestamptz_text(dt).into_bytes()
+    }
+}
+
 /// Parse PostgreSQL text timestamp format
 fn parse_timestamp_text(s: &str) -> Result<Timestamp, TypeError> {
     // Format: "2024-12-25 17:30:00" or "2024-12-25 17:30:00.123456"
@@ -497,12 +510,169 @@ fn parse_time_text(s: &str) -> Result<Time, TypeError> {
     })
 }
 
+/// Interval type, split into months, days and microseconds like PostgreSQL
+/// stores it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Interval {
+    /// Whole months (years are stored as 12 months)
+    pub months: i32,
+    /// Whole days
+    pub days: i32,
+    /// Microseconds of the time part
+    pub micros: i64,
+}
+
+impl Interval {
+    /// Create from months, days and microseconds.
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        Self {
+            months,
+            days,
+            micros,
+        }
+    }
+}
+
+impl From<Interval> for qail_core::ast::Value {
+    fn from(interval: Interval) -> Self {
+        qail_core::ast::Value::IntervalParts {
+            months: interval.months,
+            days: interval.days,
+            micros: interval.micros,
+        }
+    }
+}
+
+impl FromPg for Interval {
+    fn from_pg(bytes: &[u8], oid_val: u32, format: i16) -> Result<Self, TypeError> {
+        if oid_val != oid::INTERVAL {
+            return Err(TypeError::UnexpectedOid {
+                expected: "interval",
+                got: oid_val,
+            });
+        }
+
+        if format == 1 {
+            // Binary: 8-byte microseconds, 4-byte days, 4-byte months
+            if bytes.len() != 16 {
+                return Err(TypeError::InvalidData(
+                    "Expected 16 bytes for interval".to_string(),
+                ));
+            }
+            Ok(Interval {
+                micros: i64::from_be_bytes([
+                    bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+                ]),
+                days: i32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+                months: i32::from_be_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]),
+            })
+        } else {
+            let s =
+                std::str::from_utf8(bytes).map_err(|e| TypeError::InvalidData(e.to_string()))?;
+            parse_interval_text(s)
+        }
+    }
+}
+
+impl ToPg for Interval {
+    fn to_pg(&self) -> (Vec<u8>, u32, i16) {
+        let mut buf = Vec::with_capacity(16);
+        buf.extend_from_slice(&self.micros.to_be_bytes());
+        buf.extend_from_slice(&self.days.to_be_bytes());
+        buf.extend_from_slice(&self.months.to_be_bytes());
+        (buf, oid::INTERVAL, 1)
+    }
+}
+
+/// Parse PostgreSQL `postgres`-style interval text, e.g.
+/// `1 year 2 mons -3 days 04:05:06.5`
+fn parse_interval_text(s: &str) -> Result<Interval, TypeError> {
+    let invalid = || TypeError::InvalidData(format!("Invalid interval: {}", s));
+    let mut interval = Interval::default();
+    let mut tokens = s.split_whitespace();
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            let (negative, time) = match token.strip_prefix('-') {
+                Some(time) => (true, time),
+                None => (false, token.strip_prefix('+').unwrap_or(token)),
+            };
+            let parts: Vec<&str> = time.split(':').collect();
+            if parts.len() != 3 {
+                return Err(invalid());
+            }
+            let hours: i64 = parts[0].parse().map_err(|_| invalid())?;
+            let minutes = parse_i32_part(parts[1], "minute")? as i64;
+            let (seconds, usec) = parse_second_usec(parts[2])?;
+            let micros = hours
+                .checked_mul(3_600_000_000)
+                .and_then(|n| n.checked_add(minutes * 60_000_000 + seconds as i64 * 1_000_000))
+                .and_then(|n| n.checked_add(usec))
+                .ok_or_else(invalid)?;
+            interval.micros = if negative { -micros } else { micros };
+            continue;
+        }
+
+        let amount = parse_i32_part(token, "interval amount")?;
+        let unit = tokens.next().ok_or_else(invalid)?;
+        match unit {
+            "year" | "years" => {
+                interval.months = amount
+                    .checked_mul(12)
+                    .and_then(|n| interval.months.checked_add(n))
+                    .ok_or_else(invalid)?;
+            }
+            "mon" | "mons" => {
+                interval.months = interval.months.checked_add(amount).ok_or_else(invalid)?;
+            }
+            "day" | "days" => {
+                interval.days = interval.days.checked_add(amount).ok_or_else(invalid)?;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(interval)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[cfg(feature = "chrono")]
     use chrono::{Datelike, Timelike};
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_encode_timestamptz_text_and_binary() {
+        use chrono::TimeZone;
+
+        let dt = chrono::Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(encode_timestamptz(&dt, 0), b"2024-01-02 03:04:05+00");
+        // 1_704_164_645 s since 1970 minus the 946_684_800 s PG epoch offset.
+        assert_eq!(
+            encode_timestamptz(&dt, 1),
+            757_479_845_000_000i64.to_be_bytes().to_vec()
+        );
+
+        let fractional = dt + chrono::Duration::microseconds(250_000);
+        assert_eq!(
+            encode_timestamptz(&fractional, 0),
+            b"2024-01-02 03:04:05.25+00"
+        );
+        assert_eq!(
+            qail_core::ast::Value::from(dt),
+            qail_core::ast::Value::Timestamp("2024-01-02 03:04:05+00".to_string())
+        );
+
+        let round_trip = chrono::DateTime::<chrono::Utc>::from_pg(
+            &encode_timestamptz(&fractional, 0),
+            oid::TIMESTAMPTZ,
+            0,
+        )
+        .unwrap();
+        assert_eq!(round_trip, fractional);
+    }
+
     #[test]
     fn test_timestamp_unix_conversion() {
         // 2024-01-01 00:00:00 UTC
@@ -565,6 +735,48 @@ mod tests {
         assert_eq!(Time::new(24, 0, 0, 0), Time { usec: 0 });
     }
 
+    #[test]
+    fn test_interval_binary_round_trip() {
+        let interval = Interval::new(1, 7, 3 * 3_600_000_000);
+        let (bytes, oid_val, format) = interval.to_pg();
+        assert_eq!(oid_val, oid::INTERVAL);
+        assert_eq!(format, 1);
+        assert_eq!(
+            bytes,
+            [
+                &(3 * 3_600_000_000i64).to_be_bytes()[..],
+                &7i32.to_be_bytes()[..],
+                &1i32.to_be_bytes()[..],
+            ]
+            .concat()
+        );
+        assert_eq!(
+            Interval::from_pg(&bytes, oid::INTERVAL, 1).unwrap(),
+            interval
+        );
+        assert!(Interval::from_pg(&bytes[..12], oid::INTERVAL, 1).is_err());
+    }
+
+    #[test]
+    fn test_interval_from_pg_text() {
+        let interval = Interval::from_pg(b"1 year 2 mons -3 days 04:05:06.5", oid::INTERVAL, 0);
+        assert_eq!(
+            interval.unwrap(),
+            Interval::new(14, -3, 4 * 3_600_000_000 + 5 * 60_000_000 + 6_500_000)
+        );
+        assert_eq!(
+            parse_interval_text("-00:30:00").unwrap(),
+            Interval::new(0, 0, -1_800_000_000)
+        );
+        assert!(parse_interval_text("3 fortnights").is_err());
+
+        let text = qail_core::ast::values::interval_text(14, -3, 14_706_500_000);
+        assert_eq!(
+            parse_interval_text(&text).unwrap(),
+            Interval::new(14, -3, 14_706_500_000)
+        );
+    }
+
     #[test]
     fn test_time_from_pg_text() {
         let time = parse_time_text("14:30:00").unwrap();
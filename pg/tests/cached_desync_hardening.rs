@@ -123,6 +123,10 @@ fn empty_query_response() -> Vec<u8> {
     backend_frame(b'I', &[])
 }
 
+fn no_data() -> Vec<u8> {
+    backend_frame(b'n', &[])
+}
+
 #[tokio::test]
 async fn query_cached_unexpected_backend_invalidates_cache() {
     let (listener, port) = mock_listener().await;
@@ -717,6 +721,44 @@ async fn simple_query_allows_command_then_result_statement_sequence() {
     server.await.unwrap();
 }
 
+#[tokio::test]
+async fn driver_execute_returns_update_row_count() {
+    let (listener, port) = mock_listener().await;
+
+    let server = tokio::spawn(async move {
+        let (mut sock, _) = listener.accept().await.unwrap();
+        read_startup_message(&mut sock).await;
+        sock.write_all(&auth_ok()).await.unwrap();
+        sock.write_all(&ready_idle()).await.unwrap();
+        sock.flush().await.unwrap();
+
+        let seq = read_frontend_msg_types_until_sync(&mut sock).await;
+        assert_eq!(seq.first().copied(), Some(b'P'));
+        assert!(seq.contains(&b'E'));
+
+        sock.write_all(&parse_complete()).await.unwrap();
+        sock.write_all(&bind_complete()).await.unwrap();
+        sock.write_all(&no_data()).await.unwrap();
+        sock.write_all(&command_complete("UPDATE 2")).await.unwrap();
+        sock.write_all(&ready_idle()).await.unwrap();
+        sock.flush().await.unwrap();
+    });
+
+    let mut driver = PgDriver::connect_with_password("127.0.0.1", port, "test_user", "test_db", "")
+        .await
+        .unwrap();
+
+    let cmd = Qail::set("users").set_value("verified", true).filter(
+        "org_id",
+        qail_core::ast::Operator::Eq,
+        7,
+    );
+    let affected = driver.execute(&cmd).await.unwrap();
+    assert_eq!(affected, 2);
+
+    server.await.unwrap();
+}
+
 #[tokio::test]
 async fn driver_fetch_all_uncached_rejects_data_before_bind() {
     let (listener, port) = mock_listener().await;
@@ -94,6 +94,8 @@ fn monster_recursive_cte() -> Qail {
             is_array_unnest: false,
         }]),
         on_true: false,
+        using: Vec::new(),
+        subquery: None,
     });
     recursive.cages.push(Cage {
         kind: CageKind::Filter,
@@ -139,14 +139,12 @@ fn decode_varint(buf: &mut &[u8]) -> QdrantResult<u64> {
     let mut result: u64 = 0;
 
     for byte_index in 0..10 {
-        if buf.is_empty() {
+        let Some((&byte, rest)) = buf.split_first() else {
             return Err(QdrantError::Decode(
                 "Unexpected end of data in varint".to_string(),
             ));
-        }
-
-        let byte = buf[0];
-        *buf = &buf[1..];
+        };
+        *buf = rest;
         let payload = (byte & 0x7F) as u64;
 
         if byte_index == 9 && payload > 1 {
@@ -165,7 +163,7 @@ fn decode_varint(buf: &mut &[u8]) -> QdrantResult<u64> {
 
 /// Decode a field tag (field_number << 3 | wire_type).
 #[inline]
-fn decode_tag(buf: &mut &[u8]) -> QdrantResult<(u32, u8)> {
+pub(crate) fn decode_tag(buf: &mut &[u8]) -> QdrantResult<(u32, u8)> {
     let tag = decode_varint(buf)?;
     let field_number = tag >> 3;
     if field_number == 0 || field_number > MAX_PROTO_FIELD_NUMBER {
@@ -177,31 +175,44 @@ fn decode_tag(buf: &mut &[u8]) -> QdrantResult<(u32, u8)> {
     Ok((field_number as u32, wire_type))
 }
 
+/// Split `len` bytes off the front of `buf`, failing instead of panicking
+/// when fewer bytes remain.
+#[inline]
+fn take_bytes<'a>(buf: &mut &'a [u8], len: usize, context: &str) -> QdrantResult<&'a [u8]> {
+    let (head, rest) = buf.split_at_checked(len).ok_or_else(|| {
+        QdrantError::Decode(format!(
+            "Truncated {context}: need {len} bytes, have {}",
+            buf.len()
+        ))
+    })?;
+    *buf = rest;
+    Ok(head)
+}
+
+/// Decode a varint length prefix as `usize`.
+#[inline]
+fn decode_len(buf: &mut &[u8]) -> QdrantResult<usize> {
+    let len = decode_varint(buf)?;
+    usize::try_from(len)
+        .map_err(|_| QdrantError::Decode(format!("Length prefix {len} exceeds address space")))
+}
+
 /// Skip a field value based on wire type.
 #[inline]
-fn skip_field(buf: &mut &[u8], wire_type: u8) -> QdrantResult<()> {
+pub(crate) fn skip_field(buf: &mut &[u8], wire_type: u8) -> QdrantResult<()> {
     match wire_type {
         WIRE_VARINT => {
             decode_varint(buf)?;
         }
         WIRE_FIXED64 => {
-            if buf.len() < 8 {
-                return Err(QdrantError::Decode("Unexpected end of data".to_string()));
-            }
-            *buf = &buf[8..];
+            take_bytes(buf, 8, "fixed64 field")?;
         }
         WIRE_LEN => {
-            let len = decode_varint(buf)? as usize;
-            if buf.len() < len {
-                return Err(QdrantError::Decode("Unexpected end of data".to_string()));
-            }
-            *buf = &buf[len..];
+            let len = decode_len(buf)?;
+            take_bytes(buf, len, "length-delimited field")?;
         }
         WIRE_FIXED32 => {
-            if buf.len() < 4 {
-                return Err(QdrantError::Decode("Unexpected end of data".to_string()));
-            }
-            *buf = &buf[4..];
+            take_bytes(buf, 4, "fixed32 field")?;
         }
         _ => {
             return Err(QdrantError::Decode(format!(
@@ -215,28 +226,19 @@ fn skip_field(buf: &mut &[u8], wire_type: u8) -> QdrantResult<()> {
 
 /// Read a length-delimited submessage, returning its data slice.
 #[inline]
-fn read_submessage<'a>(buf: &mut &'a [u8]) -> QdrantResult<&'a [u8]> {
-    let len = decode_varint(buf)? as usize;
-    if buf.len() < len {
-        return Err(QdrantError::Decode("Truncated submessage".to_string()));
-    }
-    let data = &buf[..len];
-    *buf = &buf[len..];
-    Ok(data)
+pub(crate) fn read_submessage<'a>(buf: &mut &'a [u8]) -> QdrantResult<&'a [u8]> {
+    let len = decode_len(buf)?;
+    take_bytes(buf, len, "submessage")
 }
 
 fn read_fixed32_f32(buf: &mut &[u8], label: &str) -> QdrantResult<f32> {
-    if buf.len() < 4 {
-        return Err(QdrantError::Decode(format!("Truncated {label}")));
-    }
-    let bytes: [u8; 4] = buf[..4]
+    let bytes: [u8; 4] = take_bytes(buf, 4, label)?
         .try_into()
         .map_err(|_| QdrantError::Decode(format!("Truncated {label}")))?;
     let value = f32::from_le_bytes(bytes);
     if !value.is_finite() {
         return Err(QdrantError::Decode(format!("Invalid non-finite {label}")));
     }
-    *buf = &buf[4..];
     Ok(value)
 }
 
@@ -497,7 +499,7 @@ fn decode_retrieved_point(data: &[u8]) -> QdrantResult<ScoredPoint> {
 ///   Value value = 2;
 /// }
 /// ```
-fn decode_map_entry(data: &[u8], depth: usize) -> QdrantResult<(String, PayloadValue)> {
+pub(crate) fn decode_map_entry(data: &[u8], depth: usize) -> QdrantResult<(String, PayloadValue)> {
     let mut key = None;
     let mut value = None;
     let mut buf = data;
@@ -631,16 +633,12 @@ fn decode_value_with_depth(data: &[u8], depth: usize) -> QdrantResult<PayloadVal
                         "Invalid wire type for payload double value".to_string(),
                     ));
                 }
-                if buf.len() < 8 {
-                    return Err(QdrantError::Decode(
-                        "Truncated payload double value".to_string(),
-                    ));
-                }
-                let bytes: [u8; 8] = buf[..8].try_into().map_err(|_| {
-                    QdrantError::Decode("Truncated payload double value".to_string())
-                })?;
+                let bytes: [u8; 8] = take_bytes(&mut buf, 8, "payload double value")?
+                    .try_into()
+                    .map_err(|_| {
+                        QdrantError::Decode("Truncated payload double value".to_string())
+                    })?;
                 let float_value = f64::from_le_bytes(bytes);
-                buf = &buf[8..];
                 if !float_value.is_finite() {
                     return Err(QdrantError::Decode(
                         "Invalid non-finite payload float value".to_string(),
@@ -1053,11 +1051,9 @@ fn decode_packed_f32_vector(float_data: &[u8]) -> QdrantResult<Vec<f32>> {
             "Invalid vector data length".to_string(),
         ));
     }
-    let count = float_data.len() / 4;
-    let mut result = Vec::with_capacity(count);
-    for i in 0..count {
-        let offset = i * 4;
-        let bytes: [u8; 4] = float_data[offset..offset + 4]
+    let mut result = Vec::with_capacity(float_data.len() / 4);
+    for chunk in float_data.chunks_exact(4) {
+        let bytes: [u8; 4] = chunk
             .try_into()
             .map_err(|_| QdrantError::Decode("Invalid vector data length".to_string()))?;
         let value = f32::from_le_bytes(bytes);
@@ -1369,6 +1365,48 @@ mod tests {
         entry
     }
 
+    #[test]
+    fn test_decode_search_response_truncated_prefixes_return_error() {
+        let mut scored_point =
+            scored_point_with_vectors(&current_dense_vectors_output(&[0.25, 0.75]));
+        push_len_field(&mut scored_point, 0x1A, &payload_string_entry("k", "v"));
+        let mut data = Vec::new();
+        push_len_field(&mut data, 0x0A, &scored_point);
+        assert!(decode_search_response(&data).is_ok());
+
+        for cut in 1..data.len() {
+            let err = decode_search_response(&data[..cut]).unwrap_err();
+            assert!(
+                matches!(err, QdrantError::Decode(_)),
+                "cut at {cut}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_length_prefix_claiming_more_than_present_returns_error() {
+        // ScoredPoint declares 127 bytes but only one follows.
+        let err = decode_search_response(&[0x0A, 0x7F, 0x08]).unwrap_err();
+        assert!(err.to_string().contains("Truncated submessage"), "{err}");
+
+        // Unknown length-delimited field claims 10 bytes, carries 2.
+        let err = decode_search_response(&[0x7A, 0x0A, 0x01, 0x02]).unwrap_err();
+        assert!(
+            err.to_string().contains("Truncated length-delimited field"),
+            "{err}"
+        );
+
+        // Unknown fixed64 / fixed32 fields cut short.
+        let err = decode_search_response(&[0x79, 0x01, 0x02, 0x03]).unwrap_err();
+        assert!(err.to_string().contains("Truncated fixed64 field"), "{err}");
+        let err = decode_search_response(&[0x7D, 0x01]).unwrap_err();
+        assert!(err.to_string().contains("Truncated fixed32 field"), "{err}");
+
+        // Score (fixed32) cut short inside a ScoredPoint.
+        let err = decode_scored_point(&[0x1D, 0x00, 0x00]).unwrap_err();
+        assert!(err.to_string().contains("Truncated"), "{err}");
+    }
+
     #[test]
     fn test_decode_search_response_accepts_current_dense_vector_output() {
         let scored_point = scored_point_with_vectors(&current_dense_vectors_output(&[0.25, 0.75]));
@@ -296,6 +296,27 @@ impl QdrantDriver {
         })
     }
 
+    /// Connect with a per-request timeout instead of the default 30s.
+    ///
+    /// Every call that does not complete in time fails with
+    /// [`QdrantError::Timeout`].
+    pub async fn with_timeout(
+        host: &str,
+        port: u16,
+        timeout: std::time::Duration,
+    ) -> QdrantResult<Self> {
+        let client = GrpcClient::connect(host, port).await?.with_timeout(timeout);
+        Ok(Self {
+            client,
+            buffer: BytesMut::with_capacity(8192),
+        })
+    }
+
+    /// Per-request timeout applied to every call.
+    pub fn request_timeout(&self) -> std::time::Duration {
+        self.client.timeout()
+    }
+
     /// Connect with address string.
     pub async fn connect_addr(addr: &str) -> QdrantResult<Self> {
         let (host_part, port_part) = addr.rsplit_once(':').ok_or_else(|| {
@@ -1107,4 +1128,88 @@ mod tests {
         let buffer = BytesMut::with_capacity(1024);
         assert!(buffer.capacity() >= 1024);
     }
+
+    #[tokio::test]
+    async fn test_search_ast_sends_named_vector() {
+        use crate::decoder::{decode_tag, read_submessage, skip_field};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (body_tx, body_rx) = tokio::sync::oneshot::channel();
+
+        // Capture one request body, then answer with an empty result set.
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut connection = h2::server::handshake(stream).await.unwrap();
+            let (request, mut respond) = connection.accept().await.unwrap().unwrap();
+            tokio::spawn(async move {
+                let mut body = request.into_body();
+                let mut captured = Vec::new();
+                while let Some(chunk) = body.data().await {
+                    let chunk = chunk.unwrap();
+                    let _ = body.flow_control().release_capacity(chunk.len());
+                    captured.extend_from_slice(&chunk);
+                }
+                let _ = body_tx.send(captured);
+                let response = http::Response::builder().status(200).body(()).unwrap();
+                let _ = respond.send_response(response, true);
+            });
+            while connection.accept().await.is_some() {}
+        });
+
+        let mut driver = QdrantDriver::connect("127.0.0.1", port).await.unwrap();
+        let cmd = Qail::search("media")
+            .vector(vec![0.1, 0.2])
+            .vector_name("image")
+            .limit(3);
+        assert!(driver.search_ast(&cmd).await.unwrap().is_empty());
+
+        let framed = body_rx.await.unwrap();
+        let mut fields = &framed[5..];
+        let mut vector_name = None;
+        while !fields.is_empty() {
+            let (field_number, wire_type) = decode_tag(&mut fields).unwrap();
+            if field_number == 10 {
+                vector_name = Some(read_submessage(&mut fields).unwrap().to_vec());
+            } else {
+                skip_field(&mut fields, wire_type).unwrap();
+            }
+        }
+        assert_eq!(vector_name.as_deref(), Some(&b"image"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_fires_when_server_never_responds() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // Accept requests but hold them open without ever responding.
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let Ok(mut connection) = h2::server::handshake(stream).await else {
+                        return;
+                    };
+                    let mut pending = Vec::new();
+                    while let Some(Ok(request)) = connection.accept().await {
+                        pending.push(request);
+                    }
+                });
+            }
+        });
+
+        let timeout = std::time::Duration::from_millis(100);
+        let mut driver = QdrantDriver::with_timeout("127.0.0.1", port, timeout)
+            .await
+            .unwrap();
+        assert_eq!(driver.request_timeout(), timeout);
+
+        let started = std::time::Instant::now();
+        let result = driver.search("products", &[0.1, 0.2], 5, None).await;
+        assert!(
+            matches!(result, Err(QdrantError::Timeout)),
+            "expected timeout, got {result:?}"
+        );
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
 }
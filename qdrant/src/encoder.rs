@@ -4,7 +4,8 @@
 //! struct allocations. Key optimizations:
 //! - Pre-computed field tag bytes
 //! - Buffer reuse via BytesMut
-//! - Direct memcpy for vectors (no per-element loop)
+//! - Direct memcpy for vectors on little-endian targets (per-element
+//!   `put_f32_le` fallback on big-endian)
 //!
 //! ## Supported Operations
 //! - Search (with filters)
@@ -153,8 +154,10 @@ fn extend_f32_le_slice(buf: &mut BytesMut, values: &[f32]) {
 
     #[cfg(not(target_endian = "little"))]
     {
-        for value in values {
-            buf.extend_from_slice(&value.to_le_bytes());
+        // Native layout is big-endian here; byte-swap each element.
+        buf.reserve(values.len() * 4);
+        for &value in values {
+            buf.put_f32_le(value);
         }
     }
 }
@@ -2079,6 +2082,34 @@ mod tests {
         assert_eq!(&vector_bytes[0..4], &float_bytes);
     }
 
+    #[test]
+    fn test_vector_bytes_match_to_le_bytes_on_current_target() {
+        let vector = vec![
+            -0.0f32,
+            1.5,
+            -3.25e-7,
+            f32::MIN_POSITIVE,
+            f32::MAX,
+            f32::from_bits(0x0102_0304),
+        ];
+        let expected: Vec<u8> = vector.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        let mut raw = BytesMut::new();
+        extend_f32_le_slice(&mut raw, &vector);
+        assert_eq!(&raw[..], &expected[..]);
+
+        let mut buf = BytesMut::with_capacity(1024);
+        encode_search_proto(&mut buf, "test", &vector, 5, None, None, false)
+            .expect("search request should encode");
+        // collection tag + len + "test" + vector tag + len(24)
+        let vector_start = 8;
+        assert_eq!(buf[vector_start - 1] as usize, expected.len());
+        assert_eq!(
+            &buf[vector_start..vector_start + expected.len()],
+            &expected[..]
+        );
+    }
+
     #[test]
     fn test_varint_len() {
         assert_eq!(varint_len(0), 1);
@@ -2915,4 +2946,40 @@ mod tests {
         // field 3 tag (0x18) + varint(42)
         assert_eq!(buf[0], 0x18);
     }
+
+    #[test]
+    fn test_encode_point_struct_payload_map_round_trips() {
+        use crate::decoder::{decode_map_entry, decode_tag, read_submessage, skip_field};
+        use crate::point::PayloadValue;
+
+        let point = crate::Point::new_num(7, vec![0.5, 1.5])
+            .with_payload("name", "shoe")
+            .with_payload("stock", 12i64);
+        let mut buf = BytesMut::new();
+        encode_point_struct(&mut buf, &point).expect("point should encode");
+
+        let mut outer = &buf[..];
+        assert_eq!(decode_tag(&mut outer).unwrap(), (3, 2));
+        let mut fields = read_submessage(&mut outer).unwrap();
+        assert!(outer.is_empty());
+
+        let mut payload = crate::point::Payload::new();
+        while !fields.is_empty() {
+            let (field_number, wire_type) = decode_tag(&mut fields).unwrap();
+            if field_number == 3 {
+                let entry = read_submessage(&mut fields).unwrap();
+                let (key, value) = decode_map_entry(entry, 0).unwrap();
+                assert!(payload.insert(key, value).is_none());
+            } else {
+                skip_field(&mut fields, wire_type).unwrap();
+            }
+        }
+
+        assert_eq!(payload.len(), 2);
+        assert_eq!(
+            payload.get("name"),
+            Some(&PayloadValue::String("shoe".to_string()))
+        );
+        assert_eq!(payload.get("stock"), Some(&PayloadValue::Integer(12)));
+    }
 }
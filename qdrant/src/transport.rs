@@ -130,6 +130,17 @@ impl GrpcClient {
         }
     }
 
+    /// Replace the per-request timeout (default 30s).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Per-request timeout applied to every call.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
     // ========================================================================
     // Connection Establishment
     // ========================================================================